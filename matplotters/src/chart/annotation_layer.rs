@@ -0,0 +1,93 @@
+use super::ChartContext;
+
+use crate::coord::CoordTranslate;
+use crate::drawing::DrawingAreaErrorKind;
+use crate::element::IntoDynElement;
+
+use plotters_backend::DrawingBackend;
+
+impl<'a, DB: DrawingBackend, CT: CoordTranslate> ChartContext<'a, DB, CT>
+where
+    CT::From: Clone,
+{
+    /**
+    Adds an element, such as a text or an arrow, to the annotation layer of the chart. The
+    annotations are not drawn right away but collected, and [`ChartContext::draw_annotations`]
+    draws them all at once after the last series, so that they are always on top of the
+    series, whatever the order they were added in.
+
+    The position of the element is translated into pixels when it is added.
+    */
+    pub fn annotate<E: IntoDynElement<'a, DB, CT::From>>(&mut self, element: E) -> &mut Self {
+        let (base_x, base_y) = self.drawing_area.get_base_pixel();
+        let area = &self.drawing_area;
+        let element = element.into_dyn().map_points(|p| {
+            let (x, y) = area.map_coordinate(&p);
+            (x - base_x, y - base_y)
+        });
+        self.annotation_layer.push(element);
+        self
+    }
+
+    /**
+    Draws the annotations added with [`ChartContext::annotate`] so far, in the order they
+    were added, and empties the annotation layer.
+
+    See [`ChartContext::annotate`] for more information.
+    */
+    pub fn draw_annotations(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let area = self.drawing_area.strip_coord_spec();
+        for element in std::mem::take(&mut self.annotation_layer) {
+            area.draw(&element)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_annotation_layer() {
+        let rects = Rc::new(RefCell::new(vec![]));
+        let texts = Rc::new(RefCell::new(vec![]));
+        let (rects_out, texts_out) = (rects.clone(), texts.clone());
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_rect(move |c, _, _, ul, br| rects_out.borrow_mut().push((c, ul, br)));
+            m.check_draw_text(move |_, _, _, pos, text| {
+                texts_out.borrow_mut().push((pos, text.to_string()))
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .margin(20)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Build chart error");
+
+        // The annotations are added before the series they describe
+        chart
+            .annotate(Rectangle::new([(4.0, 6.0), (6.0, 4.0)], RED.filled()))
+            .annotate(Text::new("peak", (5.0, 8.0), ("sans-serif", 12)));
+        assert!(rects.borrow().is_empty() && texts.borrow().is_empty());
+        chart
+            .draw_series(MatBarSeries::new([(5.0, 9.0)], BLUE.filled()).width(4.0))
+            .expect("Drawing Error");
+        chart.draw_annotations().expect("Drawing Error");
+
+        // but they are drawn after it, on top, where they were placed
+        let rects = rects.borrow();
+        assert_eq!(rects.len(), 2);
+        assert_eq!(rects[0].0, BLUE.to_rgba());
+        assert_eq!(rects[1].0, RED.to_rgba());
+        assert_eq!(rects[1].1, chart.backend_coord(&(4.0, 6.0)));
+        assert_eq!(rects[1].2, chart.backend_coord(&(6.0, 4.0)));
+        assert_eq!(texts.borrow().len(), 1);
+        assert_eq!(texts.borrow()[0].1, "peak");
+
+        // The layer is emptied once drawn
+        chart.draw_annotations().expect("Drawing Error");
+        assert_eq!(texts.borrow().len(), 1);
+    }
+}
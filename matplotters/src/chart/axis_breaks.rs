@@ -0,0 +1,130 @@
+use super::ChartContext;
+
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::combinators::AxisBreaks;
+use crate::drawing::DrawingAreaErrorKind;
+use crate::element::PathElement;
+use crate::style::ShapeStyle;
+
+use plotters_backend::DrawingBackend;
+
+impl<'a, DB: DrawingBackend, X, Y> ChartContext<'a, DB, Cartesian2d<X, Y>>
+where
+    X: AxisBreaks,
+    Y: AxisBreaks,
+    X::ValueType: Clone,
+    Y::ValueType: Clone,
+{
+    /**
+    Draws the diagonal slash marks of the breaks of a chart with broken axes, such as
+    [`crate::coord::combinators::BrokenCoord`]: a slash standing on the bottom edge of the
+    plotting area at both ends of every break of the x axis, and on its left edge at both ends
+    of every break of the y axis. The two axes can be broken independently.
+
+    - `size`: The width and the height of the slashes, in pixels
+    - `style`: The style of the slashes, usually the style of the axes
+    */
+    pub fn draw_break_marks<S: Into<ShapeStyle>>(
+        &self,
+        size: u32,
+        style: S,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let style = style.into();
+        let coord = self.as_coord_spec();
+        let (x0, y0) = (self.x_range().start, self.y_range().start);
+        let (base_x, base_y) = self.drawing_area.get_base_pixel();
+        let height = self.drawing_area.dim_in_pixel().1 as i32;
+        let (size, half) = (size as i32, size as i32 / 2);
+        let mut slashes = vec![];
+        for gap in coord.x_spec().breaks() {
+            for x in [gap.start, gap.end] {
+                let px = self.drawing_area.map_coordinate(&(x, y0.clone())).0 - base_x;
+                slashes.push((px - half, height, px + half, height - size));
+            }
+        }
+        for gap in coord.y_spec().breaks() {
+            for y in [gap.start, gap.end] {
+                let py = self.drawing_area.map_coordinate(&(x0.clone(), y)).1 - base_y;
+                slashes.push((0, py + half, size, py - half));
+            }
+        }
+
+        // The plotting area clips the slashes, so they are drawn inside it
+        let area = self.drawing_area.strip_coord_spec();
+        for (x0, y0, x1, y1) in slashes {
+            area.draw(&PathElement::new(vec![(x0, y0), (x1, y1)], style))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_break_marks_on_both_axes() {
+        let marks = Rc::new(RefCell::new(vec![]));
+        let marks_out = marks.clone();
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_path(move |c, _, path| {
+                if c == BLACK.mix(0.9).to_rgba() {
+                    marks_out.borrow_mut().push(path);
+                }
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .margin(10)
+            .x_label_area_size(30)
+            .y_label_area_size(30)
+            .build_cartesian_2d(
+                (0.0..100.0).broken_at(30.0..70.0),
+                (0.0..10.0).broken_at(4.0..6.0),
+            )
+            .expect("Build chart error");
+        chart.configure_mesh().draw().expect("Drawing Error");
+        chart
+            .draw_series(LineSeries::new([(10.0, 1.0), (80.0, 9.0)], &RED))
+            .expect("Drawing Error");
+        chart
+            .draw_break_marks(8, BLACK.mix(0.9))
+            .expect("Drawing Error");
+
+        let marks = marks.borrow();
+        assert_eq!(marks.len(), 4);
+        for mark in marks.iter() {
+            // A diagonal slash going up to the right
+            let (a, b) = (mark[0], mark[1]);
+            assert_eq!((b.0 - a.0, b.1 - a.1), (8, -8));
+        }
+        let center = |m: &Vec<(i32, i32)>| ((m[0].0 + m[1].0) / 2, (m[0].1 + m[1].1) / 2);
+        // On the x axis at both ends of its break
+        let bottom = chart.backend_coord(&(0.0, 0.0)).1;
+        for (mark, x) in marks.iter().zip([30.0, 70.0]) {
+            let (cx, cy) = center(mark);
+            assert_eq!(cx, chart.backend_coord(&(x, 0.0)).0);
+            assert!((cy + 4 - bottom).abs() <= 1);
+        }
+        // On the y axis at both ends of its break
+        let left = chart.backend_coord(&(0.0, 0.0)).0;
+        for (mark, y) in marks[2..].iter().zip([4.0, 6.0]) {
+            let (cx, cy) = center(mark);
+            assert_eq!(cx, left + 4);
+            assert_eq!(cy, chart.backend_coord(&(0.0, y)).1);
+        }
+        assert!(chart.backend_coord(&(30.0, 0.0)).0 < chart.backend_coord(&(70.0, 0.0)).0);
+    }
+
+    #[test]
+    fn test_break_marks_on_one_axis() {
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.drop_check(|b| assert_eq!(b.num_draw_path_call, 2));
+        });
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, (0.0..1000.0).broken_at(100.0..900.0))
+            .expect("Build chart error");
+        chart.draw_break_marks(6, BLACK).expect("Drawing Error");
+    }
+}
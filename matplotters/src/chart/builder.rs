@@ -1,11 +1,12 @@
 use super::context::ChartContext;
+use super::DataDensity;
 
 use crate::coord::cartesian::{Cartesian2d, Cartesian3d};
 use crate::coord::ranged1d::AsRangedCoord;
 use crate::coord::Shift;
 
 use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
-use crate::style::{IntoTextStyle, SizeDesc, TextStyle};
+use crate::style::{Color, IntoTextStyle, RGBAColor, SizeDesc, TextStyle};
 
 use plotters_backend::DrawingBackend;
 
@@ -61,6 +62,8 @@ pub struct ChartBuilder<'a, 'b, DB: DrawingBackend> {
     root_area: &'a DrawingArea<DB, Shift>,
     title: Option<(String, TextStyle<'b>)>,
     margin: [u32; 4],
+    figure_facecolor: Option<RGBAColor>,
+    axes_facecolor: Option<RGBAColor>,
 }
 
 impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
@@ -94,6 +97,8 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
             title: None,
             margin: [0; 4],
             overlap_plotting_area: [false; 4],
+            figure_facecolor: None,
+            axes_facecolor: None,
         }
     }
 
@@ -280,6 +285,33 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
         self
     }
 
+    /**
+    Sets the background color of the whole figure, like matplotlib's figure `facecolor`.
+
+    - `color`: The color used to fill the root drawing area when the chart is built
+
+    See [`ChartBuilder::axes_facecolor()`] for the background of the plotting area.
+    */
+    pub fn figure_facecolor<C: Color>(&mut self, color: &C) -> &mut Self {
+        self.figure_facecolor = Some(color.to_rgba());
+        self
+    }
+
+    /**
+    Sets the background color of the plotting area, like matplotlib's axes `facecolor`.
+
+    - `color`: The color used to fill the data region when the chart is built
+
+    Only the plotting area behind the mesh and the series is filled; the margins, caption
+    and label areas keep the figure background.
+
+    See [`ChartBuilder::figure_facecolor()`] for the background of the whole figure.
+    */
+    pub fn axes_facecolor<C: Color>(&mut self, color: &C) -> &mut Self {
+        self.axes_facecolor = Some(color.to_rgba());
+        self
+    }
+
     /// This function has been renamed to [`ChartBuilder::build_cartesian_2d()`] and is to be removed in the future.
     #[allow(clippy::type_complexity)]
     #[deprecated(
@@ -316,6 +348,10 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
     > {
         let mut label_areas = [None, None, None, None];
 
+        if let Some(color) = self.figure_facecolor {
+            self.root_area.fill(&color)?;
+        }
+
         let mut drawing_area = DrawingArea::clone(self.root_area);
 
         if *self.margin.iter().max().unwrap_or(&0) > 0 {
@@ -410,6 +446,10 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
             }
         }
 
+        if let Some(color) = self.axes_facecolor {
+            drawing_area.fill(&color)?;
+        }
+
         let mut pixel_range = drawing_area.get_pixel_range();
         pixel_range.0.end -= 1;
         pixel_range.1.end -= 1;
@@ -436,6 +476,10 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
                 actual_drawing_area_pos[2] + title_dx + self.margin[2] as i32,
                 actual_drawing_area_pos[0] + title_dy + self.margin[0] as i32,
             ),
+            clip_inset: 0,
+            data_density: DataDensity::default(),
+            abbreviated_labels: vec![],
+            annotation_layer: vec![],
         })
     }
 
@@ -459,6 +503,10 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
         ChartContext<'a, DB, Cartesian3d<X::CoordDescType, Y::CoordDescType, Z::CoordDescType>>,
         DrawingAreaErrorKind<DB::ErrorType>,
     > {
+        if let Some(color) = self.figure_facecolor {
+            self.root_area.fill(&color)?;
+        }
+
         let mut drawing_area = DrawingArea::clone(self.root_area);
 
         if *self.margin.iter().max().unwrap_or(&0) > 0 {
@@ -479,6 +527,10 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
             (0, 0)
         };
 
+        if let Some(color) = self.axes_facecolor {
+            drawing_area.fill(&color)?;
+        }
+
         let pixel_range = drawing_area.get_pixel_range();
 
         Ok(ChartContext {
@@ -495,6 +547,10 @@ impl<'a, 'b, DB: DrawingBackend> ChartBuilder<'a, 'b, DB> {
                 title_dx + self.margin[2] as i32,
                 title_dy + self.margin[0] as i32,
             ),
+            clip_inset: 0,
+            data_density: DataDensity::default(),
+            abbreviated_labels: vec![],
+            annotation_layer: vec![],
         })
     }
 }
@@ -565,4 +621,31 @@ mod test {
         chart.caption("This is a test case", ("serif", 10));
         assert_eq!(chart.title.as_ref().unwrap().1.font.get_name(), "serif");
     }
+
+    #[test]
+    fn test_facecolor() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.check_draw_rect(|c, _, filled, u, d| {
+                assert_eq!(c, WHITE.to_rgba());
+                assert!(filled);
+                assert_eq!((u, d), ((0, 0), (200, 200)));
+            });
+            m.check_draw_rect(|c, _, filled, u, d| {
+                assert_eq!(c, RGBColor(234, 234, 242).to_rgba());
+                assert!(filled);
+                // Inside the margin and the label areas
+                assert_eq!((u, d), ((40, 10), (190, 170)));
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_rect_call, 2));
+        });
+
+        ChartBuilder::on(&drawing_area)
+            .margin(10)
+            .x_label_area_size(20)
+            .y_label_area_size(30)
+            .figure_facecolor(&WHITE)
+            .axes_facecolor(&RGBColor(234, 234, 242))
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Build chart error");
+    }
 }
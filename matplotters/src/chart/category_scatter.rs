@@ -0,0 +1,250 @@
+use super::ChartContext;
+
+use crate::chart::{LegendGlyph, StyledSeries};
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::Ranged;
+use crate::drawing::DrawingAreaErrorKind;
+use crate::element::{Marker, Polygon};
+use crate::series::MatCategoryScatter;
+
+use plotters_backend::{BackendCoord, DrawingBackend};
+use std::f64::consts::PI;
+
+/// The number of points on the arc of a full pie glyph
+const GLYPH_ARC_POINTS: usize = 32;
+
+impl<'a, DB: DrawingBackend + 'a, X, Y> ChartContext<'a, DB, Cartesian2d<X, Y>>
+where
+    X: Ranged<ValueType = f64>,
+    Y: Ranged<ValueType = f64>,
+{
+    /**
+    Draws the series of the categories of a scatter plot, such as the ones of
+    [`MatCategoryScatter::by_color`], each labeled with its category, so that every category
+    has one legend entry with its marker.
+
+    See [`MatCategoryScatter`] for more information.
+    */
+    pub fn draw_category_scatter<I>(
+        &mut self,
+        layers: I,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        I: IntoIterator<Item = MatCategoryScatter>,
+    {
+        for series in layers {
+            let category = series.category().to_string();
+            self.draw_styled_series(series)?.label(category);
+        }
+        Ok(())
+    }
+
+    /**
+    Draws the categories of a scatter plot like [`ChartContext::draw_category_scatter`], but
+    the points closer to each other than `distance` pixels are merged into clusters, like
+    the cluster markers of a map. A cluster is drawn as a pie glyph of `radius` pixels at the
+    centroid of its points, with one slice per category in the cluster, as large as its share
+    of the points and in the color of the category; the slices start at the top and go
+    clockwise, in the order of the categories. The points without a neighbor keep their own
+    markers. The glyphs are not added to the legend.
+
+    The points are clustered greedily, in the order of the categories and then of the
+    points: a point joins the first cluster whose first point is close enough, or starts a
+    new cluster.
+
+    - **returns** The number of points of each category in each cluster, indexed by cluster
+      then by category
+    */
+    pub fn draw_category_clusters<I>(
+        &self,
+        layers: I,
+        distance: u32,
+        radius: u32,
+    ) -> Result<Vec<Vec<usize>>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        I: IntoIterator<Item = MatCategoryScatter>,
+    {
+        let layers: Vec<MatCategoryScatter> = layers.into_iter().collect();
+        let (base_x, base_y) = self.drawing_area.get_base_pixel();
+        let to_pixel = |p: &(f64, f64)| {
+            let (x, y) = self.drawing_area.map_coordinate(p);
+            (x - base_x, y - base_y)
+        };
+        let near = |a: BackendCoord, b: BackendCoord| {
+            f64::from(a.0 - b.0).hypot(f64::from(a.1 - b.1)) < f64::from(distance)
+        };
+        // The first pixel of each cluster, and the category and the point of its members
+        let mut clusters: Vec<(BackendCoord, Vec<_>)> = vec![];
+        for (category, layer) in layers.iter().enumerate() {
+            for point in layer.points() {
+                let pixel = to_pixel(point);
+                match clusters.iter_mut().find(|(first, _)| near(*first, pixel)) {
+                    Some((_, members)) => members.push((category, *point)),
+                    None => clusters.push((pixel, vec![(category, *point)])),
+                }
+            }
+        }
+
+        let area = self.drawing_area.strip_coord_spec();
+        let mut ret = vec![];
+        for (_, members) in clusters {
+            let mut counts = vec![0; layers.len()];
+            for (category, _) in &members {
+                counts[*category] += 1;
+            }
+            if let [(category, point)] = members[..] {
+                if let LegendGlyph::Marker(shape, size, style) = layers[category].legend_glyph() {
+                    self.drawing_area
+                        .draw(&Marker::new(point, size, shape, style))?;
+                }
+                ret.push(counts);
+                continue;
+            }
+
+            let pixels: Vec<BackendCoord> = members.iter().map(|(_, p)| to_pixel(p)).collect();
+            let n = pixels.len() as f64;
+            let center = (
+                (pixels.iter().map(|p| f64::from(p.0)).sum::<f64>() / n).round() as i32,
+                (pixels.iter().map(|p| f64::from(p.1)).sum::<f64>() / n).round() as i32,
+            );
+            let r = f64::from(radius);
+            let at = |angle: f64| {
+                (
+                    center.0 + (r * angle.sin()).round() as i32,
+                    center.1 - (r * angle.cos()).round() as i32,
+                )
+            };
+            let mut start = 0.0;
+            for (count, layer) in counts.iter().zip(&layers).filter(|(c, _)| **c > 0) {
+                let sweep = 2.0 * PI * *count as f64 / n;
+                let steps = ((GLYPH_ARC_POINTS as f64 * sweep / (2.0 * PI)).ceil() as usize).max(2);
+                let mut slice = vec![center];
+                slice.extend((0..=steps).map(|k| at(start + sweep * k as f64 / steps as f64)));
+                let mut style = layer.series_style();
+                style.filled = true;
+                area.draw(&Polygon::new(slice, style))?;
+                start += sweep;
+            }
+            ret.push(counts);
+        }
+        Ok(ret)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::f64::consts::PI;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_draw_category_scatter() {
+        let circles = Rc::new(RefCell::new(vec![]));
+        let texts = Rc::new(RefCell::new(vec![]));
+        let (circles_out, texts_out) = (circles.clone(), texts.clone());
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_circle(move |c, _, _, center, _| {
+                circles_out.borrow_mut().push((c, center))
+            });
+            m.check_draw_text(move |_, _, _, _, t| texts_out.borrow_mut().push(t.to_string()));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Build chart error");
+
+        let points = [
+            (1.0, 1.0),
+            (2.0, 5.0),
+            (3.0, 2.0),
+            (4.0, 8.0),
+            (5.0, 4.0),
+            (6.0, 6.0),
+        ];
+        let groups = ["a", "b", "a", "c", "b", "a"];
+        chart
+            .draw_category_scatter(MatCategoryScatter::by_color(points, groups))
+            .expect("Drawing Error");
+        let drawn = circles.borrow().len();
+        chart
+            .configure_series_labels()
+            .draw()
+            .expect("Drawing Error");
+
+        // The points of a group share the color of the cycle assigned to the group
+        let cycle = ColorCycle::default();
+        let color = |group: usize| cycle.colors()[group].to_rgba();
+        let circles = circles.borrow();
+        assert_eq!(drawn, points.len());
+        for (c, center) in &circles[..drawn] {
+            let idx = points
+                .iter()
+                .position(|p| chart.backend_coord(p) == *center)
+                .expect("A point of the data");
+            let group = ["a", "b", "c"]
+                .iter()
+                .position(|g| *g == groups[idx])
+                .unwrap();
+            assert_eq!(*c, color(group));
+        }
+        // The legend has one entry per group, with a glyph of its color
+        assert_eq!(*texts.borrow(), vec!["a", "b", "c"]);
+        let glyphs: Vec<_> = circles[drawn..].iter().map(|(c, _)| *c).collect();
+        assert_eq!(glyphs, vec![color(0), color(1), color(2)]);
+    }
+
+    #[test]
+    fn test_draw_category_clusters() {
+        let slices = Rc::new(RefCell::new(vec![]));
+        let circles = Rc::new(RefCell::new(vec![]));
+        let (slices_out, circles_out) = (slices.clone(), circles.clone());
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_fill_polygon(move |c, path| slices_out.borrow_mut().push((c, path)));
+            m.check_draw_circle(move |c, _, _, center, _| {
+                circles_out.borrow_mut().push((c, center))
+            });
+        });
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Build chart error");
+
+        // Two points of each category close together, and one more point of a far away
+        let points = [(2.0, 2.0), (2.1, 2.2), (8.0, 8.0), (2.2, 1.9), (1.9, 2.1)];
+        let groups = ["a", "a", "a", "b", "b"];
+        let clusters = chart
+            .draw_category_clusters(MatCategoryScatter::by_color(points, groups), 30, 12)
+            .expect("Drawing Error");
+        assert_eq!(clusters, vec![vec![2, 2], vec![1, 0]]);
+
+        let cycle = ColorCycle::default();
+        let color = |group: usize| cycle.colors()[group].to_rgba();
+        // The lonely point keeps its marker
+        let circles = circles.borrow();
+        assert_eq!(*circles, vec![(color(0), chart.backend_coord(&(8.0, 8.0)))]);
+
+        // The cluster is a pie glyph split 50/50 between the two categories
+        let slices = slices.borrow();
+        assert_eq!(slices.len(), 2);
+        assert_eq!(slices[0].0, color(0));
+        assert_eq!(slices[1].0, color(1));
+        let area = |path: &Vec<(i32, i32)>| {
+            let twice: i32 = (0..path.len())
+                .map(|i| {
+                    let (p, q) = (path[i], path[(i + 1) % path.len()]);
+                    p.0 * q.1 - q.0 * p.1
+                })
+                .sum();
+            f64::from(twice.abs()) / 2.0
+        };
+        let (a, b) = (area(&slices[0].1), area(&slices[1].1));
+        assert!((a - b).abs() / (a + b) < 0.03, "{} {}", a, b);
+        assert!((a + b - PI * 144.0).abs() / (PI * 144.0) < 0.05);
+        // around the centroid of the points, the first slice starting at the top
+        let center = chart.backend_coord(&(2.05, 2.05));
+        for (_, path) in slices.iter() {
+            assert!((path[0].0 - center.0).abs() <= 1 && (path[0].1 - center.1).abs() <= 1);
+        }
+        assert_eq!(slices[0].1[1].0, slices[0].1[0].0);
+        assert!(slices[0].1[1].1 < slices[0].1[0].1);
+    }
+}
@@ -0,0 +1,116 @@
+use super::ChartContext;
+
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::Ranged;
+use crate::drawing::DrawingAreaErrorKind;
+use crate::element::{Marker, MarkerShape};
+use crate::style::ShapeStyle;
+
+use plotters_backend::DrawingBackend;
+
+impl<'a, DB: DrawingBackend, X, Y> ChartContext<'a, DB, Cartesian2d<X, Y>>
+where
+    X: Ranged<ValueType = f64>,
+    Y: Ranged<ValueType = f64>,
+{
+    /**
+    Draws a marker at the centroid of a cluster of points, the mean of their x and y values,
+    on top of what is already on the chart. The points with a coordinate that is not finite
+    are left out.
+
+    - `points`: The points of the cluster
+    - `shape`: The shape of the marker, usually distinct from the markers of the points
+    - `size`: The size of the marker, in pixels
+    - `style`: The style of the marker
+    - `crosshair`: The style of the lines through the centroid spanning the whole chart, like
+      [`ChartContext::reference_lines`], or `None` for no lines
+    - **returns** The centroid, or `None` if there are no finite points and nothing was drawn
+    */
+    pub fn centroid_marker<I, S>(
+        &self,
+        points: I,
+        shape: MarkerShape,
+        size: u32,
+        style: S,
+        crosshair: Option<ShapeStyle>,
+    ) -> Result<Option<(f64, f64)>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        I: IntoIterator<Item = (f64, f64)>,
+        S: Into<ShapeStyle>,
+    {
+        let (n, sx, sy) = points
+            .into_iter()
+            .filter(|(x, y)| x.is_finite() && y.is_finite())
+            .fold((0usize, 0.0, 0.0), |(n, sx, sy), (x, y)| {
+                (n + 1, sx + x, sy + y)
+            });
+        if n == 0 {
+            return Ok(None);
+        }
+        let centroid = (sx / n as f64, sy / n as f64);
+        if let Some(crosshair) = crosshair {
+            self.reference_lines([centroid.0], [centroid.1], crosshair)?;
+        }
+        self.drawing_area
+            .draw(&Marker::new(centroid, size, shape, style))?;
+        Ok(Some(centroid))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_centroid_marker() {
+        let circles = Rc::new(RefCell::new(vec![]));
+        let paths = Rc::new(RefCell::new(vec![]));
+        let (circles_out, paths_out) = (circles.clone(), paths.clone());
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_circle(move |c, _, _, center, radius| {
+                assert_eq!(c, RED.to_rgba());
+                circles_out.borrow_mut().push((center, radius));
+            });
+            m.check_draw_path(move |c, _, p| {
+                assert_eq!(c, BLACK.mix(0.5).to_rgba());
+                paths_out.borrow_mut().push(p);
+            });
+        });
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Build chart error");
+
+        let points = [
+            (1.0, 2.0),
+            (3.0, 4.0),
+            (5.0, 9.0),
+            (f64::NAN, 1.0),
+            (3.0, 1.0),
+        ];
+        let centroid = chart
+            .centroid_marker(
+                points,
+                MarkerShape::Circle,
+                6,
+                RED.filled(),
+                Some(BLACK.mix(0.5).into()),
+            )
+            .expect("Drawing Error");
+        assert_eq!(centroid, Some((3.0, 4.0)));
+
+        let center = chart.backend_coord(&(3.0, 4.0));
+        assert_eq!(*circles.borrow(), vec![(center, 6)]);
+        // The crosshair goes through the centroid
+        let paths = paths.borrow();
+        assert_eq!(paths.len(), 2);
+        assert!(paths[0].iter().all(|p| p.0 == center.0));
+        assert!(paths[1].iter().all(|p| p.1 == center.1));
+
+        let none = chart
+            .centroid_marker([(f64::NAN, f64::NAN)], MarkerShape::Circle, 6, RED, None)
+            .expect("Drawing Error");
+        assert_eq!(none, None);
+    }
+}
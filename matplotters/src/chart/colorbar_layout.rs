@@ -0,0 +1,386 @@
+use super::{ChartBuilder, ChartContext, LabelAreaPosition};
+
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::types::RangedCoordf64;
+use crate::coord::Shift;
+use crate::data::float::si_prefixed;
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
+use crate::element::Polygon;
+use crate::series::MatColorbar;
+use crate::style::{Color, RGBAColor};
+
+use plotters_backend::DrawingBackend;
+
+/// The side of the chart on which a colorbar is placed, see [`ColorbarLayout`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColorbarPosition {
+    /// A vertical colorbar on the right of the chart
+    #[default]
+    Right,
+    /// A horizontal colorbar below the chart
+    Bottom,
+}
+
+/**
+The placement of a continuous colorbar next to a chart.
+
+The layout reserves a strip along one side of a drawing area: a gap of `padding` pixels,
+then the colorbar itself, whose thickness is a fraction of the width (or height) of the
+area, then a label area for its ticks and its label. [`ColorbarLayout::split`] only
+computes the areas, while [`ColorbarLayout::draw`] also draws a [`MatColorbar`] in its strip,
+with the triangles of its extended ends, and returns the remaining area for the chart.
+*/
+#[derive(Clone, Debug)]
+pub struct ColorbarLayout {
+    position: ColorbarPosition,
+    size: f64,
+    padding: u32,
+    label_area_size: u32,
+    label: Option<String>,
+    si_labels: bool,
+}
+
+impl Default for ColorbarLayout {
+    fn default() -> Self {
+        Self {
+            position: ColorbarPosition::Right,
+            size: 0.05,
+            padding: 10,
+            label_area_size: 50,
+            label: None,
+            si_labels: false,
+        }
+    }
+}
+
+impl ColorbarLayout {
+    /**
+    Creates a layout for a colorbar on the right, 5% of the width of the area.
+
+    See [`ColorbarLayout`] for more information.
+    */
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /**
+    Sets the side of the chart on which the colorbar is placed.
+
+    See [`ColorbarLayout`] for more information.
+    */
+    pub fn position(mut self, position: ColorbarPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    /**
+    Sets the thickness of the colorbar, as a fraction of the width of the area (or of its
+    height for a bottom colorbar). The fraction is clamped to `[0, 1]`.
+
+    See [`ColorbarLayout`] for more information.
+    */
+    pub fn size(mut self, fraction: f64) -> Self {
+        self.size = fraction.clamp(0.0, 1.0);
+        self
+    }
+
+    /**
+    Sets the gap between the chart and the colorbar, in pixels.
+
+    See [`ColorbarLayout`] for more information.
+    */
+    pub fn padding(mut self, padding: u32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /**
+    Sets the size of the area holding the ticks and the label of the colorbar, in pixels.
+
+    See [`ColorbarLayout`] for more information.
+    */
+    pub fn label_area_size(mut self, size: u32) -> Self {
+        self.label_area_size = size;
+        self
+    }
+
+    /**
+    Sets the label of the colorbar, drawn along its axis.
+
+    See [`ColorbarLayout`] for more information.
+    */
+    pub fn label<S: Into<String>>(mut self, label: S) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /**
+    Prints the tick labels of the colorbar with SI prefixes, as in `1.2k` or `3.4M`, which
+    keeps them short when the values are large or small, see
+    [`crate::data::float::si_prefixed`].
+
+    See [`ColorbarLayout`] for more information.
+    */
+    pub fn si_labels(mut self) -> Self {
+        self.si_labels = true;
+        self
+    }
+
+    /**
+    Splits the area into the area of the chart and the area of the colorbar. The area of the
+    colorbar includes its label area but not the padding.
+
+    See [`ColorbarLayout`] for more information.
+    */
+    pub fn split<DB: DrawingBackend>(
+        &self,
+        area: &DrawingArea<DB, Shift>,
+    ) -> (DrawingArea<DB, Shift>, DrawingArea<DB, Shift>) {
+        let (w, h) = area.dim_in_pixel();
+        let along = match self.position {
+            ColorbarPosition::Right => w,
+            ColorbarPosition::Bottom => h,
+        };
+        let thickness = (f64::from(along) * self.size).round() as u32;
+        let reserved = (self.padding + thickness + self.label_area_size).min(along);
+        match self.position {
+            ColorbarPosition::Right => {
+                let (chart, rest) = area.split_horizontally(w - reserved);
+                let padding = self.padding.min(reserved) as i32;
+                (chart, rest.margin(0, 0, padding, 0))
+            }
+            ColorbarPosition::Bottom => {
+                let (chart, rest) = area.split_vertically(h - reserved);
+                let padding = self.padding.min(reserved) as i32;
+                (chart, rest.margin(padding, 0, 0, 0))
+            }
+        }
+    }
+
+    /**
+    Draws the colorbar in its strip and returns the area left for the chart. A bottom
+    colorbar is drawn horizontally.
+
+    See [`ColorbarLayout`] for more information.
+    */
+    pub fn draw<DB: DrawingBackend>(
+        &self,
+        area: &DrawingArea<DB, Shift>,
+        colorbar: MatColorbar,
+    ) -> Result<DrawingArea<DB, Shift>, DrawingAreaErrorKind<DB::ErrorType>> {
+        let (chart_area, colorbar_area) = self.split(area);
+        let range = colorbar.range();
+        let (w, h) = colorbar_area.dim_in_pixel();
+        let si_format = |v: &f64| si_prefixed(*v);
+        let mut builder = ChartBuilder::on(&colorbar_area);
+        match self.position {
+            ColorbarPosition::Right => {
+                let extensions = colorbar.extensions();
+                let (under, over) = colorbar.extension_margins(h);
+                builder.margin_bottom(under).margin_top(over);
+                builder.set_label_area_size(LabelAreaPosition::Right, self.label_area_size);
+                let mut chart = builder.build_cartesian_2d(0.0..1.0, range)?;
+                let mut mesh = chart.configure_mesh();
+                mesh.disable_mesh().disable_x_axis();
+                if self.si_labels {
+                    mesh.y_label_formatter(&si_format);
+                }
+                if let Some(label) = &self.label {
+                    mesh.y_desc(label.as_str());
+                }
+                mesh.draw()?;
+                chart.draw_series(colorbar)?;
+                draw_extensions(&chart, &colorbar_area, extensions)?;
+            }
+            ColorbarPosition::Bottom => {
+                let colorbar = colorbar.horizontal();
+                let extensions = colorbar.extensions();
+                let (under, over) = colorbar.extension_margins(w);
+                builder.margin_left(under).margin_right(over);
+                builder.set_label_area_size(LabelAreaPosition::Bottom, self.label_area_size);
+                let mut chart = builder.build_cartesian_2d(range, 0.0..1.0)?;
+                let mut mesh = chart.configure_mesh();
+                mesh.disable_mesh().disable_y_axis();
+                if self.si_labels {
+                    mesh.x_label_formatter(&si_format);
+                }
+                if let Some(label) = &self.label {
+                    mesh.x_desc(label.as_str());
+                }
+                mesh.draw()?;
+                chart.draw_series(colorbar)?;
+                draw_extensions(&chart, &colorbar_area, extensions)?;
+            }
+        }
+        Ok(chart_area)
+    }
+}
+
+/// Draw the extension triangles of a colorbar drawn on a chart. They are beyond the range of
+/// the chart, in its margins, so they are drawn on the area of the whole chart, which the
+/// plotting area would clip them to.
+pub(super) fn draw_extensions<DB: DrawingBackend>(
+    chart: &ChartContext<DB, Cartesian2d<RangedCoordf64, RangedCoordf64>>,
+    area: &DrawingArea<DB, Shift>,
+    extensions: Vec<(Vec<(f64, f64)>, RGBAColor)>,
+) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+    let (base_x, base_y) = area.get_base_pixel();
+    for (points, color) in extensions {
+        let pixels: Vec<_> = points
+            .iter()
+            .map(|p| {
+                let (x, y) = chart.backend_coord(p);
+                (x - base_x, y - base_y)
+            })
+            .collect();
+        area.draw(&Polygon::new(pixels, color.filled()))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use crate::style::Extend;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_colorbar_layout() {
+        let rects = Rc::new(RefCell::new(vec![]));
+        let texts = Rc::new(RefCell::new(vec![]));
+        let (rects_out, texts_out) = (rects.clone(), texts.clone());
+        let drawing_area = create_mocked_drawing_area(1000, 500, |m| {
+            m.check_draw_rect(move |_, _, filled, ul, br| {
+                if filled {
+                    rects_out.borrow_mut().push((ul, br));
+                }
+            });
+            m.check_draw_text(move |_, _, _, _, text| {
+                texts_out.borrow_mut().push(text.to_string())
+            });
+        });
+
+        let layout = ColorbarLayout::new().size(0.1).padding(20).label("Density");
+        let colorbar = MatColorbar::new(&ViridisRGB {}, Norm::new(0.0, 1.0), 8);
+        let chart_area = layout.draw(&drawing_area, colorbar).expect("Drawing Error");
+
+        // The chart keeps the area left of the padding, the colorbar strip and its labels
+        assert_eq!(chart_area.dim_in_pixel(), (1000 - 20 - 100 - 50, 500));
+        let rects = rects.borrow();
+        assert_eq!(rects.len(), 8);
+        // The slabs fill a strip of 10% of the width, right of the padding
+        for (ul, br) in rects.iter() {
+            assert_eq!(ul.0, 850);
+            assert_eq!(br.0, 949);
+        }
+        assert_eq!(rects[0].1 .1, 499);
+        assert_eq!(rects[7].0 .1, 0);
+        assert!(texts.borrow().iter().any(|t| t == "Density"));
+    }
+
+    #[test]
+    fn test_colorbar_si_labels() {
+        let texts = Rc::new(RefCell::new(vec![]));
+        let texts_out = texts.clone();
+        let drawing_area = create_mocked_drawing_area(1000, 500, |m| {
+            m.check_draw_text(move |_, _, _, _, text| {
+                texts_out.borrow_mut().push(text.to_string())
+            });
+        });
+
+        let layout = ColorbarLayout::new().si_labels();
+        let colorbar = MatColorbar::new(&ViridisRGB {}, Norm::new(0.0, 5e6), 8);
+        layout.draw(&drawing_area, colorbar).expect("Drawing Error");
+
+        let texts = texts.borrow();
+        assert!(texts.iter().any(|t| t == "1M"));
+        assert!(texts.iter().any(|t| t == "2.5M"));
+        assert!(texts.iter().any(|t| t == "500k"));
+        assert!(texts.iter().all(|t| !t.contains("000")));
+    }
+
+    #[test]
+    fn test_bottom_colorbar_layout() {
+        let rects = Rc::new(RefCell::new(vec![]));
+        let rects_out = rects.clone();
+        let drawing_area = create_mocked_drawing_area(500, 1000, |m| {
+            m.check_draw_rect(move |_, _, filled, ul, br| {
+                if filled {
+                    rects_out.borrow_mut().push((ul, br));
+                }
+            });
+        });
+
+        let layout = ColorbarLayout::new()
+            .position(ColorbarPosition::Bottom)
+            .size(0.05);
+        let colorbar = MatColorbar::new(&ViridisRGB {}, Norm::new(0.0, 1.0), 4);
+        let chart_area = layout.draw(&drawing_area, colorbar).expect("Drawing Error");
+
+        assert_eq!(chart_area.dim_in_pixel(), (500, 1000 - 10 - 50 - 50));
+        let rects = rects.borrow();
+        assert_eq!(rects.len(), 4);
+        for (ul, br) in rects.iter() {
+            assert_eq!((ul.1, br.1), (900, 949));
+        }
+        // The values increase from left to right
+        assert_eq!(rects[0].0 .0, 0);
+        assert_eq!(rects[3].1 .0, 499);
+    }
+
+    #[test]
+    fn test_colorbar_extend() {
+        let rects = Rc::new(RefCell::new(vec![]));
+        let triangles = Rc::new(RefCell::new(vec![]));
+        let (rects_out, triangles_out) = (rects.clone(), triangles.clone());
+        let drawing_area = create_mocked_drawing_area(1000, 500, |m| {
+            m.check_draw_rect(move |c, _, filled, ul, br| {
+                if filled {
+                    rects_out.borrow_mut().push((c, ul, br));
+                }
+            });
+            m.check_fill_polygon(move |c, p| triangles_out.borrow_mut().push((c, p)));
+        });
+
+        let norm = Norm::new(0.0, 10.0)
+            .extend(Extend::Both)
+            .under_color(&MAGENTA)
+            .over_color(&RED);
+        let layout = ColorbarLayout::new().size(0.1).padding(20);
+        let colorbar = MatColorbar::new(&ViridisRGB {}, norm, 8);
+        let chart_area = layout.draw(&drawing_area, colorbar).expect("Drawing Error");
+
+        // The bar leaves room for a triangle at each end
+        let slabs: Vec<_> = rects.borrow_mut().drain(..).collect();
+        assert_eq!(slabs.len(), 8);
+        let (top, bottom) = (slabs[7].1 .1, slabs[0].2 .1);
+        assert!(top > 10 && bottom < 490);
+        let triangles = triangles.borrow();
+        assert_eq!(triangles.len(), 2);
+        let (under, over) = (&triangles[0], &triangles[1]);
+        assert_eq!((under.0, over.0), (MAGENTA.to_rgba(), RED.to_rgba()));
+        // The triangles point away from the bar, in its strip
+        let (under_tip, over_tip) = (under.1[2], over.1[2]);
+        assert!(under_tip.1 > bottom && under_tip.1 <= 500);
+        assert!(over_tip.1 < top && over_tip.1 >= 0);
+        assert!(under.1.iter().all(|p| (850..950).contains(&p.0)));
+
+        // The data out of the range takes the under and over colors
+        let mut chart = ChartBuilder::on(&chart_area)
+            .build_cartesian_2d(0.0..3.0, -10.0..20.0)
+            .expect("Build chart error");
+        let bars = [(0.5, -5.0), (1.5, 5.0), (2.5, 15.0)];
+        chart
+            .draw_series(
+                MatBarSeries::new(bars, BLACK.filled()).color_by_value(&ViridisRGB {}, norm),
+            )
+            .expect("Drawing Error");
+        let colors: Vec<_> = rects.borrow().iter().map(|(c, _, _)| *c).collect();
+        let inside: RGBColor = ViridisRGB::get_color(0.5);
+        assert_eq!(
+            colors,
+            vec![MAGENTA.to_rgba(), inside.to_rgba(), RED.to_rgba()]
+        );
+    }
+}
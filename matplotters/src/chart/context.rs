@@ -2,10 +2,10 @@ use std::borrow::Borrow;
 
 use plotters_backend::{BackendCoord, DrawingBackend};
 
-use crate::chart::{SeriesAnno, SeriesLabelStyle};
+use crate::chart::{DataDensity, SeriesAnno, SeriesLabelStyle, StyledSeries};
 use crate::coord::{CoordTranslate, ReverseCoordTranslate, Shift};
 use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
-use crate::element::{CoordMapper, Drawable, PointCollection};
+use crate::element::{CoordMapper, Drawable, DynElement, PointCollection};
 
 pub(super) mod cartesian2d;
 pub(super) mod cartesian3d;
@@ -28,6 +28,10 @@ pub struct ChartContext<'a, DB: DrawingBackend, CT: CoordTranslate> {
     pub(crate) drawing_area: DrawingArea<DB, CT>,
     pub(crate) series_anno: Vec<SeriesAnno<'a, DB>>,
     pub(crate) drawing_area_pos: (i32, i32),
+    pub(crate) clip_inset: u32,
+    pub(crate) data_density: DataDensity,
+    pub(crate) abbreviated_labels: Vec<(String, String)>,
+    pub(crate) annotation_layer: Vec<DynElement<'a, DB, BackendCoord>>,
 }
 
 impl<'a, DB: DrawingBackend, CT: ReverseCoordTranslate> ChartContext<'a, DB, CT> {
@@ -79,6 +83,24 @@ impl<'a, DB: DrawingBackend, CT: CoordTranslate> ChartContext<'a, DB, CT> {
         &self.drawing_area
     }
 
+    /**
+    Clips the series drawn afterwards to the plotting area shrunk by the given margin, in
+    pixels, on every side, to leave room for annotations at the edges. The coordinates of
+    the chart, the mesh and the elements drawn on [`ChartContext::plotting_area()`] are not
+    affected. The default margin is 0.
+    */
+    pub fn clip_inset(&mut self, pixels: u32) -> &mut Self {
+        self.clip_inset = pixels;
+        self
+    }
+
+    /// The labels of the axes abbreviated by [`crate::chart::MeshStyle::label_max_len`], as
+    /// `(abbreviated, full)` pairs in the order they were drawn, to show the full labels as SVG
+    /// tooltips with [`crate::drawing::svg_label_tooltips`]
+    pub fn abbreviated_labels(&self) -> &[(String, String)] {
+        &self.abbreviated_labels
+    }
+
     /// Cast the reference to a chart context to a reference to underlying coordinate specification.
     pub fn as_coord_spec(&self) -> &CT {
         self.drawing_area.as_coord_spec()
@@ -100,8 +122,14 @@ impl<'a, DB: DrawingBackend, CT: CoordTranslate> ChartContext<'a, DB, CT> {
         R: Borrow<E>,
         S: IntoIterator<Item = R>,
     {
+        let dim = self.drawing_area.dim_in_pixel();
+        // The points are counted for the legend as they are translated for the drawing
+        let density = &mut self.data_density;
         for element in series {
-            self.drawing_area.draw(element.borrow())?;
+            self.drawing_area
+                .draw_recording(element.borrow(), self.clip_inset, |p| {
+                    density.record(p, dim)
+                })?;
         }
         Ok(())
     }
@@ -131,10 +159,37 @@ impl<'a, DB: DrawingBackend, CT: CoordTranslate> ChartContext<'a, DB, CT> {
         self.draw_series_impl(series)?;
         Ok(self.alloc_series_anno())
     }
+
+    /**
+    Draws a data series, like [`ChartContext::draw_series()`], and sets its legend glyph
+    from the style of the series, so that the color and the alpha are not repeated. The
+    glyph fits the kind of series: a marker for a scatter plot, a line for a line, and both
+    for a line with markers.
+
+    See [`StyledSeries::legend_glyph()`] for more information.
+    */
+    pub fn draw_styled_series<B, E, R, S>(
+        &mut self,
+        series: S,
+    ) -> Result<&mut SeriesAnno<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        B: CoordMapper,
+        for<'b> &'b E: PointCollection<'b, CT::From, B>,
+        E: Drawable<DB, B>,
+        R: Borrow<E>,
+        S: IntoIterator<Item = R> + StyledSeries,
+        DB: 'a,
+    {
+        let glyph = series.legend_glyph();
+        self.draw_series_impl(series)?;
+        Ok(self.alloc_series_anno().legend_glyph(glyph))
+    }
 }
 
 #[cfg(test)]
 mod test {
+    use crate::coord::types::RangedCoordf64;
+    use crate::drawing::MockedBackend;
     use crate::prelude::*;
 
     #[test]
@@ -186,6 +241,215 @@ mod test {
             .expect("Drawing error");
     }
 
+    #[cfg(all(feature = "line_series", feature = "histogram"))]
+    #[test]
+    fn test_styled_series_legend() {
+        let color = RED.mix(0.35);
+        let paths = std::rc::Rc::new(std::cell::RefCell::new(vec![]));
+        let rects = std::rc::Rc::new(std::cell::RefCell::new(vec![]));
+        let (paths_out, rects_out) = (paths.clone(), rects.clone());
+        let drawing_area = create_mocked_drawing_area(300, 300, |m| {
+            m.check_draw_path(move |c, _, p| paths_out.borrow_mut().push((c, p)));
+            m.check_draw_rect(move |c, _, f, _, _| rects_out.borrow_mut().push((c, f)));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Create chart");
+        chart
+            .draw_styled_series(MatLineSeries::new(vec![(1.0, 1.0), (9.0, 9.0)], color))
+            .expect("Drawing error")
+            .label("line");
+        chart
+            .draw_styled_series(MatBarSeries::new(vec![(5.0, 3.0)], BLUE.mix(0.5).filled()))
+            .expect("Drawing error")
+            .label("bars");
+        let drawn = (paths.borrow().len(), rects.borrow().len());
+        chart
+            .configure_series_labels()
+            .draw()
+            .expect("Drawing error");
+
+        // The legend glyphs are drawn after the series, with the exact same colors
+        let paths = paths.borrow();
+        assert_eq!(paths.len(), drawn.0 + 1);
+        let (line_glyph, glyph_path) = &paths[drawn.0];
+        assert_eq!(*line_glyph, color.to_rgba());
+        assert_eq!(line_glyph.3, 0.35);
+        assert_eq!(glyph_path.len(), 2);
+        let rects = rects.borrow();
+        let bar_glyph = rects[drawn.1..]
+            .iter()
+            .find(|(c, f)| *f && *c == BLUE.mix(0.5).to_rgba());
+        assert!(bar_glyph.is_some());
+    }
+
+    #[cfg(feature = "histogram")]
+    #[test]
+    fn test_legend_dedup() {
+        let texts = std::rc::Rc::new(std::cell::RefCell::new(vec![]));
+        let texts_out = texts.clone();
+        let drawing_area = create_mocked_drawing_area(300, 300, |m| {
+            m.check_draw_text(move |_, _, _, _, t| texts_out.borrow_mut().push(t.to_string()));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Create chart");
+        // The same category in three groups, and another one with its own style
+        for group in 0..3 {
+            let x = 3.0 * f64::from(group);
+            chart
+                .draw_styled_series(MatBarSeries::new(vec![(x, 2.0)], RED.filled()))
+                .expect("Drawing error")
+                .label("A");
+            chart
+                .draw_styled_series(MatBarSeries::new(vec![(x + 1.0, 3.0)], BLUE.filled()))
+                .expect("Drawing error")
+                .label(if group == 0 { "B" } else { "A" });
+        }
+        chart
+            .configure_series_labels()
+            .draw()
+            .expect("Drawing error");
+
+        // "A" in blue differs from "A" in red, so it has its own entry
+        assert_eq!(*texts.borrow(), vec!["A", "B", "A"]);
+    }
+
+    #[test]
+    fn test_legend_max_entries() {
+        let texts = std::rc::Rc::new(std::cell::RefCell::new(vec![]));
+        let texts_out = texts.clone();
+        let drawing_area = create_mocked_drawing_area(300, 300, |m| {
+            m.check_draw_text(move |_, _, _, _, t| texts_out.borrow_mut().push(t.to_string()));
+            // One glyph per shown entry, none for the last one
+            m.drop_check(|b| assert_eq!(b.num_draw_circle_call, 3));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Create chart");
+        for idx in 0..10 {
+            chart
+                .draw_series(std::iter::empty::<Circle<(f64, f64), u32>>())
+                .expect("Drawing error")
+                .label(format!("series {}", idx))
+                .legend(|p| Circle::new(p, 3, RED.filled()));
+        }
+        chart
+            .configure_series_labels()
+            .max_entries(3)
+            .draw()
+            .expect("Drawing error");
+
+        assert_eq!(
+            *texts.borrow(),
+            vec!["series 0", "series 1", "series 2", "+7 more"]
+        );
+    }
+
+    /// The number of paths and circles drawn by a chart with a legend
+    fn legend_parts<F>(draw: F) -> (u32, u32)
+    where
+        F: FnOnce(
+            &mut ChartContext<'_, MockedBackend, Cartesian2d<RangedCoordf64, RangedCoordf64>>,
+        ),
+    {
+        let parts = std::rc::Rc::new(std::cell::Cell::new((0, 0)));
+        let parts_out = parts.clone();
+        let drawing_area = create_mocked_drawing_area(300, 300, |m| {
+            m.drop_check(move |b| parts_out.set((b.num_draw_path_call, b.num_draw_circle_call)));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Create chart");
+        draw(&mut chart);
+        chart
+            .configure_series_labels()
+            .draw()
+            .expect("Drawing error");
+        drop(chart);
+        drop(drawing_area);
+        parts.get()
+    }
+
+    #[test]
+    fn test_contextual_legend_glyphs() {
+        let scatter = || MatCategoryScatter::new([(1.0, 1.0)], ["a"], BLUE.filled()).remove(0);
+        let line = || MatLineSeries::<MockedBackend, f64, f64>::new(vec![], RED);
+        assert_eq!(
+            scatter().legend_glyph(),
+            LegendGlyph::Marker(MarkerShape::Circle, 3, BLUE.filled())
+        );
+        assert_eq!(line().legend_glyph(), LegendGlyph::Line(RED.into()));
+        assert_eq!(
+            line().point_size(4).legend_glyph(),
+            LegendGlyph::LineMarker(MarkerShape::Circle, 4, RED.into())
+        );
+
+        // The lines have no points, so only the legend draws paths and circles, while the
+        // point of the scatter is a circle of its own
+        let parts = legend_parts(|chart| {
+            chart
+                .draw_styled_series(scatter())
+                .expect("Drawing error")
+                .label("scatter");
+        });
+        assert_eq!(parts, (0, 2));
+        let parts = legend_parts(|chart| {
+            chart
+                .draw_styled_series(line())
+                .expect("Drawing error")
+                .label("line");
+        });
+        assert_eq!(parts, (1, 0));
+        let parts = legend_parts(|chart| {
+            chart
+                .draw_styled_series(line().point_size(4))
+                .expect("Drawing error")
+                .label("line+marker");
+        });
+        assert_eq!(parts, (1, 1));
+    }
+
+    #[test]
+    fn test_best_legend_position() {
+        let rects = std::rc::Rc::new(std::cell::RefCell::new(vec![]));
+        let rects_out = rects.clone();
+        let drawing_area = create_mocked_drawing_area(300, 300, |m| {
+            m.check_draw_rect(move |c, _, filled, ul, br| {
+                if filled && c == YELLOW.to_rgba() {
+                    rects_out.borrow_mut().push((ul, br));
+                }
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Create chart");
+        // Most of the data is in the lower left corner, with a few points in the upper right
+        let crowded = (0..100).map(|i| (f64::from(i % 10) * 0.2, f64::from(i / 10) * 0.2));
+        let sparse = [(9.0, 9.0), (9.5, 9.5)];
+        chart
+            .draw_styled_series(
+                MatLineSeries::new(crowded.chain(sparse).collect::<Vec<_>>(), RED).point_size(1),
+            )
+            .expect("Drawing error")
+            .label("data");
+        chart
+            .configure_series_labels()
+            .position(SeriesLabelPosition::Best)
+            .background_style(YELLOW)
+            .draw()
+            .expect("Drawing error");
+
+        // The upper left corner is the only one without data
+        let rects = rects.borrow();
+        assert_eq!(rects.len(), 1);
+        let (ul, br) = rects[0];
+        assert!(br.0 < 150 && br.1 < 150, "legend at {:?}", (ul, br));
+    }
+
     #[test]
     fn test_chart_context_3d() {
         let drawing_area = create_mocked_drawing_area(200, 200, |_| {});
@@ -218,4 +482,35 @@ mod test {
             .draw_series(std::iter::once(Circle::new((5, 5, 5), 5, RED)))
             .expect("Drawing error");
     }
+
+    #[test]
+    fn test_clip_inset() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let path = Rc::new(RefCell::new(vec![]));
+        let path_out = path.clone();
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_path(move |_, _, p| path_out.borrow_mut().push(p));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Build chart error");
+        let data = vec![(0.0, 0.0), (0.2, 5.0), (5.0, 5.0), (10.0, 10.0)];
+
+        chart
+            .draw_series(std::iter::once(PathElement::new(data.clone(), BLUE)))
+            .expect("Drawing error");
+        chart
+            .clip_inset(20)
+            .draw_series(std::iter::once(PathElement::new(data, BLUE)))
+            .expect("Drawing error");
+        // The mapping of the coordinates is kept
+        assert_eq!(chart.backend_coord(&(5.0, 5.0)), (250, 250));
+
+        let path = path.borrow();
+        assert_eq!(path[0], vec![(0, 500), (10, 250), (250, 250), (500, 0)]);
+        // The points within 20 pixels of the edges are clipped to the inset
+        assert_eq!(path[1], vec![(20, 481), (20, 250), (250, 250), (481, 20)]);
+    }
 }
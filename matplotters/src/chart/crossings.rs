@@ -0,0 +1,162 @@
+use super::ChartContext;
+
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::Ranged;
+use crate::data::{line_crossings, zero_crossings};
+use crate::drawing::DrawingAreaErrorKind;
+use crate::element::{EmptyElement, Marker, MarkerShape, Text};
+use crate::style::{IntoFont, ShapeStyle, TextStyle};
+
+use plotters_backend::DrawingBackend;
+
+impl<'a, DB: DrawingBackend, X, Y> ChartContext<'a, DB, Cartesian2d<X, Y>>
+where
+    X: Ranged<ValueType = f64>,
+    Y: Ranged<ValueType = f64>,
+{
+    /**
+    Marks the points where two lines cross, on top of what is already on the chart, to compare
+    two series. The crossings are interpolated between the points of the lines.
+
+    - `a`, `b`: The points of the two lines
+    - `shape`: The shape of the markers
+    - `size`: The size of the markers, in pixels
+    - `style`: The style of the markers; the labels take its color
+    - `label`: Whether to write the coordinates of each crossing next to its marker
+    - **returns** The crossing points, in increasing x
+
+    See [`line_crossings`] for more information.
+    */
+    pub fn mark_crossings<S: Into<ShapeStyle>>(
+        &self,
+        a: &[(f64, f64)],
+        b: &[(f64, f64)],
+        shape: MarkerShape,
+        size: u32,
+        style: S,
+        label: bool,
+    ) -> Result<Vec<(f64, f64)>, DrawingAreaErrorKind<DB::ErrorType>> {
+        let style = style.into();
+        let font: TextStyle = ("sans-serif", 12).into_font().color(&style.color);
+        let crossings = line_crossings(a, b);
+        for &(x, y) in &crossings {
+            self.drawing_area
+                .draw(&Marker::new((x, y), size, shape, style))?;
+            if label {
+                let offset = size as i32 + 2;
+                self.drawing_area.draw(
+                    &(EmptyElement::at((x, y))
+                        + Text::new(format!("({:.2}, {:.2})", x, y), (offset, offset), &font)),
+                )?;
+            }
+        }
+        Ok(crossings)
+    }
+
+    /**
+    Marks the points where a series changes sign, crossing zero, on top of what is already on
+    the chart, such as the sign changes of an oscillator. The markers are on the zero line, at
+    the x interpolated between the points of the series.
+
+    - `points`: The points of the series
+    - `shape`: The shape of the markers
+    - `size`: The size of the markers, in pixels
+    - `style`: The style of the markers
+    - **returns** The x of the sign changes, in increasing order
+
+    See [`zero_crossings`] for more information.
+    */
+    pub fn mark_zero_crossings<S: Into<ShapeStyle>>(
+        &self,
+        points: &[(f64, f64)],
+        shape: MarkerShape,
+        size: u32,
+        style: S,
+    ) -> Result<Vec<f64>, DrawingAreaErrorKind<DB::ErrorType>> {
+        let style = style.into();
+        let crossings = zero_crossings(points);
+        for &x in &crossings {
+            self.drawing_area
+                .draw(&Marker::new((x, 0.0), size, shape, style))?;
+        }
+        Ok(crossings)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_mark_crossings() {
+        let circles = Rc::new(RefCell::new(vec![]));
+        let texts = Rc::new(RefCell::new(vec![]));
+        let (circles_out, texts_out) = (circles.clone(), texts.clone());
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_circle(move |c, _, _, center, radius| {
+                assert_eq!(c, BLACK.to_rgba());
+                circles_out.borrow_mut().push((center, radius));
+            });
+            m.check_draw_text(move |c, _, _, pos, text| {
+                assert_eq!(c, BLACK.to_rgba());
+                texts_out.borrow_mut().push((pos, text.to_string()));
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Build chart error");
+
+        // The lines cross between their samples: 1 + x = 6 - 2 / 3 * (x - 4) at x = 4.6
+        let a = [(0.0, 1.0), (6.0, 7.0), (10.0, 7.0)];
+        let b = [(0.0, 8.0), (4.0, 6.0), (10.0, 2.0)];
+        chart
+            .draw_series(LineSeries::new(a, &RED))
+            .expect("Drawing Error");
+        chart
+            .draw_series(LineSeries::new(b, &BLUE))
+            .expect("Drawing Error");
+        let crossings = chart
+            .mark_crossings(&a, &b, MarkerShape::Circle, 5, BLACK.filled(), true)
+            .expect("Drawing Error");
+
+        assert_eq!(crossings.len(), 1);
+        let (x, y) = crossings[0];
+        assert!((x - 4.6).abs() < 1e-12 && (y - 5.6).abs() < 1e-12);
+        let center = chart.backend_coord(&(4.6, 5.6));
+        assert_eq!(*circles.borrow(), vec![(center, 5)]);
+        assert_eq!(
+            *texts.borrow(),
+            vec![((center.0 + 7, center.1 + 7), "(4.60, 5.60)".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_mark_zero_crossings() {
+        let circles = Rc::new(RefCell::new(vec![]));
+        let circles_out = circles.clone();
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_circle(move |c, _, _, center, radius| {
+                assert_eq!(c, RED.to_rgba());
+                circles_out.borrow_mut().push((center, radius));
+            });
+        });
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..2.0, -2.0..2.0)
+            .expect("Build chart error");
+
+        let points = [(0.0, 1.0), (1.0, -1.0), (2.0, 1.0)];
+        let crossings = chart
+            .mark_zero_crossings(&points, MarkerShape::Circle, 4, RED.filled())
+            .expect("Drawing Error");
+        assert_eq!(crossings, vec![0.5, 1.5]);
+        assert_eq!(
+            *circles.borrow(),
+            vec![
+                (chart.backend_coord(&(0.5, 0.0)), 4),
+                (chart.backend_coord(&(1.5, 0.0)), 4)
+            ]
+        );
+    }
+}
@@ -0,0 +1,186 @@
+use super::{ChartContext, DualCoordChartContext};
+
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::Ranged;
+use crate::data::{resample_to, Extrapolation};
+use crate::drawing::DrawingAreaErrorKind;
+use crate::element::{Circle, EmptyElement, Text};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{IntoFont, ShapeStyle, TextStyle};
+
+use plotters_backend::DrawingBackend;
+
+/// The y value of each series at a cursor, `None` for the series that don't span it
+type CursorValues = Vec<Option<f64>>;
+
+impl<'a, DB: DrawingBackend, X, Y> ChartContext<'a, DB, Cartesian2d<X, Y>>
+where
+    X: Ranged<ValueType = f64>,
+    Y: Ranged<ValueType = f64>,
+{
+    /**
+    Draws a cursor at an x value, like the crosshair readout of an interactive plot: a
+    vertical line across the chart, and on each series a dot where the line crosses it, with
+    the y value of the series there, interpolated between its points, written next to it.
+
+    - `x`: The x value of the cursor
+    - `series`: The points of each series, with the style of its dot; the value takes the
+      color of the style
+    - `style`: The style of the vertical line
+    - **returns** The y value of each series at the cursor, or `None` for the series that
+      don't span it, which have no dot
+
+    See [`DualCoordChartContext::draw_dual_cursor`] for a chart with a secondary axis.
+    */
+    pub fn draw_cursor<S: Into<ShapeStyle>>(
+        &self,
+        x: f64,
+        series: &[(&[(f64, f64)], ShapeStyle)],
+        style: S,
+    ) -> Result<CursorValues, DrawingAreaErrorKind<DB::ErrorType>> {
+        self.reference_lines([x], [], style)?;
+        self.annotate_cursor(x, series)
+    }
+
+    // Draw the dots and the values of the series at the x of a cursor
+    fn annotate_cursor(
+        &self,
+        x: f64,
+        series: &[(&[(f64, f64)], ShapeStyle)],
+    ) -> Result<CursorValues, DrawingAreaErrorKind<DB::ErrorType>> {
+        let mut ret = vec![];
+        for (points, style) in series {
+            let y =
+                Some(resample_to(points, &[x], Extrapolation::Nan)[0].1).filter(|y| !y.is_nan());
+            if let Some(y) = y {
+                let font: TextStyle = ("sans-serif", 12).into_font().color(&style.color);
+                self.drawing_area.draw(
+                    &(EmptyElement::at((x, y))
+                        + Circle::new((0, 0), 3, style.filled())
+                        + Text::new(
+                            format!("{:.2}", y),
+                            (6, 0),
+                            font.pos(Pos::new(HPos::Left, VPos::Center)),
+                        )),
+                )?;
+            }
+            ret.push(y);
+        }
+        Ok(ret)
+    }
+}
+
+impl<'a, DB: DrawingBackend, X, Y, SX, SY>
+    DualCoordChartContext<'a, DB, Cartesian2d<X, Y>, Cartesian2d<SX, SY>>
+where
+    X: Ranged<ValueType = f64>,
+    Y: Ranged<ValueType = f64>,
+    SX: Ranged<ValueType = f64>,
+    SY: Ranged<ValueType = f64>,
+{
+    /**
+    Draws a cursor at an x value across both coordinate systems of the chart, like
+    [`ChartContext::draw_cursor`]: the series of the primary and of the secondary axes are
+    read at the same x and dotted in their own coordinates, so that the readouts stay in
+    sync. The x axes of both coordinate systems are expected to share their values.
+
+    - `x`: The x value of the cursor
+    - `primary`, `secondary`: The points of the series of each coordinate system, with the
+      style of their dots
+    - `style`: The style of the vertical line
+    - **returns** The y values of the primary series then of the secondary series at the
+      cursor, `None` for the series that don't span it
+    */
+    pub fn draw_dual_cursor<S: Into<ShapeStyle>>(
+        &self,
+        x: f64,
+        primary: &[(&[(f64, f64)], ShapeStyle)],
+        secondary: &[(&[(f64, f64)], ShapeStyle)],
+        style: S,
+    ) -> Result<(CursorValues, CursorValues), DrawingAreaErrorKind<DB::ErrorType>> {
+        let primary = self.draw_cursor(x, primary, style)?;
+        let secondary = self.borrow_secondary().annotate_cursor(x, secondary)?;
+        Ok((primary, secondary))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_draw_cursor() {
+        let paths = Rc::new(RefCell::new(vec![]));
+        let circles = Rc::new(RefCell::new(vec![]));
+        let texts = Rc::new(RefCell::new(vec![]));
+        let (paths_out, circles_out, texts_out) = (paths.clone(), circles.clone(), texts.clone());
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_path(move |c, _, p| paths_out.borrow_mut().push((c, p)));
+            m.check_draw_circle(move |c, _, _, center, _| {
+                circles_out.borrow_mut().push((c, center))
+            });
+            m.check_draw_text(move |c, _, _, pos, text| {
+                texts_out.borrow_mut().push((c, pos, text.to_string()))
+            });
+        });
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Build chart error");
+
+        let rising = [(0.0, 0.0), (4.0, 2.0), (8.0, 8.0)];
+        let late = [(6.0, 1.0), (9.0, 4.0)];
+        let values = chart
+            .draw_cursor(5.0, &[(&rising, RED.into()), (&late, BLUE.into())], BLACK)
+            .expect("Drawing Error");
+        assert_eq!(values, vec![Some(3.5), None]);
+
+        let c = |x: f64, y: f64| chart.backend_coord(&(x, y));
+        assert_eq!(
+            *paths.borrow(),
+            vec![(BLACK.to_rgba(), vec![c(5.0, 0.0), c(5.0, 10.0)])]
+        );
+        assert_eq!(*circles.borrow(), vec![(RED.to_rgba(), c(5.0, 3.5))]);
+        let (x, y) = c(5.0, 3.5);
+        let texts = texts.borrow();
+        assert_eq!(texts.len(), 1);
+        assert_eq!((texts[0].0, texts[0].2.as_str()), (RED.to_rgba(), "3.50"));
+        assert!(texts[0].1 .0 >= x + 6 && (texts[0].1 .1 - y).abs() <= 8);
+    }
+
+    #[test]
+    fn test_draw_dual_cursor() {
+        let circles = Rc::new(RefCell::new(vec![]));
+        let texts = Rc::new(RefCell::new(vec![]));
+        let (circles_out, texts_out) = (circles.clone(), texts.clone());
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_circle(move |_, _, _, center, _| circles_out.borrow_mut().push(center));
+            m.check_draw_text(move |_, _, _, _, text| {
+                texts_out.borrow_mut().push(text.to_string())
+            });
+        });
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Build chart error")
+            .set_secondary_coord(0.0..10.0, 0.0..1000.0);
+
+        let temperature = [(0.0, 2.0), (10.0, 6.0)];
+        let pressure = [(0.0, 100.0), (10.0, 900.0)];
+        let (primary, secondary) = chart
+            .draw_dual_cursor(
+                2.5,
+                &[(&temperature, RED.into())],
+                &[(&pressure, BLUE.into())],
+                BLACK,
+            )
+            .expect("Drawing Error");
+        assert_eq!((primary, secondary), (vec![Some(3.0)], vec![Some(300.0)]));
+
+        // Both dots are on the cursor, each in its own coordinates
+        let center = chart.backend_coord(&(2.5, 3.0));
+        let secondary_center = chart.borrow_secondary().backend_coord(&(2.5, 300.0));
+        assert_eq!(*circles.borrow(), vec![center, secondary_center]);
+        assert_eq!(*texts.borrow(), vec!["3.00", "300.00"]);
+    }
+}
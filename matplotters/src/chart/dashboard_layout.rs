@@ -0,0 +1,237 @@
+use crate::coord::Shift;
+use crate::drawing::{DrawingArea, DrawingAreaErrorKind};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::TextStyle;
+
+use plotters_backend::DrawingBackend;
+
+/// The areas of a dashboard figure, see [`DashboardLayout`]
+pub struct DashboardAreas<DB: DrawingBackend> {
+    /// The title band, across the top of the figure
+    pub title: DrawingArea<DB, Shift>,
+    /// The area of the main chart, below the title band, on the left
+    pub chart: DrawingArea<DB, Shift>,
+    /// The legend strip, right of the main chart
+    pub legend: DrawingArea<DB, Shift>,
+    /// The colorbar strip, right of the legend strip
+    pub colorbar: DrawingArea<DB, Shift>,
+}
+
+/**
+The layout of a dashboard figure: a title band, a main chart, a legend strip and a colorbar
+strip, arranged on a single drawing area without any manual area math.
+
+The title band spans the top of the figure, and its height is a fraction of the height of
+the figure. The rest of the figure is split, from left to right, into the main chart, the
+legend strip and the colorbar strip, whose widths are fractions of the width of the figure;
+the main chart takes whatever width is left. A strip of size zero is empty, so that a
+figure without a legend or without a colorbar gives all the room to the main chart. The four
+areas tile the figure without overlapping. [`DashboardLayout::split`] only computes the
+areas, while [`DashboardLayout::draw`] also draws the title in its band.
+
+```rust
+use matplotters::prelude::*;
+
+let drawing_area = SVGBackend::new("dashboard.svg", (800, 500)).into_drawing_area();
+let areas = DashboardLayout::new()
+    .title("Sensors")
+    .legend_size(0.2)
+    .draw(&drawing_area, ("sans-serif", 24))
+    .unwrap();
+let mut chart = ChartBuilder::on(&areas.chart).build_cartesian_2d(0.0..1.0, 0.0..1.0).unwrap();
+chart.draw_series(MatLineSeries::new([(0.0, 0.0), (1.0, 1.0)], BLUE)).unwrap();
+```
+*/
+#[derive(Clone, Debug)]
+pub struct DashboardLayout {
+    title: Option<String>,
+    title_size: f64,
+    legend_size: f64,
+    colorbar_size: f64,
+}
+
+impl Default for DashboardLayout {
+    fn default() -> Self {
+        Self {
+            title: None,
+            title_size: 0.1,
+            legend_size: 0.15,
+            colorbar_size: 0.1,
+        }
+    }
+}
+
+impl DashboardLayout {
+    /**
+    Creates a layout with a title band of 10% of the height of the figure, a legend strip of
+    15% of its width and a colorbar strip of 10% of its width.
+
+    See [`DashboardLayout`] for more information.
+    */
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /**
+    Sets the title drawn by [`DashboardLayout::draw`], centered in the title band.
+
+    See [`DashboardLayout`] for more information.
+    */
+    pub fn title<S: Into<String>>(mut self, title: S) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /**
+    Sets the height of the title band, as a fraction of the height of the figure. The
+    fraction is clamped to `[0, 1]`.
+
+    See [`DashboardLayout`] for more information.
+    */
+    pub fn title_size(mut self, fraction: f64) -> Self {
+        self.title_size = fraction.clamp(0.0, 1.0);
+        self
+    }
+
+    /**
+    Sets the width of the legend strip, as a fraction of the width of the figure. The
+    fraction is clamped to `[0, 1]`.
+
+    See [`DashboardLayout`] for more information.
+    */
+    pub fn legend_size(mut self, fraction: f64) -> Self {
+        self.legend_size = fraction.clamp(0.0, 1.0);
+        self
+    }
+
+    /**
+    Sets the width of the colorbar strip, as a fraction of the width of the figure. The
+    fraction is clamped to `[0, 1]`, and to what the legend strip leaves.
+
+    See [`DashboardLayout`] for more information.
+    */
+    pub fn colorbar_size(mut self, fraction: f64) -> Self {
+        self.colorbar_size = fraction.clamp(0.0, 1.0);
+        self
+    }
+
+    /**
+    Splits the area into the title band, the main chart, the legend strip and the colorbar
+    strip.
+
+    See [`DashboardLayout`] for more information.
+    */
+    pub fn split<DB: DrawingBackend>(&self, area: &DrawingArea<DB, Shift>) -> DashboardAreas<DB> {
+        let (w, h) = area.dim_in_pixel();
+        let fraction = |along: u32, size: f64| (f64::from(along) * size).round() as u32;
+        let (title, body) = area.split_vertically(fraction(h, self.title_size).min(h));
+        let legend = fraction(w, self.legend_size).min(w);
+        let colorbar = fraction(w, self.colorbar_size).min(w - legend);
+        let (chart, strips) = body.split_horizontally(w - legend - colorbar);
+        let (legend, colorbar) = strips.split_horizontally(legend);
+        DashboardAreas {
+            title,
+            chart,
+            legend,
+            colorbar,
+        }
+    }
+
+    /**
+    Draws the title, if any, centered in the title band, and returns the areas of the
+    figure.
+
+    See [`DashboardLayout`] for more information.
+    */
+    pub fn draw<'a, DB: DrawingBackend, S: Into<TextStyle<'a>>>(
+        &self,
+        area: &DrawingArea<DB, Shift>,
+        style: S,
+    ) -> Result<DashboardAreas<DB>, DrawingAreaErrorKind<DB::ErrorType>> {
+        let areas = self.split(area);
+        if let Some(title) = &self.title {
+            let (w, h) = areas.title.dim_in_pixel();
+            let style = style.into().pos(Pos::new(HPos::Center, VPos::Center));
+            areas
+                .title
+                .draw_text(title, &style, (w as i32 / 2, h as i32 / 2))?;
+        }
+        Ok(areas)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_dashboard_layout() {
+        let drawing_area = create_mocked_drawing_area(1000, 500, |_| {});
+        let areas = DashboardLayout::new()
+            .title_size(0.2)
+            .legend_size(0.15)
+            .colorbar_size(0.05)
+            .split(&drawing_area);
+
+        let rect = |area: &DrawingArea<_, _>| {
+            let (x, y) = area.get_base_pixel();
+            let (w, h) = area.dim_in_pixel();
+            (x, y, x + w as i32, y + h as i32)
+        };
+        // The regions are in the configured proportions
+        assert_eq!(rect(&areas.title), (0, 0, 1000, 100));
+        assert_eq!(rect(&areas.chart), (0, 100, 800, 500));
+        assert_eq!(rect(&areas.legend), (800, 100, 950, 500));
+        assert_eq!(rect(&areas.colorbar), (950, 100, 1000, 500));
+
+        // They tile the figure: every pixel is in exactly one of them
+        let regions = [&areas.title, &areas.chart, &areas.legend, &areas.colorbar].map(rect);
+        let area: i32 = regions
+            .iter()
+            .map(|(x0, y0, x1, y1)| (x1 - x0) * (y1 - y0))
+            .sum();
+        assert_eq!(area, 1000 * 500);
+        for (i, a) in regions.iter().enumerate() {
+            for b in &regions[i + 1..] {
+                assert!(a.2 <= b.0 || b.2 <= a.0 || a.3 <= b.1 || b.3 <= a.1);
+            }
+        }
+
+        // Oversized strips are clamped to the width of the figure
+        let areas = DashboardLayout::new()
+            .legend_size(0.7)
+            .colorbar_size(0.5)
+            .split(&drawing_area);
+        assert_eq!(areas.chart.dim_in_pixel(), (0, 450));
+        assert_eq!(areas.legend.dim_in_pixel(), (700, 450));
+        assert_eq!(areas.colorbar.dim_in_pixel(), (300, 450));
+    }
+
+    #[test]
+    fn test_dashboard_title() {
+        let texts = Rc::new(RefCell::new(vec![]));
+        let texts_out = texts.clone();
+        let drawing_area = create_mocked_drawing_area(600, 400, |m| {
+            m.check_draw_text(move |_, _, _, pos, text| {
+                texts_out.borrow_mut().push((pos, text.to_string()))
+            });
+        });
+        let areas = DashboardLayout::new()
+            .title("Sensors")
+            .legend_size(0.0)
+            .colorbar_size(0.0)
+            .draw(&drawing_area, ("sans-serif", 20))
+            .expect("Drawing Error");
+
+        // Without strips the main chart takes the whole width
+        assert_eq!(areas.chart.dim_in_pixel(), (600, 360));
+        let texts = texts.borrow();
+        assert_eq!(texts.len(), 1);
+        assert_eq!(texts[0].1, "Sensors");
+        // The title is centered in its band
+        let (x, y) = texts[0].0;
+        assert!((x - 300).abs() <= 60 && (0..40).contains(&y));
+    }
+}
@@ -4,7 +4,7 @@ use std::ops::{Deref, DerefMut};
 use std::sync::Arc;
 
 use super::mesh::SecondaryMeshStyle;
-use super::{ChartContext, ChartState, SeriesAnno};
+use super::{ChartContext, ChartState, DataDensity, SeriesAnno};
 
 use crate::coord::cartesian::Cartesian2d;
 use crate::coord::ranged1d::{Ranged, ValueFormatter};
@@ -122,6 +122,10 @@ impl<'a, DB: DrawingBackend, CT1: CoordTranslate, CT2: CoordTranslate>
                 drawing_area: secondary_drawing_area,
                 series_anno: vec![],
                 drawing_area_pos: (0, 0),
+                clip_inset: 0,
+                data_density: DataDensity::default(),
+                abbreviated_labels: vec![],
+                annotation_layer: vec![],
             },
         }
     }
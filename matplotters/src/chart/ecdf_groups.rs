@@ -0,0 +1,91 @@
+use super::ChartContext;
+
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::Ranged;
+use crate::drawing::DrawingAreaErrorKind;
+use crate::series::MatEcdf;
+
+use plotters_backend::DrawingBackend;
+
+impl<'a, DB: DrawingBackend + 'a, X, Y> ChartContext<'a, DB, Cartesian2d<X, Y>>
+where
+    X: Ranged<ValueType = f64>,
+    Y: Ranged<ValueType = f64>,
+{
+    /**
+    Draws the empirical CDFs of several groups, such as the ones of [`MatEcdf::by_group`], each
+    labeled with its group, so that the distributions are compared with one legend entry per
+    group.
+
+    See [`MatEcdf`] for more information.
+    */
+    pub fn draw_ecdf_groups<I>(
+        &mut self,
+        groups: I,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        I: IntoIterator<Item = MatEcdf<DB>>,
+    {
+        for ecdf in groups {
+            let group = ecdf.group().unwrap_or_default().to_string();
+            self.draw_styled_series(ecdf)?.label(group);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_draw_ecdf_groups() {
+        let paths = Rc::new(RefCell::new(vec![]));
+        let texts = Rc::new(RefCell::new(vec![]));
+        let (paths_out, texts_out) = (paths.clone(), texts.clone());
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_path(move |c, _, p| paths_out.borrow_mut().push((c, p)));
+            m.check_draw_text(move |_, _, _, _, t| texts_out.borrow_mut().push(t.to_string()));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..1.0)
+            .expect("Build chart error");
+
+        let samples = [1.0, 6.0, 3.0, 7.0, 5.0, 8.0, 2.0, 9.0];
+        let groups = ["low", "high", "low", "high", "low", "high", "low", "high"];
+        let ecdfs = MatEcdf::by_group(samples, groups);
+        assert_eq!(ecdfs.len(), 2);
+        assert_eq!(ecdfs[0].group(), Some("low"));
+        assert_eq!(
+            ecdfs[0].values(),
+            vec![(1.0, 0.25), (2.0, 0.5), (3.0, 0.75), (5.0, 1.0)]
+        );
+        assert_eq!(
+            ecdfs[1].values(),
+            vec![(6.0, 0.25), (7.0, 0.5), (8.0, 0.75), (9.0, 1.0)]
+        );
+        chart.draw_ecdf_groups(ecdfs).expect("Drawing Error");
+        chart
+            .configure_series_labels()
+            .draw()
+            .expect("Drawing Error");
+
+        let mut cycle = ColorCycle::default();
+        let colors = [cycle.next_color().to_rgba(), cycle.next_color().to_rgba()];
+        let paths = paths.borrow();
+        // The two step curves, then the two legend lines
+        assert_eq!(paths.len(), 4);
+        for (i, (c, path)) in paths.iter().take(2).enumerate() {
+            assert_eq!(*c, colors[i]);
+            let (first, last) = if i == 0 { (1.0, 5.0) } else { (6.0, 9.0) };
+            assert_eq!(path[0], chart.backend_coord(&(first, 0.0)));
+            assert_eq!(*path.last().unwrap(), chart.backend_coord(&(last, 1.0)));
+            assert_eq!(path.len(), 8);
+        }
+        assert_eq!(paths[2].0, colors[0]);
+        assert_eq!(paths[3].0, colors[1]);
+        assert_eq!(*texts.borrow(), vec!["low", "high"]);
+    }
+}
@@ -0,0 +1,113 @@
+use super::ChartContext;
+
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::Ranged;
+use crate::drawing::DrawingAreaErrorKind;
+use crate::element::Rectangle;
+use crate::style::ShapeStyle;
+
+use plotters_backend::DrawingBackend;
+use std::ops::Range;
+
+impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesian2d<X, Y>>
+where
+    Y::ValueType: Clone,
+{
+    /**
+    Emphasizes an x range by dimming the rest of the chart: a semi-transparent overlay is drawn
+    over the plotting area on both sides of the focus range, which is left clear. Draw it after
+    the series, so that the overlay covers them.
+
+    - `focus`: The x range to leave clear
+    - `style`: The style of the overlay, usually a translucent white or gray filled color
+    */
+    pub fn dim_outside_x<S: Into<ShapeStyle>>(
+        &self,
+        focus: Range<X::ValueType>,
+        style: S,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let style = style.into();
+        let y = self.y_range().start;
+        let (width, height) = self.drawing_area.dim_in_pixel();
+        let (width, height) = (width as i32, height as i32);
+        let base_x = self.drawing_area.get_base_pixel().0;
+        let edge =
+            |x| (self.drawing_area.map_coordinate(&(x, y.clone())).0 - base_x).clamp(0, width);
+        let (a, b) = (edge(focus.start), edge(focus.end));
+        let area = self.drawing_area.strip_coord_spec();
+        for (left, right) in [(0, a.min(b)), (a.max(b), width)] {
+            if left < right {
+                area.draw(&Rectangle::new([(left, 0), (right, height)], style))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_dim_outside_x() {
+        let rects = Rc::new(RefCell::new(vec![]));
+        let rects_out = rects.clone();
+        let drawing_area = create_mocked_drawing_area(501, 301, |m| {
+            m.check_draw_rect(move |c, _, filled, ul, br| {
+                if filled && c == WHITE.mix(0.7).to_rgba() {
+                    rects_out.borrow_mut().push((ul, br));
+                }
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .margin(10)
+            .x_label_area_size(30)
+            .build_cartesian_2d(0.0..10.0, 0.0..5.0)
+            .expect("Build chart error");
+        chart
+            .draw_series(LineSeries::new((0..=10).map(|x| (x as f64, 2.0)), &RED))
+            .expect("Drawing Error");
+        chart
+            .dim_outside_x(3.0..6.0, WHITE.mix(0.7).filled())
+            .expect("Drawing Error");
+
+        // The overlay covers the plot on both sides of the focus range, and nothing inside
+        let (left, top) = chart.backend_coord(&(0.0, 5.0));
+        let (right, bottom) = chart.backend_coord(&(10.0, 0.0));
+        let (start, end) = (
+            chart.backend_coord(&(3.0, 0.0)).0,
+            chart.backend_coord(&(6.0, 0.0)).0,
+        );
+        let rects = rects.borrow();
+        assert_eq!(rects.len(), 2);
+        assert_eq!(rects[0].0, (left, top));
+        assert_eq!(rects[0].1 .0, start);
+        assert_eq!(rects[1].0 .0, end);
+        for (ul, br) in rects.iter() {
+            assert!(ul.1 <= top && br.1 >= bottom);
+            assert!(br.0 <= start || ul.0 >= end);
+        }
+        assert!(rects[1].1 .0 >= right);
+    }
+
+    #[test]
+    fn test_dim_outside_x_at_edge() {
+        let rects = Rc::new(RefCell::new(vec![]));
+        let rects_out = rects.clone();
+        let drawing_area = create_mocked_drawing_area(501, 301, |m| {
+            m.check_draw_rect(move |_, _, _, ul, br| rects_out.borrow_mut().push((ul, br)));
+        });
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..5.0)
+            .expect("Build chart error");
+        // A focus range reaching past the chart leaves only one side dimmed
+        chart
+            .dim_outside_x(-5.0..4.0, BLACK.mix(0.3).filled())
+            .expect("Drawing Error");
+        let rects = rects.borrow();
+        assert_eq!(rects.len(), 1);
+        assert_eq!(rects[0].0 .0, chart.backend_coord(&(4.0, 0.0)).0);
+    }
+}
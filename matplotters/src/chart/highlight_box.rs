@@ -0,0 +1,105 @@
+use super::ChartContext;
+
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::Ranged;
+use crate::drawing::DrawingAreaErrorKind;
+use crate::element::Rectangle;
+use crate::style::ShapeStyle;
+
+use plotters_backend::{BackendCoord, DrawingBackend};
+
+impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesian2d<X, Y>> {
+    /**
+    Draws a colored box behind the data region of a series, the bounding box of its points
+    grown by `padding` pixels on every side, to make the series stand out on a busy chart.
+    Draw it before the series, so that it stays in the background.
+
+    - `points`: The points of the series to highlight
+    - `padding`: The space between the points and the sides of the box, in pixels
+    - `style`: The style of the box, usually a light filled color
+    - **returns** The upper left and the lower right corners of the box in backend
+      coordinates, or `None` if there are no points and nothing was drawn
+    */
+    pub fn highlight_box<I, S>(
+        &self,
+        points: I,
+        padding: i32,
+        style: S,
+    ) -> Result<Option<(BackendCoord, BackendCoord)>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        I: IntoIterator<Item = (X::ValueType, Y::ValueType)>,
+        S: Into<ShapeStyle>,
+    {
+        let bounds = points
+            .into_iter()
+            .map(|p| self.drawing_area.map_coordinate(&p))
+            .fold(
+                None,
+                |bounds: Option<(BackendCoord, BackendCoord)>, (x, y)| {
+                    Some(match bounds {
+                        Some(((x0, y0), (x1, y1))) => {
+                            ((x0.min(x), y0.min(y)), (x1.max(x), y1.max(y)))
+                        }
+                        None => ((x, y), (x, y)),
+                    })
+                },
+            );
+        let ((x0, y0), (x1, y1)) = match bounds {
+            Some(bounds) => bounds,
+            None => return Ok(None),
+        };
+        let (upper_left, lower_right) =
+            ((x0 - padding, y0 - padding), (x1 + padding, y1 + padding));
+
+        let (base_x, base_y) = self.drawing_area.get_base_pixel();
+        self.drawing_area.strip_coord_spec().draw(&Rectangle::new(
+            [
+                (upper_left.0 - base_x, upper_left.1 - base_y),
+                (lower_right.0 - base_x, lower_right.1 - base_y),
+            ],
+            style,
+        ))?;
+        Ok(Some((upper_left, lower_right)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_highlight_box() {
+        let rects = Rc::new(RefCell::new(vec![]));
+        let rects_out = rects.clone();
+        let drawing_area = create_mocked_drawing_area(501, 301, |m| {
+            m.check_draw_rect(move |c, _, filled, ul, br| {
+                assert_eq!(c, YELLOW.mix(0.3).to_rgba());
+                assert!(filled);
+                rects_out.borrow_mut().push((ul, br));
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_rect_call, 1));
+        });
+        let chart = ChartBuilder::on(&drawing_area)
+            .margin(20)
+            .x_label_area_size(30)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Build chart error");
+
+        let points = [(2.0, 3.0), (4.0, 7.5), (6.5, 5.0)];
+        let drawn = chart
+            .highlight_box(points, 8, YELLOW.mix(0.3).filled())
+            .expect("Drawing Error");
+
+        let (left, top) = chart.backend_coord(&(2.0, 7.5));
+        let (right, bottom) = chart.backend_coord(&(6.5, 3.0));
+        let expected = ((left - 8, top - 8), (right + 8, bottom + 8));
+        assert_eq!(drawn, Some(expected));
+        assert_eq!(*rects.borrow(), vec![expected]);
+
+        let none: [(f64, f64); 0] = [];
+        let drawn = chart.highlight_box(none, 8, RED).expect("Drawing Error");
+        assert_eq!(drawn, None);
+    }
+}
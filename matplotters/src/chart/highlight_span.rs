@@ -0,0 +1,192 @@
+use super::ChartContext;
+
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::Ranged;
+use crate::drawing::DrawingAreaErrorKind;
+use crate::element::{EmptyElement, PathElement, Rectangle, Text};
+use crate::style::{Color, FontDesc, FontFamily, FontStyle, ShapeStyle, BLACK, BLUE};
+
+use plotters_backend::DrawingBackend;
+
+/**
+A shaded vertical span between two x values, like matplotlib's `axvspan`, used to annotate an
+interval such as a recession period on a time series.
+
+The span covers the full y range of the chart. Optionally, vertical lines are drawn at the
+boundaries, with a label next to each of them. It is drawn with
+[`ChartContext::highlight_span()`].
+*/
+pub struct HighlightSpan<X> {
+    start: X,
+    end: X,
+    style: ShapeStyle,
+    boundary_style: Option<ShapeStyle>,
+    labels: Option<(String, String)>,
+}
+
+impl<X> HighlightSpan<X> {
+    /**
+    Creates a span from `start` to `end`, shaded in blue at 20% opacity.
+
+    See [`HighlightSpan`] for more information.
+    */
+    pub fn new(start: X, end: X) -> Self {
+        Self {
+            start,
+            end,
+            style: BLUE.mix(0.2).filled(),
+            boundary_style: None,
+            labels: None,
+        }
+    }
+
+    /**
+    Sets the style of the shaded span.
+
+    See [`HighlightSpan`] for more information.
+    */
+    pub fn style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /**
+    Draws vertical lines at the boundaries of the span, with the given style.
+
+    See [`HighlightSpan`] for more information.
+    */
+    pub fn boundaries<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.boundary_style = Some(style.into());
+        self
+    }
+
+    /**
+    Sets the labels drawn at the top of the boundaries, such as the names of the events. The
+    boundary lines are drawn in black if no style is set.
+
+    See [`HighlightSpan`] for more information.
+    */
+    pub fn labels<S: Into<String>, E: Into<String>>(mut self, start: S, end: E) -> Self {
+        self.labels = Some((start.into(), end.into()));
+        self.boundary_style.get_or_insert_with(|| BLACK.into());
+        self
+    }
+}
+
+impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesian2d<X, Y>>
+where
+    X::ValueType: Clone,
+    Y::ValueType: Clone,
+{
+    /**
+    Shades the vertical span between two x values across the full y range of the chart.
+
+    See [`HighlightSpan`] for more information.
+    */
+    pub fn highlight_span(
+        &self,
+        span: HighlightSpan<X::ValueType>,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let y = self.y_range();
+        let area = &self.drawing_area;
+        area.draw(&Rectangle::new(
+            [
+                (span.start.clone(), y.end.clone()),
+                (span.end.clone(), y.start.clone()),
+            ],
+            span.style,
+        ))?;
+
+        let style = match span.boundary_style {
+            Some(style) => style,
+            None => return Ok(()),
+        };
+        let font = FontDesc::new(FontFamily::SansSerif, 12.0, FontStyle::Normal);
+        let labels = span.labels.map(|(s, e)| [s, e]);
+        for (idx, x) in [span.start, span.end].into_iter().enumerate() {
+            area.draw(&PathElement::new(
+                vec![(x.clone(), y.start.clone()), (x.clone(), y.end.clone())],
+                style,
+            ))?;
+            if let Some(labels) = &labels {
+                area.draw(
+                    &(EmptyElement::at((x, y.end.clone()))
+                        + Text::new(labels[idx].clone(), (3, 3), font.color(&style.color))),
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_highlight_span() {
+        let rects = Rc::new(RefCell::new(vec![]));
+        let paths = Rc::new(RefCell::new(vec![]));
+        let texts = Rc::new(RefCell::new(vec![]));
+        let (rects_out, paths_out, texts_out) = (rects.clone(), paths.clone(), texts.clone());
+        let drawing_area = create_mocked_drawing_area(501, 301, |m| {
+            m.check_draw_rect(move |c, _, f, ul, br| rects_out.borrow_mut().push((c, f, ul, br)));
+            m.check_draw_path(move |_, _, p| paths_out.borrow_mut().push(p));
+            m.check_draw_text(move |_, _, _, pos, t| {
+                texts_out.borrow_mut().push((pos, t.to_string()))
+            });
+        });
+
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, -5.0..5.0)
+            .expect("Build chart error");
+        chart
+            .highlight_span(
+                HighlightSpan::new(2.0, 6.0)
+                    .style(GREEN.mix(0.3).filled())
+                    .labels("start", "end"),
+            )
+            .expect("Drawing Error");
+
+        let rects = rects.borrow();
+        assert_eq!(rects.len(), 1);
+        let (c, filled, ul, br) = rects[0];
+        assert_eq!(c, GREEN.mix(0.3).to_rgba());
+        assert!(filled);
+        assert_eq!(ul, chart.backend_coord(&(2.0, 5.0)));
+        assert_eq!(br, chart.backend_coord(&(6.0, -5.0)));
+        // The span covers the full plotting area vertically
+        assert_eq!((ul.1, br.1), (0, 300));
+
+        let paths = paths.borrow();
+        assert_eq!(paths.len(), 2);
+        for (path, x) in paths.iter().zip([2.0, 6.0]) {
+            assert_eq!(path[0], chart.backend_coord(&(x, -5.0)));
+            assert_eq!(path[1], chart.backend_coord(&(x, 5.0)));
+        }
+
+        let texts = texts.borrow();
+        assert_eq!(texts.len(), 2);
+        assert_eq!(texts[0].1, "start");
+        assert_eq!(texts[0].0 .0, chart.backend_coord(&(2.0, 5.0)).0 + 3);
+        assert_eq!(texts[1].1, "end");
+    }
+
+    #[test]
+    fn test_highlight_span_without_boundaries() {
+        let drawing_area = create_mocked_drawing_area(200, 200, |m| {
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 1);
+                assert_eq!(b.num_draw_path_call, 0);
+            });
+        });
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Build chart error");
+        chart
+            .highlight_span(HighlightSpan::new(3, 4))
+            .expect("Drawing Error");
+    }
+}
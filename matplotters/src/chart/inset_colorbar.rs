@@ -0,0 +1,127 @@
+use super::{ChartBuilder, ChartContext, LabelAreaPosition, SeriesLabelPosition};
+
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::Ranged;
+use crate::drawing::DrawingAreaErrorKind;
+use crate::element::Rectangle;
+use crate::series::MatColorbar;
+use crate::style::{IntoFont, ShapeStyle};
+
+use plotters_backend::{BackendCoord, DrawingBackend};
+
+impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesian2d<X, Y>> {
+    /**
+    Draws a small vertical colorbar inside the plotting area, instead of next to the chart
+    like [`crate::chart::ColorbarLayout`], for figures without room for a margin. The
+    colorbar and its tick labels are drawn in a box over a background, usually a
+    semi-transparent white, so that they stay legible over the data. The bar takes the left
+    third of the box, and the tick labels the rest. The triangles of the extended ends of
+    the colorbar are drawn too.
+
+    - `colorbar`: The colorbar to draw
+    - `position`: The position of the box in the plotting area, laid out like the series
+      labels; [`SeriesLabelPosition::Best`] is taken as the upper right corner
+    - `size`: The width and the height of the box, in pixels
+    - `background`: The style of the background of the box
+    - **returns** The upper left and the lower right corners of the box in backend
+      coordinates
+    */
+    pub fn draw_inset_colorbar<S: Into<ShapeStyle>>(
+        &self,
+        colorbar: MatColorbar,
+        position: SeriesLabelPosition,
+        size: (u32, u32),
+        background: S,
+    ) -> Result<(BackendCoord, BackendCoord), DrawingAreaErrorKind<DB::ErrorType>> {
+        let position = match position {
+            SeriesLabelPosition::Best => SeriesLabelPosition::UpperRight,
+            position => position,
+        };
+        let area = self.drawing_area.strip_coord_spec();
+        let dim = (size.0 as i32, size.1 as i32);
+        let (x, y) = position.layout_label_area(dim, area.dim_in_pixel());
+        area.draw(&Rectangle::new(
+            [(x, y), (x + dim.0, y + dim.1)],
+            background,
+        ))?;
+
+        let inset = area.shrink((x, y), size).margin(5, 5, 5, 5);
+        let label_area_size = inset.dim_in_pixel().0 * 2 / 3;
+        let range = colorbar.range();
+        let extensions = colorbar.extensions();
+        let (under, over) = colorbar.extension_margins(inset.dim_in_pixel().1);
+        let mut chart = ChartBuilder::on(&inset)
+            .margin_bottom(under)
+            .margin_top(over)
+            .set_label_area_size(LabelAreaPosition::Right, label_area_size)
+            .build_cartesian_2d(0.0..1.0, range)?;
+        chart
+            .configure_mesh()
+            .disable_mesh()
+            .disable_x_axis()
+            .y_labels(5)
+            .label_style(("sans-serif", 10).into_font())
+            .draw()?;
+        chart.draw_series(colorbar)?;
+        super::colorbar_layout::draw_extensions(&chart, &inset, extensions)?;
+
+        let (base_x, base_y) = self.drawing_area.get_base_pixel();
+        Ok((
+            (base_x + x, base_y + y),
+            (base_x + x + dim.0, base_y + y + dim.1),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_inset_colorbar() {
+        let rects = Rc::new(RefCell::new(vec![]));
+        let rects_out = rects.clone();
+        let drawing_area = create_mocked_drawing_area(801, 601, |m| {
+            m.check_draw_rect(move |c, _, filled, ul, br| {
+                if filled {
+                    rects_out.borrow_mut().push((c, ul, br));
+                }
+            });
+        });
+        let chart = ChartBuilder::on(&drawing_area)
+            .margin(10)
+            .x_label_area_size(30)
+            .y_label_area_size(40)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Build chart error");
+
+        let colorbar = MatColorbar::new(&ViridisRGB {}, Norm::new(0.0, 1.0), 8);
+        let (ul, br) = chart
+            .draw_inset_colorbar(
+                colorbar,
+                SeriesLabelPosition::LowerLeft,
+                (60, 150),
+                WHITE.mix(0.7).filled(),
+            )
+            .expect("Drawing Error");
+
+        // The box is in the lower left corner of the plotting area, 5 pixels from its sides
+        let (px, py) = chart.plotting_area().get_pixel_range();
+        assert_eq!(ul, (px.start + 5, py.end - 150 - 5));
+        assert_eq!(br, (ul.0 + 60, ul.1 + 150));
+
+        let rects = rects.borrow();
+        assert_eq!(rects.len(), 1 + 8);
+        // The semi-transparent background is drawn first, under the slabs
+        assert_eq!(rects[0].0, WHITE.mix(0.7).to_rgba());
+        assert_eq!((rects[0].1, rects[0].2), (ul, br));
+        for (_, slab_ul, slab_br) in &rects[1..] {
+            assert!(ul.0 < slab_ul.0 && slab_br.0 < br.0);
+            assert!(ul.1 < slab_ul.1 && slab_br.1 < br.1);
+            // The bar keeps the left third of the box
+            assert!(slab_br.0 - ul.0 <= 60 / 3 + 5);
+        }
+    }
+}
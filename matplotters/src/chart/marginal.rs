@@ -0,0 +1,130 @@
+use super::ChartContext;
+
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::Ranged;
+use crate::drawing::DrawingAreaErrorKind;
+use crate::element::Rectangle;
+use crate::style::ShapeStyle;
+
+use plotters_backend::DrawingBackend;
+
+impl<'a, DB: DrawingBackend, X, Y> ChartContext<'a, DB, Cartesian2d<X, Y>>
+where
+    X: Ranged,
+    X::ValueType: Clone,
+    Y: Ranged<ValueType = f64>,
+{
+    /**
+    Draws the distribution of the y values of a series as a histogram strip along the right
+    edge of the chart, like a marginal plot, so that the series and its distribution are
+    seen together. The bins evenly divide the y range of the chart, and the bars grow
+    leftwards from the edge, the fullest bin being `width` pixels long. The values out of
+    the y range, and those that are not finite, are left out.
+
+    - `values`: The y values of the series
+    - `bins`: The number of bins
+    - `width`: The length of the longest bar, in pixels
+    - `style`: The style of the bars, usually a light filled color
+    - **returns** The number of values in each bin, from the bottom to the top
+    */
+    pub fn y_marginal<I, S>(
+        &self,
+        values: I,
+        bins: usize,
+        width: u32,
+        style: S,
+    ) -> Result<Vec<usize>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        I: IntoIterator<Item = f64>,
+        S: Into<ShapeStyle>,
+    {
+        let style = style.into();
+        let bins = bins.max(1);
+        let range = self.y_range();
+        let (low, high) = (range.start.min(range.end), range.start.max(range.end));
+        let mut counts = vec![0; bins];
+        for v in values {
+            if v.is_finite() && low <= v && v <= high && high > low {
+                let bin = ((v - low) / (high - low) * bins as f64) as usize;
+                counts[bin.min(bins - 1)] += 1;
+            }
+        }
+
+        let max = counts.iter().copied().max().unwrap_or(0);
+        let x = self.x_range().start;
+        let base_y = self.drawing_area.get_base_pixel().1;
+        let right = self.drawing_area.dim_in_pixel().0 as i32;
+        let edge = |y: f64| self.drawing_area.map_coordinate(&(x.clone(), y)).1 - base_y;
+        let step = (high - low) / bins as f64;
+        let area = self.drawing_area.strip_coord_spec();
+        for (i, count) in counts.iter().enumerate().filter(|(_, c)| **c > 0) {
+            let length = (f64::from(width) * *count as f64 / max as f64).round() as i32;
+            let (bottom, top) = (low + step * i as f64, low + step * (i + 1) as f64);
+            area.draw(&Rectangle::new(
+                [(right - length, edge(top)), (right, edge(bottom))],
+                style,
+            ))?;
+        }
+        Ok(counts)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_y_marginal() {
+        let rects = Rc::new(RefCell::new(vec![]));
+        let rects_out = rects.clone();
+        let drawing_area = create_mocked_drawing_area(501, 301, |m| {
+            m.check_draw_rect(move |c, _, filled, ul, br| {
+                if filled && c == BLUE.mix(0.3).to_rgba() {
+                    rects_out.borrow_mut().push((ul, br));
+                }
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .margin(10)
+            .y_label_area_size(30)
+            .build_cartesian_2d(0.0..100.0, 0.0..10.0)
+            .expect("Build chart error");
+
+        // A series hovering around y = 7, with a few excursions down to y = 2
+        let series: Vec<(f64, f64)> = (0..100)
+            .map(|i| {
+                let y = if i % 10 == 0 {
+                    2.2
+                } else {
+                    7.0 + 0.4 * ((i % 3) as f64 - 1.0)
+                };
+                (f64::from(i), y)
+            })
+            .collect();
+        chart
+            .draw_series(LineSeries::new(series.clone(), &RED))
+            .expect("Drawing Error");
+        let counts = chart
+            .y_marginal(series.iter().map(|p| p.1), 10, 40, BLUE.mix(0.3).filled())
+            .expect("Drawing Error");
+        assert_eq!(counts.iter().sum::<usize>(), 100);
+        assert_eq!(counts[2], 10);
+        assert_eq!(counts[6] + counts[7], 90);
+
+        // The longest bar spans the concentration of the values, at the right edge
+        let rects = rects.borrow();
+        let longest = rects.iter().max_by_key(|(ul, br)| br.0 - ul.0).unwrap();
+        let right = chart.backend_coord(&(100.0, 7.0)).0;
+        assert!((longest.1 .0 - right).abs() <= 1);
+        assert!((longest.1 .0 - longest.0 .0 - 40).abs() <= 1);
+        let (top, bottom) = if counts[7] > counts[6] {
+            (8.0, 7.0)
+        } else {
+            (7.0, 6.0)
+        };
+        assert_eq!(longest.0 .1, chart.backend_coord(&(0.0, top)).1);
+        assert_eq!(longest.1 .1, chart.backend_coord(&(0.0, bottom)).1);
+    }
+}
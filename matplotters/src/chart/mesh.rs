@@ -4,10 +4,12 @@ use super::builder::LabelAreaPosition;
 use super::context::ChartContext;
 use crate::coord::cartesian::{Cartesian2d, MeshLine};
 use crate::coord::ranged1d::{BoldPoints, LightPoints, Ranged, ValueFormatter};
+use crate::data::float::group_thousands;
+use crate::data::{quantity_desc, Quantity};
 use crate::drawing::DrawingAreaErrorKind;
 use crate::style::{
-    AsRelative, Color, FontDesc, FontFamily, FontStyle, IntoTextStyle, RGBColor, ShapeStyle,
-    SizeDesc, TextStyle,
+    AsRelative, Color, FontDesc, FontFamily, FontStyle, IntoTextStyle, RGBColor, RcParams,
+    ShapeStyle, SizeDesc, TextStyle,
 };
 
 use plotters_backend::DrawingBackend;
@@ -69,6 +71,22 @@ where
         self
     }
 
+    /// Choose the number of labels for the X axis from its length, see
+    /// [`MeshStyle::x_label_spacing`]
+    /// - `pixels`: The desired distance between two consecutive labels
+    pub fn x_label_spacing(&mut self, pixels: u32) -> &mut Self {
+        self.style.x_label_spacing(pixels);
+        self
+    }
+
+    /// Choose the number of labels for the Y axis from its length, see
+    /// [`MeshStyle::y_label_spacing`]
+    /// - `pixels`: The desired distance between two consecutive labels
+    pub fn y_label_spacing(&mut self, pixels: u32) -> &mut Self {
+        self.style.y_label_spacing(pixels);
+        self
+    }
+
     /// Set the formatter function for the X label text
     /// - `fmt`: The formatter function
     pub fn x_label_formatter(&mut self, fmt: &'b dyn Fn(&X::ValueType) -> String) -> &mut Self {
@@ -83,6 +101,21 @@ where
         self
     }
 
+    /// Group the digits of the numeric labels by thousands, see
+    /// [`MeshStyle::thousands_separator`]
+    /// - `separator`: The separator inserted between the groups of digits
+    pub fn thousands_separator(&mut self, separator: char) -> &mut Self {
+        self.style.thousands_separator(separator);
+        self
+    }
+
+    /// Abbreviate the long labels with an ellipsis, see [`MeshStyle::label_max_len`]
+    /// - `chars`: The maximal number of characters of a label
+    pub fn label_max_len(&mut self, chars: usize) -> &mut Self {
+        self.style.label_max_len(chars);
+        self
+    }
+
     /// Set the axis description's style. If not given, use label style instead.
     /// - `style`: The text style that would be applied to descriptions
     pub fn axis_desc_style<T: IntoTextStyle<'b>>(&mut self, style: T) -> &mut Self {
@@ -105,6 +138,22 @@ where
         self
     }
 
+    /// Set the X axis's description from the unit of a quantity type, see
+    /// [`MeshStyle::x_desc_unit`]
+    /// - `name`: The name of the quantity on the X axis
+    pub fn x_desc_unit<Q: Quantity>(&mut self, name: &str) -> &mut Self {
+        self.style.x_desc_unit::<Q>(name);
+        self
+    }
+
+    /// Set the Y axis's description from the unit of a quantity type, see
+    /// [`MeshStyle::y_desc_unit`]
+    /// - `name`: The name of the quantity on the Y axis
+    pub fn y_desc_unit<Q: Quantity>(&mut self, name: &str) -> &mut Self {
+        self.style.y_desc_unit::<Q>(name);
+        self
+    }
+
     /// Draw the axes for the secondary coordinate system
     pub fn draw(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
         self.style.draw()
@@ -146,6 +195,8 @@ pub struct MeshStyle<'a, 'b, X: Ranged, Y: Ranged, DB: DrawingBackend> {
     pub(super) parent_size: (u32, u32),
     pub(super) draw_x_mesh: bool,
     pub(super) draw_y_mesh: bool,
+    pub(super) draw_major_mesh: bool,
+    pub(super) draw_minor_mesh: bool,
     pub(super) draw_x_axis: bool,
     pub(super) draw_y_axis: bool,
     pub(super) x_label_offset: i32,
@@ -154,6 +205,8 @@ pub struct MeshStyle<'a, 'b, X: Ranged, Y: Ranged, DB: DrawingBackend> {
     pub(super) y_light_lines_limit: usize,
     pub(super) n_x_labels: usize,
     pub(super) n_y_labels: usize,
+    pub(super) x_label_spacing: Option<u32>,
+    pub(super) y_label_spacing: Option<u32>,
     pub(super) axis_desc_style: Option<TextStyle<'b>>,
     pub(super) x_desc: Option<String>,
     pub(super) y_desc: Option<String>,
@@ -164,6 +217,8 @@ pub struct MeshStyle<'a, 'b, X: Ranged, Y: Ranged, DB: DrawingBackend> {
     pub(super) y_label_style: Option<TextStyle<'b>>,
     pub(super) format_x: Option<&'b dyn Fn(&X::ValueType) -> String>,
     pub(super) format_y: Option<&'b dyn Fn(&Y::ValueType) -> String>,
+    pub(super) thousands_separator: Option<char>,
+    pub(super) label_max_len: Option<usize>,
     pub(super) target: Option<&'b mut ChartContext<'a, DB, Cartesian2d<X, Y>>>,
     pub(super) _phantom_data: PhantomData<(X, Y)>,
     pub(super) x_tick_size: [i32; 2],
@@ -191,25 +246,32 @@ where
             }
         }
 
+        let grid = RcParams::get().grid;
         MeshStyle {
             parent_size: chart.drawing_area.dim_in_pixel(),
             axis_style: None,
             x_label_offset: 0,
             y_label_offset: 0,
-            draw_x_mesh: true,
-            draw_y_mesh: true,
+            draw_x_mesh: grid,
+            draw_y_mesh: grid,
+            draw_major_mesh: true,
+            draw_minor_mesh: true,
             draw_x_axis: true,
             draw_y_axis: true,
             x_light_lines_limit: 10,
             y_light_lines_limit: 10,
             n_x_labels: 11,
             n_y_labels: 11,
+            x_label_spacing: None,
+            y_label_spacing: None,
             bold_line_style: None,
             light_line_style: None,
             x_label_style: None,
             y_label_style: None,
             format_x: None,
             format_y: None,
+            thousands_separator: None,
+            label_max_len: None,
             target: Some(chart),
             _phantom_data: PhantomData,
             x_desc: None,
@@ -307,6 +369,27 @@ where
         self.disable_x_axis().disable_y_axis()
     }
 
+    /// Disable the major gridlines, the bold lines at the labels, keeping the minor ones.
+    /// The labels and the tick marks are still drawn.
+    pub fn disable_major_mesh(&mut self) -> &mut Self {
+        self.draw_major_mesh = false;
+        self
+    }
+
+    /// Disable the minor gridlines, the light lines between the labels, keeping the major ones
+    pub fn disable_minor_mesh(&mut self) -> &mut Self {
+        self.draw_minor_mesh = false;
+        self
+    }
+
+    /// Draw only the minor gridlines, for a fine grid without the bold lines, like
+    /// [`MeshStyle::disable_major_mesh`]. Along with [`MeshStyle::disable_x_mesh`] and
+    /// [`MeshStyle::disable_y_mesh`], this leaves the minor gridlines of a single axis.
+    pub fn minor_mesh_only(&mut self) -> &mut Self {
+        self.draw_minor_mesh = true;
+        self.disable_major_mesh()
+    }
+
     /// Set the style definition for the axis
     /// - `style`: The style for the axis
     pub fn axis_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
@@ -350,6 +433,30 @@ where
         self
     }
 
+    /// Choose the number of labels for the X axis from its length in pixels instead of
+    /// using a fixed number: one label about every `pixels`, and less if the labels would
+    /// overlap. This overrides [`MeshStyle::x_labels`].
+    /// - `pixels`: The desired distance between two consecutive labels
+    pub fn x_label_spacing(&mut self, pixels: u32) -> &mut Self {
+        self.x_label_spacing = Some(pixels.max(1));
+        self
+    }
+
+    /// Choose the number of labels for the Y axis from its length in pixels, see
+    /// [`MeshStyle::x_label_spacing`]. This overrides [`MeshStyle::y_labels`].
+    /// - `pixels`: The desired distance between two consecutive labels
+    pub fn y_label_spacing(&mut self, pixels: u32) -> &mut Self {
+        self.y_label_spacing = Some(pixels.max(1));
+        self
+    }
+
+    /// Choose the number of labels on both axes from their lengths in pixels, see
+    /// [`MeshStyle::x_label_spacing`]
+    /// - `pixels`: The desired distance between two consecutive labels
+    pub fn label_spacing(&mut self, pixels: u32) -> &mut Self {
+        self.x_label_spacing(pixels).y_label_spacing(pixels)
+    }
+
     /// Set the style for the coarse grind grid
     /// - `style`: This is the coarse grind grid style
     pub fn bold_line_style<T: Into<ShapeStyle>>(&mut self, style: T) -> &mut Self {
@@ -401,6 +508,44 @@ where
         self
     }
 
+    /// Group the digits of the numbers in the labels by thousands, as in `1,234,567`, see
+    /// [`crate::data::float::group_thousands`]
+    /// - `separator`: The separator inserted between the groups of digits
+    pub fn thousands_separator(&mut self, separator: char) -> &mut Self {
+        self.thousands_separator = Some(separator);
+        self
+    }
+
+    /// Abbreviate the labels longer than `chars` characters, such as long category names, to
+    /// their first `chars - 1` characters followed by `…`. The full labels are kept by the chart,
+    /// see [`ChartContext::abbreviated_labels`], to be shown as tooltips in SVG documents.
+    /// - `chars`: The maximal number of characters of a label
+    pub fn label_max_len(&mut self, chars: usize) -> &mut Self {
+        self.label_max_len = Some(chars.max(1));
+        self
+    }
+
+    // The text of a label, with the thousands grouped and abbreviated
+    fn label_text(&self, text: String) -> String {
+        self.abbreviate(self.group_thousands(text))
+    }
+
+    fn group_thousands(&self, text: String) -> String {
+        match self.thousands_separator {
+            Some(separator) => group_thousands(&text, separator),
+            None => text,
+        }
+    }
+
+    fn abbreviate(&self, text: String) -> String {
+        match self.label_max_len {
+            Some(max) if text.chars().count() > max => {
+                text.chars().take(max - 1).chain(['…']).collect()
+            }
+            _ => text,
+        }
+    }
+
     /// Set the axis description's style. If not given, use label style instead.
     /// - `style`: The text style that would be applied to descriptions
     pub fn axis_desc_style<T: IntoTextStyle<'b>>(&mut self, style: T) -> &mut Self {
@@ -422,6 +567,20 @@ where
         self
     }
 
+    /// Set the X axis's description to the name of a quantity followed by its unit, read
+    /// from the type of the quantity, such as "Energy (J)"
+    /// - `name`: The name of the quantity on the X axis
+    pub fn x_desc_unit<Q: Quantity>(&mut self, name: &str) -> &mut Self {
+        self.x_desc(quantity_desc::<Q>(name))
+    }
+
+    /// Set the Y axis's description to the name of a quantity followed by its unit, read
+    /// from the type of the quantity, such as "Energy (J)"
+    /// - `name`: The name of the quantity on the Y axis
+    pub fn y_desc_unit<Q: Quantity>(&mut self, name: &str) -> &mut Self {
+        self.y_desc(quantity_desc::<Q>(name))
+    }
+
     /// Draw the configured mesh on the target plot
     pub fn draw(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
     where
@@ -433,9 +592,12 @@ where
         let default_mesh_color_1 = RGBColor(0, 0, 0).mix(0.2);
         let default_mesh_color_2 = RGBColor(0, 0, 0).mix(0.1);
         let default_axis_color = RGBColor(0, 0, 0);
+        let default_font_size = RcParams::get()
+            .font_size
+            .unwrap_or_else(|| (12i32).percent().max(12).in_pixels(&self.parent_size) as u32);
         let default_label_font = FontDesc::new(
             FontFamily::SansSerif,
-            f64::from((12i32).percent().max(12).in_pixels(&self.parent_size)),
+            f64::from(default_font_size),
             FontStyle::Normal,
         );
 
@@ -464,17 +626,49 @@ where
             .clone()
             .unwrap_or_else(|| x_label_style.clone());
 
+        let (width, height) = target.plotting_area().dim_in_pixel();
+        let n_x_labels = match self.x_label_spacing {
+            Some(spacing) => {
+                adaptive_label_count(target.as_coord_spec().x_spec(), width, spacing, |v| {
+                    let text = self.label_text(match self.format_x {
+                        Some(fmt_func) => fmt_func(v),
+                        None => target.as_coord_spec().x_spec().format_ext(v),
+                    });
+                    let size = target
+                        .plotting_area()
+                        .estimate_text_size(&text, &x_label_style);
+                    size.map_or(0, |(w, _)| w)
+                })
+            }
+            None => self.n_x_labels,
+        };
+        let n_y_labels = match self.y_label_spacing {
+            Some(spacing) => {
+                adaptive_label_count(target.as_coord_spec().y_spec(), height, spacing, |v| {
+                    let text = self.label_text(match self.format_y {
+                        Some(fmt_func) => fmt_func(v),
+                        None => target.as_coord_spec().y_spec().format_ext(v),
+                    });
+                    let size = target
+                        .plotting_area()
+                        .estimate_text_size(&text, &y_label_style);
+                    size.map_or(0, |(_, h)| h)
+                })
+            }
+            None => self.n_y_labels,
+        };
+
         target.draw_mesh(
             (
-                LightPoints::new(self.n_y_labels, self.n_y_labels * self.y_light_lines_limit),
-                LightPoints::new(self.n_x_labels, self.n_x_labels * self.x_light_lines_limit),
+                LightPoints::new(n_y_labels, n_y_labels * self.y_light_lines_limit),
+                LightPoints::new(n_x_labels, n_x_labels * self.x_light_lines_limit),
             ),
             &light_style,
             &x_label_style,
             &y_label_style,
             |_, _, _| None,
-            self.draw_x_mesh,
-            self.draw_y_mesh,
+            self.draw_x_mesh && self.draw_minor_mesh,
+            self.draw_y_mesh && self.draw_minor_mesh,
             self.x_label_offset,
             self.y_label_offset,
             false,
@@ -487,37 +681,44 @@ where
             self.y_tick_size,
         )?;
 
-        target.draw_mesh(
-            (BoldPoints(self.n_y_labels), BoldPoints(self.n_x_labels)),
+        let mut abbreviated = vec![];
+        let mut label = |text: String| {
+            let full = self.group_thousands(text);
+            let short = self.abbreviate(full.clone());
+            if short != full {
+                abbreviated.push((short.clone(), full));
+            }
+            short
+        };
+        let result = target.draw_mesh(
+            (BoldPoints(n_y_labels), BoldPoints(n_x_labels)),
             &bold_style,
             &x_label_style,
             &y_label_style,
             |xr, yr, m| match m {
                 MeshLine::XMesh(_, _, v) => {
                     if self.draw_x_axis {
-                        if let Some(fmt_func) = self.format_x {
-                            Some(fmt_func(v))
-                        } else {
-                            Some(xr.format_ext(v))
-                        }
+                        Some(label(match self.format_x {
+                            Some(fmt_func) => fmt_func(v),
+                            None => xr.format_ext(v),
+                        }))
                     } else {
                         None
                     }
                 }
                 MeshLine::YMesh(_, _, v) => {
                     if self.draw_y_axis {
-                        if let Some(fmt_func) = self.format_y {
-                            Some(fmt_func(v))
-                        } else {
-                            Some(yr.format_ext(v))
-                        }
+                        Some(label(match self.format_y {
+                            Some(fmt_func) => fmt_func(v),
+                            None => yr.format_ext(v),
+                        }))
                     } else {
                         None
                     }
                 }
             },
-            self.draw_x_mesh,
-            self.draw_y_mesh,
+            self.draw_x_mesh && self.draw_major_mesh,
+            self.draw_y_mesh && self.draw_major_mesh,
             self.x_label_offset,
             self.y_label_offset,
             self.draw_x_axis,
@@ -528,6 +729,248 @@ where
             None,
             self.x_tick_size,
             self.y_tick_size,
-        )
+        );
+        target.abbreviated_labels.extend(abbreviated);
+        result
+    }
+}
+
+/// The minimal gap between two labels, in pixels, for [`MeshStyle::x_label_spacing`]
+const MIN_LABEL_GAP: u32 = 4;
+
+/// Find the number of labels for an axis `length` pixels long: one per `spacing` pixels,
+/// reduced until the labels, whose extents along the axis are given by `label_size`, don't
+/// overlap
+fn adaptive_label_count<R: Ranged>(
+    range: &R,
+    length: u32,
+    spacing: u32,
+    label_size: impl Fn(&R::ValueType) -> u32,
+) -> usize {
+    let mut n = (length / spacing).max(2) as usize;
+    while n > 2 {
+        let points = range.key_points(BoldPoints(n));
+        if points.is_empty() {
+            break;
+        }
+        let labels: Vec<_> = points
+            .iter()
+            .map(|p| (range.map(p, (0, length as i32)), label_size(p)))
+            .collect();
+        let overlapping = labels.windows(2).any(|pair| {
+            let ((p0, s0), (p1, s1)) = (pair[0], pair[1]);
+            p0.abs_diff(p1) < (s0 + s1) / 2 + MIN_LABEL_GAP
+        });
+        if !overlapping {
+            break;
+        }
+        n = n.min(points.len()).saturating_sub(1);
+    }
+    n
+}
+
+#[cfg(test)]
+mod test {
+    use crate::coord::ranged1d::BoldPoints;
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    fn count_x_labels(width: u32) -> usize {
+        let labels = Rc::new(RefCell::new(vec![]));
+        let labels_out = labels.clone();
+        let drawing_area = create_mocked_drawing_area(width, 300, |m| {
+            m.check_draw_text(move |_, _, _, _, text| {
+                labels_out.borrow_mut().push(text.to_string())
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(30)
+            .build_cartesian_2d(0.0..1000.0, 0.0..1.0)
+            .expect("Build chart error");
+        chart
+            .configure_mesh()
+            .disable_y_axis()
+            .x_label_spacing(80)
+            .draw()
+            .expect("Drawing Error");
+        let n = labels.borrow().len();
+        n
+    }
+
+    #[test]
+    fn test_adaptive_label_count() {
+        let narrow = count_x_labels(200);
+        let wide = count_x_labels(1000);
+        assert!(narrow >= 2);
+        assert!(
+            narrow < wide,
+            "{} labels on the narrow plot, {} on the wide",
+            narrow,
+            wide
+        );
+        assert!(wide <= 1000 / 80 + 1);
+    }
+
+    #[test]
+    fn test_adaptive_label_count_avoids_overlap() {
+        // 20 pixels per label is less than the width of the labels
+        let range: crate::coord::types::RangedCoordf64 = (0.0..1000.0).into();
+        let n = super::adaptive_label_count(&range, 400, 20, |_| 30);
+        let points = range.key_points(BoldPoints(n));
+        assert!(points.len() > 1);
+        for pair in points.windows(2) {
+            let gap = range.map(&pair[1], (0, 400)) - range.map(&pair[0], (0, 400));
+            assert!(gap >= 34);
+        }
+    }
+
+    #[test]
+    fn test_adaptive_label_count_empty_range() {
+        // A degenerate range has no key points to place labels on
+        let range: RoundTicksCoord = (1.0..1.0).round_ticks(&[1.0]).into();
+        assert!(range.key_points(BoldPoints(10)).is_empty());
+        assert_eq!(super::adaptive_label_count(&range, 400, 20, |_| 30), 20);
+        let range: crate::coord::types::RangedCoordf64 = (5.0..5.0).into();
+        super::adaptive_label_count(&range, 400, 20, |_| 30);
+
+        let drawing_area = create_mocked_drawing_area(400, 300, |_| {});
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(30)
+            .build_cartesian_2d((1.0..1.0).round_ticks(&[1.0]), 0.0..1.0)
+            .expect("Build chart error");
+        chart
+            .configure_mesh()
+            .x_label_spacing(20)
+            .draw()
+            .expect("Drawing Error");
+    }
+
+    /// The number of major (red) and minor (blue) gridlines drawn by a mesh
+    fn count_mesh_lines(minor_only: bool, major_only: bool, x_only: bool) -> (usize, usize) {
+        let lines = Rc::new(RefCell::new((0, 0)));
+        let lines_out = lines.clone();
+        let drawing_area = create_mocked_drawing_area(500, 500, move |m| {
+            m.check_draw_line(move |c, _, _, _| {
+                let mut lines = lines_out.borrow_mut();
+                if c == RED.to_rgba() {
+                    lines.0 += 1;
+                } else if c == BLUE.to_rgba() {
+                    lines.1 += 1;
+                }
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(30)
+            .y_label_area_size(30)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Build chart error");
+        let mut mesh = chart.configure_mesh();
+        mesh.bold_line_style(RED).light_line_style(BLUE);
+        if minor_only {
+            mesh.minor_mesh_only();
+        }
+        if major_only {
+            mesh.disable_minor_mesh();
+        }
+        if x_only {
+            mesh.disable_y_mesh();
+        }
+        mesh.draw().expect("Drawing Error");
+        let lines = *lines.borrow();
+        lines
+    }
+
+    #[test]
+    fn test_minor_mesh_only() {
+        let (major, minor) = count_mesh_lines(false, false, false);
+        assert!(major > 0 && minor > major);
+        // The minor gridlines only, the major ones are gone
+        assert_eq!(count_mesh_lines(true, false, false), (0, minor));
+        // and the other way around
+        assert_eq!(count_mesh_lines(false, true, false), (major, 0));
+        // The minor gridlines of the x axis only
+        let (x_major, x_minor) = count_mesh_lines(true, false, true);
+        assert!(x_major == 0 && 0 < x_minor && x_minor < minor);
+    }
+
+    #[test]
+    fn test_thousands_separator() {
+        let labels = Rc::new(RefCell::new(vec![]));
+        let labels_out = labels.clone();
+        let drawing_area = create_mocked_drawing_area(500, 500, |m| {
+            m.check_draw_text(move |_, _, _, _, text| {
+                labels_out.borrow_mut().push(text.to_string())
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(30)
+            .y_label_area_size(80)
+            .build_cartesian_2d(0.0..1.0, 0.0..5_000_000.0)
+            .expect("Build chart error");
+        chart
+            .configure_mesh()
+            .disable_x_axis()
+            .thousands_separator(',')
+            .draw()
+            .expect("Drawing Error");
+
+        let labels = labels.borrow();
+        assert!(labels.contains(&"1,000,000.0".to_string()), "{:?}", labels);
+        for label in labels.iter() {
+            assert!(!label.contains("0000"), "{}", label);
+        }
+    }
+
+    #[test]
+    fn test_desc_unit() {
+        struct Joules(f64);
+        impl Quantity for Joules {
+            const UNIT: &'static str = "J";
+            fn value(&self) -> f64 {
+                self.0
+            }
+        }
+        struct Ratio(f64);
+        impl Quantity for Ratio {
+            const UNIT: &'static str = "";
+            fn value(&self) -> f64 {
+                self.0
+            }
+        }
+
+        let texts = Rc::new(RefCell::new(vec![]));
+        let texts_out = texts.clone();
+        let drawing_area = create_mocked_drawing_area(500, 500, |m| {
+            m.check_draw_text(move |_, _, _, _, text| {
+                texts_out.borrow_mut().push(text.to_string())
+            });
+        });
+        let energies = [Joules(0.5), Joules(3.0)];
+        let efficiencies = [Ratio(0.2), Ratio(0.9)];
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .x_label_area_size(40)
+            .y_label_area_size(40)
+            .build_cartesian_2d(0.0..1.0, 0.0..5.0)
+            .expect("Build chart error");
+        chart
+            .draw_series(LineSeries::new(
+                efficiencies
+                    .iter()
+                    .map(Quantity::value)
+                    .zip(energies.iter().map(Quantity::value)),
+                &RED,
+            ))
+            .expect("Drawing Error");
+        chart
+            .configure_mesh()
+            .x_desc_unit::<Ratio>("Efficiency")
+            .y_desc_unit::<Joules>("Energy")
+            .draw()
+            .expect("Drawing Error");
+
+        let texts = texts.borrow();
+        assert!(texts.contains(&"Energy (J)".to_string()), "{:?}", texts);
+        assert!(texts.contains(&"Efficiency".to_string()), "{:?}", texts);
     }
 }
@@ -12,19 +12,52 @@ In Plotters, a series is abstracted as an iterator of elements.
 detailed description for each struct.
 */
 
+mod annotation_layer;
 mod axes3d;
+mod axis_breaks;
 mod builder;
+#[cfg(feature = "point_series")]
+mod category_scatter;
+mod centroid;
+#[cfg(feature = "colormaps")]
+mod colorbar_layout;
 mod context;
+mod crossings;
+mod cursor;
+mod dashboard_layout;
 mod dual_coord;
+#[cfg(feature = "line_series")]
+mod ecdf_groups;
+mod focus_range;
+mod highlight_box;
+mod highlight_span;
+#[cfg(feature = "colormaps")]
+mod inset_colorbar;
+mod marginal;
 mod mesh;
+mod plateaus;
+mod reference_lines;
+mod secondary_axis;
 mod series;
 mod state;
+mod threshold_line;
+mod trend_line;
+mod value_zones;
+mod zebra_bands;
 
 pub use builder::{ChartBuilder, LabelAreaPosition};
+#[cfg(feature = "colormaps")]
+pub use colorbar_layout::{ColorbarLayout, ColorbarPosition};
 pub use context::ChartContext;
+pub use dashboard_layout::{DashboardAreas, DashboardLayout};
 pub use dual_coord::{DualCoordChartContext, DualCoordChartState};
+pub use highlight_span::HighlightSpan;
 pub use mesh::{MeshStyle, SecondaryMeshStyle};
-pub use series::{SeriesAnno, SeriesLabelPosition, SeriesLabelStyle};
+pub use secondary_axis::SecondaryAxis;
+pub use series::{LegendGlyph, SeriesAnno, SeriesLabelPosition, SeriesLabelStyle, StyledSeries};
 pub use state::ChartState;
+pub use value_zones::ValueZones;
+pub use zebra_bands::ZebraBands;
 
 use context::Coord3D;
+use series::DataDensity;
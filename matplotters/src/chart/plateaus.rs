@@ -0,0 +1,120 @@
+use super::ChartContext;
+
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::Ranged;
+use crate::data::plateaus;
+use crate::drawing::DrawingAreaErrorKind;
+use crate::element::{EmptyElement, Text};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{IntoFont, ShapeStyle, TextStyle};
+
+use plotters_backend::DrawingBackend;
+
+use std::ops::Range;
+
+/// The x extent and the value of each plateau of a series
+type Plateaus = Vec<(Range<f64>, f64)>;
+
+impl<'a, DB: DrawingBackend, X, Y> ChartContext<'a, DB, Cartesian2d<X, Y>>
+where
+    X: Ranged<ValueType = f64>,
+    Y: Ranged<ValueType = f64>,
+{
+    /**
+    Finds the plateaus of a series, the runs of consecutive points at a constant value, and
+    annotates each of them, on top of what is already on the chart, such as the states of a
+    state timeline. A level holds until the next point, as in a step series. The label of a
+    plateau gives its value and its duration, the width of its x extent, as
+    `value (duration)`, above the middle of the plateau.
+
+    - `points`: The points of the series; they are not drawn
+    - `tolerance`: The largest difference to the first value of a plateau
+    - `style`: The color of the labels
+    - **returns** The x extent and the value of each plateau, in increasing x
+
+    See [`plateaus`] for more information.
+    */
+    pub fn annotate_plateaus<S: Into<ShapeStyle>>(
+        &self,
+        points: &[(f64, f64)],
+        tolerance: f64,
+        style: S,
+    ) -> Result<Plateaus, DrawingAreaErrorKind<DB::ErrorType>> {
+        let style = style.into();
+        let font: TextStyle = ("sans-serif", 12)
+            .into_font()
+            .color(&style.color)
+            .pos(Pos::new(HPos::Center, VPos::Bottom));
+        let found = plateaus(points, tolerance);
+        for (extent, value) in &found {
+            let middle = (extent.start + extent.end) / 2.0;
+            self.drawing_area.draw(
+                &(EmptyElement::at((middle, *value))
+                    + Text::new(
+                        format!("{:.2} ({:.2})", value, extent.end - extent.start),
+                        (0, -4),
+                        &font,
+                    )),
+            )?;
+        }
+        Ok(found)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use crate::series::StepMode;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_annotate_plateaus() {
+        let texts = Rc::new(RefCell::new(vec![]));
+        let texts_out = texts.clone();
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_text(move |c, _, _, pos, text| {
+                assert_eq!(c, BLACK.to_rgba());
+                texts_out.borrow_mut().push((pos, text.to_string()));
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..5.0)
+            .expect("Build chart error");
+
+        // A state timeline: idle, running for 4 units, a transient, then idle again
+        let states = [
+            (0.0, 1.0),
+            (1.0, 1.0),
+            (2.0, 3.0),
+            (4.0, 3.0),
+            (6.0, 3.0),
+            (7.0, 2.0),
+            (8.0, 1.0),
+            (9.5, 1.0),
+        ];
+        chart
+            .draw_series(
+                MatLineSeries::new(states, BLUE)
+                    .step(StepMode::Post)
+                    .point_size(0),
+            )
+            .expect("Drawing Error");
+        let found = chart
+            .annotate_plateaus(&states, 0.0, BLACK)
+            .expect("Drawing Error");
+        assert_eq!(
+            found,
+            vec![(0.0..2.0, 1.0), (2.0..7.0, 3.0), (8.0..9.5, 1.0)]
+        );
+
+        let texts = texts.borrow();
+        let labels: Vec<_> = texts.iter().map(|(_, t)| t.as_str()).collect();
+        assert_eq!(labels, vec!["1.00 (2.00)", "3.00 (5.00)", "1.00 (1.50)"]);
+        // Each label is centered above the middle of its plateau
+        for ((pos, _), (extent, value)) in texts.iter().zip(&found) {
+            let (x, y) = chart.backend_coord(&((extent.start + extent.end) / 2.0, *value));
+            assert!((pos.0 - x).abs() <= 40 && pos.1 < y);
+        }
+    }
+}
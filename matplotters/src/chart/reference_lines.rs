@@ -0,0 +1,102 @@
+use super::ChartContext;
+
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::Ranged;
+use crate::drawing::DrawingAreaErrorKind;
+use crate::element::PathElement;
+use crate::style::ShapeStyle;
+
+use plotters_backend::DrawingBackend;
+
+impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged> ChartContext<'a, DB, Cartesian2d<X, Y>>
+where
+    X::ValueType: Clone,
+    Y::ValueType: Clone,
+{
+    /**
+    Draws extra gridlines at the given positions, like matplotlib's `axvline` and `axhline`,
+    for instance to mark a target value.
+
+    - `xs`: The x values of the vertical lines, each spanning the full y range of the chart
+    - `ys`: The y values of the horizontal lines, each spanning the full x range of the chart
+    - `style`: The style of the lines
+
+    Unlike the mesh, the lines are drawn on top of what is already on the chart.
+    */
+    pub fn reference_lines<XS, YS, S>(
+        &self,
+        xs: XS,
+        ys: YS,
+        style: S,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        XS: IntoIterator<Item = X::ValueType>,
+        YS: IntoIterator<Item = Y::ValueType>,
+        S: Into<ShapeStyle>,
+    {
+        let style = style.into();
+        let (x_range, y_range) = (self.x_range(), self.y_range());
+        for x in xs {
+            self.drawing_area.draw(&PathElement::new(
+                vec![(x.clone(), y_range.start.clone()), (x, y_range.end.clone())],
+                style,
+            ))?;
+        }
+        for y in ys {
+            self.drawing_area.draw(&PathElement::new(
+                vec![(x_range.start.clone(), y.clone()), (x_range.end.clone(), y)],
+                style,
+            ))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_reference_lines() {
+        let paths = Rc::new(RefCell::new(vec![]));
+        let paths_out = paths.clone();
+        let drawing_area = create_mocked_drawing_area(501, 301, |m| {
+            m.check_draw_path(move |c, w, p| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(w, 2);
+                paths_out.borrow_mut().push(p);
+            });
+        });
+
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, -5.0..5.0)
+            .expect("Build chart error");
+        chart
+            .reference_lines([2.5, 7.0], [1.0], RED.stroke_width(2))
+            .expect("Drawing Error");
+
+        let paths = paths.borrow();
+        assert_eq!(paths.len(), 3);
+        for (path, x) in paths.iter().zip([2.5, 7.0]) {
+            assert_eq!(
+                path,
+                &vec![
+                    chart.backend_coord(&(x, -5.0)),
+                    chart.backend_coord(&(x, 5.0))
+                ]
+            );
+            // The vertical lines span the full plotting area
+            assert_eq!((path[0].1, path[1].1), (300, 0));
+        }
+        assert_eq!(
+            paths[2],
+            vec![
+                chart.backend_coord(&(0.0, 1.0)),
+                chart.backend_coord(&(10.0, 1.0))
+            ]
+        );
+        assert_eq!((paths[2][0].0, paths[2][1].0), (0, 500));
+    }
+}
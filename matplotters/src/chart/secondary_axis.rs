@@ -0,0 +1,189 @@
+use super::{ChartContext, DualCoordChartContext};
+
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::{AsRangedCoord, Ranged, ValueFormatter};
+use crate::drawing::DrawingAreaErrorKind;
+use crate::style::{
+    AsRelative, Color, FontDesc, FontFamily, FontStyle, RGBAColor, SizeDesc, BLACK,
+};
+
+use plotters_backend::DrawingBackend;
+
+/**
+An independent y axis drawn on the right side of a chart, like matplotlib's `twinx`.
+
+The axis has its own range, tick labels, description and color, and shares the x axis of the
+chart. It is attached with [`ChartContext::secondary_y_axis()`], which draws the axis and returns
+a [`DualCoordChartContext`]; series drawn with
+[`DualCoordChartContext::draw_secondary_series()`] are then mapped onto the secondary scale.
+
+The chart needs a right label area to show the tick labels, see
+[`crate::chart::ChartBuilder::right_y_label_area_size()`].
+*/
+pub struct SecondaryAxis<Y: AsRangedCoord> {
+    range: Y,
+    desc: Option<String>,
+    color: RGBAColor,
+    n_labels: usize,
+}
+
+impl<Y: AsRangedCoord> SecondaryAxis<Y> {
+    /**
+    Creates a secondary axis with the given range, drawn in black with 10 labels at most.
+
+    See [`SecondaryAxis`] for more information.
+    */
+    pub fn new(range: Y) -> Self {
+        Self {
+            range,
+            desc: None,
+            color: BLACK.to_rgba(),
+            n_labels: 10,
+        }
+    }
+
+    /**
+    Sets the description of the axis.
+
+    See [`SecondaryAxis`] for more information.
+    */
+    pub fn desc<T: Into<String>>(mut self, desc: T) -> Self {
+        self.desc = Some(desc.into());
+        self
+    }
+
+    /**
+    Sets the color of the axis line, the tick marks, the labels and the description.
+
+    See [`SecondaryAxis`] for more information.
+    */
+    pub fn color<C: Color>(mut self, color: &C) -> Self {
+        self.color = color.to_rgba();
+        self
+    }
+
+    /**
+    Sets the maximum number of labels on the axis.
+
+    See [`SecondaryAxis`] for more information.
+    */
+    pub fn labels(mut self, n: usize) -> Self {
+        self.n_labels = n;
+        self
+    }
+}
+
+impl<'a, DB: DrawingBackend, X: Ranged + Clone, Y: Ranged> ChartContext<'a, DB, Cartesian2d<X, Y>> {
+    /**
+    Attaches a secondary y axis to the right side of the chart and draws it.
+
+    - `axis`: The secondary axis to attach
+    - **returns** The dual coordinate chart context
+
+    The secondary coordinate system shares the x axis of this chart and uses the range of the
+    secondary axis.
+
+    See [`SecondaryAxis`] for more information.
+    */
+    #[allow(clippy::type_complexity)]
+    pub fn secondary_y_axis<SY: AsRangedCoord>(
+        self,
+        axis: SecondaryAxis<SY>,
+    ) -> Result<
+        DualCoordChartContext<'a, DB, Cartesian2d<X, Y>, Cartesian2d<X, SY::CoordDescType>>,
+        DrawingAreaErrorKind<DB::ErrorType>,
+    >
+    where
+        X: ValueFormatter<X::ValueType>,
+        SY::CoordDescType: ValueFormatter<SY::Value>,
+    {
+        // Use the same pixel range as the primary coordinate system, so that both coordinate
+        // systems agree on the shared x axis
+        let x_spec = self.as_coord_spec().x_spec().clone();
+        let mut pixel_range = self.drawing_area.get_pixel_range();
+        pixel_range.0.end -= 1;
+        pixel_range.1.end -= 1;
+        pixel_range.1 = pixel_range.1.end..pixel_range.1.start;
+        let secondary = Cartesian2d::new(x_spec, axis.range, pixel_range);
+        let mut chart = DualCoordChartContext::new(self, secondary);
+
+        let font_size = (12i32)
+            .percent()
+            .max(12)
+            .in_pixels(chart.secondary_plotting_area());
+        let font = FontDesc::new(
+            FontFamily::SansSerif,
+            f64::from(font_size),
+            FontStyle::Normal,
+        );
+
+        let mut mesh = chart.configure_secondary_axes();
+        mesh.x_labels(0)
+            .y_labels(axis.n_labels)
+            .axis_style(axis.color)
+            .label_style(font.color(&axis.color));
+        if let Some(desc) = axis.desc {
+            mesh.y_desc(desc);
+        }
+        mesh.draw()?;
+
+        Ok(chart)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_secondary_y_axis() {
+        let labels = Rc::new(RefCell::new(vec![]));
+        let labels_out = labels.clone();
+        let path = Rc::new(RefCell::new(vec![]));
+        let path_out = path.clone();
+        let drawing_area = create_mocked_drawing_area(500, 400, |m| {
+            m.check_draw_text(move |c, _, _, pos, text| {
+                labels_out.borrow_mut().push((c, pos, text.to_string()))
+            });
+            m.check_draw_path(move |_, _, p| path_out.borrow_mut().push(p));
+        });
+
+        let chart = ChartBuilder::on(&drawing_area)
+            .right_y_label_area_size(50)
+            .build_cartesian_2d(0.0..10.0, 0.0..1.0)
+            .expect("Build chart error");
+
+        let mut chart = chart
+            .secondary_y_axis(
+                SecondaryAxis::new(0.0..1000.0)
+                    .desc("Secondary")
+                    .color(&RED),
+            )
+            .expect("Drawing Error");
+
+        {
+            let labels = labels.borrow();
+            let ticks: Vec<_> = labels.iter().filter(|(_, _, t)| t == "800.0").collect();
+            assert_eq!(ticks.len(), 1);
+            // The secondary labels are drawn in the right label area with the axis color
+            assert_eq!(ticks[0].0, RED.to_rgba());
+            assert!(ticks[0].1 .0 >= 450);
+            assert!(labels
+                .iter()
+                .any(|(c, _, t)| t == "Secondary" && *c == RED.to_rgba()));
+        }
+
+        path.borrow_mut().clear();
+        chart
+            .draw_secondary_series(LineSeries::new(vec![(0.0, 500.0), (10.0, 500.0)], &BLUE))
+            .expect("Drawing Error");
+
+        // 500 is the middle of the secondary scale, so the line is in the middle of the chart
+        let path = path.borrow();
+        let line = path.last().unwrap();
+        assert_eq!(line[0], chart.backend_coord(&(0.0, 0.5)));
+        assert_eq!(line[1], chart.backend_coord(&(10.0, 0.5)));
+    }
+}
@@ -1,7 +1,10 @@
 use super::ChartContext;
 use crate::coord::CoordTranslate;
 use crate::drawing::DrawingAreaErrorKind;
-use crate::element::{DynElement, EmptyElement, IntoDynElement, MultiLineText, Rectangle};
+use crate::element::{
+    DynElement, EmptyElement, IntoDynElement, Marker, MarkerShape, MultiLineText, PathElement,
+    Rectangle,
+};
 use crate::style::{IntoFont, IntoTextStyle, ShapeStyle, SizeDesc, TextStyle, TRANSPARENT};
 
 use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
@@ -14,6 +17,8 @@ type SeriesAnnoDrawFn<'a, DB> = dyn Fn(BackendCoord) -> DynElement<'a, DB, Backe
 pub struct SeriesAnno<'a, DB: DrawingBackend> {
     label: Option<String>,
     draw_func: Option<Box<SeriesAnnoDrawFn<'a, DB>>>,
+    // The legend glyph, if it is made by `legend_glyph` rather than a custom function
+    glyph: Option<LegendGlyph>,
 }
 
 impl<'a, DB: DrawingBackend> SeriesAnno<'a, DB> {
@@ -31,9 +36,20 @@ impl<'a, DB: DrawingBackend> SeriesAnno<'a, DB> {
         Self {
             label: None,
             draw_func: None,
+            glyph: None,
         }
     }
 
+    /// Whether the legend entry looks the same as the other one: both have the same label,
+    /// and the same glyph or no glyph at all
+    pub(crate) fn is_same_entry(&self, other: &Self) -> bool {
+        let same_glyph = match (self.glyph, other.glyph) {
+            (Some(a), Some(b)) => a == b,
+            _ => self.draw_func.is_none() && other.draw_func.is_none(),
+        };
+        self.get_label() == other.get_label() && same_glyph
+    }
+
     /**
     Sets the series label for the current series.
 
@@ -63,8 +79,157 @@ impl<'a, DB: DrawingBackend> SeriesAnno<'a, DB> {
         func: T,
     ) -> &mut Self {
         self.draw_func = Some(Box::new(move |p| func(p).into_dyn()));
+        self.glyph = None;
         self
     }
+
+    /**
+    Sets the legend element to one of the usual glyphs, see [`LegendGlyph`].
+
+    The legend shows a single entry for the series sharing the label and the glyph, such as
+    the same category repeated across groups.
+
+    See [`ChartContext::draw_styled_series()`] to pick the glyph that fits the series.
+    */
+    pub fn legend_glyph(&mut self, glyph: LegendGlyph) -> &mut Self
+    where
+        DB: 'a,
+    {
+        match glyph {
+            LegendGlyph::Style(style) if style.filled => {
+                self.legend(move |(x, y)| Rectangle::new([(x, y - 5), (x + 10, y + 5)], style))
+            }
+            LegendGlyph::Style(style) | LegendGlyph::Line(style) => {
+                self.legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], style))
+            }
+            LegendGlyph::Marker(shape, size, style) => {
+                self.legend(move |(x, y)| Marker::new((x + 10, y), size, shape, style))
+            }
+            LegendGlyph::LineMarker(shape, size, style) => self.legend(move |(x, y)| {
+                EmptyElement::at((x, y))
+                    + PathElement::new(vec![(0, 0), (20, 0)], style)
+                    + Marker::new((10, 0), size, shape, style)
+            }),
+        };
+        self.glyph = Some(glyph);
+        self
+    }
+
+    /**
+    Sets the legend element to a glyph drawn with exactly the given style, color and alpha
+    included: a filled square for a filled style, and a short line otherwise.
+
+    The legend shows a single entry for the series sharing the label and the style, such as
+    the same category repeated across groups.
+
+    See [`ChartContext::draw_styled_series()`] to read the style from the series itself.
+    */
+    pub fn legend_from_style<S: Into<ShapeStyle>>(&mut self, style: S) -> &mut Self
+    where
+        DB: 'a,
+    {
+        self.legend_glyph(LegendGlyph::Style(style.into()))
+    }
+
+    /**
+    Sets the legend element to a marker, such as the shape of a category of a
+    [`crate::series::MatCategoryScatter`].
+
+    - `shape`: The shape of the marker
+    - `size`: The size of the marker, in pixels
+    - `style`: The style of the marker
+    */
+    pub fn legend_marker<S: Into<ShapeStyle>>(
+        &mut self,
+        shape: MarkerShape,
+        size: u32,
+        style: S,
+    ) -> &mut Self
+    where
+        DB: 'a,
+    {
+        self.legend_glyph(LegendGlyph::Marker(shape, size, style.into()))
+    }
+}
+
+/**
+The glyph drawn for a series in the legend.
+
+See [`StyledSeries::legend_glyph()`] for more information.
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LegendGlyph {
+    /// A filled square for a filled style, and a short line otherwise
+    Style(ShapeStyle),
+    /// A short line, as for a line without markers
+    Line(ShapeStyle),
+    /// A marker alone, as for a scatter plot: the shape, the size in pixels and the style
+    Marker(MarkerShape, u32, ShapeStyle),
+    /// A short line with a marker at its middle, as for a line with markers
+    LineMarker(MarkerShape, u32, ShapeStyle),
+}
+
+/**
+A series which knows the style it is drawn with, so that its legend glyph can be made from it.
+
+See [`ChartContext::draw_styled_series()`] for more information.
+*/
+pub trait StyledSeries {
+    /// The style of the series, used for its legend glyph
+    fn series_style(&self) -> ShapeStyle;
+
+    /// The legend glyph that fits the kind of series: a marker for a scatter plot, a line
+    /// for a line, and both for a line with markers. By default, the glyph is made from the
+    /// style of the series, see [`SeriesAnno::legend_from_style()`].
+    fn legend_glyph(&self) -> LegendGlyph {
+        LegendGlyph::Style(self.series_style())
+    }
+}
+
+/// The number of data points drawn in each cell of a coarse grid over the plotting area, used
+/// to place the legend where it hides the least data, see [`SeriesLabelPosition::Best`]
+#[derive(Default)]
+pub(crate) struct DataDensity {
+    cols: usize,
+    counts: Vec<u32>,
+}
+
+impl DataDensity {
+    /// The size of the cells of the grid, in pixels
+    const CELL: i32 = 10;
+
+    /// Count a point, in pixels relative to the plotting area of the given size
+    pub(crate) fn record(&mut self, (x, y): BackendCoord, (w, h): (u32, u32)) {
+        if x < 0 || y < 0 || x >= w as i32 || y >= h as i32 {
+            return;
+        }
+        if self.counts.is_empty() {
+            self.cols = (w as i32 / Self::CELL + 1) as usize;
+            self.counts = vec![0; self.cols * (h as i32 / Self::CELL + 1) as usize];
+        }
+        let idx = (y / Self::CELL) as usize * self.cols + (x / Self::CELL) as usize;
+        if let Some(count) = self.counts.get_mut(idx) {
+            *count += 1;
+        }
+    }
+
+    /// The number of points in the cells overlapping the box, given by its upper left corner
+    /// and its size
+    fn count_in(&self, (x, y): BackendCoord, (w, h): (i32, i32)) -> u32 {
+        if self.counts.is_empty() {
+            return 0;
+        }
+        let rows = self.counts.len() / self.cols;
+        let cells = |from: i32, to: i32, n: usize| {
+            let first = (from.max(0) / Self::CELL) as usize;
+            let last = ((to - 1).max(0) / Self::CELL) as usize;
+            first..=last.min(n - 1)
+        };
+        cells(y, y + h, rows)
+            .flat_map(|row| cells(x, x + w, self.cols).map(move |col| (row, col)))
+            .map(|(row, col)| self.counts[row * self.cols + col])
+            .sum()
+    }
 }
 
 /**
@@ -72,6 +237,7 @@ Useful to specify the position of the series label.
 
 See [`ChartContext::configure_series_labels()`] for more information and examples.
 */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum SeriesLabelPosition {
     /// Places the series label at the upper left
     UpperLeft,
@@ -93,10 +259,45 @@ pub enum SeriesLabelPosition {
     LowerRight,
     /// Places the series label at the specific location in backend coordinates
     Coordinate(i32, i32),
+    /// Places the series label at the position hiding the fewest data points drawn so far,
+    /// like matplotlib's `legend(loc='best')`. The corners are tried first, then the middles
+    /// of the sides and the center, and the first of the least crowded positions is taken.
+    Best,
 }
 
 impl SeriesLabelPosition {
-    fn layout_label_area(&self, label_dim: (i32, i32), area_dim: (u32, u32)) -> (i32, i32) {
+    /// The positions tried by [`SeriesLabelPosition::Best`], in the order of matplotlib
+    const BEST_CANDIDATES: [SeriesLabelPosition; 9] = [
+        SeriesLabelPosition::UpperRight,
+        SeriesLabelPosition::UpperLeft,
+        SeriesLabelPosition::LowerLeft,
+        SeriesLabelPosition::LowerRight,
+        SeriesLabelPosition::MiddleRight,
+        SeriesLabelPosition::MiddleLeft,
+        SeriesLabelPosition::LowerMiddle,
+        SeriesLabelPosition::UpperMiddle,
+        SeriesLabelPosition::MiddleMiddle,
+    ];
+
+    /// Resolve [`SeriesLabelPosition::Best`] into the least crowded position
+    fn resolve(self, density: &DataDensity, label_dim: (i32, i32), area_dim: (u32, u32)) -> Self {
+        if self != SeriesLabelPosition::Best {
+            return self;
+        }
+        let crowding = |pos: &SeriesLabelPosition| {
+            density.count_in(pos.layout_label_area(label_dim, area_dim), label_dim)
+        };
+        Self::BEST_CANDIDATES
+            .into_iter()
+            .min_by_key(crowding)
+            .unwrap_or(SeriesLabelPosition::UpperRight)
+    }
+
+    pub(super) fn layout_label_area(
+        &self,
+        label_dim: (i32, i32),
+        area_dim: (u32, u32),
+    ) -> (i32, i32) {
         use SeriesLabelPosition::*;
         (
             match self {
@@ -104,12 +305,14 @@ impl SeriesLabelPosition {
                 UpperMiddle | MiddleMiddle | LowerMiddle => (area_dim.0 as i32 - label_dim.0) / 2,
                 UpperRight | MiddleRight | LowerRight => area_dim.0 as i32 - label_dim.0 - 5,
                 Coordinate(x, _) => *x,
+                Best => unreachable!("The best position is resolved before the layout"),
             },
             match self {
                 UpperLeft | UpperMiddle | UpperRight => 5,
                 MiddleLeft | MiddleMiddle | MiddleRight => (area_dim.1 as i32 - label_dim.1) / 2,
                 LowerLeft | LowerMiddle | LowerRight => area_dim.1 as i32 - label_dim.1 - 5,
                 Coordinate(_, y) => *y,
+                Best => unreachable!("The best position is resolved before the layout"),
             },
         )
     }
@@ -124,6 +327,7 @@ pub struct SeriesLabelStyle<'a, 'b, DB: DrawingBackend, CT: CoordTranslate> {
     background: ShapeStyle,
     label_font: Option<TextStyle<'b>>,
     margin: u32,
+    max_entries: Option<usize>,
 }
 
 impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, 'b, DB, CT> {
@@ -136,6 +340,7 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
             background: (&TRANSPARENT).into(),
             label_font: None,
             margin: 10,
+            max_entries: None,
         }
     }
 
@@ -216,6 +421,18 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
         self
     }
 
+    /**
+    Limits the number of entries of the legend, for charts with too many series for a
+    legible legend. When there are more entries, the first `count` ones are shown, followed
+    by an entry without glyph telling how many are left out, such as `+7 more`.
+
+    See [`ChartContext::configure_series_labels()`] for more information and examples.
+    */
+    pub fn max_entries(&mut self, count: usize) -> &mut Self {
+        self.max_entries = Some(count);
+        self
+    }
+
     /**
     Draws the series label area.
 
@@ -235,19 +452,35 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
             temp.unwrap_or(default_style)
         };
 
-        let mut label_element = MultiLineText::<_, &str>::new((0, 0), &font);
+        let mut label_element = MultiLineText::<_, String>::new((0, 0), &font);
         let mut funcs = vec![];
 
-        for anno in self.target.series_anno.iter() {
+        let annos = &self.target.series_anno;
+        let mut shown = 0;
+        for (idx, anno) in annos.iter().enumerate() {
             let label_text = anno.get_label();
             let draw_func = anno.get_draw_func();
 
             if label_text.is_empty() && draw_func.is_none() {
                 continue;
             }
+            // Repeated identical entries are shown once
+            if annos[..idx].iter().any(|prev| prev.is_same_entry(anno)) {
+                continue;
+            }
 
+            shown += 1;
+            if matches!(self.max_entries, Some(max) if shown > max) {
+                continue;
+            }
             funcs.push(draw_func.unwrap_or(&|p: BackendCoord| EmptyElement::at(p).into_dyn()));
-            label_element.push_line(label_text);
+            label_element.push_line(label_text.to_string());
+        }
+        if let Some(more) = self.max_entries.and_then(|max| shown.checked_sub(max)) {
+            if more > 0 {
+                funcs.push(&|p: BackendCoord| EmptyElement::at(p).into_dyn());
+                label_element.push_line(format!("+{} more", more));
+            }
         }
 
         let (mut w, mut h) = label_element.estimate_dimension().map_err(|e| {
@@ -261,7 +494,10 @@ impl<'a, 'b, DB: DrawingBackend + 'a, CT: CoordTranslate> SeriesLabelStyle<'a, '
 
         let (area_w, area_h) = drawing_area.dim_in_pixel();
 
-        let (label_x, label_y) = self.position.layout_label_area((w, h), (area_w, area_h));
+        let (label_x, label_y) = self
+            .position
+            .resolve(&self.target.data_density, (w, h), (area_w, area_h))
+            .layout_label_area((w, h), (area_w, area_h));
 
         label_element.relocate((
             label_x + self.legend_area_size as i32 + margin,
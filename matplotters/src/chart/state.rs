@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use super::ChartContext;
+use super::{ChartContext, DataDensity};
 use crate::coord::{CoordTranslate, Shift};
 use crate::drawing::DrawingArea;
 use plotters_backend::DrawingBackend;
@@ -107,6 +107,10 @@ impl<CT: CoordTranslate> ChartState<CT> {
             drawing_area: area.apply_coord_spec(self.coord),
             series_anno: vec![],
             drawing_area_pos: self.drawing_area_pos,
+            clip_inset: 0,
+            data_density: DataDensity::default(),
+            abbreviated_labels: vec![],
+            annotation_layer: vec![],
         }
     }
 }
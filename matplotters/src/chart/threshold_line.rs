@@ -0,0 +1,155 @@
+use super::ChartContext;
+
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::Ranged;
+use crate::data::{outlier_fences, OutlierRule};
+use crate::drawing::DrawingAreaErrorKind;
+use crate::element::{Circle, Text};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{IntoFont, ShapeStyle, TextStyle};
+
+use plotters_backend::DrawingBackend;
+
+impl<'a, DB: DrawingBackend, X, Y> ChartContext<'a, DB, Cartesian2d<X, Y>>
+where
+    X: Ranged<ValueType = f64>,
+    Y: Ranged<ValueType = f64>,
+{
+    /**
+    Computes a threshold from the y values of the data, the upper fence of an outlier rule
+    such as `mean + 3σ` for [`OutlierRule::ZScore`]`(3.0)`, and draws it as a horizontal
+    reference line across the chart, labeled with the rule and its value at the right end.
+    The points above the threshold can be highlighted with circles, to spot the anomalies.
+
+    - `data`: The `(x, y)` points; they are not drawn, and the points with a coordinate that
+      is not finite are left out
+    - `rule`: The rule computing the threshold from the y values
+    - `style`: The style of the line; the label takes its color
+    - `highlight`: The style of the circles around the points above the threshold, or `None`
+      for no highlight
+    - **returns** The threshold, or `None` if there are no finite points and nothing was drawn
+
+    See [`outlier_fences`] for more information.
+    */
+    pub fn draw_threshold_line<I, S>(
+        &self,
+        data: I,
+        rule: OutlierRule,
+        style: S,
+        highlight: Option<ShapeStyle>,
+    ) -> Result<Option<f64>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        I: IntoIterator<Item = (f64, f64)>,
+        S: Into<ShapeStyle>,
+    {
+        let data: Vec<_> = data
+            .into_iter()
+            .filter(|(x, y)| x.is_finite() && y.is_finite())
+            .collect();
+        let ys: Vec<f64> = data.iter().map(|(_, y)| *y).collect();
+        let threshold = match outlier_fences(&ys, rule) {
+            Some((_, high)) => high,
+            None => return Ok(None),
+        };
+
+        let style = style.into();
+        self.reference_lines([], [threshold], style)?;
+        let label = match rule {
+            OutlierRule::ZScore(z) => format!("mean + {}σ = {:.2}", z, threshold),
+            OutlierRule::Iqr(k) => format!("Q3 + {} IQR = {:.2}", k, threshold),
+        };
+        let (base_x, base_y) = self.drawing_area.get_base_pixel();
+        let (x, y) = self
+            .drawing_area
+            .map_coordinate(&(self.x_range().end, threshold));
+        let font: TextStyle = ("sans-serif", 12).into_font().color(&style.color);
+        self.drawing_area.strip_coord_spec().draw(&Text::new(
+            label,
+            (x - base_x - 3, y - base_y - 3),
+            font.pos(Pos::new(HPos::Right, VPos::Bottom)),
+        ))?;
+
+        if let Some(highlight) = highlight {
+            for point in data.into_iter().filter(|(_, y)| *y > threshold) {
+                self.drawing_area.draw(&Circle::new(point, 6, highlight))?;
+            }
+        }
+        Ok(Some(threshold))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_threshold_line() {
+        let paths = Rc::new(RefCell::new(vec![]));
+        let circles = Rc::new(RefCell::new(vec![]));
+        let texts = Rc::new(RefCell::new(vec![]));
+        let (paths_out, circles_out, texts_out) = (paths.clone(), circles.clone(), texts.clone());
+        let drawing_area = create_mocked_drawing_area(501, 301, |m| {
+            m.check_draw_path(move |c, _, p| {
+                assert_eq!(c, RED.to_rgba());
+                paths_out.borrow_mut().push(p);
+            });
+            m.check_draw_circle(move |c, _, _, center, _| {
+                assert_eq!(c, BLUE.to_rgba());
+                circles_out.borrow_mut().push(center);
+            });
+            m.check_draw_text(move |_, _, _, _, text| {
+                texts_out.borrow_mut().push(text.to_string())
+            });
+        });
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..20.0, -10.0..30.0)
+            .expect("Build chart error");
+
+        // A flat signal with a few bumps and two spikes
+        let data: Vec<(f64, f64)> = (0..20)
+            .map(|i| {
+                let y = match i {
+                    5 => 25.0,
+                    13 => 20.0,
+                    i if i % 3 == 0 => 1.0,
+                    _ => 0.0,
+                };
+                (f64::from(i), y)
+            })
+            .collect();
+        let threshold = chart
+            .draw_threshold_line(
+                data.clone(),
+                OutlierRule::ZScore(1.5),
+                RED,
+                Some(BLUE.into()),
+            )
+            .expect("Drawing Error")
+            .unwrap();
+
+        let ys: Vec<f64> = data.iter().map(|p| p.1).collect();
+        let mean = ys.iter().sum::<f64>() / 20.0;
+        let std = (ys.iter().map(|y| (y - mean).powi(2)).sum::<f64>() / 20.0).sqrt();
+        assert!((threshold - (mean + 1.5 * std)).abs() < 1e-9);
+
+        // The line is at the threshold, across the chart
+        let paths = paths.borrow();
+        assert_eq!(paths.len(), 1);
+        let y = chart.backend_coord(&(0.0, threshold)).1;
+        assert!(paths[0].iter().all(|p| p.1 == y));
+        assert_eq!(
+            *texts.borrow(),
+            vec![format!("mean + 1.5σ = {:.2}", threshold)]
+        );
+        // Only the two spikes are above the threshold
+        assert_eq!(
+            *circles.borrow(),
+            vec![
+                chart.backend_coord(&(5.0, 25.0)),
+                chart.backend_coord(&(13.0, 20.0))
+            ]
+        );
+    }
+}
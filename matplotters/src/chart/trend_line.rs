@@ -0,0 +1,263 @@
+use super::ChartContext;
+
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::types::RangedCoordf64;
+use crate::data::{fit_linear, fit_quadratic, LinearFit, QuadraticFit};
+use crate::drawing::DrawingAreaErrorKind;
+use crate::element::{Arrowhead, EmptyElement, PathElement, Text};
+use crate::style::{IntoFont, ShapeStyle, TextStyle};
+
+use plotters_backend::DrawingBackend;
+
+/// The number of points the fitted curve is drawn with
+const TREND_SAMPLES: usize = 100;
+
+impl<'a, DB: DrawingBackend> ChartContext<'a, DB, Cartesian2d<RangedCoordf64, RangedCoordf64>> {
+    /**
+    Fits a quadratic to the data by least squares, draws the fitted curve over the x range of
+    the data, and writes the R² of the fit in the top left corner of the plotting area.
+
+    - `data`: The `(x, y)` points to fit; they are not drawn
+    - `style`: The style of the curve; the annotation takes its color
+    - **returns** The coefficients and the R² of the fit, or `None` if there are less than 3
+      distinct x values, in which case nothing is drawn
+
+    See [`fit_quadratic`] for more information.
+    */
+    pub fn draw_quadratic_trend<I, S>(
+        &self,
+        data: I,
+        style: S,
+    ) -> Result<Option<QuadraticFit>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        I: IntoIterator<Item = (f64, f64)>,
+        S: Into<ShapeStyle>,
+    {
+        let data: Vec<_> = data.into_iter().collect();
+        let fit = match fit_quadratic(data.iter().copied()) {
+            Some(fit) => fit,
+            None => return Ok(None),
+        };
+        let xs = data.iter().map(|(x, _)| *x).filter(|x| x.is_finite());
+        let x_min = xs.clone().fold(f64::INFINITY, f64::min);
+        let x_max = xs.fold(f64::NEG_INFINITY, f64::max);
+
+        let style = style.into();
+        let curve = (0..TREND_SAMPLES).map(|i| {
+            let x = x_min + (x_max - x_min) * i as f64 / (TREND_SAMPLES - 1) as f64;
+            (x, fit.eval(x))
+        });
+        self.drawing_area
+            .draw(&PathElement::new(curve.collect::<Vec<_>>(), style))?;
+
+        let font: TextStyle = ("sans-serif", 12).into_font().color(&style.color);
+        self.drawing_area.strip_coord_spec().draw(&Text::new(
+            format!("R² = {:.4}", fit.r_squared),
+            (10, 10),
+            font,
+        ))?;
+        Ok(Some(fit))
+    }
+
+    /**
+    Fits a line to the data by least squares and draws it over the x range of the data, with
+    an arrowhead at its right end showing the direction of the trend, and the slope written
+    next to the arrowhead.
+
+    - `data`: The `(x, y)` points to fit; they are not drawn
+    - `arrow_size`: The size of the arrowhead, in pixels
+    - `style`: The style of the line and the arrowhead; the label takes its color
+    - **returns** The slope, the intercept and the R² of the fit, or `None` if there are less
+      than 2 distinct x values, in which case nothing is drawn
+
+    See [`fit_linear`] for more information.
+    */
+    pub fn draw_linear_trend<I, S>(
+        &self,
+        data: I,
+        arrow_size: u32,
+        style: S,
+    ) -> Result<Option<LinearFit>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        I: IntoIterator<Item = (f64, f64)>,
+        S: Into<ShapeStyle>,
+    {
+        let data: Vec<_> = data.into_iter().collect();
+        let fit = match fit_linear(data.iter().copied()) {
+            Some(fit) => fit,
+            None => return Ok(None),
+        };
+        let xs = data.iter().map(|(x, _)| *x).filter(|x| x.is_finite());
+        let x_min = xs.clone().fold(f64::INFINITY, f64::min);
+        let x_max = xs.fold(f64::NEG_INFINITY, f64::max);
+
+        let style = style.into();
+        let (start, end) = ((x_min, fit.eval(x_min)), (x_max, fit.eval(x_max)));
+        self.drawing_area
+            .draw(&PathElement::new(vec![start, end], style))?;
+        // The arrowhead points on along the line, in its direction on the screen
+        self.drawing_area
+            .draw(&Arrowhead::at_end((start, end), arrow_size, style))?;
+
+        let font: TextStyle = ("sans-serif", 12).into_font().color(&style.color);
+        let offset = arrow_size as i32 + 3;
+        self.drawing_area.draw(
+            &(EmptyElement::at(end)
+                + Text::new(format!("slope = {:.3}", fit.slope), (offset, -offset), font)),
+        )?;
+        Ok(Some(fit))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_quadratic_trend() {
+        let path = Rc::new(RefCell::new(vec![]));
+        let path_out = path.clone();
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_path(move |c, _, p| {
+                assert_eq!(c, RED.to_rgba());
+                path_out.borrow_mut().extend(p);
+            });
+            m.check_draw_text(|c, _, _, pos, text| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(pos, (10, 10));
+                assert_eq!(text, "R² = 1.0000");
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 1);
+                assert_eq!(b.num_draw_text_call, 1);
+            });
+        });
+
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..100.0)
+            .expect("Build chart error");
+        let f = |x: f64| x * x - 4.0 * x + 10.0;
+        let data: Vec<_> = (1..=9).map(|x| (f64::from(x), f(f64::from(x)))).collect();
+        let fit = chart
+            .draw_quadratic_trend(data, RED)
+            .expect("Drawing Error")
+            .unwrap();
+
+        let [a, b, c] = fit.coefficients;
+        assert!((a - 1.0).abs() < 1e-9);
+        assert!((b + 4.0).abs() < 1e-9);
+        assert!((c - 10.0).abs() < 1e-9);
+        assert!((fit.r_squared - 1.0).abs() < 1e-12);
+
+        let path = path.borrow();
+        assert_eq!(path.len(), 100);
+        assert_eq!(path[0], chart.backend_coord(&(1.0, f(1.0))));
+        assert_eq!(path[99], chart.backend_coord(&(9.0, f(9.0))));
+    }
+
+    #[test]
+    fn test_linear_trend_arrow() {
+        let arrows = Rc::new(RefCell::new(vec![]));
+        let texts = Rc::new(RefCell::new(vec![]));
+        let (arrows_out, texts_out) = (arrows.clone(), texts.clone());
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_fill_polygon(move |c, p| {
+                assert_eq!(c, BLUE.to_rgba());
+                arrows_out.borrow_mut().push(p);
+            });
+            m.check_draw_text(move |c, _, _, pos, text| {
+                assert_eq!(c, BLUE.to_rgba());
+                texts_out.borrow_mut().push((pos, text.to_string()));
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_path_call, 1));
+        });
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..20.0)
+            .expect("Build chart error");
+        let data = [(1.0, 3.5), (2.0, 4.5), (3.0, 7.5), (4.0, 8.5), (5.0, 11.5)];
+        let fit = chart
+            .draw_linear_trend(data, 10, BLUE)
+            .expect("Drawing Error")
+            .unwrap();
+        assert!((fit.slope - 2.0).abs() < 1e-12);
+        assert!((fit.intercept - 1.1).abs() < 1e-12);
+
+        // The arrowhead's tip is at the end of the line, and its base is down the slope
+        let arrows = arrows.borrow();
+        assert_eq!(arrows.len(), 1);
+        let tip = chart.backend_coord(&(5.0, fit.eval(5.0)));
+        assert_eq!(arrows[0][0], tip);
+        let base = (
+            (arrows[0][1].0 + arrows[0][2].0) / 2,
+            (arrows[0][1].1 + arrows[0][2].1) / 2,
+        );
+        assert!(base.0 < tip.0 && base.1 > tip.1);
+        // It points along the line on screen, which rises to the right
+        let line = chart.backend_coord(&(1.0, fit.eval(1.0)));
+        let (dx, dy) = (f64::from(tip.0 - line.0), f64::from(tip.1 - line.1));
+        let (ax, ay) = (f64::from(tip.0 - base.0), f64::from(tip.1 - base.1));
+        assert!((dx * ay - dy * ax).abs() / dx.hypot(dy) <= 1.5);
+
+        assert_eq!(
+            *texts.borrow(),
+            vec![((tip.0 + 13, tip.1 - 13), "slope = 2.000".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_linear_trend_arrow_across_chart() {
+        let arrows = Rc::new(RefCell::new(vec![]));
+        let arrows_out = arrows.clone();
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_fill_polygon(move |_, p| arrows_out.borrow_mut().push(p));
+        });
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Build chart error");
+        // The data fills the x range, so the line ends on the right edge of the chart
+        let data: Vec<_> = (0..=10)
+            .map(|x| (f64::from(x), 1.0 + 0.5 * f64::from(x)))
+            .collect();
+        let fit = chart
+            .draw_linear_trend(data, 10, BLUE)
+            .expect("Drawing Error")
+            .unwrap();
+
+        let arrows = arrows.borrow();
+        assert_eq!(arrows.len(), 1);
+        let (start, tip) = (
+            chart.backend_coord(&(0.0, fit.eval(0.0))),
+            chart.backend_coord(&(10.0, fit.eval(10.0))),
+        );
+        assert_eq!(arrows[0][0], tip);
+        // The arrowhead has its full size, along the line
+        let base = (
+            (arrows[0][1].0 + arrows[0][2].0) / 2,
+            (arrows[0][1].1 + arrows[0][2].1) / 2,
+        );
+        let (dx, dy) = (f64::from(tip.0 - start.0), f64::from(tip.1 - start.1));
+        let (ax, ay) = (f64::from(tip.0 - base.0), f64::from(tip.1 - base.1));
+        assert!((ax.hypot(ay) - 10.0).abs() <= 1.5);
+        assert!((dx * ay - dy * ax).abs() / dx.hypot(dy) <= 1.5);
+        assert!(ax > 0.0 && ay < 0.0);
+    }
+
+    #[test]
+    fn test_quadratic_trend_degenerate() {
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_path_call, 0);
+                assert_eq!(b.num_draw_text_call, 0);
+            });
+        });
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Build chart error");
+        let fit = chart
+            .draw_quadratic_trend([(1.0, 1.0), (2.0, 2.0)], RED)
+            .expect("Drawing Error");
+        assert_eq!(fit, None);
+    }
+}
@@ -0,0 +1,127 @@
+use super::ChartContext;
+
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::Ranged;
+use crate::drawing::DrawingAreaErrorKind;
+use crate::element::Rectangle;
+use crate::style::{Color, ShapeStyle, GREEN, RED, YELLOW};
+
+use plotters_backend::DrawingBackend;
+
+/**
+Shaded horizontal background zones between value boundaries, like the good, warning and bad
+zones behind a KPI line or a gauge.
+
+The zone `i` spans y from the boundary `i` to the boundary `i + 1` across the full x range of
+the chart, and is shaded with the style `i`, the styles being used in turn if there are more
+zones than styles. The zones are drawn with [`ChartContext::shade_value_zones()`].
+*/
+pub struct ValueZones {
+    boundaries: Vec<f64>,
+    styles: Vec<ShapeStyle>,
+}
+
+impl ValueZones {
+    /**
+    Creates zones between the sorted boundaries, shaded in green, yellow and red at 20%
+    opacity, from the lowest zone up.
+
+    See [`ValueZones`] for more information.
+    */
+    pub fn new<I: IntoIterator<Item = f64>>(boundaries: I) -> Self {
+        Self {
+            boundaries: boundaries.into_iter().collect(),
+            styles: vec![
+                GREEN.mix(0.2).filled(),
+                YELLOW.mix(0.2).filled(),
+                RED.mix(0.2).filled(),
+            ],
+        }
+    }
+
+    /**
+    Sets the styles of the zones, from the lowest zone up.
+
+    See [`ValueZones`] for more information.
+    */
+    pub fn styles<I, S>(mut self, styles: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<ShapeStyle>,
+    {
+        self.styles = styles.into_iter().map(Into::into).collect();
+        self
+    }
+
+    fn zones(&self) -> impl Iterator<Item = (f64, f64, ShapeStyle)> + '_ {
+        let styles = &self.styles;
+        self.boundaries
+            .windows(2)
+            .zip(styles.iter().cycle())
+            .map(|(pair, style)| (pair[0], pair[1], *style))
+    }
+}
+
+impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged<ValueType = f64>>
+    ChartContext<'a, DB, Cartesian2d<X, Y>>
+where
+    X::ValueType: Clone,
+{
+    /**
+    Draws the shaded value zones. Draw them before the series, so that they stay in the
+    background.
+
+    See [`ValueZones`] for more information.
+    */
+    pub fn shade_value_zones(
+        &self,
+        zones: &ValueZones,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let x = self.x_range();
+        for (low, high, style) in zones.zones() {
+            self.drawing_area.draw(&Rectangle::new(
+                [(x.start.clone(), high), (x.end.clone(), low)],
+                style,
+            ))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_value_zones() {
+        let rects = Rc::new(RefCell::new(vec![]));
+        let rects_out = rects.clone();
+        let drawing_area = create_mocked_drawing_area(501, 301, |m| {
+            m.check_draw_rect(move |c, _, f, ul, br| rects_out.borrow_mut().push((c, f, ul, br)));
+        });
+
+        let chart = ChartBuilder::on(&drawing_area)
+            .margin(10)
+            .build_cartesian_2d(0.0..12.0, 0.0..100.0)
+            .expect("Build chart error");
+        chart
+            .shade_value_zones(&ValueZones::new([0.0, 50.0, 80.0, 100.0]))
+            .expect("Drawing Error");
+
+        let rects = rects.borrow();
+        assert_eq!(rects.len(), 3);
+        let colors = [GREEN.mix(0.2), YELLOW.mix(0.2), RED.mix(0.2)];
+        let spans = [(0.0, 50.0), (50.0, 80.0), (80.0, 100.0)];
+        for ((c, filled, ul, br), (color, (low, high))) in
+            rects.iter().zip(colors.iter().zip(spans))
+        {
+            assert_eq!(*c, color.to_rgba());
+            assert!(*filled);
+            // Each zone spans its values, across the full x axis
+            assert_eq!(*ul, chart.backend_coord(&(0.0, high)));
+            assert_eq!(*br, chart.backend_coord(&(12.0, low)));
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use super::ChartContext;
+
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::Ranged;
+use crate::drawing::DrawingAreaErrorKind;
+use crate::element::Rectangle;
+use crate::style::{Color, ShapeStyle, BLACK};
+
+use plotters_backend::DrawingBackend;
+
+/**
+Alternating background bands aligned with the category slots of a categorical chart, like the
+stripes of a table, to make wide charts easier to read.
+
+Category `i` of the chart is centered on its position and spans the category width, 1 by
+default, and is shaded with the first style for even `i` and the second one for odd `i`. The
+bands are drawn across the full range of the other axis with
+[`ChartContext::zebra_bands_x()`] when the categories are along the x axis, and with
+[`ChartContext::zebra_bands_y()`] when they are along the y axis.
+*/
+pub struct ZebraBands {
+    positions: Vec<f64>,
+    width: f64,
+    styles: [ShapeStyle; 2],
+}
+
+impl ZebraBands {
+    /**
+    Creates bands for the categories at the given positions, such as the x values of the bars
+    of a [`crate::series::MatBarSeries`]. The bands are light and dark gray.
+
+    See [`ZebraBands`] for more information.
+    */
+    pub fn new<I: IntoIterator<Item = f64>>(positions: I) -> Self {
+        Self {
+            positions: positions.into_iter().collect(),
+            width: 1.0,
+            styles: [BLACK.mix(0.03).filled(), BLACK.mix(0.1).filled()],
+        }
+    }
+
+    /**
+    Sets the width of the category slots, in data units.
+
+    See [`ZebraBands`] for more information.
+    */
+    pub fn width(mut self, width: f64) -> Self {
+        self.width = width;
+        self
+    }
+
+    /**
+    Sets the styles of the even and the odd category slots.
+
+    See [`ZebraBands`] for more information.
+    */
+    pub fn styles<E: Into<ShapeStyle>, O: Into<ShapeStyle>>(mut self, even: E, odd: O) -> Self {
+        self.styles = [even.into(), odd.into()];
+        self
+    }
+
+    fn slots(&self) -> impl Iterator<Item = (f64, f64, ShapeStyle)> + '_ {
+        let half = self.width / 2.0;
+        self.positions
+            .iter()
+            .enumerate()
+            .map(move |(idx, p)| (p - half, p + half, self.styles[idx % 2]))
+    }
+}
+
+impl<'a, DB: DrawingBackend, X: Ranged<ValueType = f64>, Y: Ranged>
+    ChartContext<'a, DB, Cartesian2d<X, Y>>
+where
+    Y::ValueType: Clone,
+{
+    /**
+    Draws vertical zebra bands for the categories along the x axis. Draw them before the
+    series, so that they stay in the background.
+
+    See [`ZebraBands`] for more information.
+    */
+    pub fn zebra_bands_x(
+        &self,
+        bands: &ZebraBands,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let y = self.y_range();
+        for (start, end, style) in bands.slots() {
+            self.drawing_area.draw(&Rectangle::new(
+                [(start, y.end.clone()), (end, y.start.clone())],
+                style,
+            ))?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, DB: DrawingBackend, X: Ranged, Y: Ranged<ValueType = f64>>
+    ChartContext<'a, DB, Cartesian2d<X, Y>>
+where
+    X::ValueType: Clone,
+{
+    /**
+    Draws horizontal zebra bands for the categories along the y axis, as in a horizontal bar
+    chart. Draw them before the series, so that they stay in the background.
+
+    See [`ZebraBands`] for more information.
+    */
+    pub fn zebra_bands_y(
+        &self,
+        bands: &ZebraBands,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let x = self.x_range();
+        for (start, end, style) in bands.slots() {
+            self.drawing_area.draw(&Rectangle::new(
+                [(x.start.clone(), end), (x.end.clone(), start)],
+                style,
+            ))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_zebra_bands_x() {
+        let rects = Rc::new(RefCell::new(vec![]));
+        let rects_out = rects.clone();
+        let drawing_area = create_mocked_drawing_area(501, 301, |m| {
+            m.check_draw_rect(move |c, _, f, ul, br| rects_out.borrow_mut().push((c, f, ul, br)));
+        });
+
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(-0.5..4.5, 0.0..10.0)
+            .expect("Build chart error");
+        chart
+            .zebra_bands_x(
+                &ZebraBands::new((0..5).map(f64::from))
+                    .styles(WHITE.filled(), GREEN.mix(0.2).filled()),
+            )
+            .expect("Drawing Error");
+
+        let rects = rects.borrow();
+        assert_eq!(rects.len(), 5);
+        for (idx, (c, filled, ul, br)) in rects.iter().enumerate() {
+            let expected = if idx % 2 == 0 {
+                WHITE.to_rgba()
+            } else {
+                GREEN.mix(0.2).to_rgba()
+            };
+            assert_eq!(*c, expected);
+            assert!(*filled);
+            let x = idx as f64;
+            assert_eq!(*ul, chart.backend_coord(&(x - 0.5, 10.0)));
+            assert_eq!(*br, chart.backend_coord(&(x + 0.5, 0.0)));
+        }
+        // Consecutive slots share their boundary
+        assert_eq!(rects[0].3 .0, rects[1].2 .0);
+    }
+
+    #[test]
+    fn test_zebra_bands_y() {
+        let rects = Rc::new(RefCell::new(vec![]));
+        let rects_out = rects.clone();
+        let drawing_area = create_mocked_drawing_area(501, 301, |m| {
+            m.check_draw_rect(move |c, _, _, ul, br| rects_out.borrow_mut().push((c, ul, br)));
+        });
+
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..6.0)
+            .expect("Build chart error");
+        let bands = ZebraBands::new([1.0, 3.0, 5.0]).width(2.0);
+        chart.zebra_bands_y(&bands).expect("Drawing Error");
+
+        let rects = rects.borrow();
+        assert_eq!(rects.len(), 3);
+        assert_eq!(rects[0].0, BLACK.mix(0.03).to_rgba());
+        assert_eq!(rects[1].0, BLACK.mix(0.1).to_rgba());
+        assert_eq!(rects[2].0, rects[0].0);
+        for (idx, (_, ul, br)) in rects.iter().enumerate() {
+            let y = 2.0 * idx as f64 + 1.0;
+            assert_eq!(*ul, chart.backend_coord(&(0.0, y + 1.0)));
+            assert_eq!(*br, chart.backend_coord(&(10.0, y - 1.0)));
+        }
+    }
+}
@@ -0,0 +1,248 @@
+use crate::coord::ranged1d::types::RangedCoordf64;
+use crate::coord::ranged1d::{AsRangedCoord, DefaultFormatting, KeyPointHint, Ranged};
+use crate::data::largest_gap;
+use std::ops::Range;
+
+/// Convert a range to a broken axis coordinate spec
+pub trait IntoBrokenRange {
+    /// Make the broken axis coordinate, leaving out the values in `gap`
+    fn broken_at(self, gap: Range<f64>) -> BrokenRangeExt;
+}
+
+impl IntoBrokenRange for Range<f64> {
+    fn broken_at(self, gap: Range<f64>) -> BrokenRangeExt {
+        BrokenRangeExt {
+            range: self,
+            gap: gap.start.min(gap.end)..gap.start.max(gap.end),
+            gap_fraction: 0.03,
+        }
+    }
+}
+
+/// The broken axis coordinate decorator, for data in two clusters far apart: the values in a
+/// gap of the range are left out, and the two parts of the axis on each side of it are drawn
+/// next to each other, with the same scale, separated by a small blank.
+/// The break is shown with [`crate::chart::ChartContext::draw_break_marks`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct BrokenRangeExt {
+    range: Range<f64>,
+    gap: Range<f64>,
+    gap_fraction: f64,
+}
+
+impl BrokenRangeExt {
+    /// Set the room taken by the blank between the two parts of the axis
+    ///
+    /// - `fraction`: The fraction of the length of the axis, 0.03 by default
+    pub fn gap_fraction(mut self, fraction: f64) -> Self {
+        self.gap_fraction = fraction.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Make a broken axis fitting the data, broken at the widest empty interval between the
+    /// values if it is large enough, so that the dense parts of the data get the room of the
+    /// axis. The range and the gap leave a margin of 5% of the kept length around the data.
+    /// When there is no large enough interval, the axis is not broken.
+    ///
+    /// - `values`: The data to fit
+    /// - `min_gap_fraction`: The smallest width of the interval to break the axis at, as a
+    ///   fraction of the span of the data, see [`crate::data::largest_gap`]
+    pub fn from_data(values: &[f64], min_gap_fraction: f64) -> Self {
+        let finite = values.iter().copied().filter(|v| v.is_finite());
+        let lo = finite.clone().fold(f64::INFINITY, f64::min);
+        let hi = finite.fold(f64::NEG_INFINITY, f64::max);
+        if lo > hi {
+            return (0.0..1.0).broken_at(1.0..1.0);
+        }
+        let gap = largest_gap(values, min_gap_fraction).unwrap_or(hi..hi);
+        let margin = ((gap.start - lo) + (hi - gap.end)).max(f64::EPSILON) * 0.05;
+        let gap = if gap.end > gap.start {
+            gap.start + margin..gap.end - margin
+        } else {
+            gap.start + margin..gap.end + margin
+        };
+        (lo - margin..hi + margin).broken_at(gap)
+    }
+
+    /// The range of the axis
+    pub fn range(&self) -> Range<f64> {
+        self.range.clone()
+    }
+
+    /// The values left out of the axis
+    pub fn gap(&self) -> Range<f64> {
+        self.gap.clone()
+    }
+}
+
+impl From<BrokenRangeExt> for BrokenCoord {
+    fn from(spec: BrokenRangeExt) -> BrokenCoord {
+        let Range { start, end } = spec.range;
+        let (lo, hi) = (start.min(end), start.max(end));
+        // A gap reaching out of the range only cuts it
+        let gap = spec.gap.start.clamp(lo, hi)..spec.gap.end.clamp(lo, hi);
+        // and an empty gap takes no room
+        let gap_fraction = if gap.end > gap.start {
+            spec.gap_fraction
+        } else {
+            0.0
+        };
+        BrokenCoord {
+            logic: spec.range,
+            gap,
+            gap_fraction,
+        }
+    }
+}
+
+impl AsRangedCoord for BrokenRangeExt {
+    type CoordDescType = BrokenCoord;
+    type Value = f64;
+}
+
+/// A broken coordinate axis
+pub struct BrokenCoord {
+    logic: Range<f64>,
+    gap: Range<f64>,
+    gap_fraction: f64,
+}
+
+impl BrokenCoord {
+    /// The position of a value along the axis, from 0 at the start of the range to 1 at its
+    /// end, the gap taking its fraction of the axis
+    fn position(&self, v: f64) -> f64 {
+        let Range { start, end } = self.logic;
+        let (lo, hi) = (start.min(end), start.max(end));
+        let kept = (self.gap.start - lo) + (hi - self.gap.end);
+        if kept <= 0.0 {
+            return 0.0;
+        }
+        let scale = (1.0 - self.gap_fraction) / kept;
+        let t = if v <= self.gap.start {
+            (v - lo) * scale
+        } else if v >= self.gap.end {
+            (self.gap.start - lo + v - self.gap.end) * scale + self.gap_fraction
+        } else {
+            let below = (self.gap.start - lo) * scale;
+            below + self.gap_fraction * (v - self.gap.start) / (self.gap.end - self.gap.start)
+        };
+        if start <= end {
+            t
+        } else {
+            1.0 - t
+        }
+    }
+}
+
+impl Ranged for BrokenCoord {
+    type FormatOption = DefaultFormatting;
+    type ValueType = f64;
+
+    fn map(&self, value: &f64, limit: (i32, i32)) -> i32 {
+        let t = self.position(*value);
+        limit.0 + (f64::from(limit.1 - limit.0) * t).round() as i32
+    }
+
+    /// The key points of each part of the axis, as for a linear axis over it, taking their
+    /// share of the points to their length
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<f64> {
+        let max_points = hint.max_num_points();
+        let Range { start, end } = self.logic;
+        let (lo, hi) = (start.min(end), start.max(end));
+        let length = (self.gap.start - lo) + (hi - self.gap.end);
+        let mut ret = vec![];
+        for part in [lo..self.gap.start, self.gap.end..hi] {
+            if part.end <= part.start {
+                continue;
+            }
+            let share = ((part.end - part.start) / length * max_points as f64).floor() as usize;
+            let coord: RangedCoordf64 = part.into();
+            ret.extend(coord.key_points(share.max(1)));
+        }
+        ret.truncate(max_points);
+        ret
+    }
+
+    fn range(&self) -> Range<f64> {
+        self.logic.clone()
+    }
+}
+
+/// The coordinate axes that can be broken, which tell
+/// [`crate::chart::ChartContext::draw_break_marks`] where to draw the marks
+pub trait AxisBreaks: Ranged {
+    /// The values left out of the axis, one range per break
+    fn breaks(&self) -> Vec<Range<Self::ValueType>>;
+}
+
+impl AxisBreaks for BrokenCoord {
+    fn breaks(&self) -> Vec<Range<f64>> {
+        if self.gap.end > self.gap.start {
+            vec![self.gap.clone()]
+        } else {
+            vec![]
+        }
+    }
+}
+
+impl AxisBreaks for RangedCoordf64 {
+    fn breaks(&self) -> Vec<Range<f64>> {
+        vec![]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_broken_map() {
+        let range: BrokenCoord = (0.0..100.0).broken_at(40.0..90.0).gap_fraction(0.1).into();
+        // The 50 values kept take 900 pixels, and the gap 100 pixels
+        assert_eq!(range.map(&0.0, (0, 1000)), 0);
+        assert_eq!(range.map(&20.0, (0, 1000)), 360);
+        assert_eq!(range.map(&40.0, (0, 1000)), 720);
+        assert_eq!(range.map(&90.0, (0, 1000)), 820);
+        assert_eq!(range.map(&100.0, (0, 1000)), 1000);
+        // A reversed pixel range, as for a y axis
+        assert_eq!(range.map(&40.0, (1000, 0)), 280);
+        assert_eq!(range.breaks(), vec![40.0..90.0]);
+    }
+
+    #[test]
+    fn test_broken_from_data() {
+        // Two dense clusters, at 0-10 and at 1000-1010
+        let values: Vec<f64> = (0..=10)
+            .map(f64::from)
+            .chain((1000..=1010).map(f64::from))
+            .collect();
+        let spec = BrokenRangeExt::from_data(&values, 0.5);
+        assert_eq!(spec.range(), -1.0..1011.0);
+        assert_eq!(spec.gap(), 11.0..999.0);
+        let range: BrokenCoord = spec.into();
+        assert_eq!(range.breaks(), vec![11.0..999.0]);
+
+        // Each cluster takes almost half of the axis, instead of 1% on a linear axis
+        let linear: RangedCoordf64 = (-1.0..1011.0).into();
+        assert!(linear.map(&10.0, (0, 1000)) - linear.map(&0.0, (0, 1000)) <= 10);
+        assert_eq!(range.map(&0.0, (0, 1000)), 40);
+        assert_eq!(range.map(&10.0, (0, 1000)), 445);
+        assert_eq!(range.map(&1000.0, (0, 1000)), 555);
+        assert_eq!(range.map(&1010.0, (0, 1000)), 960);
+
+        // Evenly spread data keeps a plain axis
+        let values: Vec<f64> = (0..=100).map(f64::from).collect();
+        let range: BrokenCoord = BrokenRangeExt::from_data(&values, 0.5).into();
+        assert!(range.breaks().is_empty());
+        assert_eq!(range.map(&50.0, (0, 1000)), 500);
+    }
+
+    #[test]
+    fn test_broken_key_points() {
+        let range: BrokenCoord = (0.0..100.0).broken_at(40.0..90.0).into();
+        let points = range.key_points(10);
+        assert!(points.len() <= 10);
+        assert!(points.iter().all(|p| !(40.0 < *p && *p < 90.0)));
+        assert!(points.contains(&0.0) && points.contains(&100.0));
+    }
+}
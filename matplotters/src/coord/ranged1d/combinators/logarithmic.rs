@@ -183,6 +183,50 @@ impl<V: LogScalable> LogCoord<V> {
 
         (V::as_f64(&a) - V::as_f64(&b)).abs() < std::f64::EPSILON
     }
+
+    /// Compute the normalized positions of the log ticks within the range: the decades, and
+    /// if `minor` is set, the sub-decade positions `2 * base^n, 3 * base^n, ...` between them.
+    /// The sub-decade positions are only defined for an integer base.
+    fn log_ticks(&self, minor: bool) -> Vec<f64> {
+        let base = self.base;
+        let Range { mut start, mut end } = self.normalized;
+        if start > end {
+            std::mem::swap(&mut start, &mut end);
+        }
+
+        let multipliers: Vec<f64> = if minor && base.fract() == 0.0 {
+            (1..base as u32).map(f64::from).collect()
+        } else {
+            vec![1.0]
+        };
+
+        // A tiny tolerance avoids losing the end points to floating point error
+        let (lower, upper) = (start * (1.0 - 1e-9), end * (1.0 + 1e-9));
+        let mut ret = vec![];
+        let mut decade = base.powf((start.ln() / base.ln()).floor());
+        while decade <= upper {
+            for k in multipliers.iter() {
+                let v = decade * k;
+                if v >= lower && v <= upper && !self.is_inf(v) {
+                    ret.push(v);
+                }
+            }
+            decade *= base;
+        }
+        ret
+    }
+
+    /// Returns the minor key points of the axis, which are located at the sub-decade positions
+    /// `2, 3, ..., 9` times each power of the base (for base 10), like the matplotlib log minor
+    /// locator. The decades themselves are not included.
+    pub fn minor_key_points(&self) -> Vec<V> {
+        let decades = self.log_ticks(false);
+        self.log_ticks(true)
+            .into_iter()
+            .filter(|v| !decades.contains(v))
+            .map(|v| self.f64_to_value(v))
+            .collect()
+    }
 }
 
 impl<V: LogScalable> Ranged for LogCoord<V> {
@@ -207,6 +251,15 @@ impl<V: LogScalable> Ranged for LogCoord<V> {
             std::mem::swap(&mut start, &mut end);
         }
 
+        // If light points are allowed, place them at the sub-decade positions, as long as
+        // there is enough room for all of them.
+        if hint.weight().allow_light_points() {
+            let ticks = self.log_ticks(true);
+            if ticks.len() > self.log_ticks(false).len() && ticks.len() <= max_points {
+                return ticks.into_iter().map(|v| self.f64_to_value(v)).collect();
+            }
+        }
+
         let bold_count = ((end / start).ln().abs() / base_ln).floor().max(1.0) as usize;
 
         let light_density = if max_points < bold_count {
@@ -278,10 +331,35 @@ impl<V: LogScalable> From<LogRange<V>> for LogCoord<V> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::coord::ranged1d::{BoldPoints, LightPoints};
     #[test]
     fn regression_test_issue_143() {
         let range: LogCoord<f64> = (1.0..5.0).log_scale().into();
 
         range.key_points(100);
     }
+
+    #[test]
+    fn test_log_minor_key_points() {
+        let range: LogCoord<f64> = (1.0..10.0).log_scale().into();
+        let minor = range.minor_key_points();
+        assert_eq!(minor.len(), 8);
+        for (k, v) in (2..=9).zip(minor) {
+            assert!((v - f64::from(k)).abs() < 1e-9);
+            // The mapped position is log10(k) of the way through the decade
+            let expected = (1000.0 * f64::from(k).log10()).round() as i32;
+            assert!((range.map(&v, (0, 1000)) - expected).abs() <= 1);
+        }
+
+        let range: LogCoord<f64> = (0.1..100.0).log_scale().into();
+        let light = range.key_points(LightPoints::new(10, 100));
+        assert_eq!(light.len(), 3 * 9 + 1);
+        assert!(light.iter().any(|v| (v - 0.2).abs() < 1e-9));
+        assert!(light.iter().any(|v| (v - 50.0).abs() < 1e-9));
+
+        // The bold points are left on the decades
+        for v in range.key_points(BoldPoints(10)) {
+            assert!((v.log10() - v.log10().round()).abs() < 1e-9);
+        }
+    }
 }
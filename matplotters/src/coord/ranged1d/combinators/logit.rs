@@ -0,0 +1,138 @@
+use crate::coord::ranged1d::types::RangedCoordf64;
+use crate::coord::ranged1d::{AsRangedCoord, DefaultFormatting, KeyPointHint, Ranged};
+use std::ops::Range;
+
+/// The probabilities closer than this to 0 or 1 are clamped, so that they map to a finite
+/// position
+const LOGIT_CLAMP: f64 = 1e-7;
+
+fn logit(p: f64) -> f64 {
+    let p = p.clamp(LOGIT_CLAMP, 1.0 - LOGIT_CLAMP);
+    (p / (1.0 - p)).ln()
+}
+
+/// Convert a range of probabilities to a logit scale coordinate spec
+pub trait IntoLogitRange {
+    /// Make the logit scale coordinate
+    fn logit_scale(self) -> LogitRangeExt;
+}
+
+impl IntoLogitRange for Range<f64> {
+    fn logit_scale(self) -> LogitRangeExt {
+        LogitRangeExt { range: self }
+    }
+}
+
+/// The logit coordinate decorator, for probabilities in `(0, 1)`.
+/// The axis maps a probability `p` to `log(p / (1 - p))`, which spreads the values clustering
+/// near 0 and 1, like the matplotlib `logit` scale. The probabilities of exactly 0 or 1 are
+/// clamped, thus they are drawn close to the ends of the axis instead of at infinity.
+#[derive(Clone)]
+pub struct LogitRangeExt {
+    range: Range<f64>,
+}
+
+impl From<LogitRangeExt> for LogitCoord {
+    fn from(spec: LogitRangeExt) -> LogitCoord {
+        LogitCoord {
+            linear: (logit(spec.range.start)..logit(spec.range.end)).into(),
+            logic: spec.range,
+        }
+    }
+}
+
+impl AsRangedCoord for LogitRangeExt {
+    type CoordDescType = LogitCoord;
+    type Value = f64;
+}
+
+/// A logit scaled coordinate axis
+pub struct LogitCoord {
+    linear: RangedCoordf64,
+    logic: Range<f64>,
+}
+
+impl Ranged for LogitCoord {
+    type FormatOption = DefaultFormatting;
+    type ValueType = f64;
+
+    fn map(&self, value: &f64, limit: (i32, i32)) -> i32 {
+        self.linear.map(&logit(*value), limit)
+    }
+
+    /// The key points are the median 0.5, and the probabilities `10^-k` and `1 - 10^-k`
+    /// symmetric to it. If there is no room for all of them, every other decade is dropped.
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<f64> {
+        let max_points = hint.max_num_points();
+        let (lo, hi) = {
+            let Range { start, end } = self.logic;
+            (start.min(end), start.max(end))
+        };
+        let mut decades = vec![];
+        let mut p = 0.1;
+        while p >= LOGIT_CLAMP {
+            if (lo..=hi).contains(&p) || (lo..=hi).contains(&(1.0 - p)) {
+                decades.push(p);
+            }
+            p /= 10.0;
+        }
+
+        let mut step = 1;
+        loop {
+            let mut ret: Vec<f64> = (lo..=hi)
+                .contains(&0.5)
+                .then_some(0.5)
+                .into_iter()
+                .collect();
+            for p in decades.iter().step_by(step) {
+                ret.extend([*p, 1.0 - *p].into_iter().filter(|v| *v >= lo && *v <= hi));
+            }
+            if ret.len() <= max_points || step > decades.len() {
+                ret.truncate(max_points);
+                ret.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                return ret;
+            }
+            step += 1;
+        }
+    }
+
+    fn range(&self) -> Range<f64> {
+        self.logic.clone()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_logit_map() {
+        let range: LogitCoord = (0.001..0.999).logit_scale().into();
+        assert_eq!(range.map(&0.5, (0, 1000)), 500);
+        for p in [0.001, 0.01, 0.2, 0.4] {
+            let a = range.map(&p, (0, 1000));
+            let b = range.map(&(1.0 - p), (0, 1000));
+            assert!((a + b - 1000).abs() <= 1);
+        }
+        // The values near 0 get more room than on a linear scale
+        assert!(range.map(&0.01, (0, 1000)) > 100);
+
+        // The end points are clamped rather than infinite
+        let range: LogitCoord = (0.0..1.0).logit_scale().into();
+        assert_eq!(range.map(&0.0, (0, 1000)), 0);
+        assert_eq!(range.map(&1.0, (0, 1000)), 1000);
+        assert_eq!(range.map(&0.5, (0, 1000)), 500);
+    }
+
+    #[test]
+    fn test_logit_key_points() {
+        let range: LogitCoord = (0.001..0.999).logit_scale().into();
+        let points = range.key_points(10);
+        let expected = [0.001, 0.01, 0.1, 0.5, 0.9, 0.99, 0.999];
+        assert_eq!(points.len(), expected.len());
+        for (a, b) in points.iter().zip(expected) {
+            assert!((a - b).abs() < 1e-12);
+        }
+        assert!(range.key_points(3).len() <= 3);
+    }
+}
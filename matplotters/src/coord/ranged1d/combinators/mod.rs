@@ -1,3 +1,6 @@
+mod broken;
+pub use broken::{AxisBreaks, BrokenCoord, BrokenRangeExt, IntoBrokenRange};
+
 mod ckps;
 pub use ckps::{BindKeyPointMethod, BindKeyPoints, WithKeyPointMethod, WithKeyPoints};
 
@@ -13,8 +16,17 @@ pub use logarithmic::{IntoLogRange, LogCoord, LogScalable};
 #[allow(deprecated)]
 pub use logarithmic::LogRange;
 
+mod logit;
+pub use logit::{IntoLogitRange, LogitCoord, LogitRangeExt};
+
 mod nested;
 pub use nested::{BuildNestedCoord, NestedRange, NestedValue};
 
 mod partial_axis;
 pub use partial_axis::{make_partial_axis, IntoPartialAxis};
+
+mod round_ticks;
+pub use round_ticks::{IntoRoundTicks, RoundTicksCoord, RoundTicksRange};
+
+mod symlog;
+pub use symlog::{IntoSymlogRange, SymlogCoord, SymlogRangeExt};
@@ -0,0 +1,220 @@
+use crate::coord::ranged1d::types::RangedCoordf64;
+use crate::coord::ranged1d::{AsRangedCoord, DefaultFormatting, KeyPointHint, Ranged};
+use std::ops::Range;
+
+/// The bases of the increments of a [`RoundTicksRange`] given no bases
+const DEFAULT_BASES: [f64; 3] = [1.0, 2.0, 5.0];
+
+/// Convert a range to a coordinate spec whose ticks are on round increments
+pub trait IntoRoundTicks {
+    /// Make the coordinate, with ticks on the multiples of an increment `base * 10^k`, for one
+    /// of the bases, such as `&[5.0, 10.0]` for multiples of 5 or 10
+    fn round_ticks(self, bases: &[f64]) -> RoundTicksRange;
+}
+
+impl IntoRoundTicks for Range<f64> {
+    fn round_ticks(self, bases: &[f64]) -> RoundTicksRange {
+        let mut bases: Vec<f64> = bases
+            .iter()
+            .copied()
+            .filter(|b| b.is_finite() && *b > 0.0)
+            .collect();
+        if bases.is_empty() {
+            bases = DEFAULT_BASES.to_vec();
+        }
+        RoundTicksRange { range: self, bases }
+    }
+}
+
+/// The round ticks coordinate decorator, a linear axis whose ticks are forced onto round
+/// increments, the multiples of `base * 10^k` for one of its bases, instead of the automatic
+/// ticks. The smallest increment for which the ticks fit the number of labels is chosen, see
+/// [`RoundTicksRange::increment`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoundTicksRange {
+    range: Range<f64>,
+    bases: Vec<f64>,
+}
+
+impl RoundTicksRange {
+    /// Choose the increment of the ticks: the smallest `base * 10^k` for one of the bases
+    /// with at most `max_points` multiples within the range.
+    ///
+    /// - `max_points`: The largest number of ticks
+    /// - **returns** The increment, or `None` if the range is empty or not finite, or if no
+    ///   tick is allowed
+    pub fn increment(&self, max_points: usize) -> Option<f64> {
+        let (lo, hi) = self.bounds();
+        if max_points == 0 || !lo.is_finite() || !hi.is_finite() || lo >= hi {
+            return None;
+        }
+        let magnitude = ((hi - lo) / max_points as f64).log10().floor() as i32;
+        let mut candidates: Vec<f64> = (magnitude - 2..=magnitude + 2)
+            .flat_map(|k| self.bases.iter().map(move |b| b * 10f64.powi(k)))
+            .collect();
+        candidates.retain(|step| step.is_finite() && *step > 0.0);
+        candidates.sort_by(f64::total_cmp);
+        candidates
+            .into_iter()
+            .find(|step| tick_count(lo, hi, *step) <= max_points as f64)
+    }
+
+    /// The range of the axis
+    pub fn range(&self) -> Range<f64> {
+        self.range.clone()
+    }
+
+    fn bounds(&self) -> (f64, f64) {
+        let Range { start, end } = self.range;
+        (start.min(end), start.max(end))
+    }
+}
+
+/// The number of multiples of the step within `lo..=hi`, without listing them, since a small
+/// step may give far too many of them
+fn tick_count(lo: f64, hi: f64, step: f64) -> f64 {
+    ((hi / step).floor() - (lo / step).ceil() + 1.0).max(0.0)
+}
+
+/// The multiples of the step within `lo..=hi`. A step such as 0.1 is not exact, so the
+/// multiples of a step dividing 1 are computed by dividing by its inverse, as in `3 / 10`,
+/// rather than as in `3 * 0.1`.
+fn ticks(lo: f64, hi: f64, step: f64) -> Vec<f64> {
+    let first = (lo / step).ceil() as i64;
+    let last = (hi / step).floor() as i64;
+    let inverse = 1.0 / step;
+    let divides_one = step < 1.0 && (inverse - inverse.round()).abs() < 1e-9;
+    (first..=last)
+        .map(|i| match divides_one {
+            true => i as f64 / inverse.round(),
+            false => i as f64 * step,
+        })
+        .collect()
+}
+
+impl From<RoundTicksRange> for RoundTicksCoord {
+    fn from(spec: RoundTicksRange) -> RoundTicksCoord {
+        RoundTicksCoord {
+            linear: spec.range.clone().into(),
+            spec,
+        }
+    }
+}
+
+impl AsRangedCoord for RoundTicksRange {
+    type CoordDescType = RoundTicksCoord;
+    type Value = f64;
+}
+
+/// A linear coordinate axis with ticks on round increments
+pub struct RoundTicksCoord {
+    linear: RangedCoordf64,
+    spec: RoundTicksRange,
+}
+
+impl Ranged for RoundTicksCoord {
+    type FormatOption = DefaultFormatting;
+    type ValueType = f64;
+
+    fn map(&self, value: &f64, limit: (i32, i32)) -> i32 {
+        self.linear.map(value, limit)
+    }
+
+    /// The key points are the multiples of the increment within the range
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<f64> {
+        let (lo, hi) = self.spec.bounds();
+        match self.spec.increment(hint.max_num_points()) {
+            Some(step) => ticks(lo, hi, step),
+            None => vec![],
+        }
+    }
+
+    fn range(&self) -> Range<f64> {
+        self.spec.range()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_round_ticks_increment() {
+        let spec = (3.0..97.0).round_ticks(&[5.0, 10.0]);
+        // 5 would give 19 ticks, 10 gives 9
+        assert_eq!(spec.increment(10), Some(10.0));
+        assert_eq!(spec.increment(3), Some(50.0));
+        assert_eq!((0.0..0.8).round_ticks(&[5.0]).increment(10), Some(0.5));
+        let fine: RoundTicksCoord = (0.0..0.35).round_ticks(&[1.0]).into();
+        assert_eq!(fine.key_points(5), vec![0.0, 0.1, 0.2, 0.3]);
+        assert_eq!((1.0..1.0).round_ticks(&[5.0]).increment(10), None);
+        // No valid base falls back to 1, 2 and 5
+        assert_eq!((0.0..7.0).round_ticks(&[-1.0]).increment(5), Some(2.0));
+        let invalid = [0.0, f64::NAN, f64::INFINITY];
+        assert_eq!((0.0..7.0).round_ticks(&invalid).increment(5), Some(2.0));
+        // A tiny base on a wide range is counted without listing the ticks, and too small
+        let tiny = (0.0..1e12).round_ticks(&[1e-9]);
+        assert_eq!(tiny.increment(10), None);
+        assert_eq!(tick_count(0.0, 1e12, 1e-9), 1e21 + 1.0);
+
+        let coord: RoundTicksCoord = spec.into();
+        let points = coord.key_points(10);
+        assert_eq!(
+            points,
+            vec![10.0, 20.0, 30.0, 40.0, 50.0, 60.0, 70.0, 80.0, 90.0]
+        );
+        assert_eq!(coord.map(&50.0, (0, 94)), 47);
+    }
+
+    #[test]
+    fn test_round_ticks_mesh() {
+        use crate::prelude::*;
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let draw_labels = |y_labels: usize| {
+            let labels = Rc::new(RefCell::new(vec![]));
+            let labels_out = labels.clone();
+            let drawing_area = create_mocked_drawing_area(500, 400, |m| {
+                m.check_draw_text(move |_, _, _, pos, text| {
+                    labels_out.borrow_mut().push((pos, text.to_string()))
+                });
+            });
+            let mut chart = ChartBuilder::on(&drawing_area)
+                .y_label_area_size(40)
+                .build_cartesian_2d(0.0..1.0, (3.0..97.0).round_ticks(&[5.0, 10.0]))
+                .expect("Build chart error");
+            chart
+                .configure_mesh()
+                .disable_x_axis()
+                .y_labels(y_labels)
+                .draw()
+                .expect("Drawing Error");
+            let mut values: Vec<f64> = labels
+                .borrow()
+                .iter()
+                .filter(|(pos, _)| pos.0 < 40)
+                .map(|(_, text)| text.parse().unwrap())
+                .collect();
+            values.sort_by(f64::total_cmp);
+            values
+        };
+
+        // 5 would give 19 ticks, so the ticks are on the multiples of 10
+        let values = draw_labels(10);
+        assert_eq!(
+            values,
+            vec![10.0, 20.0, 30.0, 40.0, 50.0, 60.0, 70.0, 80.0, 90.0]
+        );
+        // 20 ticks fit the multiples of 5
+        let values = draw_labels(20);
+        assert_eq!(values.len(), 19);
+        assert_eq!((values[0], values[18]), (5.0, 95.0));
+        for (v, next) in values.iter().zip(&values[1..]) {
+            assert!((3.0..=97.0).contains(v) && v % 5.0 == 0.0);
+            assert_eq!(next - v, 5.0);
+        }
+        // 10 would give 9 ticks, so 5 ticks are on the multiples of 50
+        assert_eq!(draw_labels(5), vec![50.0]);
+    }
+}
@@ -0,0 +1,198 @@
+use crate::coord::ranged1d::types::RangedCoordf64;
+use crate::coord::ranged1d::{AsRangedCoord, DefaultFormatting, KeyPointHint, Ranged};
+use std::ops::Range;
+
+/// The number of decades between the smallest and the largest magnitudes of the data from
+/// which [`SymlogRangeExt::autoscale`] chooses a symlog scale
+const SYMLOG_MIN_DECADES: f64 = 3.0;
+
+fn symlog(v: f64, linthresh: f64) -> f64 {
+    if v.abs() <= linthresh {
+        v / linthresh
+    } else {
+        v.signum() * (1.0 + (v.abs() / linthresh).log10())
+    }
+}
+
+/// Convert a range to a symmetric log scale coordinate spec
+pub trait IntoSymlogRange {
+    /// Make the symlog scale coordinate, linear within `±linthresh`
+    fn symlog_scale(self, linthresh: f64) -> SymlogRangeExt;
+}
+
+impl IntoSymlogRange for Range<f64> {
+    fn symlog_scale(self, linthresh: f64) -> SymlogRangeExt {
+        SymlogRangeExt {
+            range: self,
+            linthresh: linthresh.abs().max(f64::MIN_POSITIVE),
+        }
+    }
+}
+
+/// The symmetric log coordinate decorator, like the matplotlib `symlog` scale, for data
+/// spanning several orders of magnitude on both sides of zero.
+/// The axis is linear within `±linthresh` and logarithmic outside, with a decade taking the
+/// same room as the linear part on each side.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SymlogRangeExt {
+    range: Range<f64>,
+    linthresh: f64,
+}
+
+impl SymlogRangeExt {
+    /// Choose a symlog scale for the data if a linear or a log scale does not suit it: when
+    /// it reaches zero or below, and its non-zero magnitudes span at least 3 decades. The
+    /// linear threshold is the smallest non-zero magnitude, rounded down to a power of 10.
+    ///
+    /// - `data`: The values to draw; the values that are not finite are ignored
+    /// - **returns** The scale over the range of the data, or `None` if it is not needed
+    pub fn autoscale<I: IntoIterator<Item = f64>>(data: I) -> Option<SymlogRangeExt> {
+        let data: Vec<f64> = data.into_iter().filter(|v| v.is_finite()).collect();
+        let min = data.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = data.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let magnitudes = data.iter().map(|v| v.abs()).filter(|v| *v > 0.0);
+        let smallest = magnitudes.clone().fold(f64::INFINITY, f64::min);
+        let largest = magnitudes.fold(0.0, f64::max);
+        if min > 0.0 || !smallest.is_finite() {
+            return None;
+        }
+        if (largest / smallest).log10() < SYMLOG_MIN_DECADES {
+            return None;
+        }
+        let linthresh = 10f64.powf(smallest.log10().floor());
+        Some((min..max).symlog_scale(linthresh))
+    }
+
+    /// The threshold of the linear part of the scale
+    pub fn linthresh(&self) -> f64 {
+        self.linthresh
+    }
+
+    /// The range of the axis
+    pub fn range(&self) -> Range<f64> {
+        self.range.clone()
+    }
+}
+
+impl From<SymlogRangeExt> for SymlogCoord {
+    fn from(spec: SymlogRangeExt) -> SymlogCoord {
+        let t = spec.linthresh;
+        SymlogCoord {
+            linear: (symlog(spec.range.start, t)..symlog(spec.range.end, t)).into(),
+            logic: spec.range,
+            linthresh: t,
+        }
+    }
+}
+
+impl AsRangedCoord for SymlogRangeExt {
+    type CoordDescType = SymlogCoord;
+    type Value = f64;
+}
+
+/// A symmetric log scaled coordinate axis
+pub struct SymlogCoord {
+    linear: RangedCoordf64,
+    logic: Range<f64>,
+    linthresh: f64,
+}
+
+impl Ranged for SymlogCoord {
+    type FormatOption = DefaultFormatting;
+    type ValueType = f64;
+
+    fn map(&self, value: &f64, limit: (i32, i32)) -> i32 {
+        self.linear.map(&symlog(*value, self.linthresh), limit)
+    }
+
+    /// The key points are 0 and the decades `±linthresh * 10^k`. If there is no room for all
+    /// of them, every other decade is dropped.
+    fn key_points<Hint: KeyPointHint>(&self, hint: Hint) -> Vec<f64> {
+        let max_points = hint.max_num_points();
+        let Range { start, end } = self.logic;
+        // The decades would never end
+        if !start.is_finite() || !end.is_finite() {
+            return vec![];
+        }
+        let (lo, hi) = (start.min(end), start.max(end));
+        let mut decades = vec![];
+        let mut v = self.linthresh;
+        while v <= lo.abs().max(hi.abs()) {
+            decades.push(v);
+            v *= 10.0;
+        }
+
+        let mut step = 1;
+        loop {
+            let mut ret: Vec<f64> = (lo..=hi)
+                .contains(&0.0)
+                .then_some(0.0)
+                .into_iter()
+                .collect();
+            for v in decades.iter().step_by(step) {
+                ret.extend([-*v, *v].into_iter().filter(|v| (lo..=hi).contains(v)));
+            }
+            if ret.len() <= max_points || step > decades.len() {
+                ret.truncate(max_points);
+                ret.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                return ret;
+            }
+            step += 1;
+        }
+    }
+
+    fn range(&self) -> Range<f64> {
+        self.logic.clone()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_symlog_map() {
+        let range: SymlogCoord = (-1000.0..1000.0).symlog_scale(1.0).into();
+        assert_eq!(range.map(&0.0, (0, 800)), 400);
+        // The linear part and every decade take 100 pixels on each side
+        assert_eq!(range.map(&1.0, (0, 800)), 500);
+        assert_eq!(range.map(&0.5, (0, 800)), 450);
+        assert_eq!(range.map(&10.0, (0, 800)), 600);
+        assert_eq!(range.map(&-100.0, (0, 800)), 100);
+        assert_eq!(range.map(&1000.0, (0, 800)), 800);
+    }
+
+    #[test]
+    fn test_symlog_key_points() {
+        let range: SymlogCoord = (-1000.0..1000.0).symlog_scale(1.0).into();
+        let points = range.key_points(10);
+        assert_eq!(
+            points,
+            vec![-1000.0, -100.0, -10.0, -1.0, 0.0, 1.0, 10.0, 100.0, 1000.0]
+        );
+        assert!(range.key_points(4).len() <= 4);
+    }
+
+    #[test]
+    fn test_symlog_infinite_key_points() {
+        for range in [0.0..f64::INFINITY, f64::NEG_INFINITY..10.0, 0.0..f64::NAN] {
+            let range: SymlogCoord = range.symlog_scale(1.0).into();
+            assert!(range.key_points(10).is_empty());
+        }
+    }
+
+    #[test]
+    fn test_symlog_autoscale() {
+        let scale = SymlogRangeExt::autoscale([-1000.0, -1.0, 0.0, 1.0, 1000.0]).unwrap();
+        assert_eq!(scale.linthresh(), 1.0);
+        assert_eq!(scale.range(), -1000.0..1000.0);
+
+        let scale = SymlogRangeExt::autoscale([-5e4, 0.0, 0.03, 20.0]).unwrap();
+        assert_eq!(scale.linthresh(), 0.01);
+
+        // Positive data suits a log scale, and a narrow span a linear one
+        assert_eq!(SymlogRangeExt::autoscale([1.0, 1e6]), None);
+        assert_eq!(SymlogRangeExt::autoscale([-10.0, 0.0, 5.0, 50.0]), None);
+        assert_eq!(SymlogRangeExt::autoscale([0.0, 0.0]), None);
+    }
+}
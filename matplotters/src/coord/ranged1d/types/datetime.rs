@@ -617,12 +617,153 @@ impl<T: TimeValue> IntoMonthly<T> for Range<T> {
     }
 }
 
+/// The trait that converts a normal date coord into one that picks its tick interval
+/// from the visible time span
+pub trait IntoAutoDate<T: TimeValue> {
+    /// Converts a normal date coord into one with automatic tick intervals, see [`AutoDate`]
+    fn auto_date(self) -> AutoDate<T>;
+}
+
 impl<T: TimeValue> IntoYearly<T> for Range<T> {
     fn yearly(self) -> Yearly<T> {
         Yearly(self)
     }
 }
 
+impl<T: TimeValue> IntoAutoDate<T> for Range<T> {
+    fn auto_date(self) -> AutoDate<T> {
+        AutoDate(self)
+    }
+}
+
+/// The unit between the ticks of an [`AutoDate`] coord.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DateTickUnit {
+    /// Ticks on midnights, labelled as `2021-03-05`
+    Day,
+    /// Ticks on Mondays, labelled as `2021-03-08`
+    Week,
+    /// Ticks on the first day of months, labelled as `2021-03`
+    Month,
+    /// Ticks on January 1st, labelled as `2021`
+    Year,
+}
+
+impl DateTickUnit {
+    /// The coarsest unit that still puts this many ticks in the visible span is picked
+    const MIN_TICKS: usize = 3;
+
+    fn steps(self) -> &'static [usize] {
+        match self {
+            DateTickUnit::Day => &[1, 2],
+            DateTickUnit::Week => &[1, 2],
+            DateTickUnit::Month => &[1, 2, 3, 6],
+            DateTickUnit::Year => &[1, 2, 5, 10, 20, 50, 100, 200, 500, 1000],
+        }
+    }
+}
+
+/// Indicate the coord picks its tick interval (days, weeks, months or years) from the
+/// visible time span, and formats the labels accordingly.
+#[derive(Clone)]
+pub struct AutoDate<T: TimeValue>(Range<T>);
+
+impl<T: TimeValue + Clone> AutoDate<T> {
+    /// Returns the unit between the ticks of this coord: the coarsest unit that puts at
+    /// least three ticks in the visible span.
+    pub fn tick_unit(&self) -> DateTickUnit {
+        [DateTickUnit::Year, DateTickUnit::Month, DateTickUnit::Week]
+            .into_iter()
+            .find(|unit| self.ticks(*unit, 1).len() >= DateTickUnit::MIN_TICKS)
+            .unwrap_or(DateTickUnit::Day)
+    }
+
+    fn contains(&self, value: &T) -> bool {
+        value.subtract(&self.0.start) >= Duration::zero()
+            && self.0.end.subtract(value) >= Duration::zero()
+    }
+
+    fn ticks(&self, unit: DateTickUnit, step: usize) -> Vec<T> {
+        let mut ret = vec![];
+        match unit {
+            DateTickUnit::Day | DateTickUnit::Week => {
+                let mut value = T::earliest_after_date(self.0.start.date_ceil());
+                let days = if unit == DateTickUnit::Week {
+                    let weekday = value.date_floor().weekday().num_days_from_monday();
+                    value = value.add(&Duration::days(i64::from((7 - weekday) % 7)));
+                    7 * step
+                } else {
+                    step
+                };
+                while self.contains(&value) {
+                    ret.push(value.clone());
+                    value = value.add(&Duration::days(days as i64));
+                }
+            }
+            DateTickUnit::Month | DateTickUnit::Year => {
+                let (start, end) = (self.0.start.date_floor(), self.0.end.date_floor());
+                let per_tick = if unit == DateTickUnit::Year { 12 } else { 1 } * step as i32;
+                let mut month = start.year() * 12 + start.month0() as i32;
+                let last = end.year() * 12 + end.month0() as i32;
+                // Align the ticks to the multiples of the step, e.g. quarters or decades
+                month += (per_tick - month.rem_euclid(per_tick)) % per_tick;
+                while month <= last {
+                    let value = T::earliest_after_date(self.0.start.ymd(
+                        month.div_euclid(12),
+                        month.rem_euclid(12) as u32 + 1,
+                        1,
+                    ));
+                    if self.contains(&value) {
+                        ret.push(value);
+                    }
+                    month += per_tick;
+                }
+            }
+        }
+        ret
+    }
+}
+
+impl<T: TimeValue + Datelike + Clone> ValueFormatter<T> for AutoDate<T> {
+    fn format(value: &T) -> String {
+        format!("{}-{:02}-{:02}", value.year(), value.month(), value.day())
+    }
+
+    fn format_ext(&self, value: &T) -> String {
+        match self.tick_unit() {
+            DateTickUnit::Year => format!("{}", value.year()),
+            DateTickUnit::Month => format!("{}-{:02}", value.year(), value.month()),
+            DateTickUnit::Week | DateTickUnit::Day => Self::format(value),
+        }
+    }
+}
+
+impl<T: TimeValue + Clone> Ranged for AutoDate<T> {
+    type FormatOption = NoDefaultFormatting;
+    type ValueType = T;
+
+    fn range(&self) -> Range<T> {
+        self.0.start.clone()..self.0.end.clone()
+    }
+
+    fn map(&self, value: &Self::ValueType, limit: (i32, i32)) -> i32 {
+        T::map_coord(value, &self.0.start, &self.0.end, limit)
+    }
+
+    fn key_points<HintType: KeyPointHint>(&self, hint: HintType) -> Vec<Self::ValueType> {
+        let unit = self.tick_unit();
+        let max_points = hint.max_num_points().max(1);
+        let mut ret = vec![];
+        for step in unit.steps() {
+            ret = self.ticks(unit, *step);
+            if ret.len() <= max_points {
+                break;
+            }
+        }
+        ret
+    }
+}
+
 /// The ranged coordinate for the date and time
 #[derive(Clone)]
 pub struct RangedDateTime<DT: Datelike + Timelike + TimeValue>(DT, DT);
@@ -1292,4 +1433,40 @@ mod test {
         let value = coord.unmap(500, (0, 1000));
         assert_eq!(value, Some(mid));
     }
+
+    #[test]
+    fn test_auto_date_tick_unit() {
+        let years = (NaiveDate::from_ymd(2020, 1, 1)..NaiveDate::from_ymd(2023, 1, 1)).auto_date();
+        assert_eq!(years.tick_unit(), DateTickUnit::Year);
+        let kps = years.key_points(11);
+        assert_eq!(
+            kps,
+            (2020..=2023)
+                .map(|y| NaiveDate::from_ymd(y, 1, 1))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(years.format_ext(&kps[1]), "2021");
+
+        let days = (Utc.ymd(2021, 3, 1).and_hms(12, 0, 0)..Utc.ymd(2021, 3, 4).and_hms(12, 0, 0))
+            .auto_date();
+        assert_eq!(days.tick_unit(), DateTickUnit::Day);
+        let kps = days.key_points(11);
+        assert_eq!(
+            kps,
+            (2..=4)
+                .map(|d| Utc.ymd(2021, 3, d).and_hms(0, 0, 0))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(days.format_ext(&kps[0]), "2021-03-02");
+
+        let months =
+            (NaiveDate::from_ymd(2021, 1, 15)..NaiveDate::from_ymd(2021, 8, 1)).auto_date();
+        assert_eq!(months.tick_unit(), DateTickUnit::Month);
+        let kps = months.key_points(4);
+        assert_eq!(
+            kps,
+            [3, 5, 7].map(|m| NaiveDate::from_ymd(2021, m, 1)).to_vec()
+        );
+        assert_eq!(months.format_ext(&kps[0]), "2021-03");
+    }
 }
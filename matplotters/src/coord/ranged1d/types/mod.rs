@@ -2,7 +2,8 @@
 mod datetime;
 #[cfg(feature = "chrono")]
 pub use datetime::{
-    IntoMonthly, IntoYearly, Monthly, RangedDate, RangedDateTime, RangedDuration, Yearly,
+    AutoDate, DateTickUnit, IntoAutoDate, IntoMonthly, IntoYearly, Monthly, RangedDate,
+    RangedDateTime, RangedDuration, Yearly,
 };
 
 mod numeric;
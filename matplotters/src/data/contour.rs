@@ -0,0 +1,85 @@
+/// Trace the contour lines of a function sampled on a grid, by marching squares
+///
+/// The contour crosses each edge of a grid cell whose ends are on both sides of the level, at
+/// the point interpolated linearly between them; in a saddle cell, where the opposite corners
+/// are on the same side, the mean of the four corners decides which of them are joined.
+///
+/// - `x`, `y`: The increasing coordinates of the grid
+/// - `z`: The values sampled on the grid: `z[j][i]` is the value at `(x[i], y[j])`
+/// - `level`: The value of the contour
+/// - **returns** The segments of the contour, one or two per cell it crosses, in no particular
+///   order. It is empty if `z` has not one row of one value per x for every y.
+///
+/// ```rust
+/// use matplotters::data::contour_lines;
+///
+/// // The contour of x + y = 1 on a single cell crosses its diagonal
+/// let segments = contour_lines(&[0.0, 1.0], &[0.0, 1.0], &[vec![0.0, 1.0], vec![1.0, 2.0]], 1.0);
+/// assert_eq!(segments, vec![[(1.0, 0.0), (0.0, 1.0)]]);
+/// ```
+pub fn contour_lines(x: &[f64], y: &[f64], z: &[Vec<f64>], level: f64) -> Vec<[(f64, f64); 2]> {
+    if z.len() != y.len() || z.iter().any(|row| row.len() != x.len()) {
+        return vec![];
+    }
+    let mut ret = vec![];
+    for j in 1..y.len() {
+        for i in 1..x.len() {
+            // The corners counterclockwise from the lower left one
+            let corners = [
+                (x[i - 1], y[j - 1], z[j - 1][i - 1]),
+                (x[i], y[j - 1], z[j - 1][i]),
+                (x[i], y[j], z[j][i]),
+                (x[i - 1], y[j], z[j][i - 1]),
+            ];
+            let mut cuts = vec![];
+            for k in 0..4 {
+                let ((x0, y0, v0), (x1, y1, v1)) = (corners[k], corners[(k + 1) % 4]);
+                if (v0 < level) != (v1 < level) {
+                    let t = (level - v0) / (v1 - v0);
+                    cuts.push((x0 + (x1 - x0) * t, y0 + (y1 - y0) * t));
+                }
+            }
+            match cuts[..] {
+                [a, b] => ret.push([a, b]),
+                [a, b, c, d] => {
+                    let center = corners.iter().map(|c| c.2).sum::<f64>() / 4.0;
+                    if (center < level) == (corners[0].2 < level) {
+                        ret.extend([[a, b], [c, d]]);
+                    } else {
+                        ret.extend([[d, a], [b, c]]);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    ret
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_contour_circle() {
+        let grid: Vec<f64> = (0..=40).map(|i| -2.0 + f64::from(i) * 0.1).collect();
+        let z: Vec<Vec<f64>> = grid
+            .iter()
+            .map(|y| grid.iter().map(|x| x.hypot(*y)).collect())
+            .collect();
+        let segments = contour_lines(&grid, &grid, &z, 1.0);
+        assert!(segments.len() > 40);
+        for p in segments.iter().flatten() {
+            assert!((p.0.hypot(p.1) - 1.0).abs() < 0.01);
+        }
+        // Every end of a segment is shared with another one: the circle is closed
+        let ends: Vec<_> = segments.iter().flatten().collect();
+        for p in &ends {
+            let shared = ends
+                .iter()
+                .filter(|q| (q.0 - p.0).abs() + (q.1 - p.1).abs() < 1e-9);
+            assert!(shared.count() >= 2);
+        }
+        assert!(contour_lines(&grid, &grid, &z[1..], 1.0).is_empty());
+    }
+}
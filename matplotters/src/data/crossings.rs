@@ -0,0 +1,154 @@
+/// Find the points where two lines cross
+///
+/// Both lines are polylines through their points taken in order of x, and are compared over
+/// the x range they share: a crossing is found wherever their difference changes sign, at the
+/// point interpolated linearly between the two neighbouring x values of either line. The
+/// points where the lines touch are found too, once for each stretch where they coincide.
+///
+/// - `a`, `b`: The `(x, y)` points of the two lines; the points with a coordinate that is not
+///   finite are ignored
+/// - **returns** The crossing points, in increasing x
+///
+/// ```rust
+/// use matplotters::data::line_crossings;
+///
+/// let rising = [(0.0, 0.0), (4.0, 4.0)];
+/// let falling = [(0.0, 3.0), (2.0, 2.0), (4.0, 1.0)];
+/// assert_eq!(line_crossings(&rising, &falling), vec![(2.0, 2.0)]);
+/// ```
+pub fn line_crossings(a: &[(f64, f64)], b: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    let (a, b) = (sorted(a), sorted(b));
+    let (start, end) = match (a.first().zip(b.first()), a.last().zip(b.last())) {
+        (Some((a0, b0)), Some((a1, b1))) => (a0.0.max(b0.0), a1.0.min(b1.0)),
+        _ => return vec![],
+    };
+    let mut xs: Vec<f64> = a
+        .iter()
+        .chain(&b)
+        .map(|p| p.0)
+        .filter(|x| start <= *x && *x <= end)
+        .collect();
+    xs.sort_by(f64::total_cmp);
+    xs.dedup();
+
+    let mut ret = vec![];
+    let mut prev: Option<(f64, f64)> = None;
+    for x in xs {
+        let diff = interpolate(&a, x) - interpolate(&b, x);
+        match prev {
+            Some((_, 0.0)) if diff == 0.0 => {}
+            _ if diff == 0.0 => ret.push((x, interpolate(&a, x))),
+            Some((x0, d0)) if d0 * diff < 0.0 => {
+                let cross = x0 + (x - x0) * d0 / (d0 - diff);
+                ret.push((cross, interpolate(&a, cross)));
+            }
+            _ => {}
+        }
+        prev = Some((x, diff));
+    }
+    ret
+}
+
+/// Find the points where a series changes sign
+///
+/// The series is a polyline through its points taken in order of x. A sign change is found
+/// between two consecutive non-zero values of opposite signs, at the x interpolated linearly
+/// between them; if the series is zero in between, the sign change is at the first zero. The
+/// points where the series only touches zero, without changing sign, are not sign changes.
+///
+/// - `points`: The `(x, y)` points of the series; the points with a coordinate that is not
+///   finite are ignored
+/// - **returns** The x of the sign changes, in increasing order
+///
+/// ```rust
+/// use matplotters::data::zero_crossings;
+///
+/// let points = [(0.0, 2.0), (1.0, -2.0), (2.0, 0.0), (3.0, -1.0), (4.0, 3.0)];
+/// assert_eq!(zero_crossings(&points), vec![0.5, 3.25]);
+/// ```
+pub fn zero_crossings(points: &[(f64, f64)]) -> Vec<f64> {
+    let mut ret = vec![];
+    let mut last: Option<(f64, f64)> = None;
+    let mut first_zero: Option<f64> = None;
+    for (x, y) in sorted(points) {
+        if y == 0.0 {
+            first_zero.get_or_insert(x);
+            continue;
+        }
+        if let Some((x0, y0)) = last {
+            if y0 * y < 0.0 {
+                ret.push(first_zero.unwrap_or(x0 + (x - x0) * y0 / (y0 - y)));
+            }
+        }
+        last = Some((x, y));
+        first_zero = None;
+    }
+    ret
+}
+
+fn sorted(line: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    let mut ret: Vec<_> = line
+        .iter()
+        .copied()
+        .filter(|(x, y)| x.is_finite() && y.is_finite())
+        .collect();
+    ret.sort_by(|p, q| p.0.total_cmp(&q.0));
+    ret
+}
+
+// The y of a sorted line at an x within its range
+fn interpolate(line: &[(f64, f64)], x: f64) -> f64 {
+    let i = line.partition_point(|p| p.0 < x);
+    if i == 0 {
+        return line[0].1;
+    }
+    let ((x0, y0), (x1, y1)) = (line[i - 1], line[i.min(line.len() - 1)]);
+    if x1 == x0 {
+        return y1;
+    }
+    y0 + (y1 - y0) * (x - x0) / (x1 - x0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_line_crossings() {
+        // A sine sampled coarsely against a flat line sampled at other points
+        let sine: Vec<(f64, f64)> = (0..=20)
+            .map(|i| (f64::from(i) * 0.5, (f64::from(i) * 0.5).sin()))
+            .collect();
+        let flat = [(0.25, 0.5), (9.75, 0.5)];
+        let crossings = line_crossings(&sine, &flat);
+        assert_eq!(crossings.len(), 4);
+        for (x, y) in &crossings {
+            assert!((y - 0.5).abs() < 1e-12);
+            assert!((x.sin() - 0.5).abs() < 0.05);
+        }
+        assert!(crossings.windows(2).all(|w| w[0].0 < w[1].0));
+
+        // Touching counts once per stretch where the lines coincide
+        let a = [(0.0, 0.0), (1.0, 1.0), (2.0, 1.0), (3.0, 0.0)];
+        let b = [(0.0, 1.0), (3.0, 1.0)];
+        assert_eq!(line_crossings(&a, &b), vec![(1.0, 1.0)]);
+        assert_eq!(line_crossings(&a, &[]), vec![]);
+    }
+
+    #[test]
+    fn test_zero_crossings() {
+        let points = [(2.0, 1.0), (0.0, 1.0), (1.0, -1.0), (f64::NAN, 5.0)];
+        assert_eq!(zero_crossings(&points), vec![0.5, 1.5]);
+        // Touching zero is not a sign change, reaching it and going on is
+        let touch = [
+            (0.0, 1.0),
+            (1.0, 0.0),
+            (2.0, 1.0),
+            (3.0, 0.0),
+            (4.0, 0.0),
+            (5.0, -1.0),
+        ];
+        assert_eq!(zero_crossings(&touch), vec![3.0]);
+        assert!(zero_crossings(&[(0.0, 0.0)]).is_empty());
+    }
+}
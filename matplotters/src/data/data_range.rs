@@ -40,3 +40,99 @@ where
 
     lb.unwrap_or_else(Zero::zero)..ub.unwrap_or_else(One::one)
 }
+
+/// Find the widest empty interval between the values of the data, for instance to break an
+/// axis there, see [`crate::coord::combinators::BrokenRangeExt::from_data`]
+///
+/// - `values`: The values; the values that are not finite are left out
+/// - `min_fraction`: The smallest width of an interval to be returned, as a fraction of the
+///   span of the values
+/// - **returns** The interval between the two consecutive values farthest apart, or `None`
+///   if it is narrower than `min_fraction` of the span, or if there are less than two
+///   distinct values
+///
+/// ```rust
+/// use matplotters::data::largest_gap;
+///
+/// let values = [1.0, 3.0, 2.0, 95.0, 100.0];
+/// assert_eq!(largest_gap(&values, 0.5), Some(3.0..95.0));
+/// assert_eq!(largest_gap(&values, 0.95), None);
+/// ```
+pub fn largest_gap(values: &[f64], min_fraction: f64) -> Option<Range<f64>> {
+    let mut sorted: Vec<f64> = values.iter().copied().filter(|v| v.is_finite()).collect();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    let span = sorted.last()? - sorted.first()?;
+    let gap = sorted.windows(2).map(|w| w[0]..w[1]).max_by(|a, b| {
+        (a.end - a.start)
+            .partial_cmp(&(b.end - b.start))
+            .unwrap_or(Ordering::Equal)
+    })?;
+    if span > 0.0 && gap.end - gap.start >= min_fraction * span {
+        Some(gap)
+    } else {
+        None
+    }
+}
+
+/// Round the bounds of a range outwards to multiples of a "nice" step, one of 1, 2 or 5
+/// times a power of ten, so that the tick labels of an axis built on it are clean numbers.
+/// The step is the smallest nice number that splits the range into at most ten intervals.
+/// A range of zero width is widened by one on both sides first.
+///
+/// - `range`: the range to round, usually the [`fitting_range`] of some data
+/// - **returns** The rounded range, which contains the given one
+///
+/// ```rust
+/// use matplotters::data::nice_range;
+///
+/// assert_eq!(nice_range(0.3..9.7), 0.0..10.0);
+/// assert_eq!(nice_range(-0.13..0.42), -0.2..0.5);
+/// ```
+pub fn nice_range(range: Range<f64>) -> Range<f64> {
+    const MAX_INTERVALS: f64 = 10.0;
+
+    let (mut lo, mut hi) = if range.start <= range.end {
+        (range.start, range.end)
+    } else {
+        (range.end, range.start)
+    };
+    if lo == hi {
+        lo -= 1.0;
+        hi += 1.0;
+    }
+    if !(hi - lo).is_finite() {
+        return range;
+    }
+
+    // Work in units of 10^exp, so that the rounding is done on integers and bounds like
+    // 0.2 come out exact rather than as 0.20000000000000001
+    let raw_step = (hi - lo) / MAX_INTERVALS;
+    let exp = raw_step.log10().floor() as i32;
+    let pow10 = 10f64.powi(exp.abs());
+    let to_units = |v: f64| if exp < 0 { v * pow10 } else { v / pow10 };
+    let from_units = |v: f64| if exp < 0 { v / pow10 } else { v * pow10 };
+    let raw_units = to_units(raw_step);
+    let step = [1.0, 2.0, 5.0, 10.0]
+        .into_iter()
+        .find(|step| *step >= raw_units * (1.0 - 1e-9))
+        .unwrap_or(10.0);
+
+    let start = (to_units(lo) / step + 1e-9).floor() * step;
+    let end = (to_units(hi) / step - 1e-9).ceil() * step;
+    from_units(start)..from_units(end)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_nice_range() {
+        assert_eq!(nice_range(0.3..9.7), 0.0..10.0);
+        assert_eq!(nice_range(-0.13..0.42), -0.2..0.5);
+        assert_eq!(nice_range(12.0..887.0), 0.0..900.0);
+        assert_eq!(nice_range(2.0..8.0), 2.0..8.0);
+        assert_eq!(nice_range(5.0..5.0), 4.0..6.0);
+        assert_eq!(nice_range(9.7..0.3), 0.0..10.0);
+    }
+}
@@ -123,6 +123,102 @@ pub fn pretty_print_float(n: f64, allow_sn: bool) -> String {
     .print(n)
 }
 
+/// The SI prefixes of [`si_prefixed`], from the largest factor to the smallest one
+const SI_PREFIXES: [(f64, &str); 9] = [
+    (1e12, "T"),
+    (1e9, "G"),
+    (1e6, "M"),
+    (1e3, "k"),
+    (1.0, ""),
+    (1e-3, "m"),
+    (1e-6, "µ"),
+    (1e-9, "n"),
+    (1e-12, "p"),
+];
+
+/// Print a number with an SI prefix, as in `1.2k` or `3.4M`, so that large and small values
+/// stay short. The prefix is the largest one from `p` to `T` that leaves at least 1 before
+/// the decimal point, and at most two decimals are kept.
+///
+/// - `n`: The number to print
+/// - **returns**: The number scaled and followed by its prefix. Zero and the numbers that are
+///   not finite have no prefix.
+///
+/// ```rust
+/// use matplotters::data::float::si_prefixed;
+///
+/// assert_eq!(si_prefixed(1200.0), "1.2k");
+/// assert_eq!(si_prefixed(-3.4e6), "-3.4M");
+/// assert_eq!(si_prefixed(0.005), "5m");
+/// ```
+pub fn si_prefixed(n: f64) -> String {
+    if n == 0.0 || !n.is_finite() {
+        return pretty_print_float(n, false);
+    }
+    let printer = FloatPrettyPrinter {
+        allow_scientific: false,
+        min_decimal: 0,
+        max_decimal: 2,
+    };
+    let mut idx = SI_PREFIXES
+        .iter()
+        .position(|(factor, _)| n.abs() >= *factor)
+        .unwrap_or(SI_PREFIXES.len() - 1);
+    let mut scaled = printer.print(n / SI_PREFIXES[idx].0);
+    // Rounding may carry into the next prefix, as in 999.999k
+    if idx > 0 && scaled.trim_start_matches('-').parse::<f64>() == Ok(1000.0) {
+        idx -= 1;
+        scaled = printer.print(n / SI_PREFIXES[idx].0);
+    }
+    format!("{}{}", scaled, SI_PREFIXES[idx].1)
+}
+
+/// Insert a separator between the groups of three digits of the integer parts of the
+/// numbers in a text, counting from their ends, as in `1,234,567.25`. The signs, the other
+/// characters of the text such as a currency symbol, the decimals and the exponents are left
+/// as they are.
+///
+/// - `text`: The text, such as the output of [`pretty_print_float`]
+/// - `separator`: The separator inserted between the groups of digits
+/// - **returns**: The text with the integer parts grouped
+///
+/// ```rust
+/// use matplotters::data::float::{group_thousands, pretty_print_float};
+///
+/// assert_eq!(group_thousands(&pretty_print_float(1234567.0, false), ','), "1,234,567");
+/// assert_eq!(group_thousands("#12: -9876.54321", ' '), "#12: -9 876.54321");
+/// ```
+pub fn group_thousands(text: &str, separator: char) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut ret = String::with_capacity(text.len());
+    let mut idx = 0;
+    while idx < chars.len() {
+        let len = chars[idx..]
+            .iter()
+            .take_while(|c| c.is_ascii_digit())
+            .count();
+        if len == 0 {
+            ret.push(chars[idx]);
+            idx += 1;
+            continue;
+        }
+        // The decimals and the exponents are not integer parts
+        let before = &chars[..idx];
+        let is_integer_part = !matches!(
+            before,
+            [.., '.'] | [.., 'e' | 'E'] | [.., 'e' | 'E', '-' | '+']
+        );
+        for (pos, c) in chars[idx..idx + len].iter().enumerate() {
+            if is_integer_part && pos > 0 && (len - pos) % 3 == 0 {
+                ret.push(separator);
+            }
+            ret.push(*c);
+        }
+        idx += len;
+    }
+    ret
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -142,4 +238,34 @@ mod test {
         assert_eq!(pretty_print_float(1234567890f64, true), "1234567890");
         assert_eq!(pretty_print_float(1000000001f64, true), "1e9");
     }
+
+    #[test]
+    fn test_si_prefixed() {
+        assert_eq!(si_prefixed(0.0), "0");
+        assert_eq!(si_prefixed(999.0), "999");
+        assert_eq!(si_prefixed(1000.0), "1k");
+        assert_eq!(si_prefixed(1234.0), "1.23k");
+        assert_eq!(si_prefixed(999_999.0), "1M");
+        assert_eq!(si_prefixed(2.5e9), "2.5G");
+        assert_eq!(si_prefixed(4.2e15), "4200T");
+        assert_eq!(si_prefixed(1.5e-6), "1.5µ");
+        assert_eq!(si_prefixed(-0.25), "-250m");
+    }
+
+    #[test]
+    fn test_group_thousands() {
+        assert_eq!(
+            group_thousands(&pretty_print_float(1234567.0, false), ','),
+            "1,234,567"
+        );
+        assert_eq!(group_thousands("123", ','), "123");
+        assert_eq!(group_thousands("1234", ','), "1,234");
+        assert_eq!(group_thousands("-1234567.123456", ','), "-1,234,567.123456");
+        assert_eq!(group_thousands("1.5e10000", ','), "1.5e10000");
+        assert_eq!(group_thousands("2e-1234", ','), "2e-1234");
+        assert_eq!(
+            group_thousands("from 1000 to 25000", '.'),
+            "from 1.000 to 25.000"
+        );
+    }
 }
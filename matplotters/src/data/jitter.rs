@@ -0,0 +1,90 @@
+/// A SplitMix64 generator, small and fully specified, so that the same seed gives the same
+/// numbers on every platform
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number uniformly distributed in `[-1, 1)`
+    fn next_signed_unit(&mut self) -> f64 {
+        // The 53 upper bits make an exact f64 in [0, 1)
+        let unit = (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        2.0 * unit - 1.0
+    }
+}
+
+/// Jitter the points of a scatter plot, moving each of them by a random offset so that the
+/// points sharing the same position, such as the points of a category, don't hide each other
+///
+/// The offsets are uniformly distributed in `[-amount_x, amount_x]` and
+/// `[-amount_y, amount_y]`, and are given by a generator seeded with `seed`: the same seed
+/// always gives the same offsets, on every platform, so that a plot can be reproduced. The
+/// jittered points can be drawn with any scatter series.
+///
+/// - `points`: The `(x, y)` points to jitter
+/// - `amount_x`, `amount_y`: The largest offsets along each axis, in data units
+/// - `seed`: The seed of the generator
+/// - **returns** The jittered points, in the order of `points`
+///
+/// ```rust
+/// use matplotters::data::jitter_xy;
+///
+/// let points = [(1.0, 5.0), (1.0, 5.0), (2.0, 3.0)];
+/// let jittered = jitter_xy(points, 0.2, 0.0, 42);
+/// assert_eq!(jittered, jitter_xy(points, 0.2, 0.0, 42));
+/// assert!(jittered.iter().zip(&points).all(|(j, p)| (j.0 - p.0).abs() <= 0.2 && j.1 == p.1));
+/// ```
+pub fn jitter_xy<I: IntoIterator<Item = (f64, f64)>>(
+    points: I,
+    amount_x: f64,
+    amount_y: f64,
+    seed: u64,
+) -> Vec<(f64, f64)> {
+    let mut rng = SplitMix64(seed);
+    points
+        .into_iter()
+        .map(|(x, y)| {
+            let dx = rng.next_signed_unit() * amount_x;
+            let dy = rng.next_signed_unit() * amount_y;
+            (x + dx, y + dy)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_jitter_xy() {
+        let points: Vec<(f64, f64)> = (0..200).map(|i| (f64::from(i % 4), 10.0)).collect();
+        let first = jitter_xy(points.clone(), 0.3, 0.5, 7);
+        let second = jitter_xy(points.clone(), 0.3, 0.5, 7);
+        // The same seed gives the same points
+        assert_eq!(first, second);
+        assert_ne!(first, jitter_xy(points.clone(), 0.3, 0.5, 8));
+
+        // The offsets stay within the bounds, and spread over them
+        let offsets: Vec<(f64, f64)> = first
+            .iter()
+            .zip(&points)
+            .map(|(j, p)| (j.0 - p.0, j.1 - p.1))
+            .collect();
+        assert!(offsets
+            .iter()
+            .all(|(dx, dy)| dx.abs() <= 0.3 && dy.abs() <= 0.5));
+        let max_dx = offsets.iter().fold(0.0_f64, |m, o| m.max(o.0.abs()));
+        assert!(max_dx > 0.25);
+        assert!(offsets.iter().any(|o| o.0 < 0.0) && offsets.iter().any(|o| o.0 > 0.0));
+
+        // The numbers don't depend on the platform
+        let mut rng = SplitMix64(0);
+        assert_eq!(rng.next_u64(), 0xe220_a839_7b1d_cdaf);
+    }
+}
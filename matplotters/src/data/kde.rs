@@ -0,0 +1,122 @@
+use std::f64::consts::PI;
+
+/// Estimate the density of a 2D point cloud on a grid, with a gaussian kernel
+///
+/// The kernel is the product of a gaussian per axis. Its bandwidths default to Scott's rule,
+/// `σ n^(-1/6)` for the standard deviation `σ` of the points along each axis, like scipy's
+/// `gaussian_kde` with a diagonal covariance.
+///
+/// - `points`: The points; those with a coordinate that is not finite are ignored
+/// - `x`, `y`: The coordinates of the grid
+/// - `bandwidth`: The bandwidths along x and y, or `None` for Scott's rule
+/// - **returns** The density at every grid point: `z[j][i]` is the density at `(x[i], y[j])`.
+///   It is zero everywhere if there are no points, or if a bandwidth is not positive, as
+///   Scott's rule gives for fewer than two points or for points without spread.
+///
+/// ```rust
+/// use matplotters::data::kde_2d;
+///
+/// let z = kde_2d(&[(0.0, 0.0)], &[0.0, 1.0], &[0.0], Some((1.0, 1.0)));
+/// // The peak of a standard 2D gaussian
+/// assert!((z[0][0] - 1.0 / (2.0 * std::f64::consts::PI)).abs() < 1e-12);
+/// assert!(z[0][1] < z[0][0]);
+/// ```
+pub fn kde_2d(
+    points: &[(f64, f64)],
+    x: &[f64],
+    y: &[f64],
+    bandwidth: Option<(f64, f64)>,
+) -> Vec<Vec<f64>> {
+    let points: Vec<_> = points
+        .iter()
+        .copied()
+        .filter(|(x, y)| x.is_finite() && y.is_finite())
+        .collect();
+    let (hx, hy) = bandwidth.unwrap_or_else(|| scott_bandwidth(&points));
+    let mut ret = vec![vec![0.0; x.len()]; y.len()];
+    if points.is_empty() || !(hx > 0.0 && hy > 0.0) {
+        return ret;
+    }
+    let norm = 1.0 / (2.0 * PI * hx * hy * points.len() as f64);
+    for (row, gy) in ret.iter_mut().zip(y) {
+        for (value, gx) in row.iter_mut().zip(x) {
+            let sum: f64 = points
+                .iter()
+                .map(|(px, py)| {
+                    let (u, v) = ((gx - px) / hx, (gy - py) / hy);
+                    (-0.5 * (u * u + v * v)).exp()
+                })
+                .sum();
+            *value = sum * norm;
+        }
+    }
+    ret
+}
+
+/// The bandwidths of Scott's rule for a 2D point cloud, zero for fewer than two points
+pub(crate) fn scott_bandwidth(points: &[(f64, f64)]) -> (f64, f64) {
+    if points.len() < 2 {
+        return (0.0, 0.0);
+    }
+    let n = points.len() as f64;
+    let std = |values: Vec<f64>| {
+        let mean = values.iter().sum::<f64>() / n;
+        (values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0)).sqrt()
+    };
+    let factor = n.powf(-1.0 / 6.0);
+    (
+        std(points.iter().map(|p| p.0).collect()) * factor,
+        std(points.iter().map(|p| p.1).collect()) * factor,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_scott_bandwidth() {
+        let points: Vec<_> = (0..5).map(|i| (f64::from(i), f64::from(2 * i))).collect();
+        let (hx, hy) = scott_bandwidth(&points);
+        // The sample standard deviation of 0, 1, 2, 3, 4 is sqrt(2.5)
+        let expected = 2.5f64.sqrt() * 5f64.powf(-1.0 / 6.0);
+        assert!((hx - expected).abs() < 1e-12);
+        assert!((hy - 2.0 * expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_kde_2d_integrates_to_one() {
+        let points = [
+            (-0.5, 0.2),
+            (0.3, -0.4),
+            (0.8, 0.9),
+            (0.0, 0.0),
+            (-0.2, 0.6),
+        ];
+        let grid: Vec<f64> = (-80..=80).map(|i| f64::from(i) * 0.05).collect();
+        for bandwidth in [None, Some((0.3, 0.5))] {
+            let z = kde_2d(&points, &grid, &grid, bandwidth);
+            let integral: f64 = z.iter().flatten().sum::<f64>() * 0.05 * 0.05;
+            assert!((integral - 1.0).abs() < 1e-3, "{}", integral);
+        }
+    }
+
+    #[test]
+    fn test_kde_2d_degenerate_input() {
+        let grid = [-1.0, 0.0, 1.0];
+        let all_zero = |z: Vec<Vec<f64>>| z.iter().flatten().all(|v| *v == 0.0);
+        assert_eq!(scott_bandwidth(&[]), (0.0, 0.0));
+        assert_eq!(scott_bandwidth(&[(1.0, 1.0)]), (0.0, 0.0));
+        assert!(all_zero(kde_2d(&[], &grid, &grid, None)));
+        assert!(all_zero(kde_2d(&[(f64::NAN, 0.0)], &grid, &grid, None)));
+        assert!(all_zero(kde_2d(&[(1.0, 1.0)], &grid, &grid, None)));
+        // Constant points have no spread to derive a bandwidth from
+        assert!(all_zero(kde_2d(&[(1.0, 1.0); 4], &grid, &grid, None)));
+        assert!(all_zero(kde_2d(
+            &[(0.0, 0.0)],
+            &grid,
+            &grid,
+            Some((0.0, 1.0))
+        )));
+    }
+}
@@ -3,11 +3,47 @@ The data processing module, which implements algorithms related to visualization
 Such as, down-sampling, etc.
 */
 
+mod contour;
+pub use contour::contour_lines;
+
+mod crossings;
+pub use crossings::{line_crossings, zero_crossings};
+
 mod data_range;
-pub use data_range::fitting_range;
+pub use data_range::{fitting_range, largest_gap, nice_range};
+
+mod jitter;
+pub use jitter::jitter_xy;
+
+mod kde;
+pub use kde::kde_2d;
+#[cfg(feature = "point_series")]
+pub(crate) use kde::scott_bandwidth;
+
+mod outliers;
+pub use outliers::{detect_outliers, outlier_fences, OutlierRule};
+
+mod plateaus;
+pub use plateaus::plateaus;
+
+mod polyfit;
+pub use polyfit::{fit_linear, fit_quadratic, LinearFit, QuadraticFit};
+
+mod quantity;
+pub(crate) use quantity::quantity_desc;
+pub use quantity::Quantity;
 
 mod quartiles;
-pub use quartiles::Quartiles;
+pub use quartiles::{BoxStats, Quartiles};
+
+mod resample;
+pub use resample::{align_series, resample_to, AlignGrid, Extrapolation};
+
+mod rolling;
+pub use rolling::rolling_correlation;
+
+mod sampling;
+pub use sampling::sample_logspace;
 
 /// Handles the printing of floating-point numbers.
 pub mod float;
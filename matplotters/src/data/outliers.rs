@@ -0,0 +1,94 @@
+use super::Quartiles;
+
+/// The rule used by [`detect_outliers`] to tell the outliers from the other values
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OutlierRule {
+    /// Tukey's fences: the values more than `k` interquartile ranges below the lower quartile
+    /// or above the upper quartile, usually with `k = 1.5`, like the fliers of a box plot
+    Iqr(f64),
+    /// The values more than the given number of standard deviations away from the mean
+    ZScore(f64),
+}
+
+/// Compute the fences of an outlier rule, the values below the lower fence or above the
+/// upper one being the outliers
+///
+/// - `values`: The values to compute the statistics of; the values that are not finite are
+///   left out
+/// - `rule`: The rule telling the outliers from the other values
+/// - **returns** The lower and the upper fences, or `None` if there are no finite values
+///
+/// ```rust
+/// use matplotters::data::{outlier_fences, OutlierRule};
+///
+/// let values = [1.0, 3.0, 1.0, 3.0];
+/// assert_eq!(outlier_fences(&values, OutlierRule::ZScore(2.0)), Some((0.0, 4.0)));
+/// ```
+pub fn outlier_fences(values: &[f64], rule: OutlierRule) -> Option<(f64, f64)> {
+    let finite: Vec<f64> = values.iter().copied().filter(|v| v.is_finite()).collect();
+    if finite.is_empty() {
+        return None;
+    }
+    Some(match rule {
+        OutlierRule::Iqr(k) => {
+            let (q1, q3) = Quartiles::new(&finite).quartiles();
+            (q1 - k * (q3 - q1), q3 + k * (q3 - q1))
+        }
+        OutlierRule::ZScore(z) => {
+            let n = finite.len() as f64;
+            let mean = finite.iter().sum::<f64>() / n;
+            let std = (finite.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n).sqrt();
+            (mean - z * std, mean + z * std)
+        }
+    })
+}
+
+/// Find the outliers of the data
+///
+/// - `values`: The values to check; the values that are not finite are never outliers and
+///   are left out of the statistics
+/// - `rule`: The rule telling the outliers from the other values
+/// - **returns** The indices of the outliers in `values`, in increasing order
+///
+/// ```rust
+/// use matplotters::data::{detect_outliers, OutlierRule};
+///
+/// let values = [1.0, 2.0, 1.5, 2.5, 40.0, 2.0];
+/// assert_eq!(detect_outliers(&values, OutlierRule::Iqr(1.5)), vec![4]);
+/// ```
+pub fn detect_outliers(values: &[f64], rule: OutlierRule) -> Vec<usize> {
+    let (low, high) = match outlier_fences(values, rule) {
+        Some(fences) => fences,
+        None => return vec![],
+    };
+    values
+        .iter()
+        .enumerate()
+        .filter(|(_, v)| v.is_finite() && (**v < low || **v > high))
+        .map(|(idx, _)| idx)
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_iqr_outliers() {
+        let values = [10.0, 12.0, 11.0, 13.0, 55.0, 12.0, -30.0, f64::NAN, 11.5];
+        assert_eq!(detect_outliers(&values, OutlierRule::Iqr(1.5)), vec![4, 6]);
+        // Wider fences keep every value in
+        assert!(detect_outliers(&values, OutlierRule::Iqr(100.0)).is_empty());
+    }
+
+    #[test]
+    fn test_zscore_outliers() {
+        let mut values = vec![0.0; 20];
+        values[3] = 1.0;
+        values[8] = -1.0;
+        values[15] = 20.0;
+        assert_eq!(detect_outliers(&values, OutlierRule::ZScore(3.0)), vec![15]);
+        assert!(detect_outliers(&[], OutlierRule::ZScore(3.0)).is_empty());
+        assert!(detect_outliers(&[5.0; 4], OutlierRule::ZScore(3.0)).is_empty());
+    }
+}
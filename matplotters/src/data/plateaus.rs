@@ -0,0 +1,81 @@
+use std::ops::Range;
+
+/// Find the plateaus of a series, the runs of consecutive points at a constant value, such as
+/// the states of a state timeline or the levels of step data
+///
+/// The points are taken in order of x. A plateau is a run of at least two consecutive points
+/// whose y values stay within `tolerance` of the first one. As in a step series, a level holds
+/// until the next change: a plateau spans from the x of its first point to the x of the point
+/// following it, or to the x of its last point for the plateau ending the series.
+///
+/// - `points`: The `(x, y)` points of the series; the points with a coordinate that is not
+///   finite are ignored
+/// - `tolerance`: The largest difference to the first value of a plateau, 0 for exactly
+///   constant values
+/// - **returns** The x extent of each plateau with its mean value, in increasing x
+///
+/// ```rust
+/// use matplotters::data::plateaus;
+///
+/// let points = [(0.0, 1.0), (1.0, 1.0), (2.0, 3.0), (3.0, 3.0), (4.0, 3.0), (5.0, 0.0)];
+/// assert_eq!(plateaus(&points, 0.0), vec![(0.0..2.0, 1.0), (2.0..5.0, 3.0)]);
+/// ```
+pub fn plateaus(points: &[(f64, f64)], tolerance: f64) -> Vec<(Range<f64>, f64)> {
+    let mut sorted: Vec<_> = points
+        .iter()
+        .copied()
+        .filter(|(x, y)| x.is_finite() && y.is_finite())
+        .collect();
+    sorted.sort_by(|p, q| p.0.total_cmp(&q.0));
+
+    let mut ret = vec![];
+    let mut start = 0;
+    while start < sorted.len() {
+        let level = sorted[start].1;
+        let len = sorted[start..]
+            .iter()
+            .take_while(|(_, y)| (y - level).abs() <= tolerance)
+            .count();
+        if len > 1 {
+            let run = &sorted[start..start + len];
+            let mean = run.iter().map(|(_, y)| y).sum::<f64>() / len as f64;
+            let end = sorted.get(start + len).unwrap_or(&run[len - 1]).0;
+            ret.push((run[0].0..end, mean));
+        }
+        start += len;
+    }
+    ret
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_plateaus() {
+        let points = [
+            (3.0, 5.0),
+            (0.0, 2.0),
+            (1.0, 2.0),
+            (2.0, 2.0),
+            (4.0, 5.1),
+            (5.0, 4.9),
+            (6.0, 1.0),
+            (f64::NAN, 2.0),
+        ];
+        // The noisy level is only a plateau within a tolerance
+        assert_eq!(plateaus(&points, 0.0), vec![(0.0..3.0, 2.0)]);
+        let found = plateaus(&points, 0.2);
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0], (0.0..3.0, 2.0));
+        // Each plateau holds until the next point
+        assert_eq!(found[1].0, 3.0..6.0);
+        assert!((found[1].1 - 5.0).abs() < 1e-12);
+        assert!(plateaus(&[(0.0, 1.0)], 0.0).is_empty());
+        // The plateau ending the series ends at its last point
+        assert_eq!(
+            plateaus(&[(0.0, 1.0), (2.0, 1.0)], 0.0),
+            vec![(0.0..2.0, 1.0)]
+        );
+    }
+}
@@ -0,0 +1,214 @@
+/// A quadratic fitted to data by least squares, see [`fit_quadratic`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct QuadraticFit {
+    /// The coefficients `[a, b, c]` of the curve `y = a x^2 + b x + c`
+    pub coefficients: [f64; 3],
+    /// The coefficient of determination, 1 for a perfect fit
+    pub r_squared: f64,
+}
+
+impl QuadraticFit {
+    /// Evaluate the fitted curve at `x`
+    pub fn eval(&self, x: f64) -> f64 {
+        let [a, b, c] = self.coefficients;
+        (a * x + b) * x + c
+    }
+}
+
+/// Fit a quadratic to the data by least squares
+///
+/// - `data`: The `(x, y)` points; the points with a coordinate that is not finite are ignored
+/// - **returns** The coefficients of the curve and the R² of the fit, or `None` if there are
+///   less than 3 distinct x values
+///
+/// ```rust
+/// use matplotters::data::fit_quadratic;
+///
+/// let data: Vec<_> = (0..5).map(|x| (x as f64, (x * x) as f64 + 1.0)).collect();
+/// let fit = fit_quadratic(data).unwrap();
+/// assert!((fit.coefficients[0] - 1.0).abs() < 1e-9);
+/// assert!((fit.r_squared - 1.0).abs() < 1e-9);
+/// ```
+pub fn fit_quadratic<I: IntoIterator<Item = (f64, f64)>>(data: I) -> Option<QuadraticFit> {
+    let data: Vec<_> = data
+        .into_iter()
+        .filter(|(x, y)| x.is_finite() && y.is_finite())
+        .collect();
+    if data.len() < 3 {
+        return None;
+    }
+    // Fit around the mean of x, which keeps the normal equations well conditioned
+    let n = data.len() as f64;
+    let x_mean = data.iter().map(|(x, _)| x).sum::<f64>() / n;
+
+    // The normal equations of the centered fit y = p2 u^2 + p1 u + p0, with u = x - x_mean
+    let mut m = [[0.0; 4]; 3];
+    for &(x, y) in &data {
+        let u = x - x_mean;
+        let powers = [u * u, u, 1.0];
+        for (row, pi) in m.iter_mut().zip(powers) {
+            for (cell, pj) in row.iter_mut().zip(powers) {
+                *cell += pi * pj;
+            }
+            row[3] += pi * y;
+        }
+    }
+    let [p2, p1, p0] = solve3(m)?;
+
+    let coefficients = [
+        p2,
+        p1 - 2.0 * p2 * x_mean,
+        p0 - p1 * x_mean + p2 * x_mean * x_mean,
+    ];
+    let y_mean = data.iter().map(|(_, y)| y).sum::<f64>() / n;
+    let (mut ss_res, mut ss_tot) = (0.0, 0.0);
+    for &(x, y) in &data {
+        let u = x - x_mean;
+        ss_res += (y - ((p2 * u + p1) * u + p0)).powi(2);
+        ss_tot += (y - y_mean).powi(2);
+    }
+    let r_squared = if ss_tot > 0.0 {
+        1.0 - ss_res / ss_tot
+    } else {
+        1.0
+    };
+
+    Some(QuadraticFit {
+        coefficients,
+        r_squared,
+    })
+}
+
+/// A line fitted to data by least squares, see [`fit_linear`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LinearFit {
+    /// The slope of the line `y = slope x + intercept`
+    pub slope: f64,
+    /// The value of the line at `x = 0`
+    pub intercept: f64,
+    /// The coefficient of determination, 1 for a perfect fit
+    pub r_squared: f64,
+}
+
+impl LinearFit {
+    /// Evaluate the fitted line at `x`
+    pub fn eval(&self, x: f64) -> f64 {
+        self.slope * x + self.intercept
+    }
+}
+
+/// Fit a line to the data by least squares
+///
+/// - `data`: The `(x, y)` points; the points with a coordinate that is not finite are ignored
+/// - **returns** The slope and the intercept of the line and the R² of the fit, or `None` if
+///   there are less than 2 distinct x values
+///
+/// ```rust
+/// use matplotters::data::fit_linear;
+///
+/// let fit = fit_linear([(0.0, 1.0), (1.0, 3.0), (2.0, 5.0)]).unwrap();
+/// assert!((fit.slope - 2.0).abs() < 1e-12 && (fit.intercept - 1.0).abs() < 1e-12);
+/// ```
+pub fn fit_linear<I: IntoIterator<Item = (f64, f64)>>(data: I) -> Option<LinearFit> {
+    let data: Vec<_> = data
+        .into_iter()
+        .filter(|(x, y)| x.is_finite() && y.is_finite())
+        .collect();
+    let n = data.len() as f64;
+    let x_mean = data.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let y_mean = data.iter().map(|(_, y)| y).sum::<f64>() / n;
+    let (mut sxx, mut sxy, mut syy) = (0.0, 0.0, 0.0);
+    for &(x, y) in &data {
+        let (u, v) = (x - x_mean, y - y_mean);
+        sxx += u * u;
+        sxy += u * v;
+        syy += v * v;
+    }
+    if data.len() < 2 || sxx <= 0.0 {
+        return None;
+    }
+    let slope = sxy / sxx;
+    let r_squared = if syy > 0.0 {
+        sxy * sxy / (sxx * syy)
+    } else {
+        1.0
+    };
+    Some(LinearFit {
+        slope,
+        intercept: y_mean - slope * x_mean,
+        r_squared,
+    })
+}
+
+/// Solve a 3x3 system given as its augmented matrix, by Gaussian elimination
+fn solve3(mut m: [[f64; 4]; 3]) -> Option<[f64; 3]> {
+    let scale = m.iter().flatten().fold(0.0f64, |a, v| a.max(v.abs()));
+    for col in 0..3 {
+        let pivot = (col..3).max_by(|&a, &b| m[a][col].abs().total_cmp(&m[b][col].abs()))?;
+        if m[pivot][col].abs() <= scale * 1e-12 {
+            return None;
+        }
+        m.swap(col, pivot);
+        for row in col + 1..3 {
+            let factor = m[row][col] / m[col][col];
+            let pivot_row = m[col];
+            for (cell, p) in m[row].iter_mut().zip(pivot_row).skip(col) {
+                *cell -= factor * p;
+            }
+        }
+    }
+    let mut ret = [0.0; 3];
+    for row in (0..3).rev() {
+        let rest: f64 = (row + 1..3).map(|k| m[row][k] * ret[k]).sum();
+        ret[row] = (m[row][3] - rest) / m[row][row];
+    }
+    Some(ret)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_fit_perfect_quadratic() {
+        let f = |x: f64| 0.5 * x * x - 3.0 * x + 7.0;
+        let data = (0..20)
+            .map(|i| f64::from(i) * 0.5 + 100.0)
+            .map(|x| (x, f(x)));
+        let fit = fit_quadratic(data).unwrap();
+        let [a, b, c] = fit.coefficients;
+        assert!((a - 0.5).abs() < 1e-6);
+        assert!((b + 3.0).abs() < 1e-4);
+        assert!((c - 7.0).abs() < 1e-2);
+        assert!((fit.r_squared - 1.0).abs() < 1e-9);
+        assert!((fit.eval(105.0) - f(105.0)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_fit_noisy_data() {
+        let data = [(0.0, 1.0), (1.0, 0.0), (2.0, 3.0), (3.0, 2.0), (4.0, 5.0)];
+        let fit = fit_quadratic(data).unwrap();
+        assert!(fit.r_squared > 0.0 && fit.r_squared < 1.0);
+    }
+
+    #[test]
+    fn test_fit_linear() {
+        let data = [(1.0, 2.0), (2.0, 2.5), (3.0, 4.5), (4.0, 5.0)];
+        let fit = fit_linear(data).unwrap();
+        assert!((fit.slope - 1.1).abs() < 1e-12);
+        assert!((fit.intercept - 0.75).abs() < 1e-12);
+        assert!(fit.r_squared > 0.9 && fit.r_squared < 1.0);
+        assert_eq!(fit_linear([(1.0, 1.0), (1.0, 2.0)]), None);
+        assert_eq!(fit_linear([(f64::NAN, 1.0)]), None);
+    }
+
+    #[test]
+    fn test_fit_degenerate_data() {
+        assert_eq!(fit_quadratic([(0.0, 1.0), (1.0, 2.0)]), None);
+        assert_eq!(fit_quadratic([(1.0, 1.0), (1.0, 2.0), (2.0, 2.0)]), None);
+        assert_eq!(
+            fit_quadratic([(0.0, f64::NAN), (1.0, 2.0), (2.0, 2.0)]),
+            None
+        );
+    }
+}
@@ -0,0 +1,36 @@
+/// A physical quantity: a value along with its unit, such as an energy in joules, so that
+/// the unit of the data goes along with its type. The axis descriptions of a chart of
+/// quantities can take the unit from the type, see [`crate::chart::MeshStyle::y_desc_unit`].
+///
+/// ```rust
+/// use matplotters::data::Quantity;
+///
+/// struct Joules(f64);
+/// impl Quantity for Joules {
+///     const UNIT: &'static str = "J";
+///     fn value(&self) -> f64 {
+///         self.0
+///     }
+/// }
+///
+/// let energies = [Joules(1.5), Joules(2.0)];
+/// let values: Vec<f64> = energies.iter().map(Quantity::value).collect();
+/// assert_eq!(values, vec![1.5, 2.0]);
+/// ```
+pub trait Quantity {
+    /// The unit of the values, such as `"J"`, or empty for a dimensionless quantity
+    const UNIT: &'static str;
+
+    /// The value of the quantity in its unit
+    fn value(&self) -> f64;
+}
+
+/// The description of an axis of quantities: the name followed by the unit in parentheses,
+/// like `"Energy (J)"`, or the name alone for a dimensionless quantity
+pub(crate) fn quantity_desc<Q: Quantity>(name: &str) -> String {
+    if Q::UNIT.is_empty() {
+        name.to_string()
+    } else {
+        format!("{} ({})", name, Q::UNIT)
+    }
+}
@@ -96,6 +96,71 @@ impl Quartiles {
     pub fn median(&self) -> f64 {
         self.median
     }
+
+    /// The lower and the upper quartiles, without loss of precision
+    pub(crate) fn quartiles(&self) -> (f64, f64) {
+        (self.lower, self.upper)
+    }
+}
+
+/// Precomputed box plot statistics, like the dicts taken by matplotlib's `bxp`, for
+/// quartiles computed elsewhere, such as in a database.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BoxStats {
+    /// The median
+    pub median: f64,
+    /// The lower quartile, the bottom of the box
+    pub q1: f64,
+    /// The upper quartile, the top of the box
+    pub q3: f64,
+    /// The end of the lower whisker
+    pub whislo: f64,
+    /// The end of the upper whisker
+    pub whishi: f64,
+    /// The outliers beyond the whiskers
+    pub fliers: Vec<f64>,
+}
+
+impl BoxStats {
+    /// Create the statistics of a box without outliers.
+    ///
+    /// - `whislo`: The end of the lower whisker
+    /// - `q1`: The lower quartile
+    /// - `median`: The median
+    /// - `q3`: The upper quartile
+    /// - `whishi`: The end of the upper whisker
+    /// - **returns** The newly created statistics
+    pub fn new(whislo: f64, q1: f64, median: f64, q3: f64, whishi: f64) -> Self {
+        Self {
+            median,
+            q1,
+            q3,
+            whislo,
+            whishi,
+            fliers: vec![],
+        }
+    }
+
+    /// Set the outliers drawn beyond the whiskers.
+    ///
+    /// - `fliers`: The outliers
+    /// - **returns** The up-to-dated statistics
+    pub fn fliers<I: IntoIterator<Item = f64>>(mut self, fliers: I) -> Self {
+        self.fliers = fliers.into_iter().collect();
+        self
+    }
+}
+
+impl From<&Quartiles> for BoxStats {
+    fn from(quartiles: &Quartiles) -> Self {
+        Self::new(
+            quartiles.lower_fence,
+            quartiles.lower,
+            quartiles.median,
+            quartiles.upper,
+            quartiles.upper_fence,
+        )
+    }
 }
 
 #[cfg(test)]
@@ -124,4 +189,10 @@ mod test {
             [0.0, 15.0, 20.0, 25.0, 40.0]
         );
     }
+
+    #[test]
+    fn test_box_stats_from_quartiles() {
+        let stats = BoxStats::from(&Quartiles::new(&[10, 20, 30]));
+        assert_eq!(stats, BoxStats::new(0.0, 15.0, 20.0, 25.0, 40.0));
+    }
 }
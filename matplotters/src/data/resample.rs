@@ -0,0 +1,179 @@
+/// What [`resample_to`] returns for the grid points outside of the x range of the data
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Extrapolation {
+    /// Use the y value of the nearest end of the data, like numpy's `interp`
+    Clamp,
+    /// Use `NaN`, so that the point is a gap in a line series
+    Nan,
+}
+
+/// Resample a series onto a common x grid, with linear interpolation
+///
+/// - `data`: The `(x, y)` points of the series, in any order. The points with a `NaN`
+///   coordinate are ignored.
+/// - `x_grid`: The x values to resample the series at
+/// - `extrapolation`: The policy for the grid points outside of the x range of the data
+/// - **returns** One `(x, y)` point per grid value. If the data is empty every y is `NaN`.
+///
+/// ```rust
+/// use matplotters::data::{resample_to, Extrapolation};
+///
+/// let data = [(0.0, 0.0), (2.0, 4.0)];
+/// let resampled = resample_to(&data, &[1.0, 3.0], Extrapolation::Clamp);
+/// assert_eq!(resampled, vec![(1.0, 2.0), (3.0, 4.0)]);
+/// ```
+pub fn resample_to(
+    data: &[(f64, f64)],
+    x_grid: &[f64],
+    extrapolation: Extrapolation,
+) -> Vec<(f64, f64)> {
+    let mut data: Vec<_> = data
+        .iter()
+        .filter(|(x, y)| !x.is_nan() && !y.is_nan())
+        .copied()
+        .collect();
+    data.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+    x_grid
+        .iter()
+        .map(|&x| (x, interpolate(&data, x, extrapolation)))
+        .collect()
+}
+
+/// The common x grid [`align_series`] resamples the series onto
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AlignGrid {
+    /// Every x value of every series; a series is `NaN` outside of its own x range
+    Union,
+    /// The x values of every series within the x range all the series cover, so that every
+    /// series has a value at every grid point
+    Intersection,
+}
+
+/// Resample several series onto a unified x grid, with linear interpolation, so that they
+/// can be compared point by point or used as the bounds of a band
+///
+/// - `series`: The `(x, y)` points of each series, in any order. The points with a `NaN`
+///   coordinate are ignored.
+/// - `grid`: Which x values make the grid, see [`AlignGrid`]
+/// - **returns** One resampled series per input series, all at the same increasing x values.
+///   The intersection grid is empty if the x ranges of the series don't overlap.
+///
+/// ```rust
+/// use matplotters::data::{align_series, AlignGrid};
+///
+/// let a = [(0.0, 0.0), (2.0, 2.0), (4.0, 4.0)];
+/// let b = [(1.0, 10.0), (3.0, 30.0)];
+/// let aligned = align_series(&[&a[..], &b[..]], AlignGrid::Intersection);
+/// assert_eq!(aligned[0], vec![(1.0, 1.0), (2.0, 2.0), (3.0, 3.0)]);
+/// assert_eq!(aligned[1], vec![(1.0, 10.0), (2.0, 20.0), (3.0, 30.0)]);
+/// ```
+pub fn align_series<S: AsRef<[(f64, f64)]>>(series: &[S], grid: AlignGrid) -> Vec<Vec<(f64, f64)>> {
+    let xs = |s: &S| {
+        s.as_ref()
+            .iter()
+            .filter(|(x, y)| !x.is_nan() && !y.is_nan())
+            .map(|(x, _)| *x)
+            .collect::<Vec<_>>()
+    };
+    let mut x_grid: Vec<f64> = series.iter().flat_map(xs).collect();
+    x_grid.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    x_grid.dedup();
+    if grid == AlignGrid::Intersection {
+        let (mut start, mut end) = (f64::NEG_INFINITY, f64::INFINITY);
+        for s in series {
+            let xs = xs(s);
+            start = start.max(xs.iter().copied().fold(f64::INFINITY, f64::min));
+            end = end.min(xs.iter().copied().fold(f64::NEG_INFINITY, f64::max));
+        }
+        x_grid.retain(|x| start <= *x && *x <= end);
+    }
+    series
+        .iter()
+        .map(|s| resample_to(s.as_ref(), &x_grid, Extrapolation::Nan))
+        .collect()
+}
+
+fn interpolate(sorted: &[(f64, f64)], x: f64, extrapolation: Extrapolation) -> f64 {
+    let (first, last) = match (sorted.first(), sorted.last()) {
+        (Some(first), Some(last)) => (*first, *last),
+        _ => return f64::NAN,
+    };
+    if x < first.0 || x > last.0 || x.is_nan() {
+        return match extrapolation {
+            Extrapolation::Nan => f64::NAN,
+            Extrapolation::Clamp if x < first.0 => first.1,
+            Extrapolation::Clamp => last.1,
+        };
+    }
+    let idx = sorted.partition_point(|(px, _)| *px < x);
+    let (x1, y1) = sorted[idx];
+    if idx == 0 || x1 == x {
+        return y1;
+    }
+    let (x0, y0) = sorted[idx - 1];
+    y0 + (y1 - y0) * (x - x0) / (x1 - x0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_resample_midpoints() {
+        let data = [(0.0, 1.0), (1.0, 3.0), (3.0, -1.0), (2.0, 5.0)];
+        let resampled = resample_to(&data, &[0.5, 1.5, 2.5, 1.0], Extrapolation::Nan);
+        assert_eq!(
+            resampled,
+            vec![(0.5, 2.0), (1.5, 4.0), (2.5, 2.0), (1.0, 3.0)]
+        );
+    }
+
+    #[test]
+    fn test_resample_extrapolation() {
+        let data = [(0.0, 1.0), (1.0, 3.0)];
+        let grid = [-1.0, 0.0, 1.0, 2.0];
+
+        let clamped = resample_to(&data, &grid, Extrapolation::Clamp);
+        let ys: Vec<_> = clamped.iter().map(|(_, y)| *y).collect();
+        assert_eq!(ys, vec![1.0, 1.0, 3.0, 3.0]);
+
+        let nan = resample_to(&data, &grid, Extrapolation::Nan);
+        assert!(nan[0].1.is_nan() && nan[3].1.is_nan());
+        assert_eq!((nan[1].1, nan[2].1), (1.0, 3.0));
+
+        assert!(resample_to(&[], &grid, Extrapolation::Clamp)
+            .iter()
+            .all(|(_, y)| y.is_nan()));
+    }
+
+    #[test]
+    fn test_align_series() {
+        // Sampled every 2 units and every 3 units
+        let a = [(0.0, 0.0), (2.0, 4.0), (4.0, 8.0), (6.0, 12.0)];
+        let b = [(3.0, 30.0), (9.0, 90.0), (6.0, 60.0)];
+
+        let aligned = align_series(&[a.to_vec(), b.to_vec()], AlignGrid::Intersection);
+        let grid = [3.0, 4.0, 6.0];
+        for series in &aligned {
+            let xs: Vec<_> = series.iter().map(|(x, _)| *x).collect();
+            assert_eq!(xs, grid);
+        }
+        let ys = |s: &[(f64, f64)]| s.iter().map(|(_, y)| *y).collect::<Vec<_>>();
+        assert_eq!(ys(&aligned[0]), vec![6.0, 8.0, 12.0]);
+        assert_eq!(ys(&aligned[1]), vec![30.0, 40.0, 60.0]);
+
+        // The union grid has gaps outside of the range of each series
+        let aligned = align_series(&[a.to_vec(), b.to_vec()], AlignGrid::Union);
+        assert_eq!(aligned[0].len(), 6);
+        assert_eq!(aligned[0][1], (2.0, 4.0));
+        assert!(aligned[1][1].1.is_nan() && aligned[0][5].1.is_nan());
+        assert_eq!(aligned[1][5], (9.0, 90.0));
+
+        let apart = align_series(
+            &[vec![(0.0, 0.0), (1.0, 1.0)], vec![(2.0, 0.0)]],
+            AlignGrid::Intersection,
+        );
+        assert!(apart.iter().all(Vec::is_empty));
+    }
+}
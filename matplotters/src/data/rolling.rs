@@ -0,0 +1,73 @@
+/// Compute the rolling Pearson correlation of two series over a window
+///
+/// - `a`, `b`: The two series, sampled at the same points. The extra values of the longer one
+///   are ignored.
+/// - `window`: The number of values in each window
+/// - **returns** One value per point: the correlation of the window ending at this point,
+///   bounded to `[-1, 1]`. It is `NaN` for the first `window - 1` points, and for the windows
+///   where one of the series is constant or has a `NaN`.
+///
+/// ```rust
+/// use matplotters::data::rolling_correlation;
+///
+/// let a = [1.0, 2.0, 4.0, 3.0];
+/// let corr = rolling_correlation(&a, &a, 3);
+/// assert!(corr[0].is_nan() && corr[1].is_nan());
+/// assert!((corr[2] - 1.0).abs() < 1e-12 && (corr[3] - 1.0).abs() < 1e-12);
+/// ```
+pub fn rolling_correlation(a: &[f64], b: &[f64], window: usize) -> Vec<f64> {
+    let n = a.len().min(b.len());
+    (0..n)
+        .map(|end| {
+            if window < 2 || end + 1 < window {
+                return f64::NAN;
+            }
+            let range = end + 1 - window..end + 1;
+            correlation(&a[range.clone()], &b[range])
+        })
+        .collect()
+}
+
+fn correlation(a: &[f64], b: &[f64]) -> f64 {
+    let n = a.len() as f64;
+    let (mean_a, mean_b) = (a.iter().sum::<f64>() / n, b.iter().sum::<f64>() / n);
+    let (mut cov, mut var_a, mut var_b) = (0.0, 0.0, 0.0);
+    for (x, y) in a.iter().zip(b) {
+        let (dx, dy) = (x - mean_a, y - mean_b);
+        cov += dx * dy;
+        var_a += dx * dx;
+        var_b += dy * dy;
+    }
+    if var_a == 0.0 || var_b == 0.0 {
+        return f64::NAN;
+    }
+    // NaN values propagate through the sums
+    (cov / (var_a * var_b).sqrt()).clamp(-1.0, 1.0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rolling_correlation() {
+        let a: Vec<f64> = (0..50)
+            .map(|i| (f64::from(i) * 0.3).sin() + 0.01 * f64::from(i))
+            .collect();
+        let neg: Vec<f64> = a.iter().map(|v| 5.0 - 2.0 * v).collect();
+
+        let same = rolling_correlation(&a, &a, 10);
+        let anti = rolling_correlation(&a, &neg, 10);
+        assert_eq!(same.len(), 50);
+        for i in 0..9 {
+            assert!(same[i].is_nan() && anti[i].is_nan());
+        }
+        for i in 9..50 {
+            assert!((same[i] - 1.0).abs() < 1e-12);
+            assert!((anti[i] + 1.0).abs() < 1e-12);
+        }
+
+        let constant = vec![1.0; 50];
+        assert!(rolling_correlation(&a, &constant, 10)[20].is_nan());
+    }
+}
@@ -0,0 +1,72 @@
+/// Sample a function at log-spaced x values, like numpy's `geomspace`, so that the curve is
+/// evenly sampled when drawn over a log x axis
+///
+/// - `f`: The function to sample
+/// - `x_min`: The first x value, which must be positive
+/// - `x_max`: The last x value, which must be positive
+/// - `n`: The number of samples
+/// - **returns** The `(x, f(x))` points, with consecutive x values in a constant ratio and the
+///   ends exactly at `x_min` and `x_max`. It is empty if a bound is not positive and finite.
+///
+/// ```rust
+/// use matplotters::data::sample_logspace;
+///
+/// let points = sample_logspace(|x| 2.0 * x, 1.0, 100.0, 3);
+/// assert_eq!(points, vec![(1.0, 2.0), (10.0, 20.0), (100.0, 200.0)]);
+/// ```
+pub fn sample_logspace<F: FnMut(f64) -> f64>(
+    mut f: F,
+    x_min: f64,
+    x_max: f64,
+    n: usize,
+) -> Vec<(f64, f64)> {
+    let valid = |x: f64| x.is_finite() && x > 0.0;
+    if !valid(x_min) || !valid(x_max) || n == 0 {
+        return vec![];
+    }
+    if n == 1 {
+        return vec![(x_min, f(x_min))];
+    }
+    let span = x_max / x_min;
+    (0..n)
+        .map(|i| {
+            let x = match i {
+                0 => x_min,
+                i if i == n - 1 => x_max,
+                i => x_min * span.powf(i as f64 / (n - 1) as f64),
+            };
+            (x, f(x))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_sample_logspace() {
+        let f = |x: f64| x.sqrt() + 1.0;
+        let points = sample_logspace(f, 0.01, 1e4, 13);
+        assert_eq!(points.len(), 13);
+        assert_eq!(points[0].0, 0.01);
+        assert_eq!(points[12].0, 1e4);
+        // The x values are geometrically spaced, every half decade
+        let ratio = 10f64.sqrt();
+        for pair in points.windows(2) {
+            assert!((pair[1].0 / pair[0].0 - ratio).abs() < 1e-9);
+        }
+        for (x, y) in &points {
+            assert_eq!(*y, f(*x));
+        }
+    }
+
+    #[test]
+    fn test_sample_logspace_invalid_bounds() {
+        assert!(sample_logspace(|x| x, 0.0, 10.0, 5).is_empty());
+        assert!(sample_logspace(|x| x, 1.0, f64::INFINITY, 5).is_empty());
+        // Decreasing bounds sample from x_min down to x_max
+        let points = sample_logspace(|x| x, 100.0, 1.0, 3);
+        assert_eq!(points[1].0, 10.0);
+    }
+}
@@ -15,6 +15,12 @@ use std::iter::{once, repeat};
 use std::ops::Range;
 use std::rc::Rc;
 
+/// The position of the `idx`-th break when evenly splitting `from..to` into `n` parts
+fn compute_evenly_split(from: i32, to: i32, n: usize, idx: usize) -> i32 {
+    let size = (to - from) as usize;
+    from + idx as i32 * (size / n) as i32 + idx.min(size % n) as i32
+}
+
 /// The representation of the rectangle in backend canvas
 #[derive(Clone, Debug)]
 pub struct Rect {
@@ -53,10 +59,6 @@ impl Rect {
 
     /// Evenly split the rectangle to a row * col mesh
     fn split_evenly(&self, (row, col): (usize, usize)) -> impl Iterator<Item = Rect> + '_ {
-        fn compute_evenly_split(from: i32, to: i32, n: usize, idx: usize) -> i32 {
-            let size = (to - from) as usize;
-            from + idx as i32 * (size / n) as i32 + idx.min(size % n) as i32
-        }
         (0..row)
             .flat_map(move |x| repeat(x).zip(0..col))
             .map(move |(ri, ci)| Self {
@@ -319,9 +321,56 @@ impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
         &'a E: PointCollection<'a, CT::From, B>,
         E: Drawable<DB, B>,
     {
+        self.draw_with_inset(element, 0)
+    }
+
+    /// Draw an high-level element, with its coordinates clipped to the area shrunk by `inset`
+    /// pixels on every side
+    pub(crate) fn draw_with_inset<'a, E, B>(
+        &self,
+        element: &'a E,
+        inset: u32,
+    ) -> Result<(), DrawingAreaError<DB>>
+    where
+        B: CoordMapper,
+        &'a E: PointCollection<'a, CT::From, B>,
+        E: Drawable<DB, B>,
+    {
+        self.draw_recording(element, inset, |_| {})
+    }
+
+    /// Draw an high-level element like [`DrawingArea::draw_with_inset`], and pass each of its
+    /// points falling strictly inside the clipping area to `record`, in pixels relative to the
+    /// area, as they are translated for the drawing. The points out of the clipping area are
+    /// moved onto its border by the translation, so the points on the border are not passed.
+    pub(crate) fn draw_recording<'a, E, B, F>(
+        &self,
+        element: &'a E,
+        inset: u32,
+        mut record: F,
+    ) -> Result<(), DrawingAreaError<DB>>
+    where
+        B: CoordMapper,
+        &'a E: PointCollection<'a, CT::From, B>,
+        E: Drawable<DB, B>,
+        F: FnMut(BackendCoord),
+    {
+        let inset = inset as i32;
+        let (x0, y0) = (self.rect.x0 + inset, self.rect.y0 + inset);
+        let clip = Rect {
+            x0,
+            y0,
+            x1: (self.rect.x1 - inset).max(x0),
+            y1: (self.rect.y1 - inset).max(y0),
+        };
         let backend_coords = element.point_iter().into_iter().map(|p| {
-            let b = p.borrow();
-            B::map(&self.coord, b, &self.rect)
+            let mapped = B::map(&self.coord, p.borrow(), &clip);
+            if let Some((x, y)) = B::backend_coord(&mapped) {
+                if clip.x0 < x && x < clip.x1 && clip.y0 < y && y < clip.y1 {
+                    record((x - self.rect.x0, y - self.rect.y0));
+                }
+            }
+            mapped
         });
         self.backend_ops(move |b| element.draw(backend_coords, b, self.dim_in_pixel()))
     }
@@ -453,6 +502,12 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
             .collect()
     }
 
+    /// Create a gridspec-like layout of `row * col` cells over the drawing area, in which a
+    /// subplot can span several rows and columns, see [`GridSpec`]
+    pub fn grid_spec(&self, (row, col): (usize, usize)) -> GridSpec<DB> {
+        GridSpec::new(self, (row, col))
+    }
+
     /// Split the drawing area into a grid with specified breakpoints on both X axis and Y axis
     pub fn split_by_breakpoints<
         XSize: SizeDesc,
@@ -523,6 +578,52 @@ impl<DB: DrawingBackend> DrawingArea<DB, Shift> {
     }
 }
 
+/// A grid of evenly sized cells over a drawing area, like matplotlib's `GridSpec`.
+///
+/// Unlike [`DrawingArea::split_evenly`], a subplot can span several rows and columns, such as
+/// a wide top panel over two narrow bottom panels. The cells are the same as the ones of
+/// [`DrawingArea::split_evenly`], so subplots sharing an edge of the grid are aligned.
+pub struct GridSpec<DB: DrawingBackend> {
+    area: DrawingArea<DB, Shift>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<DB: DrawingBackend> GridSpec<DB> {
+    /// Create a grid of `row * col` cells over the drawing area
+    pub fn new(area: &DrawingArea<DB, Shift>, (row, col): (usize, usize)) -> Self {
+        Self {
+            area: area.clone(),
+            rows: row.max(1),
+            cols: col.max(1),
+        }
+    }
+
+    /// Get the drawing area of a subplot spanning the given rows and columns, such as
+    /// `subplot(0..1, 0..2)` for the first row of a grid with two columns. The ranges are
+    /// clamped to the grid.
+    pub fn subplot(&self, rows: Range<usize>, cols: Range<usize>) -> DrawingArea<DB, Shift> {
+        let rect = &self.area.rect;
+        let span = |r: Range<usize>, n: usize| {
+            let end = r.end.min(n);
+            (r.start.min(end), end)
+        };
+        let (r0, r1) = span(rows, self.rows);
+        let (c0, c1) = span(cols, self.cols);
+        let rect = Rect {
+            x0: compute_evenly_split(rect.x0, rect.x1, self.cols, c0),
+            x1: compute_evenly_split(rect.x0, rect.x1, self.cols, c1),
+            y0: compute_evenly_split(rect.y0, rect.y1, self.rows, r0),
+            y1: compute_evenly_split(rect.y0, rect.y1, self.rows, r1),
+        };
+        DrawingArea {
+            coord: Shift((rect.x0, rect.y0)),
+            rect,
+            backend: self.area.backend.clone(),
+        }
+    }
+}
+
 impl<DB: DrawingBackend, CT: CoordTranslate> DrawingArea<DB, CT> {
     /// Returns the coordinates by value
     pub fn into_coord_spec(self) -> CT {
@@ -709,6 +810,56 @@ mod drawing_area_tests {
             }
         }
     }
+
+    #[test]
+    fn test_grid_spec() {
+        let drawing_area = create_mocked_drawing_area(900, 600, |m| {
+            // A wide top panel over two narrow bottom panels
+            m.check_draw_rect(|c, _, _, u, d| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!((u, d), ((0, 0), (900, 300)));
+            });
+            m.check_draw_rect(|c, _, _, u, d| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert_eq!((u, d), ((0, 300), (450, 600)));
+            });
+            m.check_draw_rect(|c, _, _, u, d| {
+                assert_eq!(c, GREEN.to_rgba());
+                assert_eq!((u, d), ((450, 300), (900, 600)));
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_rect_call, 3));
+        });
+
+        let grid = drawing_area.grid_spec((2, 2));
+        grid.subplot(0..1, 0..2).fill(&RED).expect("Drawing Error");
+        grid.subplot(1..2, 0..1).fill(&BLUE).expect("Drawing Error");
+        grid.subplot(1..2, 1..5)
+            .fill(&GREEN)
+            .expect("Drawing Error");
+    }
+
+    #[test]
+    fn test_grid_spec_matches_split_evenly() {
+        let drawing_area = create_mocked_drawing_area(1003, 701, |_| {});
+        let cells = drawing_area.split_evenly((3, 4));
+        let grid = GridSpec::new(&drawing_area, (3, 4));
+        let range = |a: &DrawingArea<_, _>| a.get_pixel_range();
+        for r in 0..3 {
+            for c in 0..4 {
+                assert_eq!(
+                    range(&grid.subplot(r..r + 1, c..c + 1)),
+                    range(&cells[r * 4 + c])
+                );
+            }
+        }
+        // A spanning subplot covers exactly its cells
+        let (xs, ys) = range(&grid.subplot(1..3, 1..3));
+        assert_eq!(xs.start, range(&cells[5]).0.start);
+        assert_eq!(ys.start, range(&cells[5]).1.start);
+        assert_eq!(xs.end, range(&cells[10]).0.end);
+        assert_eq!(ys.end, range(&cells[10]).1.end);
+    }
+
     #[test]
     fn test_titled() {
         let drawing_area = create_mocked_drawing_area(1024, 768, |m| {
@@ -13,6 +13,29 @@ about the [coordinate abstraction](../coord/index.html) and [element system](../
 mod area;
 mod backend_impl;
 
-pub use area::{DrawingArea, DrawingAreaErrorKind, IntoDrawingArea, Rect};
+pub use area::{DrawingArea, DrawingAreaErrorKind, GridSpec, IntoDrawingArea, Rect};
 
 pub use backend_impl::*;
+
+#[cfg(feature = "svg_backend")]
+mod svg_fragment;
+#[cfg(feature = "svg_backend")]
+pub use svg_fragment::svg_fragment;
+
+#[cfg(feature = "svg_backend")]
+mod svg_tooltips;
+#[cfg(feature = "svg_backend")]
+pub use svg_tooltips::svg_label_tooltips;
+
+#[cfg(all(
+    feature = "svg_backend",
+    feature = "bitmap_backend",
+    feature = "bitmap_encoder"
+))]
+mod multi_backend;
+#[cfg(all(
+    feature = "svg_backend",
+    feature = "bitmap_backend",
+    feature = "bitmap_encoder"
+))]
+pub use multi_backend::{render_to_all, AnyBackend, AnyBackendError, Backend};
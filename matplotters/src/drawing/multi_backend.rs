@@ -0,0 +1,253 @@
+use std::error::Error;
+use std::path::PathBuf;
+
+use super::{DrawingArea, IntoDrawingArea};
+use crate::coord::Shift;
+use plotters_backend::{
+    BackendColor, BackendCoord, BackendStyle, BackendTextStyle, DrawingBackend, DrawingErrorKind,
+};
+use plotters_bitmap::{BitMapBackend, BitMapBackendError};
+use plotters_svg::SVGBackend;
+
+/// An output of [`render_to_all`]
+#[derive(Clone, Debug, PartialEq)]
+pub enum Backend {
+    /// An SVG file with the given path and size in pixels
+    Svg(PathBuf, (u32, u32)),
+    /// A bitmap file with the given path and size in pixels. The image format follows the
+    /// extension of the path, such as `.png`.
+    Bitmap(PathBuf, (u32, u32)),
+}
+
+/// The backend the drawing closure of [`render_to_all`] draws on, which is one of the tier 1
+/// backends
+pub enum AnyBackend<'a> {
+    /// The SVG backend
+    Svg(SVGBackend<'a>),
+    /// The bitmap backend
+    Bitmap(BitMapBackend<'a>),
+}
+
+/// The error of an [`AnyBackend`]
+#[derive(Debug)]
+pub enum AnyBackendError {
+    /// An error of the SVG backend
+    Svg(std::io::Error),
+    /// An error of the bitmap backend
+    Bitmap(BitMapBackendError),
+}
+
+impl std::fmt::Display for AnyBackendError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnyBackendError::Svg(e) => write!(f, "SVG backend error: {}", e),
+            AnyBackendError::Bitmap(e) => write!(f, "Bitmap backend error: {}", e),
+        }
+    }
+}
+
+impl Error for AnyBackendError {}
+
+fn wrap_error<E: Error + Send + Sync>(
+    error: DrawingErrorKind<E>,
+    wrap: fn(E) -> AnyBackendError,
+) -> DrawingErrorKind<AnyBackendError> {
+    match error {
+        DrawingErrorKind::DrawingError(e) => DrawingErrorKind::DrawingError(wrap(e)),
+        DrawingErrorKind::FontError(e) => DrawingErrorKind::FontError(e),
+    }
+}
+
+macro_rules! dispatch {
+    ($self:expr, $backend:ident => $call:expr) => {
+        match $self {
+            AnyBackend::Svg($backend) => $call.map_err(|e| wrap_error(e, AnyBackendError::Svg)),
+            AnyBackend::Bitmap($backend) => {
+                $call.map_err(|e| wrap_error(e, AnyBackendError::Bitmap))
+            }
+        }
+    };
+}
+
+impl<'a> DrawingBackend for AnyBackend<'a> {
+    type ErrorType = AnyBackendError;
+
+    fn get_size(&self) -> (u32, u32) {
+        match self {
+            AnyBackend::Svg(backend) => backend.get_size(),
+            AnyBackend::Bitmap(backend) => backend.get_size(),
+        }
+    }
+
+    fn ensure_prepared(&mut self) -> Result<(), DrawingErrorKind<AnyBackendError>> {
+        dispatch!(self, backend => backend.ensure_prepared())
+    }
+
+    fn present(&mut self) -> Result<(), DrawingErrorKind<AnyBackendError>> {
+        dispatch!(self, backend => backend.present())
+    }
+
+    fn draw_pixel(
+        &mut self,
+        point: BackendCoord,
+        color: BackendColor,
+    ) -> Result<(), DrawingErrorKind<AnyBackendError>> {
+        dispatch!(self, backend => backend.draw_pixel(point, color))
+    }
+
+    fn draw_line<S: BackendStyle>(
+        &mut self,
+        from: BackendCoord,
+        to: BackendCoord,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<AnyBackendError>> {
+        dispatch!(self, backend => backend.draw_line(from, to, style))
+    }
+
+    fn draw_rect<S: BackendStyle>(
+        &mut self,
+        upper_left: BackendCoord,
+        bottom_right: BackendCoord,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<AnyBackendError>> {
+        dispatch!(self, backend => backend.draw_rect(upper_left, bottom_right, style, fill))
+    }
+
+    fn draw_path<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        path: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<AnyBackendError>> {
+        dispatch!(self, backend => backend.draw_path(path, style))
+    }
+
+    fn draw_circle<S: BackendStyle>(
+        &mut self,
+        center: BackendCoord,
+        radius: u32,
+        style: &S,
+        fill: bool,
+    ) -> Result<(), DrawingErrorKind<AnyBackendError>> {
+        dispatch!(self, backend => backend.draw_circle(center, radius, style, fill))
+    }
+
+    fn fill_polygon<S: BackendStyle, I: IntoIterator<Item = BackendCoord>>(
+        &mut self,
+        vert: I,
+        style: &S,
+    ) -> Result<(), DrawingErrorKind<AnyBackendError>> {
+        dispatch!(self, backend => backend.fill_polygon(vert, style))
+    }
+
+    fn draw_text<TStyle: BackendTextStyle>(
+        &mut self,
+        text: &str,
+        style: &TStyle,
+        pos: BackendCoord,
+    ) -> Result<(), DrawingErrorKind<AnyBackendError>> {
+        dispatch!(self, backend => backend.draw_text(text, style, pos))
+    }
+
+    fn estimate_text_size<TStyle: BackendTextStyle>(
+        &self,
+        text: &str,
+        style: &TStyle,
+    ) -> Result<(u32, u32), DrawingErrorKind<AnyBackendError>> {
+        dispatch!(self, backend => backend.estimate_text_size(text, style))
+    }
+
+    fn blit_bitmap(
+        &mut self,
+        pos: BackendCoord,
+        size: (u32, u32),
+        src: &[u8],
+    ) -> Result<(), DrawingErrorKind<AnyBackendError>> {
+        dispatch!(self, backend => backend.blit_bitmap(pos, size, src))
+    }
+}
+
+/// Replay the same drawing code against several outputs, for instance to save a figure as
+/// both SVG and PNG
+///
+/// - `outputs`: The files to render
+/// - `draw_fn`: The drawing code, which is called once per output with its root drawing area
+/// - **returns** The first error of the drawing code or of writing an output; the outputs
+///   before it are written
+///
+/// ```rust,no_run
+/// use matplotters::prelude::*;
+///
+/// render_to_all(
+///     &[
+///         Backend::Svg("figure.svg".into(), (640, 480)),
+///         Backend::Bitmap("figure.png".into(), (640, 480)),
+///     ],
+///     |root| {
+///         root.fill(&WHITE)?;
+///         let mut chart = ChartBuilder::on(&root).build_cartesian_2d(0.0..1.0, 0.0..1.0)?;
+///         chart.draw_series(LineSeries::new([(0.0, 0.0), (1.0, 1.0)], &RED))?;
+///         Ok(())
+///     },
+/// )
+/// .unwrap();
+/// ```
+pub fn render_to_all<F>(outputs: &[Backend], mut draw_fn: F) -> Result<(), Box<dyn Error>>
+where
+    F: FnMut(DrawingArea<AnyBackend<'_>, Shift>) -> Result<(), Box<dyn Error>>,
+{
+    for output in outputs {
+        let backend = match output {
+            Backend::Svg(path, size) => AnyBackend::Svg(SVGBackend::new(path, *size)),
+            Backend::Bitmap(path, size) => AnyBackend::Bitmap(BitMapBackend::new(path, *size)),
+        };
+        let root = backend.into_drawing_area();
+        draw_fn(root.clone())?;
+        root.present()?;
+    }
+    Ok(())
+}
+
+#[cfg(all(test, feature = "image"))]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_render_to_all() {
+        let dir = std::env::temp_dir().join(format!("matplotters-render-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let (svg_path, png_path) = (dir.join("figure.svg"), dir.join("figure.png"));
+
+        let mut calls = 0;
+        render_to_all(
+            &[
+                Backend::Svg(svg_path.clone(), (200, 100)),
+                Backend::Bitmap(png_path.clone(), (200, 100)),
+            ],
+            |root| {
+                calls += 1;
+                root.fill(&WHITE)?;
+                root.draw(&Rectangle::new([(20, 10), (80, 60)], RED.filled()))?;
+                Ok(())
+            },
+        )
+        .expect("Drawing Error");
+        assert_eq!(calls, 2);
+
+        let svg = std::fs::read_to_string(&svg_path).unwrap();
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains("width=\"200\" height=\"100\""));
+        assert!(svg.contains(
+            "<rect x=\"20\" y=\"10\" width=\"60\" height=\"50\" opacity=\"1\" fill=\"#FF0000\""
+        ));
+
+        let png = std::fs::read(&png_path).unwrap();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        let image = image::load_from_memory(&png).unwrap().to_rgb8();
+        assert_eq!(image.dimensions(), (200, 100));
+        assert_eq!(image.get_pixel(50, 30).0, [255, 0, 0]);
+        assert_eq!(image.get_pixel(150, 80).0, [255, 255, 255]);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
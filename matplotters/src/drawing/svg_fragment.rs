@@ -0,0 +1,112 @@
+use super::{DrawingArea, DrawingAreaErrorKind, IntoDrawingArea};
+use crate::coord::cartesian::Cartesian2d;
+use crate::coord::ranged1d::Ranged;
+use plotters_svg::SVGBackend;
+
+/// Render a series to an SVG `<g>` fragment, to be embedded into an SVG document built by
+/// other means, such as a report that lays out several plots
+///
+/// - `coord`: The mapping from the data coordinates to the pixel coordinates of the
+///   document, whose pixel ranges also size the drawing
+/// - `draw`: The function drawing the series on a drawing area with the given mapping, see
+///   [`DrawingArea::draw`]
+/// - **returns** The `<g>` element holding the drawn elements, without the enclosing `<svg>`
+///   element, or the first drawing error
+///
+/// ```rust
+/// use matplotters::coord::cartesian::Cartesian2d;
+/// use matplotters::coord::types::RangedCoordf64;
+/// use matplotters::drawing::svg_fragment;
+/// use matplotters::prelude::*;
+///
+/// let coord = Cartesian2d::<RangedCoordf64, RangedCoordf64>::new(0.0..1.0, 0.0..1.0, (0..200, 0..100));
+/// let fragment = svg_fragment(coord, |area| {
+///     area.draw(&PathElement::new(vec![(0.0, 0.0), (1.0, 1.0)], BLUE))
+/// })
+/// .unwrap();
+/// assert!(fragment.starts_with("<g>") && fragment.contains("<polyline"));
+/// ```
+pub fn svg_fragment<X, Y, F>(
+    coord: Cartesian2d<X, Y>,
+    draw: F,
+) -> Result<String, DrawingAreaErrorKind<std::io::Error>>
+where
+    X: Ranged,
+    Y: Ranged,
+    F: FnOnce(
+        &DrawingArea<SVGBackend, Cartesian2d<X, Y>>,
+    ) -> Result<(), DrawingAreaErrorKind<std::io::Error>>,
+{
+    let (x, y) = (
+        coord.get_x_axis_pixel_range(),
+        coord.get_y_axis_pixel_range(),
+    );
+    let size = (
+        x.start.max(x.end).max(0) as u32 + 1,
+        y.start.max(y.end).max(0) as u32 + 1,
+    );
+    let mut document = String::new();
+    {
+        let root = SVGBackend::with_string(&mut document, size).into_drawing_area();
+        let area = root.apply_coord_spec(coord);
+        draw(&area)?;
+        root.present()?;
+    }
+    // The content of the document is between the opening and the closing `svg` tags
+    let start = document
+        .find("<svg")
+        .and_then(|i| document[i..].find('>').map(|j| i + j + 1))
+        .unwrap_or(0);
+    let end = document.rfind("</svg>").unwrap_or(document.len());
+    Ok(format!("<g>{}</g>\n", document[start..end].trim_end()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::svg_fragment;
+    use crate::coord::cartesian::Cartesian2d;
+    use crate::coord::types::RangedCoordf64;
+    use crate::prelude::*;
+
+    /// Whether the tags of the XML text are balanced, ignoring the attributes
+    fn is_balanced(xml: &str) -> bool {
+        let mut stack = vec![];
+        for tag in xml.split('<').skip(1) {
+            let tag = match tag.find('>') {
+                Some(end) => &tag[..end],
+                None => return false,
+            };
+            if let Some(name) = tag.strip_prefix('/') {
+                if stack.pop() != Some(name.trim().to_string()) {
+                    return false;
+                }
+            } else if !tag.ends_with('/') {
+                stack.push(tag.split_whitespace().next().unwrap_or("").to_string());
+            }
+        }
+        stack.is_empty()
+    }
+
+    #[test]
+    fn test_svg_fragment() {
+        let coord: Cartesian2d<RangedCoordf64, RangedCoordf64> =
+            Cartesian2d::new(0.0..10.0, 0.0..10.0, (0..300, 0..200));
+        let fragment = svg_fragment(coord, |area| {
+            for element in
+                MatLineSeries::new(vec![(1.0, 1.0), (5.0, 8.0), (9.0, 3.0)], RED).point_size(3)
+            {
+                area.draw(&element)?;
+            }
+            Ok(())
+        })
+        .expect("Drawing Error");
+
+        assert!(fragment.starts_with("<g>"), "{}", fragment);
+        assert!(fragment.trim_end().ends_with("</g>"), "{}", fragment);
+        assert!(!fragment.contains("<svg"));
+        assert_eq!(fragment.matches("<circle").count(), 3);
+        assert_eq!(fragment.matches("<polyline").count(), 1);
+        assert!(is_balanced(&fragment), "{}", fragment);
+        assert!(!is_balanced("<g><polyline></g>"));
+    }
+}
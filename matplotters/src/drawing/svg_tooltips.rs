@@ -0,0 +1,126 @@
+/// Add tooltips to the abbreviated labels of an SVG document, so that the full label shows
+/// when the pointer hovers over it
+///
+/// - `svg`: The document drawn by the SVG backend
+/// - `labels`: The `(abbreviated, full)` pairs of the labels in the order they are drawn,
+///   usually [`crate::chart::ChartContext::abbreviated_labels`]; each pair goes to the next
+///   `<text>` element with its abbreviation, so that labels sharing an abbreviation each get
+///   their own full label
+/// - **returns** The document where the `<text>` element of every abbreviated label holds a
+///   `<title>` element with the full label
+///
+/// ```rust
+/// use matplotters::drawing::svg_label_tooltips;
+///
+/// let svg = "<text x=\"10\" y=\"20\">\nA very…\n</text>\n";
+/// let labels = [("A very…".to_string(), "A very long name".to_string())];
+/// let svg = svg_label_tooltips(svg, &labels);
+/// assert!(svg.contains("A very…<title>A very long name</title>"));
+/// ```
+pub fn svg_label_tooltips(svg: &str, labels: &[(String, String)]) -> String {
+    let mut ret = String::with_capacity(svg.len());
+    let mut rest = svg;
+    for (short, full) in labels {
+        // The backend writes the escaped text on its own line before the closing tag
+        let text = format!(">\n{}\n</text>", escape(short));
+        if let Some(idx) = rest.find(&text) {
+            ret.push_str(&rest[..idx]);
+            ret.push_str(&format!(
+                ">\n{}<title>{}</title>\n</text>",
+                escape(short),
+                escape(full)
+            ));
+            rest = &rest[idx + text.len()..];
+        }
+    }
+    ret.push_str(rest);
+    ret
+}
+
+// Escape a text like the SVG backend does
+fn escape(text: &str) -> String {
+    let mut ret = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '<' => ret.push_str("&lt;"),
+            '>' => ret.push_str("&gt;"),
+            '&' => ret.push_str("&amp;"),
+            '"' => ret.push_str("&quot;"),
+            '\'' => ret.push_str("&apos;"),
+            other => ret.push(other),
+        }
+    }
+    ret
+}
+
+#[cfg(test)]
+mod test {
+    use super::svg_label_tooltips;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_abbreviated_label_tooltips() {
+        let categories = ["North", "International & Overseas Operations", "South"];
+        let mut svg = String::new();
+        let labels = {
+            let root = SVGBackend::with_string(&mut svg, (400, 300)).into_drawing_area();
+            let mut chart = ChartBuilder::on(&root)
+                .x_label_area_size(30)
+                .build_cartesian_2d(0.0..3.0, 0.0..10.0)
+                .expect("Build chart error");
+            let label = |x: &f64| categories.get(*x as usize).unwrap_or(&"").to_string();
+            chart
+                .configure_mesh()
+                .x_labels(4)
+                .x_label_formatter(&label)
+                .label_max_len(12)
+                .draw()
+                .expect("Drawing Error");
+            root.present().expect("Drawing Error");
+            chart.abbreviated_labels().to_vec()
+        };
+        assert_eq!(
+            labels,
+            vec![("Internation…".to_string(), categories[1].to_string())]
+        );
+
+        let svg = svg_label_tooltips(&svg, &labels);
+        assert!(svg.contains(
+            "Internation…<title>International &amp; Overseas Operations</title>\n</text>"
+        ));
+        assert!(svg.contains(">\nNorth\n</text>") && svg.contains(">\nSouth\n</text>"));
+        assert!(!svg.contains("Overseas Operations\n</text>"));
+    }
+
+    #[test]
+    fn test_tooltips_sharing_an_abbreviation() {
+        let categories = ["Northern Europe", "Northern Africa"];
+        let mut svg = String::new();
+        let labels = {
+            let root = SVGBackend::with_string(&mut svg, (400, 300)).into_drawing_area();
+            let mut chart = ChartBuilder::on(&root)
+                .x_label_area_size(30)
+                .build_cartesian_2d(0.0..2.0, 0.0..10.0)
+                .expect("Build chart error");
+            let label = |x: &f64| categories.get(*x as usize).unwrap_or(&"").to_string();
+            chart
+                .configure_mesh()
+                .x_labels(3)
+                .x_label_formatter(&label)
+                .label_max_len(9)
+                .draw()
+                .expect("Drawing Error");
+            root.present().expect("Drawing Error");
+            chart.abbreviated_labels().to_vec()
+        };
+        assert_eq!(labels.len(), 2);
+        assert_eq!(labels[0].0, labels[1].0);
+
+        // Each label gets its own full label, in the order they are drawn
+        let svg = svg_label_tooltips(&svg, &labels);
+        let europe = svg.find("<title>Northern Europe</title>").unwrap();
+        let africa = svg.find("<title>Northern Africa</title>").unwrap();
+        assert!(europe < africa);
+        assert_eq!(svg.matches("<title>").count(), 2);
+    }
+}
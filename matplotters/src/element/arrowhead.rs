@@ -0,0 +1,75 @@
+use super::{Drawable, PointCollection};
+use crate::style::ShapeStyle;
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// A filled arrowhead at the first point, pointing to the second point on the screen, or at
+/// the end of a line, pointing on along it, see [`Arrowhead::at_end`]
+pub(crate) struct Arrowhead {
+    points: [(f64, f64); 2],
+    size: u32,
+    style: ShapeStyle,
+    at_end: bool,
+}
+
+impl Arrowhead {
+    pub(crate) fn new((from, to): ((f64, f64), (f64, f64)), size: u32, style: ShapeStyle) -> Self {
+        Self {
+            points: [from, to],
+            size,
+            style,
+            at_end: false,
+        }
+    }
+
+    /// An arrowhead at the end of the line from `start` to `end`, pointing away from `start`.
+    /// Both points are usually on the chart, so that the direction on the screen is not
+    /// distorted by a point moved into the plotting area.
+    pub(crate) fn at_end(
+        (start, end): ((f64, f64), (f64, f64)),
+        size: u32,
+        style: ShapeStyle,
+    ) -> Self {
+        Self {
+            at_end: true,
+            ..Self::new((start, end), size, style)
+        }
+    }
+}
+
+impl<'a> PointCollection<'a, (f64, f64)> for &'a Arrowhead {
+    type Point = &'a (f64, f64);
+    type IntoIter = &'a [(f64, f64)];
+    fn point_iter(self) -> &'a [(f64, f64)] {
+        &self.points
+    }
+}
+
+impl<DB: DrawingBackend> Drawable<DB> for Arrowhead {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (first, second) = match (points.next(), points.next()) {
+            (Some(first), Some(second)) => (first, second),
+            _ => return Ok(()),
+        };
+        let from = if self.at_end { second } else { first };
+        let (dx, dy) = (f64::from(second.0 - first.0), f64::from(second.1 - first.1));
+        let len = dx.hypot(dy);
+        if len == 0.0 {
+            return Ok(());
+        }
+        let s = f64::from(self.size);
+        let (ux, uy) = (dx / len, dy / len);
+        let (bx, by) = (f64::from(from.0) - ux * s, f64::from(from.1) - uy * s);
+        let corner = |side: f64| {
+            (
+                (bx - uy * s / 2.0 * side).round() as i32,
+                (by + ux * s / 2.0 * side).round() as i32,
+            )
+        };
+        backend.fill_polygon(vec![from, corner(1.0), corner(-1.0)], &self.style.color)
+    }
+}
@@ -269,6 +269,7 @@ pub struct Rectangle<Coord> {
     points: [Coord; 2],
     style: ShapeStyle,
     margin: (u32, u32, u32, u32),
+    corner_radius: f64,
 }
 
 impl<Coord> Rectangle<Coord> {
@@ -281,6 +282,7 @@ impl<Coord> Rectangle<Coord> {
             points,
             style: style.into(),
             margin: (0, 0, 0, 0),
+            corner_radius: 0.0,
         }
     }
 
@@ -293,6 +295,52 @@ impl<Coord> Rectangle<Coord> {
         self.margin = (t, b, l, r);
         self
     }
+
+    /// Round the two corners on the side of the first point, the top of the rectangle, even
+    /// when it ends up below the second point on the canvas, as for a bar with a negative value
+    /// - `radius`: The radius of the corners, in pixels, clamped to half the width and to the
+    ///   height of the rectangle
+    pub fn set_top_corner_radius(&mut self, radius: f64) -> &mut Self {
+        self.corner_radius = radius;
+        self
+    }
+}
+
+/// The number of segments of the arc of each rounded corner
+const CORNER_SEGMENTS: usize = 8;
+
+// The outline of a rectangle from `a` to `b` whose two upper corners on the canvas, or the two
+// lower ones if `upper` is false, are rounded
+fn rounded_outline(
+    a: BackendCoord,
+    b: BackendCoord,
+    upper: bool,
+    radius: f64,
+) -> Vec<BackendCoord> {
+    let (w, h) = (f64::from(b.0 - a.0), f64::from(b.1 - a.1));
+    let r = radius.min(w / 2.0).min(h).max(0.0);
+    let (edge, base, dir) = if upper {
+        (a.1, b.1, 1.0)
+    } else {
+        (b.1, a.1, -1.0)
+    };
+    let cy = f64::from(edge) + dir * r;
+    let (left, right) = (f64::from(a.0) + r, f64::from(b.0) - r);
+    let arc = |k: usize| {
+        let phi = std::f64::consts::FRAC_PI_2 * k as f64 / CORNER_SEGMENTS as f64;
+        (r * phi.cos(), cy - dir * r * phi.sin())
+    };
+    let mut ret: Vec<BackendCoord> = vec![(a.0, base)];
+    ret.extend((0..=CORNER_SEGMENTS).map(|k| {
+        let (dx, y) = arc(k);
+        ((left - dx).round() as i32, y.round() as i32)
+    }));
+    ret.extend((0..=CORNER_SEGMENTS).rev().map(|k| {
+        let (dx, y) = arc(k);
+        ((right + dx).round() as i32, y.round() as i32)
+    }));
+    ret.push((b.0, base));
+    ret
 }
 
 impl<'a, Coord> PointCollection<'a, Coord> for &'a Rectangle<Coord> {
@@ -312,11 +360,20 @@ impl<Coord, DB: DrawingBackend> Drawable<DB> for Rectangle<Coord> {
     ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
         match (points.next(), points.next()) {
             (Some(a), Some(b)) => {
+                let first_is_top = a.1 <= b.1;
                 let (mut a, mut b) = ((a.0.min(b.0), a.1.min(b.1)), (a.0.max(b.0), a.1.max(b.1)));
                 a.1 += self.margin.0 as i32;
                 b.1 -= self.margin.1 as i32;
                 a.0 += self.margin.2 as i32;
                 b.0 -= self.margin.3 as i32;
+                if self.corner_radius > 0.0 && a.0 < b.0 && a.1 < b.1 {
+                    let mut outline = rounded_outline(a, b, first_is_top, self.corner_radius);
+                    if self.style.filled {
+                        return backend.fill_polygon(outline, &self.style.color.to_backend_color());
+                    }
+                    outline.push(outline[0]);
+                    return backend.draw_path(outline, &self.style);
+                }
                 backend.draw_rect(a, b, &self.style, self.style.filled)
             }
             _ => Ok(()),
@@ -0,0 +1,324 @@
+use super::{Drawable, PointCollection};
+use crate::data::float::group_thousands;
+use crate::data::{detect_outliers, OutlierRule};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{Color, RGBAColor, ShapeStyle, TextStyle, BLACK, WHITE};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// A label box on the screen, as its upper-left and bottom-right corners
+type LabelBox = (BackendCoord, BackendCoord);
+
+fn overlaps(a: &LabelBox, b: &LabelBox) -> bool {
+    a.0 .0 < b.1 .0 && b.0 .0 < a.1 .0 && a.0 .1 < b.1 .1 && b.0 .1 < a.1 .1
+}
+
+/// Greedily place the label boxes in order: a box overlapping one already placed is moved up
+/// until it is just above it, which terminates since a box only moves up.
+fn avoid_collisions(boxes: &mut [LabelBox], gap: i32) {
+    for idx in 1..boxes.len() {
+        let (placed, rest) = boxes.split_at_mut(idx);
+        let candidate = &mut rest[0];
+        while let Some(other) = placed.iter().find(|b| overlaps(b, candidate)) {
+            let dy = candidate.1 .1 - (other.0 .1 - gap);
+            candidate.0 .1 -= dy;
+            candidate.1 .1 -= dy;
+        }
+    }
+}
+
+/// Where a label is placed relative to its anchor, such as the end of a bar, see
+/// [`DataLabels::label_position`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum LabelPosition {
+    /// Above the anchor, outside of the bar
+    #[default]
+    Outside,
+    /// Below the anchor, inside of the bar near its end
+    Inside,
+    /// Centered on the anchor, which is the middle of the bar
+    Center,
+}
+
+/// Black or white, whichever is the more readable on the background
+fn contrast_color(background: &RGBAColor) -> RGBAColor {
+    let (r, g, b) = background.rgb();
+    let luminance = 0.299 * f64::from(r) + 0.587 * f64::from(g) + 0.114 * f64::from(b);
+    if luminance > 128.0 {
+        BLACK.to_rgba()
+    } else {
+        WHITE.to_rgba()
+    }
+}
+
+/**
+Text labels attached to data points, such as the values of bars or the names of scatter
+points, which are kept readable when the points are close.
+
+Each label is centered above its anchor. Unless [`DataLabels::avoid_collisions`] is turned
+off, a label overlapping a previous one is nudged up until it is clear of it, and a leader
+line can be drawn from the anchor to each moved label, see [`DataLabels::leader_lines`].
+
+For exploring data, [`DataLabels::outliers`] labels the outliers of a series only.
+
+The labels of bars can be placed inside the bars or at their middle instead, see
+[`DataLabels::label_position`], and [`crate::series::MatBarSeries::data_labels`] labels
+every bar with its value.
+*/
+pub struct DataLabels<'a, Coord> {
+    anchors: Vec<Coord>,
+    texts: Vec<String>,
+    style: TextStyle<'a>,
+    offset: i32,
+    avoid: bool,
+    leader_style: Option<ShapeStyle>,
+    position: LabelPosition,
+    downward: Vec<bool>,
+    backgrounds: Vec<RGBAColor>,
+}
+
+impl<'a, Coord> DataLabels<'a, Coord> {
+    /// Create new data labels, 4 pixels above their anchors
+    /// - `labels`: The anchors of the labels, in the guest coordinates, and their texts
+    /// - `style`: The style of the texts
+    /// - returns the created element
+    pub fn new<I, S, T>(labels: I, style: T) -> Self
+    where
+        I: IntoIterator<Item = (Coord, S)>,
+        S: Into<String>,
+        T: Into<TextStyle<'a>>,
+    {
+        let (anchors, texts): (Vec<_>, Vec<String>) =
+            labels.into_iter().map(|(c, s)| (c, s.into())).unzip();
+        Self {
+            anchors,
+            texts,
+            style: style.into().pos(Pos::new(HPos::Left, VPos::Top)),
+            offset: 4,
+            avoid: true,
+            leader_style: None,
+            position: LabelPosition::Outside,
+            downward: vec![],
+            backgrounds: vec![],
+        }
+    }
+
+    /// Set the distance between the anchors and the bottom of their labels, in pixels
+    pub fn offset(mut self, offset: i32) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Set whether the overlapping labels are moved apart, which is the default
+    pub fn avoid_collisions(mut self, avoid: bool) -> Self {
+        self.avoid = avoid;
+        self
+    }
+
+    /// Draw a line with the given style from the anchor of every moved label to the label
+    pub fn leader_lines<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.leader_style = Some(style.into());
+        self
+    }
+
+    /// Set where the labels are placed relative to their anchors, above them by default.
+    /// The labels inside or at the middle of a bar take the color contrasting the most with
+    /// the bar, see [`DataLabels::inside_colors`].
+    pub fn label_position(mut self, position: LabelPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Set the colors of the bars behind the labels, in the order of the labels. The labels
+    /// placed inside of the bars are drawn in black or white, whichever is the more readable
+    /// on their bar, instead of the color of the text style.
+    pub fn inside_colors<I: IntoIterator<Item = C>, C: Color>(mut self, colors: I) -> Self {
+        self.backgrounds = colors.into_iter().map(|c| c.to_rgba()).collect();
+        self
+    }
+
+    /// Set which labels belong to bars going down from their baseline, in the order of the
+    /// labels: they are mirrored, the outside labels being below their anchors
+    #[cfg(feature = "histogram")]
+    pub(crate) fn downward<I: IntoIterator<Item = bool>>(mut self, downward: I) -> Self {
+        self.downward = downward.into_iter().collect();
+        self
+    }
+
+    /// Group the digits of the numbers in the labels by thousands, as in `1,234,567`, see
+    /// [`group_thousands`]
+    /// - `separator`: The separator inserted between the groups of digits
+    pub fn thousands_separator(mut self, separator: char) -> Self {
+        for text in self.texts.iter_mut() {
+            *text = group_thousands(text, separator);
+        }
+        self
+    }
+}
+
+impl<'a> DataLabels<'a, (f64, f64)> {
+    /// Create labels for the outliers of the y values of a series, each showing the index of
+    /// the point in the data and its value, such as `#4: 40`
+    /// - `data`: The points of the series
+    /// - `rule`: The rule telling the outliers from the other values, see [`detect_outliers`]
+    /// - `style`: The style of the texts
+    /// - returns the created element, without labels if there are no outliers
+    pub fn outliers<T: Into<TextStyle<'a>>>(
+        data: &[(f64, f64)],
+        rule: OutlierRule,
+        style: T,
+    ) -> Self {
+        let ys: Vec<f64> = data.iter().map(|(_, y)| *y).collect();
+        let labels = detect_outliers(&ys, rule)
+            .into_iter()
+            .map(|idx| (data[idx], format!("#{}: {}", idx, data[idx].1)));
+        Self::new(labels, style)
+    }
+}
+
+impl<'a, 'b, Coord> PointCollection<'b, Coord> for &'b DataLabels<'a, Coord> {
+    type Point = &'b Coord;
+    type IntoIter = &'b [Coord];
+    fn point_iter(self) -> &'b [Coord] {
+        &self.anchors
+    }
+}
+
+impl<'a, Coord, DB: DrawingBackend> Drawable<DB> for DataLabels<'a, Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let anchors: Vec<_> = points.collect();
+        let mut boxes = vec![];
+        for (idx, ((x, y), text)) in anchors.iter().zip(&self.texts).enumerate() {
+            let (w, h) = backend.estimate_text_size(text, &self.style)?;
+            let (w, h) = (w as i32, h as i32);
+            let down = self.downward.get(idx).copied().unwrap_or(false);
+            let top = match (self.position, down) {
+                (LabelPosition::Outside, false) | (LabelPosition::Inside, true) => {
+                    y - self.offset - h
+                }
+                (LabelPosition::Outside, true) | (LabelPosition::Inside, false) => y + self.offset,
+                (LabelPosition::Center, _) => y - h / 2,
+            };
+            boxes.push(((x - w / 2, top), (x - w / 2 + w, top + h)));
+        }
+        let initial = boxes.clone();
+        if self.avoid {
+            avoid_collisions(&mut boxes, 1);
+        }
+        for (idx, (text, label)) in self.texts.iter().zip(&boxes).enumerate() {
+            if let Some(style) = &self.leader_style {
+                if *label != initial[idx] {
+                    let target = ((label.0 .0 + label.1 .0) / 2, label.1 .1);
+                    backend.draw_line(anchors[idx], target, style)?;
+                }
+            }
+            match self.backgrounds.get(idx) {
+                Some(background) if self.position != LabelPosition::Outside => {
+                    let color = contrast_color(background);
+                    backend.draw_text(text, &self.style.color(&color), label.0)?;
+                }
+                _ => backend.draw_text(text, &self.style, label.0)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_avoid_collisions() {
+        let mut boxes = vec![
+            ((0, 50), (40, 60)),
+            ((10, 52), (50, 62)),
+            ((100, 50), (140, 60)),
+        ];
+        avoid_collisions(&mut boxes, 1);
+        assert_eq!(boxes[1], ((10, 39), (50, 49)));
+        // The labels clear of the others stay in place
+        assert_eq!(boxes[2], ((100, 50), (140, 60)));
+    }
+
+    #[test]
+    fn test_close_labels_do_not_overlap() {
+        let texts = Rc::new(RefCell::new(vec![]));
+        let texts_out = texts.clone();
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_text(move |_, _, _, pos, text| {
+                texts_out.borrow_mut().push((pos, text.to_string()))
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_line_call, 1));
+        });
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Build chart error");
+
+        let style = TextStyle::from(("sans-serif", 15));
+        let labels = DataLabels::new(
+            [((5.0, 5.0), "first"), ((5.05, 5.02), "second")],
+            style.clone(),
+        )
+        .leader_lines(BLACK);
+        chart.plotting_area().draw(&labels).expect("Drawing Error");
+
+        let texts = texts.borrow();
+        assert_eq!(texts.len(), 2);
+        let label_box = |(pos, text): &(BackendCoord, String)| {
+            let (w, h) = drawing_area.estimate_text_size(text, &style).unwrap();
+            (*pos, (pos.0 + w as i32, pos.1 + h as i32))
+        };
+        let (first, second) = (label_box(&texts[0]), label_box(&texts[1]));
+        assert!(!overlaps(&first, &second));
+        // The first label stays right above its point
+        let anchor = chart.backend_coord(&(5.0, 5.0));
+        assert_eq!(first.1 .1, anchor.1 - 4);
+    }
+
+    #[test]
+    fn test_outlier_labels() {
+        let texts = Rc::new(RefCell::new(vec![]));
+        let texts_out = texts.clone();
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_text(move |_, _, _, pos, text| {
+                texts_out.borrow_mut().push((pos, text.to_string()))
+            });
+        });
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, -50.0..120.0)
+            .expect("Build chart error");
+
+        let ys = [10.0, 12.0, 11.0, 100.0, 13.0, 12.0, 11.0, -40.0, 12.5, 11.5];
+        let data: Vec<_> = ys.iter().enumerate().map(|(i, y)| (i as f64, *y)).collect();
+        let labels = DataLabels::outliers(&data, OutlierRule::Iqr(1.5), ("sans-serif", 12));
+        chart.plotting_area().draw(&labels).expect("Drawing Error");
+
+        let texts = texts.borrow();
+        let names: Vec<_> = texts.iter().map(|(_, t)| t.as_str()).collect();
+        assert_eq!(names, vec!["#3: 100", "#7: -40"]);
+        // Each label is right above its point
+        for ((pos, _), idx) in texts.iter().zip([3, 7]) {
+            let anchor = chart.backend_coord(&data[idx]);
+            assert!((pos.0 - anchor.0).abs() < 40);
+            assert!(pos.1 < anchor.1);
+        }
+    }
+
+    #[test]
+    fn test_thousands_separator() {
+        let labels = DataLabels::new(
+            [((0.0, 0.0), "1234567"), ((1.0, 1.0), "#3: 86400.5")],
+            ("sans-serif", 12),
+        )
+        .thousands_separator(',');
+        assert_eq!(labels.texts, vec!["1,234,567", "#3: 86,400.5"]);
+    }
+}
@@ -33,6 +33,16 @@ where
     drawable: Box<dyn DynDrawable<DB> + 'a>,
 }
 
+impl<'a, DB: DrawingBackend, Coord: Clone> DynElement<'a, DB, Coord> {
+    /// Translate the points of the element into another coordinate system
+    pub(crate) fn map_points<C: Clone, F: FnMut(Coord) -> C>(self, f: F) -> DynElement<'a, DB, C> {
+        DynElement {
+            points: self.points.into_iter().map(f).collect(),
+            drawable: self.drawable,
+        }
+    }
+}
+
 impl<'a, 'b: 'a, DB: DrawingBackend, Coord: Clone> PointCollection<'a, Coord>
     for &'a DynElement<'b, DB, Coord>
 {
@@ -0,0 +1,156 @@
+use super::{Drawable, PointCollection};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{Color, RGBAColor, TextStyle};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/**
+A legend for bubble charts that encode one variable with both the marker size and the
+marker color.
+
+Each entry is drawn as a filled circle with the size and color of a representative value,
+followed by its label. The entries are stacked downwards from the upper left corner.
+*/
+pub struct SizeColorLegend<'a, Coord> {
+    pos: Coord,
+    entries: Vec<(f64, RGBAColor, String)>,
+    style: TextStyle<'a>,
+}
+
+impl<'a, Coord> SizeColorLegend<'a, Coord> {
+    /// The gap between the markers and the labels, and between the entries, in pixels
+    const SPACING: i32 = 5;
+
+    /// Create an empty legend
+    /// - `pos`: The upper left corner of the legend
+    /// - `style`: The text style of the labels
+    /// - returns the created element
+    pub fn new<S: Into<TextStyle<'a>>>(pos: Coord, style: S) -> Self {
+        Self {
+            pos,
+            entries: vec![],
+            style: style.into(),
+        }
+    }
+
+    /// Create a legend from representative sample values
+    /// - `pos`: The upper left corner of the legend
+    /// - `samples`: The values shown in the legend
+    /// - `size_of`: Maps a value to the marker radius in pixels, as in the bubble chart
+    /// - `color_of`: Maps a value to the marker color, as in the bubble chart
+    /// - `style`: The text style of the labels
+    /// - returns the created element
+    pub fn from_samples<S, SF, CF, C>(
+        pos: Coord,
+        samples: &[f64],
+        mut size_of: SF,
+        mut color_of: CF,
+        style: S,
+    ) -> Self
+    where
+        S: Into<TextStyle<'a>>,
+        SF: FnMut(f64) -> f64,
+        CF: FnMut(f64) -> C,
+        C: Color,
+    {
+        let mut legend = Self::new(pos, style);
+        for &v in samples {
+            legend.push_entry(size_of(v), &color_of(v), format!("{}", v));
+        }
+        legend
+    }
+
+    /// Append an entry to the legend
+    /// - `size`: The marker radius in pixels
+    /// - `color`: The marker color
+    /// - `label`: The label of the entry
+    pub fn push_entry<C: Color, L: Into<String>>(&mut self, size: f64, color: &C, label: L) {
+        self.entries.push((size, color.to_rgba(), label.into()));
+    }
+}
+
+impl<'b, 'a, Coord: 'a> PointCollection<'a, Coord> for &'a SizeColorLegend<'b, Coord> {
+    type Point = &'a Coord;
+    type IntoIter = std::iter::Once<&'a Coord>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.pos)
+    }
+}
+
+impl<'a, Coord: 'a, DB: DrawingBackend> Drawable<DB> for SizeColorLegend<'a, Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (x0, mut y) = match points.next() {
+            Some(a) => a,
+            None => return Ok(()),
+        };
+        let max_radius = self
+            .entries
+            .iter()
+            .map(|(size, _, _)| size.round() as i32)
+            .max()
+            .unwrap_or(0);
+        let style = TextStyle {
+            pos: Pos::new(HPos::Left, VPos::Center),
+            ..self.style.clone()
+        };
+        let font_height = self.style.font.get_size().round() as i32;
+        let label_x = x0 + 2 * max_radius + Self::SPACING;
+
+        for (size, color, label) in &self.entries {
+            let radius = size.round() as i32;
+            let row_height = (2 * radius).max(font_height);
+            let center_y = y + row_height / 2;
+            backend.draw_circle(
+                (x0 + max_radius, center_y),
+                radius.max(0) as u32,
+                &color.to_backend_color(),
+                true,
+            )?;
+            backend.draw_text(label, &style, (label_x, center_y))?;
+            y += row_height + Self::SPACING;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_size_color_legend() {
+    use crate::prelude::*;
+    let samples = [1.0, 2.0, 4.0];
+    let size_of = |v: f64| 5.0 * v;
+    let color_of = |v: f64| RGBColor((60.0 * v) as u8, 0, 255 - (60.0 * v) as u8);
+
+    let da = crate::create_mocked_drawing_area(300, 300, move |m| {
+        for &v in samples.iter() {
+            m.check_draw_circle(move |c, _, filled, center, radius| {
+                assert_eq!(c, color_of(v).to_rgba());
+                assert!(filled);
+                assert_eq!(radius, size_of(v) as u32);
+                // All markers share the same column
+                assert_eq!(center.0, 10 + 20);
+            });
+            m.check_draw_text(move |_, _, _, pos, text| {
+                assert_eq!(text, format!("{}", v));
+                assert_eq!(pos.0, 10 + 40 + 5);
+            });
+        }
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_circle_call, 3);
+            assert_eq!(b.num_draw_text_call, 3);
+        });
+    });
+
+    da.draw(&SizeColorLegend::from_samples(
+        (10, 10),
+        &samples,
+        size_of,
+        color_of,
+        ("sans-serif", 12),
+    ))
+    .expect("Drawing Failure");
+}
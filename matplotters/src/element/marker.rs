@@ -0,0 +1,422 @@
+use super::patch::hatch_segments;
+use super::{Drawable, PointCollection};
+use crate::style::{Color, Pattern, ShapeStyle};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// The shape of a [`Marker`], named after the matplotlib marker codes
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum MarkerShape {
+    /// A circle, `"o"`
+    #[default]
+    Circle,
+    /// A square, `"s"`
+    Square,
+    /// A triangle pointing up, `"^"`
+    TriangleUp,
+    /// A triangle pointing down, `"v"`
+    TriangleDown,
+    /// A diamond, `"D"`
+    Diamond,
+    /// A diagonal cross, `"x"`
+    Cross,
+    /// A plus sign, `"+"`
+    Plus,
+}
+
+/// The distance between the stripes or the dots of a pattern, in pixels
+const PATTERN_SPACING: f64 = 3.0;
+
+/// Whether a point is inside a polygon, with the even-odd rule
+fn inside(polygon: &[BackendCoord], (px, py): (f64, f64)) -> bool {
+    let mut ret = false;
+    for i in 0..polygon.len() {
+        let (a, b) = (polygon[i], polygon[(i + 1) % polygon.len()]);
+        let (ax, ay, bx, by) = (
+            f64::from(a.0),
+            f64::from(a.1),
+            f64::from(b.0),
+            f64::from(b.1),
+        );
+        if (ay > py) != (by > py) && px < ax + (bx - ax) * (py - ay) / (by - ay) {
+            ret = !ret;
+        }
+    }
+    ret
+}
+
+impl MarkerShape {
+    /// The outline of a polygonal shape of the given size centered at `(x, y)`, `None` for the
+    /// shapes which are not polygons
+    fn outline(self, (x, y): BackendCoord, size: i32) -> Option<Vec<BackendCoord>> {
+        let (s, h) = (size, size / 2);
+        match self {
+            MarkerShape::Square => Some(vec![
+                (x - s, y - s),
+                (x + s, y - s),
+                (x + s, y + s),
+                (x - s, y + s),
+            ]),
+            MarkerShape::TriangleUp => Some(vec![(x, y - s), (x + s, y + h), (x - s, y + h)]),
+            MarkerShape::TriangleDown => Some(vec![(x, y + s), (x - s, y - h), (x + s, y - h)]),
+            MarkerShape::Diamond => Some(vec![(x, y - s), (x + s, y), (x, y + s), (x - s, y)]),
+            _ => None,
+        }
+    }
+
+    /// The area of a closed shape as a polygon, approximating a circle with 32 sides, `None`
+    /// for the crosses
+    fn area(self, (x, y): BackendCoord, size: i32) -> Option<Vec<BackendCoord>> {
+        match self {
+            MarkerShape::Circle => Some(
+                (0..32)
+                    .map(|i| {
+                        let theta = std::f64::consts::PI * f64::from(i) / 16.0;
+                        (
+                            x + (f64::from(size) * theta.cos()).round() as i32,
+                            y + (f64::from(size) * theta.sin()).round() as i32,
+                        )
+                    })
+                    .collect(),
+            ),
+            shape => shape.outline((x, y), size),
+        }
+    }
+}
+
+/**
+A marker of any [`MarkerShape`], used to distinguish the points of several series.
+
+- `center`: The position of the marker
+- `size`: The half width of the marker, in pixels, like the radius of a circle
+- `shape`: The shape of the marker
+- `style`: The style of the marker. The closed shapes are filled if the style is filled, and
+  the crosses are always stroked.
+
+A closed shape can be filled with a pattern instead, such as for the swatch of a legend, see
+[`Marker::pattern`]. Over busy backgrounds, a halo in a contrasting color can be drawn around
+the marker, see [`Marker::halo`].
+*/
+pub struct Marker<Coord> {
+    center: Coord,
+    size: u32,
+    shape: MarkerShape,
+    style: ShapeStyle,
+    pattern: Option<Pattern>,
+    halo: Option<(u32, ShapeStyle)>,
+}
+
+impl<Coord> Marker<Coord> {
+    /// Create a new marker
+    pub fn new<S: Into<ShapeStyle>>(
+        center: Coord,
+        size: u32,
+        shape: MarkerShape,
+        style: S,
+    ) -> Self {
+        Self {
+            center,
+            size,
+            shape,
+            style: style.into(),
+            pattern: None,
+            halo: None,
+        }
+    }
+
+    /// Fill the marker with a pattern, stroked with the color of its style and clipped to the
+    /// shape, and stroke its outline. The crosses have no area and ignore the pattern.
+    pub fn pattern(mut self, pattern: Pattern) -> Self {
+        self.pattern = Some(pattern);
+        self
+    }
+
+    /// Draw a halo beneath the marker so that it stands out: the same shape, filled and
+    /// `width` pixels larger on every side, in the given color. The halo of a hollow marker
+    /// is its outline stroked `width` pixels wider on every side, which keeps it hollow.
+    pub fn halo<C: Color>(mut self, width: u32, color: &C) -> Self {
+        let style = ShapeStyle {
+            color: color.to_rgba(),
+            filled: self.style.filled,
+            stroke_width: self.style.stroke_width + 2 * width,
+        };
+        self.halo = Some((width, style));
+        self
+    }
+}
+
+impl<'a, Coord: 'a> PointCollection<'a, Coord> for &'a Marker<Coord> {
+    type Point = &'a Coord;
+    type IntoIter = std::iter::Once<&'a Coord>;
+    fn point_iter(self) -> std::iter::Once<&'a Coord> {
+        std::iter::once(&self.center)
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for Marker<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (x, y) = match points.next() {
+            Some(p) => p,
+            None => return Ok(()),
+        };
+        if let Some((width, style)) = &self.halo {
+            let hollow = !style.filled
+                && self.pattern.is_none()
+                && !matches!(self.shape, MarkerShape::Cross | MarkerShape::Plus);
+            let size = if hollow { self.size } else { self.size + width };
+            self.draw_shape((x, y), size, style, backend)?;
+        }
+        if let (Some(pattern), Some(area)) =
+            (self.pattern, self.shape.area((x, y), self.size as i32))
+        {
+            return self.draw_pattern(pattern, area, backend);
+        }
+        self.draw_shape((x, y), self.size, &self.style, backend)
+    }
+}
+
+impl<Coord> Marker<Coord> {
+    fn draw_shape<DB: DrawingBackend>(
+        &self,
+        (x, y): BackendCoord,
+        size: u32,
+        style: &ShapeStyle,
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let s = size as i32;
+        match self.shape {
+            MarkerShape::Circle => backend.draw_circle((x, y), size, style, style.filled),
+            MarkerShape::Square => {
+                backend.draw_rect((x - s, y - s), (x + s, y + s), style, style.filled)
+            }
+            MarkerShape::Cross => {
+                backend.draw_line((x - s, y - s), (x + s, y + s), style)?;
+                backend.draw_line((x - s, y + s), (x + s, y - s), style)
+            }
+            MarkerShape::Plus => {
+                backend.draw_line((x - s, y), (x + s, y), style)?;
+                backend.draw_line((x, y - s), (x, y + s), style)
+            }
+            shape => {
+                let mut outline = shape.outline((x, y), s).unwrap_or_default();
+                if style.filled {
+                    backend.fill_polygon(outline, style)
+                } else {
+                    outline.push(outline[0]);
+                    backend.draw_path(outline, style)
+                }
+            }
+        }
+    }
+
+    fn draw_pattern<DB: DrawingBackend>(
+        &self,
+        pattern: Pattern,
+        mut area: Vec<BackendCoord>,
+        backend: &mut DB,
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let stroke = ShapeStyle {
+            filled: false,
+            ..self.style
+        };
+        match pattern {
+            Pattern::Stripes(hatch) => {
+                for angle in hatch.angles() {
+                    for (from, to) in hatch_segments(&area, *angle, PATTERN_SPACING) {
+                        backend.draw_line(from, to, &stroke)?;
+                    }
+                }
+            }
+            Pattern::Dots => {
+                let (x0, x1) = (
+                    area.iter().map(|p| p.0).min(),
+                    area.iter().map(|p| p.0).max(),
+                );
+                let (y0, y1) = (
+                    area.iter().map(|p| p.1).min(),
+                    area.iter().map(|p| p.1).max(),
+                );
+                if let (Some(x0), Some(x1), Some(y0), Some(y1)) = (x0, x1, y0, y1) {
+                    let step = PATTERN_SPACING as usize;
+                    for y in (y0..=y1).step_by(step) {
+                        for x in (x0..=x1).step_by(step) {
+                            if inside(&area, (f64::from(x), f64::from(y))) {
+                                backend.draw_pixel((x, y), self.style.color.to_backend_color())?;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        area.push(area[0]);
+        backend.draw_path(area, &stroke)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_marker_shapes() {
+        let da = crate::create_mocked_drawing_area(300, 300, |m| {
+            m.check_draw_circle(|_, _, f, c, r| {
+                assert!(f);
+                assert_eq!((c, r), ((100, 100), 5));
+            });
+            m.check_draw_rect(|_, _, f, ul, br| {
+                assert!(f);
+                assert_eq!((ul, br), ((95, 95), (105, 105)));
+            });
+            m.check_fill_polygon(|_, p| assert_eq!(p, vec![(100, 90), (110, 105), (90, 105)]));
+            m.check_draw_path(|_, _, p| {
+                assert_eq!(
+                    p,
+                    vec![(100, 90), (110, 100), (100, 110), (90, 100), (100, 90)]
+                );
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_circle_call, 1);
+                assert_eq!(b.num_draw_rect_call, 1);
+                assert_eq!(b.num_fill_polygon_call, 1);
+                assert_eq!(b.num_draw_path_call, 1);
+                assert_eq!(b.num_draw_line_call, 4);
+            });
+        });
+        let filled = BLUE.filled();
+        da.draw(&Marker::new((100, 100), 5, MarkerShape::Circle, filled))
+            .unwrap();
+        da.draw(&Marker::new((100, 100), 5, MarkerShape::Square, filled))
+            .unwrap();
+        da.draw(&Marker::new(
+            (100, 100),
+            10,
+            MarkerShape::TriangleUp,
+            filled,
+        ))
+        .unwrap();
+        da.draw(&Marker::new((100, 100), 10, MarkerShape::Diamond, BLUE))
+            .unwrap();
+        da.draw(&Marker::new((100, 100), 5, MarkerShape::Cross, BLUE))
+            .unwrap();
+        da.draw(&Marker::new((100, 100), 5, MarkerShape::Plus, BLUE))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_pattern_markers() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let lines = Rc::new(RefCell::new(vec![]));
+        let pixels = Rc::new(RefCell::new(vec![]));
+        let (lines_out, pixels_out) = (lines.clone(), pixels.clone());
+        let da = crate::create_mocked_drawing_area(300, 300, |m| {
+            m.check_draw_line(move |_, _, from, to| lines_out.borrow_mut().push((from, to)));
+            m.check_draw_pixel(move |c, p| {
+                assert_eq!(c, RED.to_rgba());
+                pixels_out.borrow_mut().push(p);
+            });
+            m.drop_check(|b| {
+                // No solid fill, only the pattern and the outlines
+                assert_eq!(b.num_fill_polygon_call, 0);
+                assert_eq!(b.num_draw_circle_call, 0);
+                assert_eq!(b.num_draw_path_call, 2);
+            });
+        });
+        let marker = Marker::new((100, 100), 10, MarkerShape::Circle, RED.filled())
+            .pattern(Pattern::Stripes(Hatch::Horizontal));
+        da.draw(&marker).unwrap();
+        let lines = lines.borrow();
+        assert!(lines.len() > 4);
+        // The stripes are clipped to the circle
+        for (from, to) in lines.iter() {
+            assert_eq!(from.1, to.1);
+            for p in [from, to] {
+                let (dx, dy) = (f64::from(p.0 - 100), f64::from(p.1 - 100));
+                assert!(dx.hypot(dy) <= 10.5);
+            }
+        }
+
+        let marker = Marker::new((200, 200), 9, MarkerShape::Diamond, RED).pattern(Pattern::Dots);
+        da.draw(&marker).unwrap();
+        let pixels = pixels.borrow();
+        assert!(!pixels.is_empty());
+        assert!(pixels
+            .iter()
+            .all(|(x, y)| (x - 200).abs() + (y - 200).abs() <= 9));
+    }
+
+    #[test]
+    fn test_marker_halo() {
+        let circles = std::rc::Rc::new(std::cell::RefCell::new(vec![]));
+        let lines = std::rc::Rc::new(std::cell::RefCell::new(vec![]));
+        let (circles_out, lines_out) = (circles.clone(), lines.clone());
+        let da = crate::create_mocked_drawing_area(300, 300, |m| {
+            m.check_draw_circle(move |c, _, f, center, r| {
+                circles_out.borrow_mut().push((c, f, center, r))
+            });
+            m.check_draw_line(move |c, w, from, to| lines_out.borrow_mut().push((c, w, from, to)));
+        });
+        for center in [(50, 50), (100, 80)] {
+            da.draw(&Marker::new(center, 4, MarkerShape::Circle, BLUE.filled()).halo(2, &WHITE))
+                .unwrap();
+        }
+        da.draw(&Marker::new((200, 200), 5, MarkerShape::Plus, RED).halo(1, &BLACK))
+            .unwrap();
+
+        // Each marker is drawn on top of a larger halo in the halo color
+        assert_eq!(
+            *circles.borrow(),
+            vec![
+                (WHITE.to_rgba(), true, (50, 50), 6),
+                (BLUE.to_rgba(), true, (50, 50), 4),
+                (WHITE.to_rgba(), true, (100, 80), 6),
+                (BLUE.to_rgba(), true, (100, 80), 4),
+            ]
+        );
+        // The halo of a cross is a wider and longer stroke
+        let lines = lines.borrow();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0], (BLACK.to_rgba(), 3, (194, 200), (206, 200)));
+        assert_eq!(lines[2], (RED.to_rgba(), 1, (195, 200), (205, 200)));
+    }
+
+    #[test]
+    fn test_hollow_marker_halo() {
+        let circles = std::rc::Rc::new(std::cell::RefCell::new(vec![]));
+        let paths = std::rc::Rc::new(std::cell::RefCell::new(vec![]));
+        let (circles_out, paths_out) = (circles.clone(), paths.clone());
+        let da = crate::create_mocked_drawing_area(300, 300, |m| {
+            m.check_draw_circle(move |c, w, f, center, r| {
+                circles_out.borrow_mut().push((c, w, f, center, r))
+            });
+            m.check_draw_path(move |c, w, p| paths_out.borrow_mut().push((c, w, p)));
+            m.drop_check(|b| assert_eq!(b.num_fill_polygon_call, 0));
+        });
+        let ring = ShapeStyle::from(&BLUE).stroke_width(2);
+        da.draw(&Marker::new((50, 50), 4, MarkerShape::Circle, ring).halo(3, &WHITE))
+            .unwrap();
+        da.draw(&Marker::new((100, 100), 4, MarkerShape::Diamond, ring).halo(3, &WHITE))
+            .unwrap();
+
+        // The halo of a hollow marker is a wider stroke along its outline, not a disc
+        assert_eq!(
+            *circles.borrow(),
+            vec![
+                (WHITE.to_rgba(), 8, false, (50, 50), 4),
+                (BLUE.to_rgba(), 2, false, (50, 50), 4),
+            ]
+        );
+        let paths = paths.borrow();
+        assert_eq!(paths.len(), 2);
+        assert_eq!((paths[0].0, paths[0].1), (WHITE.to_rgba(), 8));
+        assert_eq!((paths[1].0, paths[1].1), (BLUE.to_rgba(), 2));
+        assert_eq!(paths[0].2, paths[1].2);
+    }
+}
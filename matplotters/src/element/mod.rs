@@ -157,6 +157,9 @@
 use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 use std::borrow::Borrow;
 
+mod arrowhead;
+pub(crate) use arrowhead::Arrowhead;
+
 mod basic_shapes;
 pub use basic_shapes::*;
 
@@ -192,12 +195,27 @@ mod image;
 #[cfg(feature = "bitmap_backend")]
 pub use self::image::BitMapElement;
 
+mod data_labels;
+pub use data_labels::{DataLabels, LabelPosition};
+
 mod dynelem;
 pub use dynelem::{DynElement, IntoDynElement};
 
 mod pie;
 pub use pie::Pie;
 
+mod legend;
+pub use legend::SizeColorLegend;
+
+mod marker;
+pub use marker::{Marker, MarkerShape};
+
+mod patch;
+pub use patch::{EllipsePatch, HatchedPolygon, PolygonPatch, Wedge};
+
+mod table;
+pub use table::MatTable;
+
 use crate::coord::CoordTranslate;
 use crate::drawing::Rect;
 
@@ -257,6 +275,10 @@ pub trait CoordMapper {
     type Output;
     /// Performs the translation from guest coordinates to backend coordinates
     fn map<CT: CoordTranslate>(coord_trans: &CT, from: &CT::From, rect: &Rect) -> Self::Output;
+    /// The backend coordinate of a translated point, if the output holds one
+    fn backend_coord(_output: &Self::Output) -> Option<BackendCoord> {
+        None
+    }
 }
 
 /// Used for 2d coordinate transformations.
@@ -267,6 +289,33 @@ impl CoordMapper for BackendCoordOnly {
     fn map<CT: CoordTranslate>(coord_trans: &CT, from: &CT::From, rect: &Rect) -> BackendCoord {
         rect.truncate(coord_trans.translate(from))
     }
+    fn backend_coord(output: &BackendCoord) -> Option<BackendCoord> {
+        Some(*output)
+    }
+}
+
+/// Used for the elements that crop themselves to the drawing area, such as images, which
+/// need the backend coordinates of their points even when they are out of the area. Each
+/// point is translated without being moved into the area, along with the upper left and the
+/// bottom right corners of the area.
+pub struct BackendCoordAndArea;
+
+impl CoordMapper for BackendCoordAndArea {
+    type Output = (BackendCoord, (BackendCoord, BackendCoord));
+    fn map<CT: CoordTranslate>(
+        coord_trans: &CT,
+        from: &CT::From,
+        rect: &Rect,
+    ) -> (BackendCoord, (BackendCoord, BackendCoord)) {
+        let area = (
+            rect.truncate((i32::MIN, i32::MIN)),
+            rect.truncate((i32::MAX, i32::MAX)),
+        );
+        (coord_trans.translate(from), area)
+    }
+    fn backend_coord(output: &Self::Output) -> Option<BackendCoord> {
+        Some(output.0)
+    }
 }
 
 /**
@@ -287,4 +336,7 @@ impl CoordMapper for BackendCoordAndZ {
         let z = coord_trans.depth(from);
         (coord, z)
     }
+    fn backend_coord(output: &(BackendCoord, i32)) -> Option<BackendCoord> {
+        Some(output.0)
+    }
 }
@@ -0,0 +1,582 @@
+use super::{Drawable, PointCollection};
+use crate::style::{Color, Hatch, ShapeStyle};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/// Fills the polygon with `fill` and strokes its outline with `edge`. A fully transparent
+/// style is skipped, so either part can be turned off with [`crate::style::TRANSPARENT`].
+fn draw_patch<DB: DrawingBackend>(
+    points: &[BackendCoord],
+    fill: &ShapeStyle,
+    edge: &ShapeStyle,
+    closed: bool,
+    backend: &mut DB,
+) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+    if points.is_empty() {
+        return Ok(());
+    }
+    if fill.color.alpha() > 0.0 {
+        backend.fill_polygon(points.iter().copied(), &fill.color.to_backend_color())?;
+    }
+    if edge.color.alpha() > 0.0 {
+        let mut outline = points.to_vec();
+        if closed && points.len() > 2 && points[0] != points[points.len() - 1] {
+            outline.push(points[0]);
+        }
+        backend.draw_path(outline, edge)?;
+    }
+    Ok(())
+}
+
+/// Sample the arc of radius `radius` around `center` from `start` to `end` degrees, going
+/// counter-clockwise on the screen for increasing angles, with at most one degree per segment.
+fn arc_points(center: BackendCoord, radius: f64, start: f64, end: f64) -> Vec<BackendCoord> {
+    let n = ((end - start).abs().ceil() as usize).max(1);
+    (0..=n)
+        .map(|i| {
+            let theta = (start + (end - start) * i as f64 / n as f64).to_radians();
+            (
+                center.0 + (radius * theta.cos()).round() as i32,
+                center.1 - (radius * theta.sin()).round() as i32,
+            )
+        })
+        .collect()
+}
+
+/**
+A polygon patch: an arbitrary polygon with separate fill and edge styles.
+
+Unlike [`crate::element::Polygon`], which only fills the area, the patch also strokes the
+outline. The outline is closed automatically, see [`PolygonPatch::closed`].
+*/
+pub struct PolygonPatch<Coord> {
+    points: Vec<Coord>,
+    fill: ShapeStyle,
+    edge: ShapeStyle,
+    closed: bool,
+}
+
+impl<Coord> PolygonPatch<Coord> {
+    /// Create a new polygon patch
+    /// - `points`: The vertices of the polygon
+    /// - `fill`: The style used to fill the polygon
+    /// - `edge`: The style used to stroke the outline
+    /// - returns the created element
+    pub fn new<P: Into<Vec<Coord>>, F: Into<ShapeStyle>, E: Into<ShapeStyle>>(
+        points: P,
+        fill: F,
+        edge: E,
+    ) -> Self {
+        Self {
+            points: points.into(),
+            fill: fill.into(),
+            edge: edge.into(),
+            closed: true,
+        }
+    }
+
+    /// Set whether the outline goes back from the last vertex to the first one, which is the
+    /// default. The filled area is always closed.
+    pub fn closed(mut self, closed: bool) -> Self {
+        self.closed = closed;
+        self
+    }
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a PolygonPatch<Coord> {
+    type Point = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for PolygonPatch<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.collect();
+        draw_patch(&points, &self.fill, &self.edge, self.closed, backend)
+    }
+}
+
+/// The segments of the hatch lines with the given direction, `spacing` pixels apart, clipped
+/// to the inside of the polygon with the even-odd rule. The lines are laid out from the
+/// origin of the backend, so that the hatches of adjacent polygons line up.
+pub(crate) fn hatch_segments(
+    points: &[BackendCoord],
+    angle: f64,
+    spacing: f64,
+) -> Vec<(BackendCoord, BackendCoord)> {
+    let theta = angle.to_radians();
+    // The direction of the lines and their normal, on the screen where y goes down
+    let dir = (theta.cos(), -theta.sin());
+    let normal = (theta.sin(), theta.cos());
+    let project = |p: BackendCoord, v: (f64, f64)| f64::from(p.0) * v.0 + f64::from(p.1) * v.1;
+
+    let offsets: Vec<f64> = points.iter().map(|p| project(*p, normal)).collect();
+    let lo = offsets.iter().copied().fold(f64::INFINITY, f64::min);
+    let hi = offsets.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let mut segments = vec![];
+    // The lines through the extreme vertices only touch the polygon
+    let mut offset = (lo / spacing).floor() * spacing + spacing;
+    while offset < hi {
+        let mut hits = vec![];
+        for i in 0..points.len() {
+            let j = (i + 1) % points.len();
+            let (da, db) = (offsets[i] - offset, offsets[j] - offset);
+            if (da > 0.0) != (db > 0.0) {
+                let t = da / (da - db);
+                let (a, b) = (points[i], points[j]);
+                let x = f64::from(a.0) + f64::from(b.0 - a.0) * t;
+                let y = f64::from(a.1) + f64::from(b.1 - a.1) * t;
+                hits.push(x * dir.0 + y * dir.1);
+            }
+        }
+        hits.sort_by(|a, b| a.total_cmp(b));
+        let at = |s: f64| {
+            (
+                (offset * normal.0 + s * dir.0).round() as i32,
+                (offset * normal.1 + s * dir.1).round() as i32,
+            )
+        };
+        for pair in hits.chunks_exact(2) {
+            segments.push((at(pair[0]), at(pair[1])));
+        }
+        offset += spacing;
+    }
+    segments
+}
+
+/**
+A polygon filled with a hatch pattern instead of a solid color.
+
+The hatch lines are clipped to the polygon and stroked with the given style, so that the
+area stays distinguishable in black and white print. No outline is drawn.
+*/
+pub struct HatchedPolygon<Coord> {
+    points: Vec<Coord>,
+    hatch: Hatch,
+    spacing: u32,
+    angle: Option<f64>,
+    style: ShapeStyle,
+}
+
+impl<Coord> HatchedPolygon<Coord> {
+    /// Create a new hatched polygon, with hatch lines 8 pixels apart
+    /// - `points`: The vertices of the polygon
+    /// - `hatch`: The hatch pattern
+    /// - `style`: The style used to stroke the hatch lines
+    /// - returns the created element
+    pub fn new<P: Into<Vec<Coord>>, S: Into<ShapeStyle>>(
+        points: P,
+        hatch: Hatch,
+        style: S,
+    ) -> Self {
+        Self {
+            points: points.into(),
+            hatch,
+            spacing: 8,
+            angle: None,
+            style: style.into(),
+        }
+    }
+
+    /// Set the distance between the hatch lines, in pixels
+    pub fn spacing(mut self, spacing: u32) -> Self {
+        self.spacing = spacing.max(1);
+        self
+    }
+
+    /// Set the direction of the hatch lines, in degrees counter-clockwise from the x axis,
+    /// instead of the direction of the pattern. The second lines of a crossing pattern keep
+    /// their angle to the first ones.
+    pub fn angle(mut self, angle: f64) -> Self {
+        self.angle = Some(angle);
+        self
+    }
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a HatchedPolygon<Coord> {
+    type Point = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for HatchedPolygon<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let points: Vec<_> = points.collect();
+        if points.len() < 3 {
+            return Ok(());
+        }
+        let angles = self.hatch.angles();
+        let rotation = self.angle.map_or(0.0, |angle| angle - angles[0]);
+        for angle in angles {
+            for (from, to) in hatch_segments(&points, angle + rotation, f64::from(self.spacing)) {
+                backend.draw_line(from, to, &self.style)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_hatched_polygon() {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    let lines = Rc::new(RefCell::new(vec![]));
+    let lines_out = lines.clone();
+    let da = crate::create_mocked_drawing_area(800, 800, |m| {
+        m.check_draw_line(move |c, _, from, to| {
+            assert_eq!(c, BLACK.to_rgba());
+            lines_out.borrow_mut().push((from, to));
+        });
+        m.drop_check(|b| assert_eq!(b.num_fill_polygon_call, 0));
+    });
+    let square = vec![(100, 100), (100, 200), (200, 200), (200, 100)];
+    da.draw(&HatchedPolygon::new(square.clone(), Hatch::Horizontal, BLACK).spacing(10))
+        .expect("Drawing Failure");
+    {
+        let lines = lines.borrow();
+        // One line every 10 pixels strictly inside the square, spanning its width
+        assert_eq!(lines.len(), 9);
+        for (i, (from, to)) in lines.iter().enumerate() {
+            let y = 110 + 10 * i as i32;
+            assert_eq!((*from, *to), ((100, y), (200, y)));
+        }
+    }
+
+    lines.borrow_mut().clear();
+    da.draw(&HatchedPolygon::new(square, Hatch::Forward, BLACK).spacing(10))
+        .expect("Drawing Failure");
+    for (from, to) in lines.borrow().iter() {
+        for p in [from, to] {
+            assert!((100..=200).contains(&p.0) && (100..=200).contains(&p.1));
+        }
+        // The lines rise to the right
+        assert!(from.0 < to.0 && from.1 > to.1);
+    }
+
+    // The default spacing is 8 pixels, and a crossed pattern rotates as a whole
+    lines.borrow_mut().clear();
+    let square = vec![(100, 100), (100, 200), (200, 200), (200, 100)];
+    da.draw(&HatchedPolygon::new(square, Hatch::Cross, BLACK).angle(30.0))
+        .expect("Drawing Failure");
+    let mut angles: Vec<i32> = lines
+        .borrow()
+        .iter()
+        .map(|(from, to)| {
+            let (dx, dy) = (f64::from(to.0 - from.0), f64::from(from.1 - to.1));
+            (dy.atan2(dx).to_degrees().rem_euclid(180.0) / 10.0).round() as i32 * 10
+        })
+        .collect();
+    angles.dedup();
+    assert_eq!(angles, vec![30, 120]);
+}
+
+#[cfg(test)]
+#[test]
+fn test_polygon_patch() {
+    use crate::prelude::*;
+    let points = vec![(100, 100), (50, 500), (300, 400), (550, 200)];
+    let expected_fill = points.clone();
+    let mut expected_edge = points.clone();
+    expected_edge.push(points[0]);
+
+    let da = crate::create_mocked_drawing_area(800, 800, |m| {
+        m.check_fill_polygon(move |c, p| {
+            assert_eq!(c, BLUE.mix(0.3).to_rgba());
+            assert_eq!(expected_fill, p);
+        });
+        m.check_draw_path(move |c, s, p| {
+            assert_eq!(c, BLACK.to_rgba());
+            assert_eq!(s, 2);
+            assert_eq!(expected_edge, p);
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_fill_polygon_call, 1);
+            assert_eq!(b.num_draw_path_call, 1);
+            assert_eq!(b.draw_count, 2);
+        });
+    });
+
+    da.draw(&PolygonPatch::new(
+        points.clone(),
+        BLUE.mix(0.3),
+        BLACK.stroke_width(2),
+    ))
+    .expect("Drawing Failure");
+
+    let da = crate::create_mocked_drawing_area(800, 800, |m| {
+        m.check_draw_path(|_, _, p| assert_eq!(p.len(), 3));
+        m.drop_check(|b| {
+            assert_eq!(b.num_fill_polygon_call, 0);
+            assert_eq!(b.num_draw_path_call, 1);
+        });
+    });
+
+    da.draw(&PolygonPatch::new(vec![(0, 0), (10, 0), (10, 10)], TRANSPARENT, RED).closed(false))
+        .expect("Drawing Failure");
+}
+
+/**
+A wedge patch: the sector of a circle between two angles, or the sector of an annulus if an
+inner radius is given.
+
+The radii are in pixels and the angles in degrees, measured counter-clockwise from the
+positive x direction, as in matplotlib.
+*/
+pub struct Wedge<Coord> {
+    center: Coord,
+    radius: f64,
+    inner_radius: Option<f64>,
+    angles: (f64, f64),
+    fill: ShapeStyle,
+    edge: ShapeStyle,
+}
+
+impl<Coord> Wedge<Coord> {
+    /// Create a new wedge
+    /// - `center`: The center of the circle
+    /// - `radius`: The radius of the circle in pixels
+    /// - `angles`: The start and end angles in degrees
+    /// - `fill`: The style used to fill the wedge
+    /// - `edge`: The style used to stroke the outline
+    /// - returns the created element
+    pub fn new<F: Into<ShapeStyle>, E: Into<ShapeStyle>>(
+        center: Coord,
+        radius: f64,
+        angles: (f64, f64),
+        fill: F,
+        edge: E,
+    ) -> Self {
+        Self {
+            center,
+            radius,
+            inner_radius: None,
+            angles,
+            fill: fill.into(),
+            edge: edge.into(),
+        }
+    }
+
+    /// Set the inner radius in pixels, which turns the wedge into an annular (donut) sector
+    pub fn inner_radius(mut self, inner_radius: f64) -> Self {
+        self.inner_radius = Some(inner_radius);
+        self
+    }
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a Wedge<Coord> {
+    type Point = &'a Coord;
+    type IntoIter = std::iter::Once<&'a Coord>;
+    fn point_iter(self) -> std::iter::Once<&'a Coord> {
+        std::iter::once(&self.center)
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for Wedge<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let Some(center) = points.next() {
+            let (start, end) = self.angles;
+            let mut outline = arc_points(center, self.radius, start, end);
+            match self.inner_radius {
+                Some(r) if r > 0.0 => {
+                    outline.extend(arc_points(center, r, start, end).into_iter().rev())
+                }
+                _ => outline.push(center),
+            }
+            return draw_patch(&outline, &self.fill, &self.edge, true, backend);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_wedge() {
+    use crate::prelude::*;
+    let distance = |(x, y): BackendCoord| f64::from(x - 200).hypot(f64::from(y - 200));
+
+    let da = crate::create_mocked_drawing_area(400, 400, |m| {
+        m.check_fill_polygon(move |_, p| {
+            assert_eq!(p[0], (300, 200));
+            assert_eq!(p[p.len() - 2], (200, 100));
+            assert_eq!(p[p.len() - 1], (200, 200));
+            for &(x, y) in &p[..p.len() - 1] {
+                let angle = f64::from(200 - y).atan2(f64::from(x - 200)).to_degrees();
+                assert!((-1.0..=91.0).contains(&angle));
+                assert!((distance((x, y)) - 100.0).abs() <= 1.0);
+            }
+        });
+        m.drop_check(|b| assert_eq!(b.num_fill_polygon_call, 1));
+    });
+
+    da.draw(&Wedge::new(
+        (200, 200),
+        100.0,
+        (0.0, 90.0),
+        RED,
+        TRANSPARENT,
+    ))
+    .expect("Drawing Failure");
+
+    let da = crate::create_mocked_drawing_area(400, 400, |m| {
+        m.check_fill_polygon(move |_, p| {
+            let n = p.len() / 2;
+            assert_eq!(p.len(), 2 * n);
+            assert_eq!(p[0], (300, 200));
+            assert_eq!(p[n - 1], (100, 200));
+            assert_eq!(p[n], (150, 200));
+            assert_eq!(p[2 * n - 1], (250, 200));
+            // No vertex falls into the hole of the donut
+            for &v in &p[..n] {
+                assert!((distance(v) - 100.0).abs() <= 1.0);
+            }
+            for &v in &p[n..] {
+                assert!((distance(v) - 50.0).abs() <= 1.0);
+            }
+        });
+        m.check_draw_path(|_, _, p| assert_eq!(p[0], p[p.len() - 1]));
+        m.drop_check(|b| {
+            assert_eq!(b.num_fill_polygon_call, 1);
+            assert_eq!(b.num_draw_path_call, 1);
+        });
+    });
+
+    da.draw(&Wedge::new((200, 200), 100.0, (0.0, 180.0), RED, BLACK).inner_radius(50.0))
+        .expect("Drawing Failure");
+}
+
+/**
+An ellipse patch, given by its center, its semi-axes and the angle of its first axis.
+
+The semi-axes are in pixels and the rotation in degrees, measured counter-clockwise from the
+positive x direction.
+*/
+pub struct EllipsePatch<Coord> {
+    center: Coord,
+    semi_axes: (f64, f64),
+    rotation: f64,
+    fill: ShapeStyle,
+    edge: ShapeStyle,
+}
+
+impl<Coord> EllipsePatch<Coord> {
+    /// Create a new ellipse patch
+    /// - `center`: The center of the ellipse
+    /// - `semi_axes`: The semi-axes of the ellipse in pixels
+    /// - `rotation`: The rotation of the first axis in degrees
+    /// - `fill`: The style used to fill the ellipse
+    /// - `edge`: The style used to stroke the outline
+    /// - returns the created element
+    pub fn new<F: Into<ShapeStyle>, E: Into<ShapeStyle>>(
+        center: Coord,
+        semi_axes: (f64, f64),
+        rotation: f64,
+        fill: F,
+        edge: E,
+    ) -> Self {
+        Self {
+            center,
+            semi_axes,
+            rotation,
+            fill: fill.into(),
+            edge: edge.into(),
+        }
+    }
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a EllipsePatch<Coord> {
+    type Point = &'a Coord;
+    type IntoIter = std::iter::Once<&'a Coord>;
+    fn point_iter(self) -> std::iter::Once<&'a Coord> {
+        std::iter::once(&self.center)
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for EllipsePatch<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let Some((cx, cy)) = points.next() {
+            let (a, b) = self.semi_axes;
+            let (sin_r, cos_r) = self.rotation.to_radians().sin_cos();
+            let outline: Vec<_> = (0..360)
+                .map(|i| {
+                    let (sin_t, cos_t) = f64::from(i).to_radians().sin_cos();
+                    let (x, y) = (a * cos_t, b * sin_t);
+                    (
+                        cx + (x * cos_r - y * sin_r).round() as i32,
+                        cy - (x * sin_r + y * cos_r).round() as i32,
+                    )
+                })
+                .collect();
+            return draw_patch(&outline, &self.fill, &self.edge, true, backend);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_ellipse_patch() {
+    use crate::prelude::*;
+    let (a, b, rotation) = (120.0f64, 40.0f64, 30.0f64);
+
+    let da = crate::create_mocked_drawing_area(400, 400, move |m| {
+        m.check_fill_polygon(move |_, p| {
+            assert_eq!(p.len(), 360);
+            let (sin_r, cos_r) = rotation.to_radians().sin_cos();
+            let close = |(x, y): BackendCoord, (ex, ey): (f64, f64)| {
+                (f64::from(x) - ex).abs() <= 1.0 && (f64::from(y) - ey).abs() <= 1.0
+            };
+            // The end points of the axes, following the rotated parametric equation
+            assert!(close(p[0], (200.0 + a * cos_r, 200.0 - a * sin_r)));
+            assert!(close(p[90], (200.0 - b * sin_r, 200.0 - b * cos_r)));
+            assert!(close(p[180], (200.0 - a * cos_r, 200.0 + a * sin_r)));
+            assert!(close(p[270], (200.0 + b * sin_r, 200.0 + b * cos_r)));
+            // The bounding box of the rotated ellipse
+            let half_w = (a * a * cos_r * cos_r + b * b * sin_r * sin_r).sqrt();
+            let half_h = (a * a * sin_r * sin_r + b * b * cos_r * cos_r).sqrt();
+            let max_x = p.iter().map(|v| v.0).max().unwrap();
+            let min_y = p.iter().map(|v| v.1).min().unwrap();
+            assert!((f64::from(max_x) - (200.0 + half_w)).abs() <= 1.0);
+            assert!((f64::from(min_y) - (200.0 - half_h)).abs() <= 1.0);
+        });
+        m.check_draw_path(|_, _, p| assert_eq!(p.len(), 361));
+        m.drop_check(|b| {
+            assert_eq!(b.num_fill_polygon_call, 1);
+            assert_eq!(b.num_draw_path_call, 1);
+        });
+    });
+
+    da.draw(&EllipsePatch::new(
+        (200, 200),
+        (a, b),
+        rotation,
+        BLUE.mix(0.2),
+        BLUE,
+    ))
+    .expect("Drawing Failure");
+}
@@ -0,0 +1,225 @@
+use super::{Drawable, PointCollection};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{Color, FontStyle, RGBAColor, TextStyle, BLACK};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/**
+A table of text cells, like matplotlib's `table`, usually drawn below a chart in an area
+reserved for it.
+
+The cells are `cell_size` pixels and have a border, and their text is centered. The optional
+header row is drawn first, with its own text style on a filled background, and the rows
+follow downwards from the upper left corner. By default, the columns are laid out side by
+side; they can be centered on given x positions instead, such as the x ticks of a chart, see
+[`MatTable::column_centers`].
+*/
+pub struct MatTable<'a, Coord> {
+    pos: Coord,
+    header: Vec<String>,
+    rows: Vec<Vec<String>>,
+    cell_size: (u32, u32),
+    column_centers: Option<Vec<i32>>,
+    style: TextStyle<'a>,
+    header_style: TextStyle<'a>,
+    header_fill: RGBAColor,
+    border: RGBAColor,
+}
+
+impl<'a, Coord> MatTable<'a, Coord> {
+    /// Create a table without header, with 80 x 20 pixels cells
+    /// - `pos`: The upper left corner of the table
+    /// - `rows`: The text of the cells, row by row
+    /// - `style`: The text style of the cells
+    /// - returns the created element
+    pub fn new<R, C, S, T>(pos: Coord, rows: R, style: T) -> Self
+    where
+        R: IntoIterator<Item = C>,
+        C: IntoIterator<Item = S>,
+        S: ToString,
+        T: Into<TextStyle<'a>>,
+    {
+        let style = style.into();
+        Self {
+            pos,
+            header: vec![],
+            rows: rows
+                .into_iter()
+                .map(|row| row.into_iter().map(|cell| cell.to_string()).collect())
+                .collect(),
+            cell_size: (80, 20),
+            column_centers: None,
+            header_style: TextStyle {
+                font: style.font.style(FontStyle::Bold),
+                ..style.clone()
+            },
+            style,
+            header_fill: BLACK.mix(0.1),
+            border: BLACK.mix(0.5),
+        }
+    }
+
+    /// Set the header row, drawn above the rows in the header style
+    /// - `labels`: The text of the header cells
+    pub fn header<I: IntoIterator<Item = S>, S: ToString>(mut self, labels: I) -> Self {
+        self.header = labels.into_iter().map(|l| l.to_string()).collect();
+        self
+    }
+
+    /// Set the style of the header row
+    /// - `style`: The text style of the header cells, bold by default
+    /// - `fill`: The background color of the header cells, a light gray by default
+    pub fn header_style<T: Into<TextStyle<'a>>, C: Color>(mut self, style: T, fill: &C) -> Self {
+        self.header_style = style.into();
+        self.header_fill = fill.to_rgba();
+        self
+    }
+
+    /// Set the size of the cells
+    /// - `width`, `height`: The width and the height of a cell, in pixels
+    pub fn cell_size(mut self, width: u32, height: u32) -> Self {
+        self.cell_size = (width, height);
+        self
+    }
+
+    /// Set the color of the borders of the cells, a half transparent black by default
+    pub fn border_color<C: Color>(mut self, color: &C) -> Self {
+        self.border = color.to_rgba();
+        self
+    }
+
+    /// Center the columns on the given x positions in backend pixels, instead of laying them
+    /// out side by side. To align the columns to the x ticks of a chart, the positions are
+    /// the x of [`crate::chart::ChartContext::backend_coord`] at the ticks.
+    pub fn column_centers<I: IntoIterator<Item = i32>>(mut self, centers: I) -> Self {
+        self.column_centers = Some(centers.into_iter().collect());
+        self
+    }
+}
+
+impl<'b, 'a, Coord: 'a> PointCollection<'a, Coord> for &'a MatTable<'b, Coord> {
+    type Point = &'a Coord;
+    type IntoIter = std::iter::Once<&'a Coord>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.pos)
+    }
+}
+
+impl<'a, Coord: 'a, DB: DrawingBackend> Drawable<DB> for MatTable<'a, Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (x0, y0) = match points.next() {
+            Some(a) => a,
+            None => return Ok(()),
+        };
+        let (w, h) = (self.cell_size.0 as i32, self.cell_size.1 as i32);
+        let left = |col: usize| match &self.column_centers {
+            Some(centers) => centers.get(col).map(|c| c - w / 2),
+            None => Some(x0 + w * col as i32),
+        };
+
+        let header = Some(&self.header).filter(|h| !h.is_empty());
+        let rows = header
+            .into_iter()
+            .map(|row| (row, true))
+            .chain(self.rows.iter().map(|row| (row, false)));
+        for (r, (row, is_header)) in rows.enumerate() {
+            let top = y0 + h * r as i32;
+            let style = if is_header {
+                &self.header_style
+            } else {
+                &self.style
+            };
+            let style = style.pos(Pos::new(HPos::Center, VPos::Center));
+            for (col, text) in row.iter().enumerate() {
+                let x = match left(col) {
+                    Some(x) => x,
+                    None => break,
+                };
+                let (ul, br) = ((x, top), (x + w - 1, top + h - 1));
+                if is_header {
+                    backend.draw_rect(ul, br, &self.header_fill.to_backend_color(), true)?;
+                }
+                backend.draw_rect(ul, br, &self.border.to_backend_color(), false)?;
+                backend.draw_text(text, &style, (x + w / 2, top + h / 2))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_table() {
+        let texts = Rc::new(RefCell::new(vec![]));
+        let rects = Rc::new(RefCell::new(vec![]));
+        let (texts_out, rects_out) = (texts.clone(), rects.clone());
+        let drawing_area = create_mocked_drawing_area(400, 300, |m| {
+            m.check_draw_text(move |_, _, _, pos, text| {
+                texts_out.borrow_mut().push((pos, text.to_string()))
+            });
+            m.check_draw_rect(move |c, _, filled, ul, br| {
+                rects_out.borrow_mut().push((c, filled, ul, br))
+            });
+        });
+
+        let rows = [["1.5", "2.0", "0.3"], ["4", "5", "6"]];
+        let table = MatTable::new((10, 200), rows, ("sans-serif", 12))
+            .header(["A", "B", "C"])
+            .cell_size(60, 20);
+        drawing_area.draw(&table).expect("Drawing Error");
+
+        // A header row and two rows of three columns
+        let texts = texts.borrow();
+        let cells: Vec<&str> = texts.iter().map(|(_, t)| t.as_str()).collect();
+        assert_eq!(cells, ["A", "B", "C", "1.5", "2.0", "0.3", "4", "5", "6"]);
+        for (i, (pos, _)) in texts.iter().enumerate() {
+            let (row, col) = (i / 3, i % 3);
+            assert_eq!(
+                *pos,
+                (10 + 60 * col as i32 + 30, 200 + 20 * row as i32 + 10)
+            );
+        }
+        let rects = rects.borrow();
+        let header = rects.iter().filter(|r| r.1).count();
+        let borders = rects.iter().filter(|r| !r.1).count();
+        assert_eq!((header, borders), (3, 9));
+        assert_eq!((rects[0].2, rects[0].3), ((10, 200), (69, 219)));
+    }
+
+    #[test]
+    fn test_table_column_centers() {
+        let positions = Rc::new(RefCell::new(vec![]));
+        let positions_out = positions.clone();
+        let drawing_area = create_mocked_drawing_area(500, 400, |m| {
+            m.check_draw_text(move |_, _, _, pos, _| positions_out.borrow_mut().push(pos));
+        });
+        let (upper, lower) = drawing_area.split_vertically(300);
+        let chart = ChartBuilder::on(&upper)
+            .margin(20)
+            .build_cartesian_2d(0.0..4.0, 0.0..1.0)
+            .expect("Build chart error");
+
+        // The columns are aligned to the ticks at 1, 2 and 3
+        let centers: Vec<i32> = [1.0, 2.0, 3.0]
+            .iter()
+            .map(|x| chart.backend_coord(&(*x, 0.0)).0)
+            .collect();
+        let table = MatTable::new((0, 10), [["a", "b", "c"]], ("sans-serif", 12))
+            .column_centers(centers.clone());
+        lower.draw(&table).expect("Drawing Error");
+
+        let positions = positions.borrow();
+        let xs: Vec<i32> = positions.iter().map(|p| p.0).collect();
+        assert_eq!(xs, centers);
+        assert!(positions.iter().all(|p| p.1 == 300 + 10 + 10));
+    }
+}
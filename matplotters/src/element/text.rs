@@ -2,6 +2,7 @@ use std::borrow::Borrow;
 use std::i32;
 
 use super::{Drawable, PointCollection};
+use crate::style::text_anchor::{HPos, Pos};
 use crate::style::{FontDesc, FontResult, LayoutBox, TextStyle};
 use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 
@@ -50,6 +51,178 @@ impl<'a, Coord: 'a, DB: DrawingBackend, T: Borrow<str>> Drawable<DB> for Text<'a
     }
 }
 
+/// The vertical placement of a run of text in a [`MathText`] element
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ScriptLevel {
+    Base,
+    Superscript,
+    Subscript,
+}
+
+/// Split a text into runs of base text, `^{...}` superscripts and `_{...}` subscripts.
+/// A marker without a matching closing brace is kept as plain text.
+fn parse_scripts(text: &str) -> Vec<(ScriptLevel, &str)> {
+    let mut ret = vec![];
+    let mut base_start = 0;
+    let mut idx = 0;
+    while idx < text.len() {
+        let rest = &text[idx..];
+        let level = if rest.starts_with("^{") {
+            Some(ScriptLevel::Superscript)
+        } else if rest.starts_with("_{") {
+            Some(ScriptLevel::Subscript)
+        } else {
+            None
+        };
+        if let (Some(level), Some(close)) = (level, rest.find('}')) {
+            if base_start < idx {
+                ret.push((ScriptLevel::Base, &text[base_start..idx]));
+            }
+            ret.push((level, &rest[2..close]));
+            idx += close + 1;
+            base_start = idx;
+        } else {
+            idx += rest.chars().next().map_or(1, char::len_utf8);
+        }
+    }
+    if base_start < text.len() {
+        ret.push((ScriptLevel::Base, &text[base_start..]));
+    }
+    ret
+}
+
+/// A single line text element with lightweight math markup: `^{...}` is drawn as a
+/// superscript and `_{...}` as a subscript, e.g. `"x^{2}"` or `"CO_{2}"`.
+/// The scripts use a smaller font and are shifted up or down from the base text.
+pub struct MathText<'a, Coord, T: Borrow<str>> {
+    text: T,
+    coord: Coord,
+    style: TextStyle<'a>,
+}
+
+impl<'a, Coord, T: Borrow<str>> MathText<'a, Coord, T> {
+    /// The size of the scripts relative to the base text
+    const SCRIPT_SCALE: f64 = 0.7;
+
+    /// Create a new math text element
+    /// - `text`: The text for the element, possibly containing `^{...}` and `_{...}`
+    /// - `points`: The anchor point for the text element
+    /// - `style`: The text style of the base text
+    /// - Return the newly created text element
+    pub fn new<S: Into<TextStyle<'a>>>(text: T, points: Coord, style: S) -> Self {
+        Self {
+            text,
+            coord: points,
+            style: style.into(),
+        }
+    }
+}
+
+impl<'b, 'a, Coord: 'a, T: Borrow<str> + 'a> PointCollection<'a, Coord>
+    for &'a MathText<'b, Coord, T>
+{
+    type Point = &'a Coord;
+    type IntoIter = std::iter::Once<&'a Coord>;
+    fn point_iter(self) -> Self::IntoIter {
+        std::iter::once(&self.coord)
+    }
+}
+
+impl<'a, Coord: 'a, DB: DrawingBackend, T: Borrow<str>> Drawable<DB> for MathText<'a, Coord, T> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (x0, y0) = match points.next() {
+            Some(a) => a,
+            None => return Ok(()),
+        };
+
+        let size = self.style.font.get_size();
+        // The runs are laid out left to right, so the horizontal anchor is applied to the
+        // whole text and every run is drawn left aligned.
+        let base_style = TextStyle {
+            pos: Pos::new(HPos::Left, self.style.pos.v_pos),
+            ..self.style.clone()
+        };
+        let script_style = TextStyle {
+            font: base_style.font.resize(size * Self::SCRIPT_SCALE),
+            ..base_style.clone()
+        };
+
+        let mut runs = vec![];
+        let mut width = 0;
+        for (level, text) in parse_scripts(self.text.borrow()) {
+            let (style, dy) = match level {
+                ScriptLevel::Base => (&base_style, 0.0),
+                ScriptLevel::Superscript => (&script_style, -0.4 * size),
+                ScriptLevel::Subscript => (&script_style, 0.4 * size),
+            };
+            let (w, _) = backend.estimate_text_size(text, style)?;
+            runs.push((text, style, width, dy.round() as i32));
+            width += w as i32;
+        }
+
+        let x0 = match self.style.pos.h_pos {
+            HPos::Left => x0,
+            HPos::Center => x0 - width / 2,
+            HPos::Right => x0 - width,
+        };
+        for (text, style, dx, dy) in runs {
+            backend.draw_text(text, style, (x0 + dx, y0 + dy))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_parse_scripts() {
+    use ScriptLevel::*;
+    assert_eq!(
+        parse_scripts("x^{2}"),
+        vec![(Base, "x"), (Superscript, "2")]
+    );
+    assert_eq!(
+        parse_scripts("CO_{2} + H_{2}O"),
+        vec![
+            (Base, "CO"),
+            (Subscript, "2"),
+            (Base, " + H"),
+            (Subscript, "2"),
+            (Base, "O")
+        ]
+    );
+    assert_eq!(parse_scripts("a^{b"), vec![(Base, "a^{b")]);
+}
+
+#[cfg(test)]
+#[test]
+fn test_math_text() {
+    use crate::prelude::*;
+    let da = crate::create_mocked_drawing_area(300, 300, |m| {
+        m.check_draw_text(|_, _, size, pos, text| {
+            assert_eq!(text, "x");
+            assert_eq!(size, 20.0);
+            assert_eq!(pos, (100, 100));
+        });
+        m.check_draw_text(|_, _, size, pos, text| {
+            assert_eq!(text, "2");
+            assert_eq!(size, 14.0);
+            // Right of the base text, and raised
+            assert!(pos.0 > 100);
+            assert!(pos.1 < 100);
+        });
+        m.drop_check(|b| {
+            assert_eq!(b.num_draw_text_call, 2);
+        });
+    });
+    da.draw(&MathText::new("x^{2}", (100, 100), ("sans-serif", 20)))
+        .expect("Drawing Failure");
+}
+
 /// An multi-line text element. The `Text` element allows only single line text
 /// and the `MultiLineText` supports drawing multiple lines
 pub struct MultiLineText<'a, Coord, T: Borrow<str>> {
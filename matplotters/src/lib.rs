@@ -807,15 +807,23 @@ pub use palette;
 /// The module imports the most commonly used types and modules in Plotters
 pub mod prelude {
     // Chart related types
-    pub use crate::chart::{ChartBuilder, ChartContext, LabelAreaPosition, SeriesLabelPosition};
+    pub use crate::chart::{
+        ChartBuilder, ChartContext, DashboardAreas, DashboardLayout, HighlightSpan,
+        LabelAreaPosition, LegendGlyph, SecondaryAxis, SeriesLabelPosition, StyledSeries,
+        ValueZones, ZebraBands,
+    };
+    #[cfg(feature = "colormaps")]
+    pub use crate::chart::{ColorbarLayout, ColorbarPosition};
 
     // Coordinates
     pub use crate::coord::{
         cartesian::Cartesian2d,
         combinators::{
-            make_partial_axis, BindKeyPointMethod, BindKeyPoints, BuildNestedCoord, GroupBy,
-            IntoLinspace, IntoLogRange, IntoPartialAxis, Linspace, LogCoord, LogScalable,
-            NestedRange, NestedValue, ToGroupByRange,
+            make_partial_axis, AxisBreaks, BindKeyPointMethod, BindKeyPoints, BrokenCoord,
+            BrokenRangeExt, BuildNestedCoord, GroupBy, IntoBrokenRange, IntoLinspace, IntoLogRange,
+            IntoLogitRange, IntoPartialAxis, IntoRoundTicks, IntoSymlogRange, Linspace, LogCoord,
+            LogScalable, LogitCoord, NestedRange, NestedValue, RoundTicksCoord, RoundTicksRange,
+            SymlogCoord, SymlogRangeExt, ToGroupByRange,
         },
         ranged1d::{DiscreteRanged, IntoSegmentedCoord, Ranged, SegmentValue},
         CoordTranslate,
@@ -826,7 +834,7 @@ pub mod prelude {
 
     #[cfg(feature = "chrono")]
     pub use crate::coord::types::{
-        IntoMonthly, IntoYearly, RangedDate, RangedDateTime, RangedDuration,
+        IntoAutoDate, IntoMonthly, IntoYearly, RangedDate, RangedDateTime, RangedDuration,
     };
 
     // Re-export the backend for backward compatibility
@@ -839,12 +847,46 @@ pub mod prelude {
     pub use crate::series::AreaSeries;
     #[cfg(feature = "histogram")]
     pub use crate::series::Histogram;
-    #[cfg(feature = "point_series")]
-    pub use crate::series::PointSeries;
+    #[cfg(feature = "boxplot")]
+    pub use crate::series::MatBoxPlot;
+    #[cfg(all(feature = "chrono", feature = "colormaps"))]
+    pub use crate::series::MatCalendarHeatmap;
+    #[cfg(feature = "area_series")]
+    pub use crate::series::MatErrorBand;
+    #[cfg(feature = "area_series")]
+    pub use crate::series::MatFillBetween;
+    #[cfg(feature = "histogram")]
+    pub use crate::series::MatHistogram;
+    #[cfg(feature = "histogram")]
+    pub use crate::series::MatPopulationPyramid;
+    #[cfg(feature = "histogram")]
+    pub use crate::series::MatRadialBar;
+    #[cfg(feature = "area_series")]
+    pub use crate::series::MatRibbon;
+    #[cfg(feature = "area_series")]
+    pub use crate::series::MatSankey;
+    pub use crate::series::MatSeriesError;
+    #[cfg(feature = "histogram")]
+    pub use crate::series::MatWaterfall;
+    #[cfg(feature = "histogram")]
+    pub use crate::series::MatWindRose;
     #[cfg(feature = "surface_series")]
     pub use crate::series::SurfaceSeries;
+    #[cfg(feature = "histogram")]
+    pub use crate::series::{BarWidth, MatBarSeries};
     #[cfg(feature = "line_series")]
     pub use crate::series::{DashedLineSeries, LineSeries};
+    #[cfg(feature = "point_series")]
+    pub use crate::series::{
+        MatBubbleSeries, MatCategoryScatter, MatDensityContour, PointSeries, SizeScale,
+    };
+    #[cfg(feature = "colormaps")]
+    pub use crate::series::{MatColorbar, MatImshow, MatScatterDensity};
+    #[cfg(feature = "line_series")]
+    pub use crate::series::{
+        MatDecisionBoundary, MatEcdf, MatLineErrorSeries, MatLineSeries, MatRollingCorrelation,
+        MatStairs, MatStreamplot, MatTimeWindow,
+    };
 
     // Styles
     pub use crate::style::{BLACK, BLUE, CYAN, GREEN, MAGENTA, RED, TRANSPARENT, WHITE, YELLOW};
@@ -856,15 +898,18 @@ pub mod prelude {
     pub use crate::style::colors::colormaps::*;
 
     pub use crate::style::{
-        AsRelative, Color, FontDesc, FontFamily, FontStyle, FontTransform, HSLColor, IntoFont,
-        IntoTextStyle, Palette, Palette100, Palette99, Palette9999, PaletteColor, RGBAColor,
-        RGBColor, ShapeStyle, TextStyle,
+        AsRelative, Color, ColorCycle, FontDesc, FontFamily, FontStyle, FontTransform, HSLColor,
+        Hatch, IntoFont, IntoTextStyle, LineStyle, LineStyleCycle, MarkerCycle, Norm, Palette,
+        Palette100, Palette99, Palette9999, PaletteColor, Pattern, RGBAColor, RGBColor, RcParams,
+        ShapeStyle, TextStyle,
     };
 
     // Elements
     pub use crate::element::{
-        Circle, Cross, Cubiod, DynElement, EmptyElement, IntoDynElement, MultiLineText,
-        PathElement, Pie, Pixel, Polygon, Rectangle, Text, TriangleMarker,
+        Circle, Cross, Cubiod, DataLabels, DynElement, EllipsePatch, EmptyElement, HatchedPolygon,
+        IntoDynElement, LabelPosition, Marker, MarkerShape, MatTable, MathText, MultiLineText,
+        PathElement, Pie, Pixel, Polygon, PolygonPatch, Rectangle, SizeColorLegend, Text,
+        TriangleMarker, Wedge,
     };
 
     #[cfg(feature = "boxplot")]
@@ -878,7 +923,7 @@ pub mod prelude {
     pub use crate::element::BitMapElement;
 
     // Data
-    pub use crate::data::Quartiles;
+    pub use crate::data::{BoxStats, OutlierRule, Quantity, Quartiles};
 
     // TODO: This should be deprecated and completely removed
     #[cfg(feature = "deprecated_items")]
@@ -895,6 +940,13 @@ pub mod prelude {
     #[cfg(feature = "evcxr")]
     pub use crate::evcxr::evcxr_figure;
 
+    #[cfg(all(
+        feature = "svg_backend",
+        feature = "bitmap_backend",
+        feature = "bitmap_encoder"
+    ))]
+    pub use crate::drawing::{render_to_all, Backend};
+
     // Re-export tier 1 backends for backward compatibility
     #[cfg(feature = "bitmap_backend")]
     pub use plotters_bitmap::BitMapBackend;
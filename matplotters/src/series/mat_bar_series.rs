@@ -0,0 +1,683 @@
+use crate::chart::StyledSeries;
+use crate::data::float::pretty_print_float;
+use crate::element::{DataLabels, LabelPosition, Rectangle};
+use crate::style::{Color, Norm, ShapeStyle, TextStyle, GREEN};
+
+#[cfg(feature = "colormaps")]
+use crate::style::colors::colormaps::ColorMap;
+
+/**
+A bar chart, like matplotlib's `bar(x, height)`.
+
+Each `(x, height)` pair is drawn as a bar centered on x, from its baseline to the height.
+The baseline is zero unless it is set for all the bars (see [`MatBarSeries::baseline`]) or
+for each of them, for floating bars (see [`MatBarSeries::baselines`] and
+[`MatBarSeries::baseline_func`]). The bars have a single style, unless they are colored by
+value through a colormap, see [`MatBarSeries::color_by_value`]. Several series of values can
+be stacked on top of each other, see [`MatBarSeries::stacked`]. The bars can be labeled with
+their values, see [`MatBarSeries::data_labels`]. The width of the bars is given in data
+units or relative to the spacing of the bars, see [`BarWidth`].
+*/
+pub struct MatBarSeries {
+    style: ShapeStyle,
+    bar_styles: Option<Vec<ShapeStyle>>,
+    bars: Vec<(f64, f64)>,
+    baselines: Vec<f64>,
+    width: f64,
+    corner_radius: f64,
+    idx: usize,
+}
+
+/// The width of the bars of a [`MatBarSeries`], see [`MatBarSeries::bar_width`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BarWidth {
+    /// An absolute width in data units, for bars over a numeric x axis
+    Data(f64),
+    /// A fraction of the spacing of the bars, the smallest distance between two distinct x
+    /// values, which suits categories at any spacing. A single bar takes a spacing of 1.
+    Fraction(f64),
+}
+
+impl MatBarSeries {
+    /**
+    Creates a bar chart from `(x, height)` pairs. The bars are 0.8 wide by default.
+
+    See [`MatBarSeries`] for more information.
+    */
+    pub fn new<I: IntoIterator<Item = (f64, f64)>, S: Into<ShapeStyle>>(data: I, style: S) -> Self {
+        Self {
+            style: style.into(),
+            bar_styles: None,
+            bars: data.into_iter().collect(),
+            baselines: vec![],
+            width: 0.8,
+            corner_radius: 0.0,
+            idx: 0,
+        }
+    }
+
+    /**
+    Creates a stacked bar chart, like successive calls of matplotlib's `bar(x, height,
+    bottom=...)`, with one layer of bars per series of values.
+
+    The positive values of each category are stacked upwards from zero, and the negative
+    values downwards from zero, independently: a negative value is drawn below the negative
+    values of the previous layers, whatever their positive values. One layer is returned per
+    series, using the styles in turn, or green bars if no style is given. Drawing the layers
+    separately allows to label each series in the legend.
+
+    See [`MatBarSeries`] for more information.
+    */
+    pub fn stacked<S: Into<ShapeStyle> + Clone>(
+        x: &[f64],
+        layers: &[Vec<f64>],
+        styles: &[S],
+    ) -> Vec<Self> {
+        let mut positive = vec![0.0; x.len()];
+        let mut negative = vec![0.0; x.len()];
+        let mut ret = vec![];
+        for (idx, values) in layers.iter().enumerate() {
+            let style = styles
+                .get(idx % styles.len().max(1))
+                .cloned()
+                .map_or_else(|| GREEN.filled(), Into::into);
+            let mut bars = vec![];
+            let mut baselines = vec![];
+            for (i, (x, value)) in x.iter().zip(values).enumerate() {
+                let stack = if *value < 0.0 {
+                    &mut negative[i]
+                } else {
+                    &mut positive[i]
+                };
+                baselines.push(*stack);
+                *stack += value;
+                bars.push((*x, *stack));
+            }
+            ret.push(Self::new(bars, style).baselines(baselines));
+        }
+        ret
+    }
+
+    /**
+    Creates a stacked bar chart like [`MatBarSeries::stacked`], but with the layers reordered
+    by their total magnitude, the sum of their absolute values, so that the largest layer is
+    at the bottom of the stack, next to zero, and the smallest one at the end. The layers of
+    equal magnitude keep their order. Each layer keeps the style it would have in the given
+    order, and comes with its index in `layers`, to be labeled: drawing the layers in the
+    returned order lists them in the legend from the bottom of the stack to its end.
+
+    See [`MatBarSeries`] for more information.
+    */
+    pub fn stacked_by_magnitude<S: Into<ShapeStyle> + Clone>(
+        x: &[f64],
+        layers: &[Vec<f64>],
+        styles: &[S],
+    ) -> Vec<(usize, Self)> {
+        let magnitude = |values: &[f64]| values.iter().map(|v| v.abs()).sum::<f64>();
+        let mut order: Vec<usize> = (0..layers.len()).collect();
+        order.sort_by(|a, b| magnitude(&layers[*b]).total_cmp(&magnitude(&layers[*a])));
+        let sorted: Vec<_> = order.iter().map(|idx| layers[*idx].clone()).collect();
+        let styles: Vec<ShapeStyle> = order
+            .iter()
+            .map(|idx| {
+                styles
+                    .get(idx % styles.len().max(1))
+                    .cloned()
+                    .map_or_else(|| GREEN.filled(), Into::into)
+            })
+            .collect();
+        order
+            .into_iter()
+            .zip(Self::stacked(x, &sorted, &styles))
+            .collect()
+    }
+
+    /**
+    Sets the width of the bars, in data units, like `bar_width(BarWidth::Data(width))`.
+
+    See [`MatBarSeries`] for more information.
+    */
+    pub fn width(mut self, width: f64) -> Self {
+        self.width = width;
+        self
+    }
+
+    /**
+    Sets the width of the bars, in data units or as a fraction of the spacing of the bars.
+
+    See [`MatBarSeries`] for more information.
+    */
+    pub fn bar_width(mut self, width: BarWidth) -> Self {
+        self.width = match width {
+            BarWidth::Data(width) => width,
+            BarWidth::Fraction(fraction) => fraction * self.spacing(),
+        };
+        self
+    }
+
+    /// The smallest distance between two distinct x values of the bars, or 1 if there are
+    /// less than two of them
+    fn spacing(&self) -> f64 {
+        let mut xs: Vec<f64> = self.bars.iter().map(|(x, _)| *x).collect();
+        xs.sort_by(f64::total_cmp);
+        xs.windows(2)
+            .map(|w| w[1] - w[0])
+            .filter(|d| *d > 0.0)
+            .fold(None, |min: Option<f64>, d| {
+                Some(min.map_or(d, |m| m.min(d)))
+            })
+            .unwrap_or(1.0)
+    }
+
+    /**
+    Rounds the top corners of the bars, the corners away from the baseline, with a radius in
+    pixels. The radius is clamped to half the width of each bar, so that a large radius gives
+    a semicircular end, and to its height. The bars are square by default.
+
+    See [`MatBarSeries`] for more information.
+    */
+    pub fn corner_radius(mut self, radius: f64) -> Self {
+        self.corner_radius = radius;
+        self
+    }
+
+    /**
+    Sets the baseline the bars start from.
+
+    See [`MatBarSeries`] for more information.
+    */
+    pub fn baseline(self, baseline: f64) -> Self {
+        self.baseline_func(|_| baseline)
+    }
+
+    /**
+    Sets the baseline of each bar, in the order of the data; the bars without one start from
+    zero.
+
+    See [`MatBarSeries`] for more information.
+    */
+    pub fn baselines<I: IntoIterator<Item = f64>>(mut self, baselines: I) -> Self {
+        self.baselines = baselines.into_iter().collect();
+        self
+    }
+
+    /**
+    Sets the baseline of each bar to a function of its x value.
+
+    See [`MatBarSeries`] for more information.
+    */
+    pub fn baseline_func<F: Fn(f64) -> f64>(mut self, func: F) -> Self {
+        self.baselines = self.bars.iter().map(|(x, _)| func(*x)).collect();
+        self
+    }
+
+    /**
+    Colors each bar by its height, with the color `colormap.get_color(norm.normalize(height))`
+    or the under or over color of an extended norm, see [`Norm::colorize`]. The other
+    properties of the bar style, such as filling, are kept.
+
+    Drawing a [`crate::series::MatColorbar`] with the same colormap and norm shows the value of
+    each color.
+
+    See [`MatBarSeries`] for more information.
+    */
+    #[cfg(feature = "colormaps")]
+    pub fn color_by_value<C: Color, M: ColorMap<C, f64>>(
+        mut self,
+        colormap: &M,
+        norm: Norm,
+    ) -> Self {
+        let style = self.style;
+        self.bar_styles = Some(
+            self.bars
+                .iter()
+                .map(|(_, h)| ShapeStyle {
+                    color: norm.colorize(colormap, *h),
+                    ..style
+                })
+                .collect(),
+        );
+        self
+    }
+
+    /**
+    Returns the normalization spanning the heights of the bars, to be used with
+    [`MatBarSeries::color_by_value`].
+
+    See [`MatBarSeries`] for more information.
+    */
+    pub fn value_norm(&self) -> Norm {
+        Norm::autoscale(self.bars.iter().map(|(_, h)| *h))
+    }
+}
+
+impl MatBarSeries {
+    /**
+    Creates labels showing the value of each bar, its height above its baseline, like
+    matplotlib's `bar_label`. The labels are placed outside of the end of the bars, below it
+    for the bars going down, inside near their end, or at their middle, in which case they
+    take black or white for contrast with their bar. The middle is taken in data units, so
+    it is only the middle on screen over a linear axis.
+
+    See [`MatBarSeries`] for more information.
+    */
+    pub fn data_labels<'a, T: Into<TextStyle<'a>>>(
+        &self,
+        position: LabelPosition,
+        style: T,
+    ) -> DataLabels<'a, (f64, f64)> {
+        let bars: Vec<_> = self
+            .bars
+            .iter()
+            .enumerate()
+            .map(|(idx, (x, height))| {
+                let baseline = self.baselines.get(idx).copied().unwrap_or(0.0);
+                (*x, *height, baseline)
+            })
+            .collect();
+        let labels = bars.iter().map(|(x, height, baseline)| {
+            let anchor = match position {
+                LabelPosition::Center => (*x, (height + baseline) / 2.0),
+                _ => (*x, *height),
+            };
+            (anchor, pretty_print_float(height - baseline, false))
+        });
+        let colors = (0..bars.len()).map(|idx| match &self.bar_styles {
+            Some(styles) => styles[idx].color,
+            None => self.style.color,
+        });
+        DataLabels::new(labels, style)
+            .label_position(position)
+            .inside_colors(colors)
+            .downward(bars.iter().map(|(_, height, baseline)| height < baseline))
+    }
+}
+
+impl StyledSeries for MatBarSeries {
+    fn series_style(&self) -> ShapeStyle {
+        self.style
+    }
+}
+
+impl Iterator for MatBarSeries {
+    type Item = Rectangle<(f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let (x, height) = *self.bars.get(self.idx)?;
+        let style = self
+            .bar_styles
+            .as_ref()
+            .map_or(self.style, |styles| styles[self.idx]);
+        let baseline = self.baselines.get(self.idx).copied().unwrap_or(0.0);
+        self.idx += 1;
+        let half = self.width / 2.0;
+        let mut bar = Rectangle::new([(x - half, height), (x + half, baseline)], style);
+        bar.set_top_corner_radius(self.corner_radius);
+        Some(bar)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_bar_series() {
+        let rects = Rc::new(RefCell::new(vec![]));
+        let rects_out = rects.clone();
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_rect(move |c, _, filled, ul, br| {
+                assert_eq!(c, BLUE.to_rgba());
+                assert!(filled);
+                rects_out.borrow_mut().push((ul, br));
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..5.0, 0.0..10.0)
+            .expect("Build chart error");
+        chart
+            .draw_series(MatBarSeries::new(vec![(1.0, 4.0), (3.0, 8.0)], BLUE.filled()).width(1.0))
+            .expect("Drawing Error");
+
+        let rects = rects.borrow();
+        assert_eq!(rects.len(), 2);
+        assert_eq!(rects[0].0, chart.backend_coord(&(0.5, 4.0)));
+        assert_eq!(rects[0].1, chart.backend_coord(&(1.5, 0.0)));
+        assert_eq!(rects[1].0, chart.backend_coord(&(2.5, 8.0)));
+    }
+
+    #[test]
+    fn test_bar_width() {
+        let rects = Rc::new(RefCell::new(vec![]));
+        let rects_out = rects.clone();
+        let drawing_area = create_mocked_drawing_area(1001, 501, |m| {
+            m.check_draw_rect(move |_, _, _, ul, br| rects_out.borrow_mut().push((ul, br)));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..20.0, 0.0..10.0)
+            .expect("Build chart error");
+
+        let data = [(2.0, 4.0), (4.0, 8.0)];
+        let data_width = MatBarSeries::new(data, BLUE.filled()).bar_width(BarWidth::Data(0.5));
+        chart.draw_series(data_width).expect("Drawing Error");
+        // The categories are 5 apart, so a fraction of 0.8 makes bars 4 units wide
+        let spaced = [(10.0, 4.0), (15.0, 8.0)];
+        let fraction = MatBarSeries::new(spaced, BLUE.filled()).bar_width(BarWidth::Fraction(0.8));
+        chart.draw_series(fraction).expect("Drawing Error");
+
+        let c = |x: f64, y: f64| chart.backend_coord(&(x, y));
+        let expected = vec![
+            (c(1.75, 4.0), c(2.25, 0.0)),
+            (c(3.75, 8.0), c(4.25, 0.0)),
+            (c(8.0, 4.0), c(12.0, 0.0)),
+            (c(13.0, 8.0), c(17.0, 0.0)),
+        ];
+        assert_eq!(*rects.borrow(), expected);
+    }
+
+    #[test]
+    fn test_bar_label_positions() {
+        for position in [
+            LabelPosition::Outside,
+            LabelPosition::Inside,
+            LabelPosition::Center,
+        ] {
+            let texts = Rc::new(RefCell::new(vec![]));
+            let texts_out = texts.clone();
+            let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+                m.check_draw_text(move |c, _, _, pos, text| {
+                    texts_out.borrow_mut().push((c, pos, text.to_string()))
+                });
+            });
+            let chart = ChartBuilder::on(&drawing_area)
+                .build_cartesian_2d(0.0..4.0, -10.0..10.0)
+                .expect("Build chart error");
+
+            let style = TextStyle::from(("sans-serif", 12));
+            let bars = MatBarSeries::new([(1.0, 8.0), (3.0, -4.0)], BLUE.filled());
+            let labels = bars.data_labels(position, style.clone());
+            chart.plotting_area().draw(&labels).expect("Drawing Error");
+
+            let texts = texts.borrow();
+            let names: Vec<_> = texts.iter().map(|(_, _, t)| t.as_str()).collect();
+            assert_eq!(names, vec!["8", "-4"]);
+            let zero = chart.backend_coord(&(0.0, 0.0)).1;
+            for ((color, pos, text), value) in texts.iter().zip([8.0, -4.0]) {
+                let h = drawing_area.estimate_text_size(text, &style).unwrap().1 as i32;
+                let end = chart.backend_coord(&(0.0, value)).1;
+                let (top, expected_color) = match (position, value > 0.0) {
+                    (LabelPosition::Outside, true) => (end - 4 - h, BLACK),
+                    (LabelPosition::Outside, false) => (end + 4, BLACK),
+                    (LabelPosition::Inside, true) => (end + 4, WHITE),
+                    (LabelPosition::Inside, false) => (end - 4 - h, WHITE),
+                    (LabelPosition::Center, _) => ((end + zero) / 2 - h / 2, WHITE),
+                };
+                assert_eq!(pos.1, top);
+                // The labels on the dark bars are white
+                assert_eq!(*color, expected_color.to_rgba());
+            }
+        }
+    }
+
+    #[test]
+    fn test_stacked_bar_labels() {
+        let texts = Rc::new(RefCell::new(vec![]));
+        let texts_out = texts.clone();
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_text(move |_, _, _, _, text| {
+                texts_out.borrow_mut().push(text.to_string())
+            });
+        });
+        let chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..4.0, 0.0..1.0)
+            .expect("Build chart error");
+
+        // The upper layer spans 0.1..0.30000000000000004
+        let layers = MatBarSeries::stacked(&[1.0], &[vec![0.1], vec![0.2]], &[BLUE.filled()]);
+        for layer in &layers {
+            let labels = layer.data_labels(LabelPosition::Outside, ("sans-serif", 12));
+            chart.plotting_area().draw(&labels).expect("Drawing Error");
+        }
+        assert_eq!(*texts.borrow(), vec!["0.1", "0.2"]);
+    }
+
+    #[test]
+    fn test_bar_baselines() {
+        let rects = Rc::new(RefCell::new(vec![]));
+        let rects_out = rects.clone();
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_rect(move |_, _, _, ul, br| rects_out.borrow_mut().push((ul, br)));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..5.0, 0.0..10.0)
+            .expect("Build chart error");
+        let data = vec![(1.0, 4.0), (2.0, 8.0), (3.0, 6.0)];
+        chart
+            .draw_series(MatBarSeries::new(data.clone(), RED.filled()).baselines([1.0, 5.0]))
+            .expect("Drawing Error");
+        chart
+            .draw_series(MatBarSeries::new(data.clone(), RED.filled()).baseline_func(|x| x / 2.0))
+            .expect("Drawing Error");
+        chart
+            .draw_series(MatBarSeries::new(data.clone(), RED.filled()).baseline(2.0))
+            .expect("Drawing Error");
+
+        let rects = rects.borrow();
+        assert_eq!(rects.len(), 9);
+        // The third bar of the first series has no baseline and starts from zero
+        let baselines = [1.0, 5.0, 0.0, 0.5, 1.0, 1.5, 2.0, 2.0, 2.0];
+        for ((ul, br), (baseline, (x, height))) in
+            rects.iter().zip(baselines.iter().zip(data.iter().cycle()))
+        {
+            assert_eq!(*ul, chart.backend_coord(&(x - 0.4, *height)));
+            assert_eq!(*br, chart.backend_coord(&(x + 0.4, *baseline)));
+        }
+    }
+
+    #[test]
+    fn test_bar_corner_radius() {
+        let bars = Rc::new(RefCell::new(vec![]));
+        let bars_out = bars.clone();
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_fill_polygon(move |c, path| {
+                assert_eq!(c, BLUE.to_rgba());
+                bars_out.borrow_mut().push(path);
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_rect_call, 0));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..5.0, -10.0..10.0)
+            .expect("Build chart error");
+        let data = vec![(1.0, 6.0), (2.5, -6.0), (4.0, 8.0)];
+        chart
+            .draw_series(
+                MatBarSeries::new(data.clone(), BLUE.filled())
+                    .width(1.0)
+                    .corner_radius(10.0),
+            )
+            .expect("Drawing Error");
+        // A radius larger than half of the bar is clamped
+        chart
+            .draw_series(
+                MatBarSeries::new(vec![(1.0, 9.0)], BLUE.filled())
+                    .width(0.2)
+                    .corner_radius(1000.0),
+            )
+            .expect("Drawing Error");
+
+        let bars = bars.borrow();
+        assert_eq!(bars.len(), 4);
+        // The points of the outline strictly inside the square of side r at a corner
+        let arc_points = |path: &[(i32, i32)], corner: (i32, i32), r: i32| {
+            path.iter()
+                .filter(|p| {
+                    let (dx, dy) = ((p.0 - corner.0).abs(), (p.1 - corner.1).abs());
+                    0 < dx && dx < r && 0 < dy && dy < r
+                })
+                .count()
+        };
+        for (path, (x, height)) in bars.iter().zip(&data) {
+            let (tl, tr) = (
+                chart.backend_coord(&(x - 0.5, *height)),
+                chart.backend_coord(&(x + 0.5, *height)),
+            );
+            let (bl, br) = (
+                chart.backend_coord(&(x - 0.5, 0.0)),
+                chart.backend_coord(&(x + 0.5, 0.0)),
+            );
+            // The corners at the baseline are square, and those at the top are cut by arcs
+            assert!(path.contains(&bl) && path.contains(&br));
+            assert!(!path.contains(&tl) && !path.contains(&tr));
+            assert!(arc_points(path, tl, 10) >= 3);
+            assert!(arc_points(path, tr, 10) >= 3);
+            // The arcs end 10 pixels from the corners
+            let dir = (bl.1 - tl.1).signum();
+            assert!(path.contains(&(tl.0 + 10, tl.1)) && path.contains(&(tr.0 - 10, tr.1)));
+            assert!(path.contains(&(tl.0, tl.1 + 10 * dir)));
+            assert!(path.contains(&(tr.0, tr.1 + 10 * dir)));
+        }
+        // The clamped bar ends in a semicircle as wide as the bar
+        let (tl, tr) = (
+            chart.backend_coord(&(0.9, 9.0)),
+            chart.backend_coord(&(1.1, 9.0)),
+        );
+        let r = (tr.0 - tl.0) / 2;
+        assert_eq!(bars[3].iter().map(|p| p.1).min(), Some(tl.1));
+        assert!(bars[3].contains(&(tl.0 + r, tl.1)));
+        assert!(bars[3].contains(&(tl.0, tl.1 + r)) && bars[3].contains(&(tr.0, tr.1 + r)));
+        assert!(arc_points(&bars[3], tl, r) >= 3);
+    }
+
+    #[test]
+    fn test_stacked_bars_mixed_signs() {
+        let rects = Rc::new(RefCell::new(vec![]));
+        let rects_out = rects.clone();
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_rect(move |_, _, _, ul, br| rects_out.borrow_mut().push((ul, br)));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..3.0, -10.0..10.0)
+            .expect("Build chart error");
+
+        let x = [1.0, 2.0];
+        let layers = vec![
+            vec![3.0, -2.0],
+            vec![-4.0, -1.5],
+            vec![2.0, 5.0],
+            vec![-1.0, 2.5],
+        ];
+        for layer in MatBarSeries::stacked(&x, &layers, &[BLUE.filled(), RED.filled()]) {
+            chart.draw_series(layer).expect("Drawing Error");
+        }
+
+        // (baseline, top) of each bar, layer by layer: the positives stack upwards and the
+        // negatives downwards from zero
+        let expected = [
+            [(0.0, 3.0), (0.0, -2.0)],
+            [(0.0, -4.0), (-2.0, -3.5)],
+            [(3.0, 5.0), (0.0, 5.0)],
+            [(-4.0, -5.0), (5.0, 7.5)],
+        ];
+        let rects = rects.borrow();
+        assert_eq!(rects.len(), 8);
+        for (rect, (x, (baseline, top))) in rects
+            .iter()
+            .zip(expected.iter().flat_map(|layer| x.iter().zip(layer.iter())))
+        {
+            let (a, b) = (
+                chart.backend_coord(&(x - 0.4, *top)),
+                chart.backend_coord(&(x + 0.4, *baseline)),
+            );
+            let (ul, br) = ((a.0.min(b.0), a.1.min(b.1)), (a.0.max(b.0), a.1.max(b.1)));
+            assert_eq!(*rect, (ul, br));
+        }
+    }
+
+    #[test]
+    fn test_stacked_by_magnitude() {
+        let rects = Rc::new(RefCell::new(vec![]));
+        let texts = Rc::new(RefCell::new(vec![]));
+        let (rects_out, texts_out) = (rects.clone(), texts.clone());
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_rect(move |c, _, _, ul, br| rects_out.borrow_mut().push((c, ul, br)));
+            m.check_draw_text(move |_, _, _, pos, text| {
+                texts_out.borrow_mut().push((pos, text.to_string()))
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..3.0, 0.0..20.0)
+            .expect("Build chart error");
+
+        let x = [1.0, 2.0];
+        let names = ["small", "large", "medium"];
+        let layers = vec![vec![1.0, 1.0], vec![6.0, 4.0], vec![3.0, 2.0]];
+        let styles = [RED.filled(), GREEN.filled(), BLUE.filled()];
+        let stack = MatBarSeries::stacked_by_magnitude(&x, &layers, &styles);
+        let order: Vec<_> = stack.iter().map(|(idx, _)| *idx).collect();
+        assert_eq!(order, vec![1, 2, 0]);
+        for (idx, layer) in stack {
+            chart
+                .draw_series(layer)
+                .expect("Drawing Error")
+                .label(names[idx])
+                .legend_glyph(LegendGlyph::Style(styles[idx]));
+        }
+
+        // The largest layer is at the bottom with its own color, the smallest one on top
+        let c = |x: f64, y: f64| chart.backend_coord(&(x, y));
+        let bars: Vec<_> = rects.borrow().iter().step_by(2).copied().collect();
+        assert_eq!(
+            bars,
+            vec![
+                (GREEN.to_rgba(), c(0.6, 6.0), c(1.4, 0.0)),
+                (BLUE.to_rgba(), c(0.6, 9.0), c(1.4, 6.0)),
+                (RED.to_rgba(), c(0.6, 10.0), c(1.4, 9.0)),
+            ]
+        );
+
+        chart
+            .configure_series_labels()
+            .draw()
+            .expect("Drawing Error");
+        let texts = texts.borrow();
+        let legend: Vec<_> = texts.iter().map(|(_, t)| t.as_str()).collect();
+        assert_eq!(legend, vec!["large", "medium", "small"]);
+        assert!(texts.windows(2).all(|w| w[0].0 .1 < w[1].0 .1));
+    }
+
+    #[cfg(feature = "colormaps")]
+    #[test]
+    fn test_bar_color_by_value() {
+        let heights = [2.0, 5.0, 8.0, 3.5];
+        let colors = Rc::new(RefCell::new(vec![]));
+        let colors_out = colors.clone();
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_rect(move |c, _, _, _, _| colors_out.borrow_mut().push(c));
+        });
+
+        let bars = MatBarSeries::new(
+            heights.iter().enumerate().map(|(i, h)| (i as f64, *h)),
+            BLUE.filled(),
+        );
+        let norm = bars.value_norm();
+        assert_eq!((norm.vmin(), norm.vmax()), (2.0, 8.0));
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(-1.0..4.0, 0.0..10.0)
+            .expect("Build chart error");
+        chart
+            .draw_series(bars.color_by_value(&ViridisRGB {}, norm))
+            .expect("Drawing Error");
+
+        let colors = colors.borrow();
+        assert_eq!(colors.len(), heights.len());
+        for (c, h) in colors.iter().zip(heights) {
+            let expected: RGBColor = ViridisRGB::get_color(norm.normalize(h));
+            assert_eq!(*c, expected.to_rgba());
+        }
+        assert_ne!(colors[0], colors[2]);
+    }
+}
@@ -0,0 +1,161 @@
+use crate::data::BoxStats;
+use crate::element::{Circle, DynElement, IntoDynElement, PathElement, Rectangle};
+use crate::style::{ShapeStyle, BLACK};
+use plotters_backend::DrawingBackend;
+use std::ops::Range;
+
+/**
+A box plot drawn from precomputed statistics, like matplotlib's `bxp`.
+
+Box i is centered on x = i: the box spans the quartiles `q1` to `q3` with a line at the
+median, the whiskers go from the box to `whislo` and `whishi` and end with a cap, and the
+fliers are drawn as circles. Unlike [`crate::element::Boxplot`], which computes the quartiles
+of raw data, the statistics are drawn exactly as given.
+*/
+pub struct MatBoxPlot<DB: DrawingBackend> {
+    style: ShapeStyle,
+    flier_style: ShapeStyle,
+    stats: Vec<BoxStats>,
+    width: f64,
+    elements: Option<std::vec::IntoIter<DynElement<'static, DB, (f64, f64)>>>,
+}
+
+impl<DB: DrawingBackend> MatBoxPlot<DB> {
+    /**
+    Creates a box plot with a box per statistics, 0.5 wide and drawn in black.
+
+    See [`MatBoxPlot`] for more information.
+    */
+    pub fn from_stats<I: IntoIterator<Item = BoxStats>>(stats: I) -> Self {
+        Self {
+            style: BLACK.into(),
+            flier_style: BLACK.into(),
+            stats: stats.into_iter().collect(),
+            width: 0.5,
+            elements: None,
+        }
+    }
+
+    /**
+    Sets the style of the boxes, the medians and the whiskers.
+
+    See [`MatBoxPlot`] for more information.
+    */
+    pub fn style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /**
+    Sets the style of the circles marking the fliers.
+
+    See [`MatBoxPlot`] for more information.
+    */
+    pub fn flier_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.flier_style = style.into();
+        self
+    }
+
+    /**
+    Sets the width of the boxes, in data units. The caps of the whiskers are half as wide.
+
+    See [`MatBoxPlot`] for more information.
+    */
+    pub fn width(mut self, width: f64) -> Self {
+        self.width = width;
+        self
+    }
+
+    /**
+    Returns the x range holding all the boxes, to be used as the x range of the chart.
+
+    See [`MatBoxPlot`] for more information.
+    */
+    pub fn key_range(&self) -> Range<f64> {
+        -0.5..self.stats.len() as f64 - 0.5
+    }
+
+    fn build_elements(&self) -> Vec<DynElement<'static, DB, (f64, f64)>> {
+        let mut elements = vec![];
+        let (half, cap) = (self.width / 2.0, self.width / 4.0);
+        for (i, stats) in self.stats.iter().enumerate() {
+            let x = i as f64;
+            let line = |from: (f64, f64), to: (f64, f64)| {
+                PathElement::new(vec![from, to], self.style).into_dyn()
+            };
+            elements.push(
+                Rectangle::new([(x - half, stats.q3), (x + half, stats.q1)], self.style).into_dyn(),
+            );
+            elements.push(line((x - half, stats.median), (x + half, stats.median)));
+            elements.push(line((x, stats.q1), (x, stats.whislo)));
+            elements.push(line((x, stats.q3), (x, stats.whishi)));
+            elements.push(line((x - cap, stats.whislo), (x + cap, stats.whislo)));
+            elements.push(line((x - cap, stats.whishi), (x + cap, stats.whishi)));
+            for flier in &stats.fliers {
+                elements.push(Circle::new((x, *flier), 3, self.flier_style).into_dyn());
+            }
+        }
+        elements
+    }
+}
+
+impl<DB: DrawingBackend> Iterator for MatBoxPlot<DB> {
+    type Item = DynElement<'static, DB, (f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.elements.is_none() {
+            self.elements = Some(self.build_elements().into_iter());
+        }
+        self.elements.as_mut().and_then(|e| e.next())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_box_plot_from_stats() {
+        let rects = Rc::new(RefCell::new(vec![]));
+        let paths = Rc::new(RefCell::new(vec![]));
+        let circles = Rc::new(RefCell::new(vec![]));
+        let (rects_out, paths_out, circles_out) = (rects.clone(), paths.clone(), circles.clone());
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_rect(move |_, _, filled, ul, br| {
+                assert!(!filled);
+                rects_out.borrow_mut().push((ul, br));
+            });
+            m.check_draw_path(move |_, _, p| paths_out.borrow_mut().push(p));
+            m.check_draw_circle(move |_, _, _, c, _| circles_out.borrow_mut().push(c));
+        });
+
+        let stats = vec![
+            BoxStats::new(2.0, 4.0, 5.0, 7.0, 9.0).fliers([0.5, 9.5]),
+            BoxStats::new(1.0, 3.0, 6.0, 8.0, 10.0),
+        ];
+        let plot = MatBoxPlot::from_stats(stats.clone()).width(0.4);
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(plot.key_range(), 0.0..10.0)
+            .expect("Build chart error");
+        chart.draw_series(plot).expect("Drawing Error");
+
+        let c = |x: f64, y: f64| chart.backend_coord(&(x, y));
+        let rects = rects.borrow();
+        let paths = paths.borrow();
+        assert_eq!(rects.len(), 2);
+        assert_eq!(paths.len(), 10);
+        for (i, s) in stats.iter().enumerate() {
+            let x = i as f64;
+            // The box spans the quartiles exactly
+            assert_eq!(rects[i], (c(x - 0.2, s.q3), c(x + 0.2, s.q1)));
+            let lines = &paths[5 * i..5 * i + 5];
+            assert_eq!(lines[0], vec![c(x - 0.2, s.median), c(x + 0.2, s.median)]);
+            assert_eq!(lines[1], vec![c(x, s.q1), c(x, s.whislo)]);
+            assert_eq!(lines[2], vec![c(x, s.q3), c(x, s.whishi)]);
+            assert_eq!(lines[3], vec![c(x - 0.1, s.whislo), c(x + 0.1, s.whislo)]);
+            assert_eq!(lines[4], vec![c(x - 0.1, s.whishi), c(x + 0.1, s.whishi)]);
+        }
+        assert_eq!(*circles.borrow(), vec![c(0.0, 0.5), c(0.0, 9.5)]);
+    }
+}
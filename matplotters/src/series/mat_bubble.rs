@@ -0,0 +1,255 @@
+use crate::chart::{LegendGlyph, StyledSeries};
+use crate::element::{Circle, MarkerShape};
+use crate::style::{RcParams, ShapeStyle};
+
+/// How the size values of a [`MatBubbleSeries`] are mapped to the marker radii
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SizeScale {
+    /// The radii grow linearly with the size values
+    Linear,
+    /// The radii grow with the logarithm of the size values, so that sizes spanning orders
+    /// of magnitude stay comparable. Sizes that are not positive have no logarithm and are
+    /// drawn with the smallest radius.
+    Log,
+}
+
+/**
+A bubble chart, like matplotlib's `scatter(x, y, s=sizes)`, where each point is drawn as a
+circle whose radius tells a third variable.
+
+The smallest size value is drawn with the smallest radius, the largest one with the
+largest radius, see [`MatBubbleSeries::radius_range`]; the values in between are
+interpolated according to the [`SizeScale`], see [`MatBubbleSeries::size_scale`]. A fourth
+variable can be told by the opacity of the bubbles, see [`MatBubbleSeries::alpha_by`].
+*/
+pub struct MatBubbleSeries {
+    style: ShapeStyle,
+    radius_range: (f64, f64),
+    size_scale: SizeScale,
+    // (x, y, size)
+    points: Vec<(f64, f64, f64)>,
+    // The range of the scaled sizes, computed when the first bubble is drawn
+    scaled_range: Option<(f64, f64)>,
+    alphas: Vec<f64>,
+    idx: usize,
+}
+
+impl MatBubbleSeries {
+    /// The smallest and the largest radius in pixels, by default
+    const DEFAULT_RADIUS_RANGE: (f64, f64) = (2.0, 20.0);
+    /// The opacity of the bubble with the smallest value of [`MatBubbleSeries::alpha_by`]
+    const MIN_ALPHA: f64 = 0.1;
+
+    /**
+    Creates a bubble chart from `(x, y, size)` triples, with linearly scaled radii.
+
+    See [`MatBubbleSeries`] for more information.
+    */
+    pub fn new<I, S>(points: I, style: S) -> Self
+    where
+        I: IntoIterator<Item = (f64, f64, f64)>,
+        S: Into<ShapeStyle>,
+    {
+        Self {
+            style: style.into(),
+            radius_range: Self::DEFAULT_RADIUS_RANGE,
+            size_scale: SizeScale::Linear,
+            points: points.into_iter().collect(),
+            scaled_range: None,
+            alphas: vec![],
+            idx: 0,
+        }
+    }
+
+    /**
+    Sets the radii in pixels of the smallest and the largest bubbles.
+
+    See [`MatBubbleSeries`] for more information.
+    */
+    pub fn radius_range(mut self, min: f64, max: f64) -> Self {
+        self.radius_range = (min, max);
+        self
+    }
+
+    /**
+    Sets how the size values are mapped to the radii.
+
+    See [`MatBubbleSeries`] for more information.
+    */
+    pub fn size_scale(mut self, scale: SizeScale) -> Self {
+        self.size_scale = scale;
+        self
+    }
+
+    /**
+    Sets the opacity of each bubble from a value, such as the importance or the confidence of
+    the point, in the order of the points. The values are normalized over their range: the
+    largest one keeps the opacity of the style, and the smallest one is drawn at 10% of it.
+    The bubbles without a finite value keep the opacity of the style.
+
+    See [`MatBubbleSeries`] for more information.
+    */
+    pub fn alpha_by<I: IntoIterator<Item = f64>>(mut self, values: I) -> Self {
+        let values: Vec<f64> = values.into_iter().collect();
+        let (lo, hi) = values
+            .iter()
+            .filter(|v| v.is_finite())
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), v| {
+                (lo.min(*v), hi.max(*v))
+            });
+        self.alphas = values
+            .into_iter()
+            .map(|v| match v {
+                v if !v.is_finite() => 1.0,
+                v if hi > lo => Self::MIN_ALPHA + (v - lo) / (hi - lo) * (1.0 - Self::MIN_ALPHA),
+                _ => 1.0,
+            })
+            .collect();
+        self
+    }
+
+    fn scaled(&self, size: f64) -> Option<f64> {
+        match self.size_scale {
+            SizeScale::Linear => Some(size),
+            SizeScale::Log if size > 0.0 => Some(size.log10()),
+            SizeScale::Log => None,
+        }
+    }
+
+    fn scaled_range(&self) -> (f64, f64) {
+        self.points
+            .iter()
+            .filter_map(|&(_, _, s)| self.scaled(s))
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), s| {
+                (lo.min(s), hi.max(s))
+            })
+    }
+
+    fn radius(&self, size: f64, (lo, hi): (f64, f64)) -> f64 {
+        let (min_radius, max_radius) = self.radius_range;
+        match self.scaled(size) {
+            Some(s) if hi > lo => min_radius + (s - lo) / (hi - lo) * (max_radius - min_radius),
+            Some(_) => max_radius,
+            None => min_radius,
+        }
+    }
+}
+
+impl StyledSeries for MatBubbleSeries {
+    fn series_style(&self) -> ShapeStyle {
+        self.style
+    }
+
+    /// A circle with the marker size of the [`RcParams`], as the bubbles vary in size
+    fn legend_glyph(&self) -> LegendGlyph {
+        LegendGlyph::Marker(MarkerShape::Circle, RcParams::get().marker_size, self.style)
+    }
+}
+
+impl Iterator for MatBubbleSeries {
+    type Item = Circle<(f64, f64), f64>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let &(x, y, size) = self.points.get(self.idx)?;
+        let mut style = self.style;
+        if let Some(alpha) = self.alphas.get(self.idx) {
+            style.color.3 *= alpha;
+        }
+        self.idx += 1;
+        let range = match self.scaled_range {
+            Some(range) => range,
+            None => *self.scaled_range.insert(self.scaled_range()),
+        };
+        Some(Circle::new((x, y), self.radius(size, range).round(), style))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    fn draw_radii(series: MatBubbleSeries) -> Vec<u32> {
+        let radii = Rc::new(RefCell::new(vec![]));
+        let radii_out = radii.clone();
+        let drawing_area = create_mocked_drawing_area(500, 500, |m| {
+            m.check_draw_circle(move |_, _, _, _, radius| radii_out.borrow_mut().push(radius));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Build chart error");
+        chart.draw_series(series).expect("Drawing Error");
+        drop(chart);
+        drop(drawing_area);
+        let ret = radii.borrow().clone();
+        ret
+    }
+
+    #[test]
+    fn test_log_size_scale() {
+        let points = [
+            (1.0, 1.0, 1.0),
+            (2.0, 2.0, 10.0),
+            (3.0, 3.0, 100.0),
+            (4.0, 4.0, 1000.0),
+        ];
+        let log = MatBubbleSeries::new(points, BLUE.filled())
+            .radius_range(2.0, 32.0)
+            .size_scale(SizeScale::Log);
+        // Every decade adds the same amount to the radius
+        assert_eq!(draw_radii(log), vec![2, 12, 22, 32]);
+
+        let linear = MatBubbleSeries::new(points, BLUE.filled()).radius_range(2.0, 1000.0);
+        assert_eq!(draw_radii(linear), vec![2, 11, 101, 1000]);
+    }
+
+    #[test]
+    fn test_alpha_by() {
+        let alphas = Rc::new(RefCell::new(vec![]));
+        let alphas_out = alphas.clone();
+        let drawing_area = create_mocked_drawing_area(500, 500, |m| {
+            m.check_draw_circle(move |c, _, _, _, _| {
+                assert_eq!((c.0, c.1, c.2), (0, 0, 255));
+                alphas_out.borrow_mut().push(c.3);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Build chart error");
+        let points = [
+            (1.0, 1.0, 1.0),
+            (2.0, 2.0, 1.0),
+            (3.0, 3.0, 1.0),
+            (4.0, 4.0, 1.0),
+            (5.0, 5.0, 1.0),
+        ];
+        let confidence = [0.2, 1.0, 0.6, f64::NAN];
+        chart
+            .draw_series(MatBubbleSeries::new(points, BLUE.mix(0.8).filled()).alpha_by(confidence))
+            .expect("Drawing Error");
+
+        // The higher the value, the more opaque the bubble
+        let alphas = alphas.borrow();
+        assert_eq!(alphas.len(), 5);
+        assert!(alphas[0] < alphas[2] && alphas[2] < alphas[1]);
+        assert!((alphas[0] - 0.08).abs() < 1e-9);
+        assert!((alphas[1] - 0.8).abs() < 1e-9);
+        assert!((alphas[2] - 0.44).abs() < 1e-9);
+        // Without a value, the style is kept
+        assert!((alphas[3] - 0.8).abs() < 1e-9 && (alphas[4] - 0.8).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_log_size_scale_non_positive() {
+        let points = [
+            (1.0, 1.0, 0.0),
+            (2.0, 2.0, -5.0),
+            (3.0, 3.0, 1.0),
+            (4.0, 4.0, 100.0),
+        ];
+        let log = MatBubbleSeries::new(points, RED)
+            .radius_range(4.0, 10.0)
+            .size_scale(SizeScale::Log);
+        assert_eq!(draw_radii(log), vec![4, 4, 4, 10]);
+    }
+}
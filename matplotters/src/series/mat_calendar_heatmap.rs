@@ -0,0 +1,255 @@
+use crate::element::{DynElement, IntoDynElement, Rectangle, Text};
+use crate::style::colors::colormaps::ColorMap;
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{Color, IntoFont, Norm, RGBAColor, TextStyle};
+use chrono::{Datelike, Duration, NaiveDate};
+use plotters_backend::DrawingBackend;
+use std::ops::Range;
+
+/**
+A calendar heatmap, like the contribution graph of GitHub: one cell per day in a grid of
+weeks and weekdays, filled with the color of the value of the day.
+
+The weeks start on Monday, and the week `w` counted from the week of the first day is the
+column spanning x from `w` to `w + 1`; Monday is the top row, spanning y from 6 to 7, and
+Sunday the bottom one, spanning y from 0 to 1, so that the chart is meant to be built over
+[`MatCalendarHeatmap::week_range`] for x and `0.0..7.0` for y. The cell of a date is given
+by [`MatCalendarHeatmap::cell`]. A month is labeled above the first week containing its
+first day, along with the year for the first label and for January.
+
+The days without a value, or whose value is not finite, have no cell.
+*/
+pub struct MatCalendarHeatmap<DB: DrawingBackend> {
+    first_monday: NaiveDate,
+    // (date, color), sorted by date
+    days: Vec<(NaiveDate, RGBAColor)>,
+    gap: f64,
+    elements: Option<std::vec::IntoIter<DynElement<'static, DB, (f64, f64)>>>,
+}
+
+impl<DB: DrawingBackend> MatCalendarHeatmap<DB> {
+    const MONTHS: [&'static str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+
+    /**
+    Creates a calendar heatmap from daily values, colored with
+    `colormap.get_color(norm.normalize(value))`, see [`Norm::colorize`].
+
+    See [`MatCalendarHeatmap`] for more information.
+    */
+    pub fn new<I, C, M>(days: I, colormap: &M, norm: Norm) -> Self
+    where
+        I: IntoIterator<Item = (NaiveDate, f64)>,
+        C: Color,
+        M: ColorMap<C, f64>,
+    {
+        let mut days: Vec<(NaiveDate, RGBAColor)> = days
+            .into_iter()
+            .filter(|(_, v)| v.is_finite())
+            .map(|(d, v)| (d, norm.colorize(colormap, v)))
+            .collect();
+        days.sort_by_key(|(d, _)| *d);
+        // Without any day there is no cell, and the first Monday is never used
+        let first_monday = match days.first() {
+            Some((first, _)) => {
+                let weekday = Duration::days(first.weekday().num_days_from_monday().into());
+                first.checked_sub_signed(weekday).unwrap_or(*first)
+            }
+            None => NaiveDate::MIN,
+        };
+        Self {
+            first_monday,
+            days,
+            gap: 0.1,
+            elements: None,
+        }
+    }
+
+    /**
+    Sets the space between two cells, as a fraction of the cell size. It is 0.1 by default.
+
+    See [`MatCalendarHeatmap`] for more information.
+    */
+    pub fn gap(mut self, gap: f64) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /**
+    Returns the week and the weekday, from 0 for Monday to 6 for Sunday, of the cell of a
+    date.
+
+    See [`MatCalendarHeatmap`] for more information.
+    */
+    pub fn cell(&self, date: NaiveDate) -> (i64, u32) {
+        (
+            (date - self.first_monday).num_days().div_euclid(7),
+            date.weekday().num_days_from_monday(),
+        )
+    }
+
+    /**
+    Returns the x range fitting all the weeks.
+
+    See [`MatCalendarHeatmap`] for more information.
+    */
+    pub fn week_range(&self) -> Range<f64> {
+        let weeks = self.days.last().map_or(0, |(d, _)| self.cell(*d).0 + 1);
+        0.0..weeks as f64
+    }
+
+    fn elements(&self) -> Vec<DynElement<'static, DB, (f64, f64)>> {
+        let inset = self.gap / 2.0;
+        let mut ret = vec![];
+        for (date, color) in &self.days {
+            let (week, weekday) = self.cell(*date);
+            let (x, y) = (week as f64, f64::from(6 - weekday));
+            ret.push(
+                Rectangle::new(
+                    [(x + inset, y + 1.0 - inset), (x + 1.0 - inset, y + inset)],
+                    color.filled(),
+                )
+                .into_dyn(),
+            );
+        }
+
+        let font =
+            TextStyle::from(("sans-serif", 12).into_font()).pos(Pos::new(HPos::Left, VPos::Bottom));
+        let mut months = self
+            .days
+            .iter()
+            .map(|(d, _)| (d.year(), d.month()))
+            .collect::<Vec<_>>();
+        months.dedup();
+        for (i, (year, month)) in months.into_iter().enumerate() {
+            let start = NaiveDate::from_ymd_opt(year, month, 1).unwrap_or(self.first_monday);
+            let week = self.cell(start.max(self.first_monday)).0;
+            let name = Self::MONTHS[month as usize - 1];
+            let label = if i == 0 || month == 1 {
+                format!("{} {}", name, year)
+            } else {
+                name.to_string()
+            };
+            ret.push(Text::new(label, (week as f64, 7.0), font.clone()).into_dyn());
+        }
+        ret
+    }
+}
+
+impl<DB: DrawingBackend> Iterator for MatCalendarHeatmap<DB> {
+    type Item = DynElement<'static, DB, (f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.elements.is_none() {
+            self.elements = Some(self.elements().into_iter());
+        }
+        self.elements.as_mut()?.next()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use chrono::NaiveDate;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_calendar_heatmap() {
+        let rects = Rc::new(RefCell::new(vec![]));
+        let texts = Rc::new(RefCell::new(vec![]));
+        let (rects_out, texts_out) = (rects.clone(), texts.clone());
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_rect(move |c, _, filled, ul, br| {
+                assert!(filled);
+                rects_out.borrow_mut().push((c, ul, br));
+            });
+            m.check_draw_text(move |_, _, _, _, text| {
+                texts_out.borrow_mut().push(text.to_string())
+            });
+        });
+
+        // April 2021 starts on a Thursday and ends on a Friday, over five weeks
+        let date = |day| NaiveDate::from_ymd_opt(2021, 4, day).unwrap();
+        let days: Vec<_> = (1..=30).map(|d| (date(d), f64::from(d % 7))).collect();
+        let norm = Norm::new(0.0, 6.0);
+        let heatmap = MatCalendarHeatmap::new(days.clone(), &ViridisRGB {}, norm);
+        assert_eq!(heatmap.cell(date(1)), (0, 3));
+        assert_eq!(heatmap.cell(date(4)), (0, 6));
+        assert_eq!(heatmap.cell(date(5)), (1, 0));
+        assert_eq!(heatmap.cell(date(30)), (4, 4));
+        assert_eq!(heatmap.week_range(), 0.0..5.0);
+        let cells: Vec<_> = days.iter().map(|(d, _)| heatmap.cell(*d)).collect();
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(heatmap.week_range(), 0.0..7.0)
+            .expect("Build chart error");
+        chart.draw_series(heatmap).expect("Drawing Error");
+
+        let rects = rects.borrow();
+        assert_eq!(rects.len(), 30);
+        for (((c, ul, br), (_, value)), (week, weekday)) in rects.iter().zip(&days).zip(cells) {
+            let center = (week as f64 + 0.5, 6.5 - f64::from(weekday));
+            let (x, y) = chart.backend_coord(&center);
+            assert!(ul.0 < x && x < br.0 && ul.1 < y && y < br.1);
+            let expected: RGBColor = ViridisRGB::get_color(norm.normalize(*value));
+            assert_eq!(*c, expected.to_rgba());
+        }
+        assert_eq!(*texts.borrow(), vec!["Apr 2021"]);
+    }
+
+    #[test]
+    fn test_calendar_year_boundary() {
+        let texts = Rc::new(RefCell::new(vec![]));
+        let texts_out = texts.clone();
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_text(move |_, _, _, _, text| {
+                texts_out.borrow_mut().push(text.to_string())
+            });
+        });
+
+        let days = [
+            (NaiveDate::from_ymd_opt(2021, 12, 30).unwrap(), 1.0),
+            (NaiveDate::from_ymd_opt(2022, 1, 2).unwrap(), 2.0),
+            (NaiveDate::from_ymd_opt(2022, 1, 3).unwrap(), 3.0),
+        ];
+        let heatmap = MatCalendarHeatmap::new(days, &ViridisRGB {}, Norm::new(0.0, 3.0));
+        // The first days of the new year stay in the last week of the old one
+        assert_eq!(heatmap.cell(days[0].0), (0, 3));
+        assert_eq!(heatmap.cell(days[1].0), (0, 6));
+        assert_eq!(heatmap.cell(days[2].0), (1, 0));
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(heatmap.week_range(), 0.0..7.0)
+            .expect("Build chart error");
+        chart.draw_series(heatmap).expect("Drawing Error");
+        assert_eq!(*texts.borrow(), vec!["Dec 2021", "Jan 2022"]);
+    }
+
+    #[test]
+    fn test_calendar_without_days() {
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 0);
+                assert_eq!(b.num_draw_text_call, 0);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..1.0, 0.0..7.0)
+            .expect("Build chart error");
+
+        let norm = Norm::new(0.0, 1.0);
+        let empty = MatCalendarHeatmap::new(vec![], &ViridisRGB {}, norm);
+        assert_eq!(empty.week_range(), 0.0..0.0);
+        chart.draw_series(empty).expect("Drawing Error");
+
+        let date = NaiveDate::from_ymd_opt(2021, 4, 1).unwrap();
+        let nan = MatCalendarHeatmap::new([(date, f64::NAN)], &ViridisRGB {}, norm);
+        assert_eq!(nan.week_range(), 0.0..0.0);
+        chart.draw_series(nan).expect("Drawing Error");
+
+        // The week of the earliest date is cut short instead of starting before it
+        let first: MatCalendarHeatmap<MockedBackend> =
+            MatCalendarHeatmap::new([(NaiveDate::MIN, 1.0)], &ViridisRGB {}, norm);
+        assert_eq!(first.cell(NaiveDate::MIN).0, 0);
+        assert_eq!(first.week_range(), 0.0..1.0);
+    }
+}
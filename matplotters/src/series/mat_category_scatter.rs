@@ -0,0 +1,348 @@
+use crate::chart::{LegendGlyph, StyledSeries};
+use crate::element::{Marker, MarkerShape};
+use crate::style::{Color, ColorCycle, MarkerCycle, RcParams, ShapeStyle};
+
+/**
+A categorical scatter plot, like seaborn's `scatterplot(style=category)`, where the marker
+shape of each point tells its category.
+
+The points are split into one series per category, in the order the categories first
+appear. The categories take the shapes of the [`MarkerCycle`] in turn: circles for the first
+one, squares for the second one, and so on. Drawing the series separately allows to show each
+category in the legend with its marker, see [`crate::chart::SeriesAnno::legend_marker`]:
+
+```rust
+use matplotters::prelude::*;
+
+let drawing_area = SVGBackend::new("category_scatter.svg", (300, 200)).into_drawing_area();
+let mut chart = ChartBuilder::on(&drawing_area).build_cartesian_2d(0.0..4.0, 0.0..4.0).unwrap();
+let points = [(1.0, 1.0), (2.0, 3.0), (3.0, 2.0)];
+for series in MatCategoryScatter::new(points, ["a", "b", "a"], BLUE.filled()) {
+    let (category, marker, style) = (series.category().to_string(), series.marker(), series.series_style());
+    chart.draw_series(series).unwrap().label(category).legend_marker(marker, 4, style);
+}
+chart.configure_series_labels().draw().unwrap();
+```
+
+The categories can be told by their colors instead, see [`MatCategoryScatter::by_color`], or
+by the sizes of their markers when they are ordered, see [`MatCategoryScatter::ordinal`], and
+[`crate::chart::ChartContext::draw_category_scatter`] draws all of them with their legend
+entries at once.
+*/
+pub struct MatCategoryScatter {
+    category: String,
+    marker: MarkerShape,
+    style: ShapeStyle,
+    point_size: u32,
+    points: Vec<(f64, f64)>,
+    idx: usize,
+}
+
+impl MatCategoryScatter {
+    /**
+    Creates one series per category from the points and their categories, which are paired
+    in order. The points are drawn with the marker size of the [`RcParams`].
+
+    See [`MatCategoryScatter`] for more information.
+    */
+    pub fn new<P, C, L, S>(points: P, categories: C, style: S) -> Vec<Self>
+    where
+        P: IntoIterator<Item = (f64, f64)>,
+        C: IntoIterator<Item = L>,
+        L: Into<String>,
+        S: Into<ShapeStyle>,
+    {
+        let style = style.into();
+        let mut markers = MarkerCycle::default();
+        Self::group(points, categories, || (markers.next_marker(), style))
+    }
+
+    /**
+    Creates one series per category like [`MatCategoryScatter::new`], but the categories are
+    told by their colors: they take the colors of the default [`ColorCycle`] in turn, with
+    filled circles.
+
+    See [`MatCategoryScatter`] for more information.
+    */
+    pub fn by_color<P, C, L>(points: P, categories: C) -> Vec<Self>
+    where
+        P: IntoIterator<Item = (f64, f64)>,
+        C: IntoIterator<Item = L>,
+        L: Into<String>,
+    {
+        let mut colors = ColorCycle::default();
+        Self::group(points, categories, || {
+            (MarkerShape::Circle, colors.next_color().filled())
+        })
+    }
+
+    /**
+    Creates one series per category of ordinal data, such as ratings or grades, where the
+    size of the markers encodes the order: the categories are taken in the order given by
+    `order`, from the smallest markers of `sizes.0` pixels to the largest ones of `sizes.1`
+    pixels, with the sizes in between spread evenly, all filled circles in the style. The
+    series come in the same order, so drawing them with
+    [`crate::chart::ChartContext::draw_category_scatter`] lists the categories in order in the
+    legend, with their marker sizes. The points of a category missing from `order` are left
+    out, and so are the categories without points.
+
+    See [`MatCategoryScatter`] for more information.
+    */
+    pub fn ordinal<P, C, L, O, S>(
+        points: P,
+        categories: C,
+        order: O,
+        sizes: (u32, u32),
+        style: S,
+    ) -> Vec<Self>
+    where
+        P: IntoIterator<Item = (f64, f64)>,
+        C: IntoIterator<Item = L>,
+        L: Into<String>,
+        O: IntoIterator,
+        O::Item: Into<String>,
+        S: Into<ShapeStyle>,
+    {
+        let style = style.into();
+        let order: Vec<String> = order.into_iter().map(Into::into).collect();
+        let last = order.len().saturating_sub(1).max(1) as f64;
+        let mut ret: Vec<Self> = order
+            .iter()
+            .enumerate()
+            .map(|(rank, category)| {
+                let size = sizes.0 as f64 + (sizes.1 as f64 - sizes.0 as f64) * rank as f64 / last;
+                Self {
+                    category: category.clone(),
+                    marker: MarkerShape::Circle,
+                    style,
+                    point_size: size.round() as u32,
+                    points: vec![],
+                    idx: 0,
+                }
+            })
+            .collect();
+        for (point, category) in points.into_iter().zip(categories) {
+            let category = category.into();
+            if let Some(series) = ret.iter_mut().find(|s| s.category == category) {
+                series.points.push(point);
+            }
+        }
+        ret.retain(|s| !s.points.is_empty());
+        ret
+    }
+
+    /// Split the points by category, the marker and the style of each new category being
+    /// given by `assign`
+    fn group<P, C, L, F>(points: P, categories: C, mut assign: F) -> Vec<Self>
+    where
+        P: IntoIterator<Item = (f64, f64)>,
+        C: IntoIterator<Item = L>,
+        L: Into<String>,
+        F: FnMut() -> (MarkerShape, ShapeStyle),
+    {
+        let point_size = RcParams::get().marker_size;
+        let mut ret: Vec<Self> = vec![];
+        for (point, category) in points.into_iter().zip(categories) {
+            let category = category.into();
+            match ret.iter_mut().find(|s| s.category == category) {
+                Some(series) => series.points.push(point),
+                None => {
+                    let (marker, style) = assign();
+                    ret.push(Self {
+                        category,
+                        marker,
+                        style,
+                        point_size,
+                        points: vec![point],
+                        idx: 0,
+                    })
+                }
+            }
+        }
+        ret
+    }
+
+    /**
+    Sets the size of the points, in pixels.
+
+    See [`MatCategoryScatter`] for more information.
+    */
+    pub fn point_size(mut self, size: u32) -> Self {
+        self.point_size = size;
+        self
+    }
+
+    /**
+    Returns the category of the points of the series.
+
+    See [`MatCategoryScatter`] for more information.
+    */
+    pub fn category(&self) -> &str {
+        &self.category
+    }
+
+    /**
+    Returns the points of the category.
+
+    See [`MatCategoryScatter`] for more information.
+    */
+    pub fn points(&self) -> &[(f64, f64)] {
+        &self.points
+    }
+
+    /**
+    Returns the size of the markers of the category, in pixels.
+
+    See [`MatCategoryScatter`] for more information.
+    */
+    pub fn size(&self) -> u32 {
+        self.point_size
+    }
+
+    /**
+    Returns the marker shape assigned to the category.
+
+    See [`MatCategoryScatter`] for more information.
+    */
+    pub fn marker(&self) -> MarkerShape {
+        self.marker
+    }
+}
+
+impl StyledSeries for MatCategoryScatter {
+    fn series_style(&self) -> ShapeStyle {
+        self.style
+    }
+
+    fn legend_glyph(&self) -> LegendGlyph {
+        LegendGlyph::Marker(self.marker, self.point_size, self.style)
+    }
+}
+
+impl Iterator for MatCategoryScatter {
+    type Item = Marker<(f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let point = *self.points.get(self.idx)?;
+        self.idx += 1;
+        Some(Marker::new(point, self.point_size, self.marker, self.style))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_category_markers() {
+        let circles = Rc::new(RefCell::new(vec![]));
+        let rects = Rc::new(RefCell::new(vec![]));
+        let (circles_out, rects_out) = (circles.clone(), rects.clone());
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_circle(move |_, _, _, center, _| circles_out.borrow_mut().push(center));
+            m.check_draw_rect(move |_, _, _, ul, br| rects_out.borrow_mut().push((ul, br)));
+            m.drop_check(|b| {
+                // The triangles of the third category, and the one of its legend entry
+                assert_eq!(b.num_fill_polygon_call, 2);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Build chart error");
+
+        let points = [(1.0, 1.0), (2.0, 5.0), (3.0, 2.0), (4.0, 8.0), (5.0, 4.0)];
+        let categories = ["setosa", "virginica", "setosa", "versicolor", "virginica"];
+        let layers = MatCategoryScatter::new(points, categories, RED.filled());
+        let summary: Vec<_> = layers.iter().map(|s| (s.category(), s.marker())).collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("setosa", MarkerShape::Circle),
+                ("virginica", MarkerShape::Square),
+                ("versicolor", MarkerShape::TriangleUp),
+            ]
+        );
+
+        for series in layers {
+            let (category, marker) = (series.category().to_string(), series.marker());
+            chart
+                .draw_series(series.point_size(3))
+                .expect("Drawing Error")
+                .label(category)
+                .legend_marker(marker, 3, RED.filled());
+        }
+        let drawn = (circles.borrow().len(), rects.borrow().len());
+        // The circles of "setosa" and the squares of "virginica"
+        assert_eq!(
+            *circles.borrow(),
+            vec![
+                chart.backend_coord(&points[0]),
+                chart.backend_coord(&points[2])
+            ]
+        );
+        let square_at = |p: &(f64, f64)| {
+            let (x, y) = chart.backend_coord(p);
+            ((x - 3, y - 3), (x + 3, y + 3))
+        };
+        assert_eq!(
+            *rects.borrow(),
+            vec![square_at(&points[1]), square_at(&points[4])]
+        );
+
+        chart
+            .configure_series_labels()
+            .draw()
+            .expect("Drawing Error");
+        // The legend has one glyph of the matching shape per category
+        assert_eq!(circles.borrow().len(), drawn.0 + 1);
+        let squares = rects.borrow()[drawn.1..]
+            .iter()
+            .filter(|(ul, br)| (br.0 - ul.0, br.1 - ul.1) == (6, 6))
+            .count();
+        assert_eq!(squares, 1);
+    }
+
+    #[test]
+    fn test_ordinal_sizes() {
+        let circles = Rc::new(RefCell::new(vec![]));
+        let circles_out = circles.clone();
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_circle(move |_, _, _, center, r| {
+                circles_out.borrow_mut().push((center, r))
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Build chart error");
+
+        let points = [(1.0, 1.0), (2.0, 5.0), (3.0, 2.0), (4.0, 8.0), (5.0, 4.0)];
+        let grades = ["high", "low", "medium", "low", "unrated"];
+        let layers = MatCategoryScatter::ordinal(
+            points,
+            grades,
+            ["low", "medium", "high"],
+            (2, 8),
+            BLUE.filled(),
+        );
+        let summary: Vec<_> = layers.iter().map(|s| (s.category(), s.size())).collect();
+        assert_eq!(summary, vec![("low", 2), ("medium", 5), ("high", 8)]);
+        assert_eq!(layers[0].points(), &[points[1], points[3]]);
+
+        chart.draw_category_scatter(layers).expect("Drawing Error");
+        let radii: Vec<_> = circles.borrow().iter().map(|(_, r)| *r).collect();
+        assert_eq!(radii, vec![2, 2, 5, 8]);
+
+        chart
+            .configure_series_labels()
+            .draw()
+            .expect("Drawing Error");
+        // The legend lists the grades from top to bottom in order, with growing markers
+        let legend = circles.borrow()[4..].to_vec();
+        assert_eq!(
+            legend.iter().map(|(_, r)| *r).collect::<Vec<_>>(),
+            vec![2, 5, 8]
+        );
+        assert!(legend.windows(2).all(|w| w[0].0 .1 < w[1].0 .1));
+    }
+}
@@ -0,0 +1,177 @@
+use crate::element::Rectangle;
+use crate::style::colors::colormaps::ColorMap;
+use crate::style::{Color, Norm, RGBAColor};
+use std::ops::Range;
+
+/// The length of the extension triangles of a [`MatColorbar`], as a fraction of the length of
+/// the bar, as in matplotlib
+const EXTEND_FRACTION: f64 = 0.05;
+
+/**
+A continuous colorbar, showing the colors of a colormap over the value range of a norm.
+
+The colorbar is a stack of slabs spanning x from 0 to 1 and y from `vmin` to `vmax`, each
+filled with the color of its middle value. It is meant to be drawn on its own chart, built
+over `0.0..1.0` and [`MatColorbar::range`], so that the y axis labels the values. A
+horizontal colorbar (see [`MatColorbar::horizontal`]) swaps the two axes.
+
+When the norm is extended, see [`Norm::extend`], [`crate::chart::ColorbarLayout`] draws a
+triangle beyond each extended end of the colorbar, in the under or over color.
+
+[`crate::chart::ColorbarLayout`] reserves the area of the colorbar next to a chart and draws it.
+*/
+pub struct MatColorbar {
+    norm: Norm,
+    colors: Vec<RGBAColor>,
+    under: RGBAColor,
+    over: RGBAColor,
+    horizontal: bool,
+    idx: usize,
+}
+
+impl MatColorbar {
+    /**
+    Creates a colorbar sampling the colormap in `steps` slabs.
+
+    See [`MatColorbar`] for more information.
+    */
+    pub fn new<C: Color, M: ColorMap<C, f64>>(colormap: &M, norm: Norm, steps: usize) -> Self {
+        let steps = steps.max(1);
+        let colors = (0..steps)
+            .map(|i| {
+                colormap
+                    .get_color((i as f64 + 0.5) / steps as f64)
+                    .to_rgba()
+            })
+            .collect();
+        let end_color = |value: f64, end: f64| {
+            norm.extended_color(value)
+                .unwrap_or_else(|| colormap.get_color(end).to_rgba())
+        };
+        Self {
+            norm,
+            colors,
+            under: end_color(f64::NEG_INFINITY, 0.0),
+            over: end_color(f64::INFINITY, 1.0),
+            horizontal: false,
+            idx: 0,
+        }
+    }
+
+    /**
+    Lays the colorbar out horizontally, with the values along the x axis and the slabs
+    spanning y from 0 to 1.
+
+    See [`MatColorbar`] for more information.
+    */
+    pub fn horizontal(mut self) -> Self {
+        self.horizontal = true;
+        self
+    }
+
+    /**
+    Returns the value range of the colorbar, to be used as the y range of its chart, or the x
+    range if it is horizontal.
+
+    See [`MatColorbar`] for more information.
+    */
+    pub fn range(&self) -> Range<f64> {
+        self.norm.vmin()..self.norm.vmax()
+    }
+
+    /// The triangles of the extended ends of the norm with their colors, pointing away from
+    /// the bar beyond the range of the colorbar
+    pub(crate) fn extensions(&self) -> Vec<(Vec<(f64, f64)>, RGBAColor)> {
+        let (vmin, vmax) = (self.norm.vmin(), self.norm.vmax());
+        let length = (vmax - vmin) * EXTEND_FRACTION;
+        let extend = self.norm.get_extend();
+        let triangle = |base: f64, tip: f64, color: RGBAColor| {
+            let points = if self.horizontal {
+                vec![(base, 0.0), (base, 1.0), (tip, 0.5)]
+            } else {
+                vec![(0.0, base), (1.0, base), (0.5, tip)]
+            };
+            (points, color)
+        };
+        let mut ret = vec![];
+        if extend.has_min() {
+            ret.push(triangle(vmin, vmin - length, self.under));
+        }
+        if extend.has_max() {
+            ret.push(triangle(vmax, vmax + length, self.over));
+        }
+        ret
+    }
+
+    /// The margins to leave before and after the bar for the extension triangles, in pixels,
+    /// when the bar and its triangles span `length` pixels
+    pub(crate) fn extension_margins(&self, length: u32) -> (u32, u32) {
+        let extend = self.norm.get_extend();
+        let ends = u8::from(extend.has_min()) + u8::from(extend.has_max());
+        let margin =
+            f64::from(length) * EXTEND_FRACTION / (1.0 + f64::from(ends) * EXTEND_FRACTION);
+        let margin = margin.round() as u32;
+        (
+            if extend.has_min() { margin } else { 0 },
+            if extend.has_max() { margin } else { 0 },
+        )
+    }
+}
+
+impl Iterator for MatColorbar {
+    type Item = Rectangle<(f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let color = *self.colors.get(self.idx)?;
+        let steps = self.colors.len() as f64;
+        let (vmin, vmax) = (self.norm.vmin(), self.norm.vmax());
+        let value_at = |i: usize| vmin + (vmax - vmin) * i as f64 / steps;
+        let (bottom, top) = (value_at(self.idx), value_at(self.idx + 1));
+        self.idx += 1;
+        let corners = if self.horizontal {
+            [(bottom, 1.0), (top, 0.0)]
+        } else {
+            [(0.0, top), (1.0, bottom)]
+        };
+        Some(Rectangle::new(corners, color.filled()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_colorbar() {
+        let rects = Rc::new(RefCell::new(vec![]));
+        let rects_out = rects.clone();
+        let drawing_area = create_mocked_drawing_area(51, 501, |m| {
+            m.check_draw_rect(move |c, _, filled, ul, br| {
+                assert!(filled);
+                rects_out.borrow_mut().push((c, ul, br));
+            });
+        });
+
+        let norm = Norm::new(2.0, 8.0);
+        let colorbar = MatColorbar::new(&ViridisRGB {}, norm, 10);
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..1.0, colorbar.range())
+            .expect("Build chart error");
+        chart.draw_series(colorbar).expect("Drawing Error");
+
+        let rects = rects.borrow();
+        assert_eq!(rects.len(), 10);
+        // The bar covers exactly the value range of the norm
+        assert_eq!(rects[0].2, chart.backend_coord(&(1.0, 2.0)));
+        assert_eq!(rects[9].1, chart.backend_coord(&(0.0, 8.0)));
+        // Each slab has the color of the value at its middle
+        for (i, (c, ul, br)) in rects.iter().enumerate() {
+            let mid = 2.0 + 6.0 * (i as f64 + 0.5) / 10.0;
+            let y = chart.backend_coord(&(0.5, mid)).1;
+            assert!(ul.1 <= y && y <= br.1);
+            let expected: RGBColor = ViridisRGB::get_color(norm.normalize(mid));
+            assert_eq!(*c, expected.to_rgba());
+        }
+    }
+}
@@ -0,0 +1,233 @@
+use crate::data::contour_lines;
+use crate::element::{Circle, DynElement, IntoDynElement, PathElement, Rectangle};
+use crate::style::{Color, ColorCycle, RGBColor, ShapeStyle, BLACK};
+use plotters_backend::DrawingBackend;
+use std::ops::Range;
+
+/**
+The decision boundary of a binary classifier over a 2D feature space, such as a logistic
+regression, drawn as the contour where its decision function is 0.5.
+
+The decision function, usually the probability of the second class, is sampled on a grid of
+[`MatDecisionBoundary::resolution`] cells per axis over the given ranges, and the contour at
+0.5 is traced through the grid, see [`contour_lines`]. The two class regions can be shaded,
+see [`MatDecisionBoundary::shade`], and labeled points drawn on top, see
+[`MatDecisionBoundary::points`]. The first class, where the function is below 0.5, takes the
+first color of the default [`ColorCycle`], and the second class the second color.
+*/
+pub struct MatDecisionBoundary<DB: DrawingBackend> {
+    func: Box<dyn Fn(f64, f64) -> f64>,
+    x: Range<f64>,
+    y: Range<f64>,
+    resolution: usize,
+    style: ShapeStyle,
+    shade: bool,
+    points: Vec<(f64, f64, bool)>,
+    elements: Option<std::vec::IntoIter<DynElement<'static, DB, (f64, f64)>>>,
+}
+
+impl<DB: DrawingBackend> MatDecisionBoundary<DB> {
+    /// The level of the decision function the boundary is drawn at
+    const LEVEL: f64 = 0.5;
+
+    /**
+    Creates the decision boundary of a decision function over the given ranges, drawn in
+    black, with 50 cells per axis.
+
+    See [`MatDecisionBoundary`] for more information.
+    */
+    pub fn new<F: Fn(f64, f64) -> f64 + 'static>(x: Range<f64>, y: Range<f64>, func: F) -> Self {
+        Self {
+            func: Box::new(func),
+            x,
+            y,
+            resolution: 50,
+            style: BLACK.stroke_width(2),
+            shade: false,
+            points: vec![],
+            elements: None,
+        }
+    }
+
+    /**
+    Sets the number of grid cells per axis the decision function is sampled on.
+
+    See [`MatDecisionBoundary`] for more information.
+    */
+    pub fn resolution(mut self, cells: usize) -> Self {
+        self.resolution = cells.max(1);
+        self
+    }
+
+    /**
+    Sets the style of the boundary line.
+
+    See [`MatDecisionBoundary`] for more information.
+    */
+    pub fn style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /**
+    Shades every grid cell with the color of the class of its center, at 20% opacity.
+
+    See [`MatDecisionBoundary`] for more information.
+    */
+    pub fn shade(mut self) -> Self {
+        self.shade = true;
+        self
+    }
+
+    /**
+    Sets the labeled points drawn over the regions, as `(x, y, label)` where the label is
+    `true` for the second class.
+
+    See [`MatDecisionBoundary`] for more information.
+    */
+    pub fn points<I: IntoIterator<Item = (f64, f64, bool)>>(mut self, points: I) -> Self {
+        self.points = points.into_iter().collect();
+        self
+    }
+
+    fn grid(&self) -> (Vec<f64>, Vec<f64>, Vec<Vec<f64>>) {
+        let n = self.resolution;
+        let steps = |r: &Range<f64>| -> Vec<f64> {
+            (0..=n)
+                .map(|i| r.start + (r.end - r.start) * i as f64 / n as f64)
+                .collect()
+        };
+        let (xs, ys) = (steps(&self.x), steps(&self.y));
+        let values = ys
+            .iter()
+            .map(|y| xs.iter().map(|x| (self.func)(*x, *y)).collect())
+            .collect();
+        (xs, ys, values)
+    }
+
+    fn elements(&self) -> Vec<DynElement<'static, DB, (f64, f64)>> {
+        let mut cycle = ColorCycle::default();
+        let colors: [RGBColor; 2] = [cycle.next_color(), cycle.next_color()];
+        let (xs, ys, values) = self.grid();
+        let mut ret = vec![];
+        if self.shade {
+            for y in ys.windows(2) {
+                for x in xs.windows(2) {
+                    let value = (self.func)((x[0] + x[1]) / 2.0, (y[0] + y[1]) / 2.0);
+                    let color = colors[usize::from(value >= Self::LEVEL)].mix(0.2);
+                    ret.push(
+                        Rectangle::new([(x[0], y[1]), (x[1], y[0])], color.filled()).into_dyn(),
+                    );
+                }
+            }
+        }
+        for segment in contour_lines(&xs, &ys, &values, Self::LEVEL) {
+            ret.push(PathElement::new(segment.to_vec(), self.style).into_dyn());
+        }
+        for &(x, y, label) in &self.points {
+            ret.push(Circle::new((x, y), 4, colors[usize::from(label)].filled()).into_dyn());
+        }
+        ret
+    }
+}
+
+impl<DB: DrawingBackend> Iterator for MatDecisionBoundary<DB> {
+    type Item = DynElement<'static, DB, (f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.elements.is_none() {
+            self.elements = Some(self.elements().into_iter());
+        }
+        self.elements.as_mut()?.next()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_decision_boundary() {
+        let segments = Rc::new(RefCell::new(vec![]));
+        let cells = Rc::new(RefCell::new(vec![]));
+        let (segments_out, cells_out) = (segments.clone(), cells.clone());
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_path(move |c, _, path| {
+                assert_eq!(c, BLACK.to_rgba());
+                segments_out.borrow_mut().push(path);
+            });
+            m.check_draw_rect(move |c, _, _, ul, br| cells_out.borrow_mut().push((c, ul, br)));
+            m.drop_check(|b| assert_eq!(b.num_draw_circle_call, 8));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Build chart error");
+
+        // A logistic regression separating the points under and over the line x + y = 10
+        let points = [
+            (1.0, 2.0, false),
+            (3.0, 4.0, false),
+            (6.0, 1.0, false),
+            (2.0, 6.5, false),
+            (8.0, 9.0, true),
+            (5.0, 7.0, true),
+            (9.0, 3.0, true),
+            (4.0, 8.5, true),
+        ];
+        let boundary = MatDecisionBoundary::new(0.0..10.0, 0.0..10.0, |x, y| {
+            1.0 / (1.0 + (10.0 - x - y).exp())
+        })
+        .resolution(20)
+        .shade()
+        .points(points);
+        chart.draw_series(boundary).expect("Drawing Error");
+
+        let (first, second) = (
+            chart.backend_coord(&(0.0, 10.0)),
+            chart.backend_coord(&(10.0, 0.0)),
+        );
+        // The side of the boundary line a pixel is on
+        let side = |(x, y): (i32, i32)| {
+            let cross = (second.0 - first.0) * (y - first.1) - (second.1 - first.1) * (x - first.0);
+            cross.signum()
+        };
+        let segments = segments.borrow();
+        assert!(segments.len() >= 20);
+        for p in segments.iter().flatten() {
+            // Every point of the contour is on the line, up to the rounding to pixels
+            let cross =
+                (second.0 - first.0) * (p.1 - first.1) - (second.1 - first.1) * (p.0 - first.0);
+            let distance = f64::from(cross.abs())
+                / f64::from(second.0 - first.0).hypot(f64::from(second.1 - first.1));
+            assert!(distance <= 1.5, "{:?}", p);
+        }
+        // The contour separates the two classes
+        let (under, over) = (
+            side(chart.backend_coord(&(0.0, 0.0))),
+            side(chart.backend_coord(&(10.0, 10.0))),
+        );
+        assert_ne!(under, over);
+        for (x, y, label) in points {
+            let expected = if label { over } else { under };
+            assert_eq!(side(chart.backend_coord(&(x, y))), expected);
+        }
+
+        // The cells are shaded with the color of their class
+        let mut cycle = ColorCycle::default();
+        let colors = [
+            cycle.next_color().mix(0.2).to_rgba(),
+            cycle.next_color().mix(0.2).to_rgba(),
+        ];
+        let cells = cells.borrow();
+        assert_eq!(cells.len(), 400);
+        for (c, ul, br) in cells.iter() {
+            let center = ((ul.0 + br.0) / 2, (ul.1 + br.1) / 2);
+            if side(center) == under {
+                assert_eq!(*c, colors[0]);
+            } else if side(center) == over {
+                assert_eq!(*c, colors[1]);
+            }
+        }
+    }
+}
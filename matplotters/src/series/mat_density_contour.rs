@@ -0,0 +1,191 @@
+use crate::data::{contour_lines, kde_2d, scott_bandwidth};
+use crate::element::{Circle, DynElement, IntoDynElement, PathElement};
+use crate::style::{Color, ShapeStyle, BLACK, BLUE};
+use plotters_backend::DrawingBackend;
+
+/**
+A scatter of points overlaid with the contour lines of their density, like seaborn's
+`kdeplot` over a `scatterplot`, to show the structure of a dense point cloud.
+
+The density is estimated with a gaussian kernel, see [`kde_2d`], on a grid of
+[`MatDensityContour::grid`] cells per axis spanning the points with a margin of three
+bandwidths, and the contours are traced through the grid, see [`contour_lines`]. The `n`
+levels of [`MatDensityContour::levels`] evenly divide the range from zero to the highest
+density: the level `k` is `k / (n + 1)` of the highest density. The points are drawn first, as
+small half transparent blue dots, and the contours in black on top of them.
+*/
+pub struct MatDensityContour<DB: DrawingBackend> {
+    points: Vec<(f64, f64)>,
+    point_style: ShapeStyle,
+    style: ShapeStyle,
+    levels: usize,
+    grid: usize,
+    bandwidth: Option<(f64, f64)>,
+    elements: Option<std::vec::IntoIter<DynElement<'static, DB, (f64, f64)>>>,
+}
+
+impl<DB: DrawingBackend> MatDensityContour<DB> {
+    /**
+    Creates a scatter of the points with 5 density contours on a 50 x 50 grid.
+
+    See [`MatDensityContour`] for more information.
+    */
+    pub fn new<I: IntoIterator<Item = (f64, f64)>>(points: I) -> Self {
+        Self {
+            points: points
+                .into_iter()
+                .filter(|(x, y)| x.is_finite() && y.is_finite())
+                .collect(),
+            point_style: BLUE.mix(0.5).filled(),
+            style: BLACK.into(),
+            levels: 5,
+            grid: 50,
+            bandwidth: None,
+            elements: None,
+        }
+    }
+
+    /**
+    Sets the number of contour levels.
+
+    See [`MatDensityContour`] for more information.
+    */
+    pub fn levels(mut self, levels: usize) -> Self {
+        self.levels = levels;
+        self
+    }
+
+    /**
+    Sets the number of grid cells per axis the density is estimated on.
+
+    See [`MatDensityContour`] for more information.
+    */
+    pub fn grid(mut self, cells: usize) -> Self {
+        self.grid = cells.max(1);
+        self
+    }
+
+    /**
+    Sets the bandwidths of the kernel along x and y, instead of Scott's rule.
+
+    See [`MatDensityContour`] for more information.
+    */
+    pub fn bandwidth(mut self, x: f64, y: f64) -> Self {
+        self.bandwidth = Some((x, y));
+        self
+    }
+
+    /**
+    Sets the style of the points.
+
+    See [`MatDensityContour`] for more information.
+    */
+    pub fn point_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.point_style = style.into();
+        self
+    }
+
+    /**
+    Sets the style of the contour lines.
+
+    See [`MatDensityContour`] for more information.
+    */
+    pub fn style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    fn elements(&self) -> Vec<DynElement<'static, DB, (f64, f64)>> {
+        let mut ret: Vec<DynElement<'static, DB, (f64, f64)>> = self
+            .points
+            .iter()
+            .map(|p| Circle::new(*p, 2, self.point_style).into_dyn())
+            .collect();
+        if self.points.len() < 2 {
+            return ret;
+        }
+
+        let (hx, hy) = self
+            .bandwidth
+            .unwrap_or_else(|| scott_bandwidth(&self.points));
+        let axis = |values: Vec<f64>, h: f64| -> Vec<f64> {
+            let lo = values.iter().copied().fold(f64::INFINITY, f64::min) - 3.0 * h;
+            let hi = values.iter().copied().fold(f64::NEG_INFINITY, f64::max) + 3.0 * h;
+            (0..=self.grid)
+                .map(|i| lo + (hi - lo) * i as f64 / self.grid as f64)
+                .collect()
+        };
+        let xs = axis(self.points.iter().map(|p| p.0).collect(), hx);
+        let ys = axis(self.points.iter().map(|p| p.1).collect(), hy);
+        let density = kde_2d(&self.points, &xs, &ys, Some((hx, hy)));
+        let max = density.iter().flatten().copied().fold(0.0, f64::max);
+        for k in 1..=self.levels {
+            let level = max * k as f64 / (self.levels + 1) as f64;
+            for segment in contour_lines(&xs, &ys, &density, level) {
+                ret.push(PathElement::new(segment.to_vec(), self.style).into_dyn());
+            }
+        }
+        ret
+    }
+}
+
+impl<DB: DrawingBackend> Iterator for MatDensityContour<DB> {
+    type Item = DynElement<'static, DB, (f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.elements.is_none() {
+            self.elements = Some(self.elements().into_iter());
+        }
+        self.elements.as_mut()?.next()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_density_contour() {
+        let contours = Rc::new(RefCell::new(vec![]));
+        let contours_out = contours.clone();
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_path(move |c, _, path| {
+                assert_eq!(c, RED.to_rgba());
+                contours_out.borrow_mut().extend(path);
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_circle_call, 116));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Build chart error");
+
+        // A dense cluster around (3, 7) over a sparse background
+        let mut points: Vec<(f64, f64)> = (0..100)
+            .map(|i| {
+                let (a, r) = (f64::from(i) * 2.4, 0.05 * f64::from(i % 10));
+                (3.0 + r * a.cos(), 7.0 + r * a.sin())
+            })
+            .collect();
+        points
+            .extend((0..16).map(|i| (0.5 + f64::from(i % 4) * 3.0, 0.5 + f64::from(i / 4) * 3.0)));
+        chart
+            .draw_series(MatDensityContour::new(points).levels(1).style(RED))
+            .expect("Drawing Error");
+
+        // The contour at half of the highest density goes around the cluster only
+        let contours = contours.borrow();
+        assert!(!contours.is_empty());
+        let center = chart.backend_coord(&(3.0, 7.0));
+        let radius = f64::from(chart.backend_coord(&(4.5, 7.0)).0 - center.0);
+        for p in contours.iter() {
+            let d = f64::from(p.0 - center.0).hypot(f64::from(p.1 - center.1));
+            assert!(d < radius, "{:?}", p);
+        }
+        for (dx, dy) in [(1, 0), (-1, 0), (0, 1), (0, -1)] {
+            assert!(contours
+                .iter()
+                .any(|p| (p.0 - center.0) * dx + (p.1 - center.1) * dy > 5));
+        }
+    }
+}
@@ -0,0 +1,257 @@
+use super::{MatLineSeries, StepMode};
+use crate::chart::{LegendGlyph, StyledSeries};
+use crate::element::{Circle, DynElement, IntoDynElement, MarkerShape};
+use crate::style::{ColorCycle, ShapeStyle};
+use plotters_backend::DrawingBackend;
+
+/// How a [`MatEcdf`] is drawn, see [`MatEcdf::draw_style`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum EcdfStyle {
+    /// A step function, rising from 0 at the smallest sample
+    #[default]
+    Steps,
+    /// A marker at every distinct sample value, with the value of the function there
+    Points,
+}
+
+/**
+The empirical cumulative distribution function of a sample, like seaborn's `ecdfplot`.
+
+At every distinct sample value x, the function is the fraction of the samples lower than or
+equal to x, so it rises from 0 to 1 in steps. The complementary function, the fraction of the
+samples greater than x, can be drawn instead with [`MatEcdf::complementary`]. NaN samples are
+ignored.
+
+To compare the distributions of several groups, [`MatEcdf::by_group`] splits the samples into
+one function per group, and [`crate::chart::ChartContext::draw_ecdf_groups`] draws them with
+one legend entry per group.
+*/
+pub struct MatEcdf<DB: DrawingBackend> {
+    samples: Vec<f64>,
+    style: ShapeStyle,
+    complementary: bool,
+    draw_style: EcdfStyle,
+    point_size: u32,
+    group: Option<String>,
+    elements: Option<std::vec::IntoIter<DynElement<'static, DB, (f64, f64)>>>,
+}
+
+impl<DB: DrawingBackend> MatEcdf<DB> {
+    /**
+    Creates the empirical CDF of the samples.
+
+    See [`MatEcdf`] for more information.
+    */
+    pub fn new<I: IntoIterator<Item = f64>, S: Into<ShapeStyle>>(samples: I, style: S) -> Self {
+        let mut samples: Vec<f64> = samples.into_iter().filter(|v| !v.is_nan()).collect();
+        samples.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        Self {
+            samples,
+            style: style.into(),
+            complementary: false,
+            draw_style: EcdfStyle::Steps,
+            point_size: 3,
+            group: None,
+            elements: None,
+        }
+    }
+
+    /**
+    Creates one function per group from the samples and their groups, which are paired in
+    order. The groups are in the order they first appear, and take the colors of the default
+    [`ColorCycle`] in turn.
+
+    See [`MatEcdf`] for more information.
+    */
+    pub fn by_group<I, G, L>(samples: I, groups: G) -> Vec<Self>
+    where
+        I: IntoIterator<Item = f64>,
+        G: IntoIterator<Item = L>,
+        L: Into<String>,
+    {
+        let mut split: Vec<(String, Vec<f64>)> = vec![];
+        for (sample, group) in samples.into_iter().zip(groups) {
+            let group = group.into();
+            match split.iter_mut().find(|(g, _)| *g == group) {
+                Some((_, samples)) => samples.push(sample),
+                None => split.push((group, vec![sample])),
+            }
+        }
+        let mut colors = ColorCycle::default();
+        split
+            .into_iter()
+            .map(|(group, samples)| {
+                let mut ecdf = Self::new(samples, colors.next_color());
+                ecdf.group = Some(group);
+                ecdf
+            })
+            .collect()
+    }
+
+    /**
+    Returns the group of the function, if it was created by [`MatEcdf::by_group`].
+
+    See [`MatEcdf`] for more information.
+    */
+    pub fn group(&self) -> Option<&str> {
+        self.group.as_deref()
+    }
+
+    /**
+    Draws the complementary CDF, also known as the survival function, instead of the CDF.
+
+    See [`MatEcdf`] for more information.
+    */
+    pub fn complementary(mut self, complementary: bool) -> Self {
+        self.complementary = complementary;
+        self
+    }
+
+    /**
+    Sets how the function is drawn, see [`EcdfStyle`].
+
+    See [`MatEcdf`] for more information.
+    */
+    pub fn draw_style(mut self, style: EcdfStyle) -> Self {
+        self.draw_style = style;
+        self
+    }
+
+    /**
+    Sets the size of the markers of [`EcdfStyle::Points`], in pixels. It is 3 by default.
+
+    See [`MatEcdf`] for more information.
+    */
+    pub fn point_size(mut self, size: u32) -> Self {
+        self.point_size = size;
+        self
+    }
+
+    /**
+    Returns the value of the function at every distinct sample value, in increasing order.
+
+    See [`MatEcdf`] for more information.
+    */
+    pub fn values(&self) -> Vec<(f64, f64)> {
+        let n = self.samples.len() as f64;
+        let mut ret: Vec<(f64, f64)> = vec![];
+        for (i, x) in self.samples.iter().enumerate() {
+            let mut value = (i + 1) as f64 / n;
+            if self.complementary {
+                value = 1.0 - value;
+            }
+            match ret.last_mut() {
+                // Equal samples are a single step
+                Some(last) if last.0 == *x => last.1 = value,
+                _ => ret.push((*x, value)),
+            }
+        }
+        ret
+    }
+
+    fn elements(&self) -> Vec<DynElement<'static, DB, (f64, f64)>> {
+        let values = self.values();
+        match self.draw_style {
+            EcdfStyle::Points => values
+                .into_iter()
+                .map(|p| Circle::new(p, self.point_size, self.style).into_dyn())
+                .collect(),
+            EcdfStyle::Steps => {
+                let start = values
+                    .first()
+                    .map(|(x, _)| (*x, if self.complementary { 1.0 } else { 0.0 }));
+                let points = start.into_iter().chain(values);
+                MatLineSeries::new(points, self.style)
+                    .step(StepMode::Post)
+                    .collect()
+            }
+        }
+    }
+}
+
+impl<DB: DrawingBackend> StyledSeries for MatEcdf<DB> {
+    fn series_style(&self) -> ShapeStyle {
+        self.style
+    }
+
+    fn legend_glyph(&self) -> LegendGlyph {
+        match self.draw_style {
+            EcdfStyle::Steps => LegendGlyph::Line(self.style),
+            EcdfStyle::Points => {
+                LegendGlyph::Marker(MarkerShape::Circle, self.point_size, self.style)
+            }
+        }
+    }
+}
+
+impl<DB: DrawingBackend> Iterator for MatEcdf<DB> {
+    type Item = DynElement<'static, DB, (f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.elements.is_none() {
+            self.elements = Some(self.elements().into_iter());
+        }
+        self.elements.as_mut()?.next()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::EcdfStyle;
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_ecdf() {
+        let path = Rc::new(RefCell::new(vec![]));
+        let path_out = path.clone();
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_path(move |_, _, p| path_out.borrow_mut().extend(p));
+            m.drop_check(|b| assert_eq!(b.num_draw_path_call, 1));
+        });
+
+        let ecdf = MatEcdf::new(vec![3.0, 1.0, 4.0, 2.0], BLUE);
+        assert_eq!(
+            ecdf.values(),
+            vec![(1.0, 0.25), (2.0, 0.5), (3.0, 0.75), (4.0, 1.0)]
+        );
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..5.0, 0.0..1.0)
+            .expect("Build chart error");
+        chart.draw_series(ecdf).expect("Drawing Error");
+
+        let coord = |x, y| chart.backend_coord(&(x, y));
+        let expected = vec![
+            coord(1.0, 0.0),
+            coord(1.0, 0.25),
+            coord(2.0, 0.25),
+            coord(2.0, 0.5),
+            coord(3.0, 0.5),
+            coord(3.0, 0.75),
+            coord(4.0, 0.75),
+            coord(4.0, 1.0),
+        ];
+        assert_eq!(*path.borrow(), expected);
+    }
+
+    #[test]
+    fn test_complementary_ecdf_points() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_circle_call, 3);
+                assert_eq!(b.num_draw_path_call, 0);
+            });
+        });
+
+        let ecdf = MatEcdf::new(vec![1.0, 2.0, 2.0, f64::NAN, 4.0], BLUE)
+            .complementary(true)
+            .draw_style(EcdfStyle::Points);
+        assert_eq!(ecdf.values(), vec![(1.0, 0.75), (2.0, 0.25), (4.0, 0.0)]);
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..5.0, 0.0..1.0)
+            .expect("Build chart error");
+        chart.draw_series(ecdf).expect("Drawing Error");
+    }
+}
@@ -0,0 +1,27 @@
+use std::{error::Error, fmt::Display};
+
+/// The error raised when the input of a matplotlib-style series is inconsistent
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MatSeriesError {
+    /// Two inputs that must agree in length do not
+    LengthMismatch {
+        /// The number of elements required
+        expected: usize,
+        /// The number of elements given
+        actual: usize,
+    },
+}
+
+impl Display for MatSeriesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MatSeriesError::LengthMismatch { expected, actual } => write!(
+                f,
+                "Length Mismatch: expected {} elements, got {}",
+                expected, actual
+            ),
+        }
+    }
+}
+
+impl Error for MatSeriesError {}
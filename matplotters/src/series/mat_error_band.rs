@@ -0,0 +1,358 @@
+use crate::chart::StyledSeries;
+use crate::element::{DynElement, HatchedPolygon, IntoDynElement, PathElement, Polygon};
+use crate::style::{Color, Hatch, ShapeStyle};
+use plotters_backend::DrawingBackend;
+
+/**
+An error band series, which evaluates a center function and a tolerance over an x-grid
+and draws the center line on top of a shaded `center ± tolerance` band.
+
+The tolerance is either a constant (see [`MatErrorBand::new`]) or a function of x
+(see [`MatErrorBand::from_fn`]). The band can also be given by its own bounds, such as the
+prediction interval of a regression model around its fit (see
+[`MatErrorBand::from_bounds`]). By default the band is filled with the line color at 20%
+opacity; for black and white print, it can be hatched instead, see [`MatErrorBand::hatch`].
+*/
+pub struct MatErrorBand<DB: DrawingBackend, X: Clone> {
+    line_style: ShapeStyle,
+    band_style: ShapeStyle,
+    hatch: Option<Hatch>,
+    hatch_spacing: u32,
+    hatch_angle: Option<f64>,
+    // (x, center, lower, upper)
+    data: Vec<(X, f64, f64, f64)>,
+    state: u32,
+    _p: std::marker::PhantomData<DB>,
+}
+
+impl<DB: DrawingBackend, X: Clone> MatErrorBand<DB, X> {
+    /**
+    Creates an error band with a constant tolerance.
+
+    See [`MatErrorBand`] for more information.
+    */
+    pub fn new<I, F, S>(grid: I, center: F, tolerance: f64, style: S) -> Self
+    where
+        I: IntoIterator<Item = X>,
+        F: FnMut(X) -> f64,
+        S: Into<ShapeStyle>,
+    {
+        Self::from_fn(grid, center, |_| tolerance, style)
+    }
+
+    /**
+    Creates an error band whose tolerance is evaluated at every x of the grid.
+
+    See [`MatErrorBand`] for more information.
+    */
+    pub fn from_fn<I, F, T, S>(grid: I, mut center: F, mut tolerance: T, style: S) -> Self
+    where
+        I: IntoIterator<Item = X>,
+        F: FnMut(X) -> f64,
+        T: FnMut(X) -> f64,
+        S: Into<ShapeStyle>,
+    {
+        let data = grid
+            .into_iter()
+            .map(|x| {
+                let c = center(x.clone());
+                let t = tolerance(x.clone()).abs();
+                (x, c, c - t, c + t)
+            })
+            .collect();
+        Self::with_data(data, style.into())
+    }
+
+    fn with_data(data: Vec<(X, f64, f64, f64)>, line_style: ShapeStyle) -> Self {
+        Self {
+            line_style,
+            band_style: line_style.color.mix(0.2).filled(),
+            hatch: None,
+            hatch_spacing: 8,
+            hatch_angle: None,
+            data,
+            state: 0,
+            _p: std::marker::PhantomData,
+        }
+    }
+
+    /**
+    Creates a band whose lower and upper bounds are evaluated at every x of the grid, along
+    with the center line, like the fit of a model and its prediction interval. The bounds
+    need not be symmetric around the center.
+
+    See [`MatErrorBand`] for more information.
+    */
+    pub fn from_bounds<I, F, L, U, S>(
+        grid: I,
+        mut center: F,
+        mut lower: L,
+        mut upper: U,
+        style: S,
+    ) -> Self
+    where
+        I: IntoIterator<Item = X>,
+        F: FnMut(X) -> f64,
+        L: FnMut(X) -> f64,
+        U: FnMut(X) -> f64,
+        S: Into<ShapeStyle>,
+    {
+        let data = grid
+            .into_iter()
+            .map(|x| {
+                let c = center(x.clone());
+                (x.clone(), c, lower(x.clone()), upper(x))
+            })
+            .collect();
+        Self::with_data(data, style.into())
+    }
+
+    /**
+    Sets the style of the shaded band.
+
+    See [`MatErrorBand`] for more information.
+    */
+    pub fn band_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.band_style = style.into();
+        self
+    }
+
+    /**
+    Hatches the band instead of filling it: the hatch lines are clipped to the band and
+    stroked with the given style.
+
+    See [`MatErrorBand`] for more information.
+    */
+    pub fn hatch<S: Into<ShapeStyle>>(mut self, hatch: Hatch, style: S) -> Self {
+        self.hatch = Some(hatch);
+        self.band_style = style.into();
+        self
+    }
+
+    /**
+    Sets the distance between the hatch lines, in pixels, 8 by default: a smaller spacing
+    gives a denser hatch. It only applies to a hatched band.
+
+    See [`MatErrorBand`] for more information.
+    */
+    pub fn hatch_spacing(mut self, spacing: u32) -> Self {
+        self.hatch_spacing = spacing;
+        self
+    }
+
+    /**
+    Sets the direction of the hatch lines, in degrees counter-clockwise from the x axis,
+    instead of the direction of the hatch pattern. The second lines of a crossing pattern
+    keep their angle to the first ones. It only applies to a hatched band.
+
+    See [`MatErrorBand`] for more information.
+    */
+    pub fn hatch_angle(mut self, angle: f64) -> Self {
+        self.hatch_angle = Some(angle);
+        self
+    }
+}
+
+impl<DB: DrawingBackend, X: Clone> StyledSeries for MatErrorBand<DB, X> {
+    fn series_style(&self) -> ShapeStyle {
+        self.line_style
+    }
+}
+
+impl<DB: DrawingBackend, X: Clone + 'static> Iterator for MatErrorBand<DB, X> {
+    type Item = DynElement<'static, DB, (X, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.state == 0 {
+            self.state = 1;
+            let band: Vec<_> = self
+                .data
+                .iter()
+                .map(|(x, _, _, upper)| (x.clone(), *upper))
+                .chain(
+                    self.data
+                        .iter()
+                        .rev()
+                        .map(|(x, _, lower, _)| (x.clone(), *lower)),
+                )
+                .collect();
+            Some(match self.hatch {
+                Some(hatch) => {
+                    let hatched = HatchedPolygon::new(band, hatch, self.band_style)
+                        .spacing(self.hatch_spacing);
+                    match self.hatch_angle {
+                        Some(angle) => hatched.angle(angle).into_dyn(),
+                        None => hatched.into_dyn(),
+                    }
+                }
+                None => Polygon::new(band, self.band_style).into_dyn(),
+            })
+        } else if self.state == 1 {
+            self.state = 2;
+            let line: Vec<_> = self
+                .data
+                .iter()
+                .map(|(x, c, _, _)| (x.clone(), *c))
+                .collect();
+            Some(PathElement::new(line, self.line_style).into_dyn())
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_error_band() {
+        let band = Rc::new(RefCell::new(vec![]));
+        let line = Rc::new(RefCell::new(vec![]));
+        let (band_out, line_out) = (band.clone(), line.clone());
+        let drawing_area = create_mocked_drawing_area(1000, 1000, |m| {
+            m.check_fill_polygon(move |c, path| {
+                assert_eq!(c, RED.mix(0.2).to_rgba());
+                band_out.borrow_mut().extend(path);
+            });
+            m.check_draw_path(move |c, _, path| {
+                assert_eq!(c, RED.to_rgba());
+                line_out.borrow_mut().extend(path);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 1);
+                assert_eq!(b.num_draw_path_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..40.0)
+            .expect("Build chart error");
+
+        let grid: Vec<f64> = (0..=10).map(f64::from).collect();
+        let center = |x: f64| 2.0 * x + 10.0;
+        let tolerance = |x: f64| 0.5 * x + 1.0;
+        chart
+            .draw_series(MatErrorBand::from_fn(grid.clone(), center, tolerance, RED))
+            .expect("Drawing Error");
+
+        let n = grid.len();
+        let band = band.borrow();
+        let line = line.borrow();
+        assert_eq!(band.len(), 2 * n);
+        for (i, &x) in grid.iter().enumerate() {
+            let upper = chart.backend_coord(&(x, center(x) + tolerance(x)));
+            let lower = chart.backend_coord(&(x, center(x) - tolerance(x)));
+            assert_eq!(band[i], upper);
+            assert_eq!(band[2 * n - 1 - i], lower);
+            assert_eq!(line[i], chart.backend_coord(&(x, center(x))));
+        }
+    }
+
+    #[test]
+    fn test_prediction_interval() {
+        let band = Rc::new(RefCell::new(vec![]));
+        let line = Rc::new(RefCell::new(vec![]));
+        let (band_out, line_out) = (band.clone(), line.clone());
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_fill_polygon(move |_, path| band_out.borrow_mut().extend(path));
+            m.check_draw_path(move |_, _, path| line_out.borrow_mut().extend(path));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..50.0)
+            .expect("Build chart error");
+
+        let fit = |x: f64| 3.0 * x + 5.0;
+        let lower = |x: f64| fit(x) - 2.0 - 0.1 * x * x;
+        let upper = |x: f64| fit(x) + 4.0;
+        let grid: Vec<f64> = (0..=20).map(|i| f64::from(i) * 0.5).collect();
+        chart
+            .draw_series(MatErrorBand::from_bounds(
+                grid.clone(),
+                fit,
+                lower,
+                upper,
+                GREEN,
+            ))
+            .expect("Drawing Error");
+
+        let (band, line) = (band.borrow(), line.borrow());
+        let n = grid.len();
+        for (i, &x) in grid.iter().enumerate() {
+            assert_eq!(band[i], chart.backend_coord(&(x, upper(x))));
+            assert_eq!(band[2 * n - 1 - i], chart.backend_coord(&(x, lower(x))));
+            assert_eq!(line[i], chart.backend_coord(&(x, fit(x))));
+        }
+    }
+
+    #[test]
+    fn test_constant_error_band() {
+        let band = Rc::new(RefCell::new(vec![]));
+        let band_out = band.clone();
+        let drawing_area = create_mocked_drawing_area(1000, 1000, |m| {
+            m.check_fill_polygon(move |_, path| band_out.borrow_mut().extend(path));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0.0..40.0)
+            .expect("Build chart error");
+
+        chart
+            .draw_series(MatErrorBand::new(
+                0..=10,
+                |x| f64::from(x) + 10.0,
+                5.0,
+                BLUE,
+            ))
+            .expect("Drawing Error");
+
+        let band = band.borrow();
+        for x in 0..=10 {
+            let c = f64::from(x) + 10.0;
+            assert_eq!(band[x as usize], chart.backend_coord(&(x, c + 5.0)));
+            assert_eq!(band[21 - x as usize], chart.backend_coord(&(x, c - 5.0)));
+        }
+    }
+
+    #[test]
+    fn test_hatched_error_band() {
+        let lines = Rc::new(RefCell::new(vec![]));
+        let lines_out = lines.clone();
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_line(move |c, _, from, to| {
+                assert_eq!(c, BLACK.to_rgba());
+                lines_out.borrow_mut().push((from, to));
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 0);
+                assert_eq!(b.num_draw_path_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Build chart error");
+        // The band is 2 units (100 pixels) tall around the line y = 5
+        chart
+            .draw_series(
+                MatErrorBand::new((0..=10).map(f64::from), |_| 5.0, 1.0, RED)
+                    .hatch(Hatch::Forward, BLACK),
+            )
+            .expect("Drawing Error");
+
+        let lines = lines.borrow();
+        assert!(lines.len() > 10);
+        let (top, bottom) = (
+            chart.backend_coord(&(0.0, 6.0)).1,
+            chart.backend_coord(&(0.0, 4.0)).1,
+        );
+        for (from, to) in lines.iter() {
+            for p in [from, to] {
+                assert!((top - 1..=bottom + 1).contains(&p.1));
+                assert!((-1..=501).contains(&p.0));
+            }
+        }
+    }
+}
@@ -0,0 +1,277 @@
+use crate::chart::StyledSeries;
+use crate::element::{DynElement, HatchedPolygon, IntoDynElement, PathElement, Polygon};
+use crate::style::colors::TRANSPARENT;
+use crate::style::{Hatch, ShapeStyle};
+use plotters_backend::DrawingBackend;
+
+/**
+A series that fills the area between two curves, or between a curve and a baseline.
+
+A point with a NaN or infinite bound is a gap: the fill breaks there into one polygon per
+run of finite points, instead of one polygon spanning the gap. The optional border is
+drawn along the upper curve and breaks at the same gaps. The area can be hatched instead
+of filled, see [`MatFillBetween::hatch`].
+*/
+pub struct MatFillBetween<DB: DrawingBackend, X: Clone> {
+    area_style: ShapeStyle,
+    border_style: ShapeStyle,
+    hatch: Option<Hatch>,
+    hatch_spacing: u32,
+    hatch_angle: Option<f64>,
+    // (x, lower, upper)
+    data: Vec<(X, f64, f64)>,
+    elements: Option<std::vec::IntoIter<DynElement<'static, DB, (X, f64)>>>,
+}
+
+impl<DB: DrawingBackend, X: Clone> MatFillBetween<DB, X> {
+    /**
+    Creates a series filling between the `lower` and `upper` values of every point.
+
+    See [`MatFillBetween`] for more information.
+    */
+    pub fn new<I, S>(iter: I, area_style: S) -> Self
+    where
+        I: IntoIterator<Item = (X, f64, f64)>,
+        S: Into<ShapeStyle>,
+    {
+        Self {
+            area_style: area_style.into(),
+            border_style: (&TRANSPARENT).into(),
+            hatch: None,
+            hatch_spacing: 8,
+            hatch_angle: None,
+            data: iter.into_iter().collect(),
+            elements: None,
+        }
+    }
+
+    /**
+    Creates a series filling between a curve and a constant baseline.
+
+    See [`MatFillBetween`] for more information.
+    */
+    pub fn fill_to<I, S>(iter: I, baseline: f64, area_style: S) -> Self
+    where
+        I: IntoIterator<Item = (X, f64)>,
+        S: Into<ShapeStyle>,
+    {
+        Self::new(iter.into_iter().map(|(x, y)| (x, baseline, y)), area_style)
+    }
+
+    /**
+    Sets the style of the border drawn along the upper curve.
+
+    See [`MatFillBetween`] for more information.
+    */
+    pub fn border_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.border_style = style.into();
+        self
+    }
+
+    /**
+    Hatches the area instead of filling it: the hatch lines are clipped to the area and
+    stroked with the given style.
+
+    See [`MatFillBetween`] for more information.
+    */
+    pub fn hatch<S: Into<ShapeStyle>>(mut self, hatch: Hatch, style: S) -> Self {
+        self.hatch = Some(hatch);
+        self.area_style = style.into();
+        self
+    }
+
+    /**
+    Sets the distance between the hatch lines, in pixels, 8 by default: a smaller spacing
+    gives a denser hatch. It only applies to a hatched area.
+
+    See [`MatFillBetween`] for more information.
+    */
+    pub fn hatch_spacing(mut self, spacing: u32) -> Self {
+        self.hatch_spacing = spacing;
+        self
+    }
+
+    /**
+    Sets the direction of the hatch lines, in degrees counter-clockwise from the x axis,
+    instead of the direction of the hatch pattern. The second lines of a crossing pattern
+    keep their angle to the first ones. It only applies to a hatched area.
+
+    See [`MatFillBetween`] for more information.
+    */
+    pub fn hatch_angle(mut self, angle: f64) -> Self {
+        self.hatch_angle = Some(angle);
+        self
+    }
+
+    fn segments(&self) -> Vec<&[(X, f64, f64)]> {
+        self.data
+            .split(|(_, lower, upper)| !lower.is_finite() || !upper.is_finite())
+            .filter(|segment| !segment.is_empty())
+            .collect()
+    }
+}
+
+impl<DB: DrawingBackend, X: Clone> StyledSeries for MatFillBetween<DB, X> {
+    fn series_style(&self) -> ShapeStyle {
+        self.area_style
+    }
+}
+
+impl<DB: DrawingBackend, X: Clone + 'static> Iterator for MatFillBetween<DB, X> {
+    type Item = DynElement<'static, DB, (X, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.elements.is_none() {
+            let mut elements = vec![];
+            let segments = self.segments();
+            for segment in &segments {
+                let polygon: Vec<_> = segment
+                    .iter()
+                    .map(|(x, _, upper)| (x.clone(), *upper))
+                    .chain(
+                        segment
+                            .iter()
+                            .rev()
+                            .map(|(x, lower, _)| (x.clone(), *lower)),
+                    )
+                    .collect();
+                elements.push(match self.hatch {
+                    Some(hatch) => {
+                        let hatched = HatchedPolygon::new(polygon, hatch, self.area_style)
+                            .spacing(self.hatch_spacing);
+                        match self.hatch_angle {
+                            Some(angle) => hatched.angle(angle).into_dyn(),
+                            None => hatched.into_dyn(),
+                        }
+                    }
+                    None => Polygon::new(polygon, self.area_style).into_dyn(),
+                });
+            }
+            for segment in &segments {
+                let border: Vec<_> = segment
+                    .iter()
+                    .map(|(x, _, upper)| (x.clone(), *upper))
+                    .collect();
+                elements.push(PathElement::new(border, self.border_style).into_dyn());
+            }
+            self.elements = Some(elements.into_iter());
+        }
+        self.elements.as_mut().and_then(|e| e.next())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_fill_splits_at_gaps() {
+        let polygons = Rc::new(RefCell::new(vec![]));
+        let polygons_out = polygons.clone();
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_fill_polygon(move |_, path| polygons_out.borrow_mut().push(path));
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 2);
+                assert_eq!(b.num_draw_path_call, 2);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Build chart error");
+
+        let data = vec![
+            (0.0, 2.0),
+            (1.0, 4.0),
+            (2.0, f64::NAN),
+            (3.0, 6.0),
+            (4.0, 8.0),
+            (5.0, 5.0),
+        ];
+        chart
+            .draw_series(MatFillBetween::fill_to(data, 1.0, BLUE.mix(0.3)).border_style(BLUE))
+            .expect("Drawing Error");
+
+        let polygons = polygons.borrow();
+        let c = |x, y| chart.backend_coord(&(x, y));
+        assert_eq!(
+            polygons[0],
+            vec![c(0.0, 2.0), c(1.0, 4.0), c(1.0, 1.0), c(0.0, 1.0)]
+        );
+        assert_eq!(
+            polygons[1],
+            vec![
+                c(3.0, 6.0),
+                c(4.0, 8.0),
+                c(5.0, 5.0),
+                c(5.0, 1.0),
+                c(4.0, 1.0),
+                c(3.0, 1.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fill_between_curves() {
+        let polygons = Rc::new(RefCell::new(vec![]));
+        let polygons_out = polygons.clone();
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_fill_polygon(move |_, path| polygons_out.borrow_mut().push(path));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0.0..10.0)
+            .expect("Build chart error");
+
+        chart
+            .draw_series(MatFillBetween::new(
+                vec![(0, 1.0, 3.0), (1, 2.0, f64::INFINITY), (2, 2.0, 5.0)],
+                RED.mix(0.3),
+            ))
+            .expect("Drawing Error");
+
+        let polygons = polygons.borrow();
+        assert_eq!(polygons.len(), 2);
+        // A single point still yields a (degenerate) polygon, closing at its lower bound
+        assert_eq!(
+            polygons[1],
+            vec![
+                chart.backend_coord(&(2, 5.0)),
+                chart.backend_coord(&(2, 2.0))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_hatch_spacing_and_angle() {
+        let hatch_lines = |spacing: u32, angle: f64| {
+            let lines = Rc::new(RefCell::new(vec![]));
+            let lines_out = lines.clone();
+            let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+                m.check_draw_line(move |_, _, from, to| lines_out.borrow_mut().push((from, to)));
+            });
+            let mut chart = ChartBuilder::on(&drawing_area)
+                .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+                .expect("Build chart error");
+            let area = MatFillBetween::new([(2.0, 2.0, 8.0), (8.0, 2.0, 8.0)], BLUE)
+                .hatch(Hatch::Forward, BLACK)
+                .hatch_spacing(spacing)
+                .hatch_angle(angle);
+            chart.draw_series(area).expect("Drawing Error");
+            let lines = lines.borrow().clone();
+            lines
+        };
+
+        let sparse = hatch_lines(12, 60.0);
+        let dense = hatch_lines(4, 60.0);
+        assert!(dense.len() > 2 * sparse.len());
+        for (from, to) in dense.iter().filter(|(from, to)| {
+            let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+            dx * dx + dy * dy > 2500
+        }) {
+            let (dx, dy) = (f64::from(to.0 - from.0), f64::from(from.1 - to.1));
+            assert!((dy.atan2(dx).to_degrees() - 60.0).abs() < 2.0);
+        }
+    }
+}
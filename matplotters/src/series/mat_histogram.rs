@@ -0,0 +1,247 @@
+use crate::chart::StyledSeries;
+use crate::element::Rectangle;
+use crate::style::{Color, ShapeStyle, GREEN};
+
+/// Count the samples falling into each bin. The bins are half-open `[edges[i], edges[i + 1])`,
+/// except the last one which also includes its right edge, as in numpy.
+fn bin_counts(samples: &[f64], edges: &[f64]) -> Vec<f64> {
+    let n_bins = edges.len().saturating_sub(1);
+    let mut counts = vec![0.0; n_bins];
+    if n_bins == 0 {
+        return counts;
+    }
+    for &v in samples {
+        // NaN is out of every range
+        if !(edges[0]..=edges[n_bins]).contains(&v) {
+            continue;
+        }
+        let bin = edges
+            .partition_point(|e| *e <= v)
+            .saturating_sub(1)
+            .min(n_bins - 1);
+        counts[bin] += 1.0;
+    }
+    counts
+}
+
+/**
+A histogram of raw samples over explicit bin edges, like matplotlib's `hist(x, bins=edges)`.
+
+Each non-empty bin is drawn as a bar from its bottom to its count. The bars start at zero,
+unless the histogram is a layer of a stacked histogram, see [`MatHistogram::stacked`].
+
+When the counts span orders of magnitude, the histogram can be drawn over a log-scaled count
+axis, see [`MatHistogram::log_floor`].
+*/
+pub struct MatHistogram {
+    style: ShapeStyle,
+    edges: Vec<f64>,
+    counts: Vec<f64>,
+    bottoms: Vec<f64>,
+    floor: Option<f64>,
+    idx: usize,
+}
+
+impl MatHistogram {
+    /**
+    Creates a histogram of the samples over the bins defined by the sorted `edges`.
+
+    See [`MatHistogram`] for more information.
+    */
+    pub fn new<I: IntoIterator<Item = f64>, S: Into<ShapeStyle>>(
+        samples: I,
+        edges: &[f64],
+        style: S,
+    ) -> Self {
+        let samples: Vec<f64> = samples.into_iter().collect();
+        let counts = bin_counts(&samples, edges);
+        Self {
+            style: style.into(),
+            edges: edges.to_vec(),
+            bottoms: vec![0.0; counts.len()],
+            counts,
+            floor: None,
+            idx: 0,
+        }
+    }
+
+    /**
+    Creates a stacked histogram, like matplotlib's `hist([a, b, c], stacked=True)`.
+
+    All the datasets share the bin edges, and the bars of each dataset are stacked on top of
+    the bars of the previous ones. One layer is returned per dataset, using the styles in
+    turn, or green bars if no style is given. Drawing the layers separately allows to label
+    each dataset in the legend.
+
+    See [`MatHistogram`] for more information.
+    */
+    pub fn stacked<S: Into<ShapeStyle> + Clone>(
+        datasets: &[Vec<f64>],
+        edges: &[f64],
+        styles: &[S],
+    ) -> Vec<Self> {
+        let mut bottoms = vec![0.0; edges.len().saturating_sub(1)];
+        let mut layers = vec![];
+        for (idx, samples) in datasets.iter().enumerate() {
+            let counts = bin_counts(samples, edges);
+            let style = styles
+                .get(idx % styles.len().max(1))
+                .cloned()
+                .map_or_else(|| GREEN.filled(), Into::into);
+            let next_bottoms = bottoms.iter().zip(&counts).map(|(b, c)| b + c).collect();
+            layers.push(Self {
+                style,
+                edges: edges.to_vec(),
+                counts,
+                bottoms: std::mem::replace(&mut bottoms, next_bottoms),
+                floor: None,
+                idx: 0,
+            });
+        }
+        layers
+    }
+
+    /**
+    Prepares the histogram for a log-scaled count axis starting at `floor`, such as one built
+    with `(floor..top).log_scale()`, where zero can't be shown. The bars start at the floor
+    instead of zero, and the empty bins, which have no bar otherwise, are drawn as flat bars
+    at the floor so that they stay visible. The floor is usually below 1, like 0.5.
+
+    See [`MatHistogram`] for more information.
+    */
+    pub fn log_floor(mut self, floor: f64) -> Self {
+        self.floor = Some(floor);
+        self
+    }
+
+    /**
+    Returns the number of samples in each bin.
+
+    See [`MatHistogram`] for more information.
+    */
+    pub fn counts(&self) -> &[f64] {
+        &self.counts
+    }
+}
+
+impl StyledSeries for MatHistogram {
+    fn series_style(&self) -> ShapeStyle {
+        self.style
+    }
+}
+
+impl Iterator for MatHistogram {
+    type Item = Rectangle<(f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.idx < self.counts.len() {
+            let idx = self.idx;
+            self.idx += 1;
+            let (bottom, count) = (self.bottoms[idx], self.counts[idx]);
+            let (bottom, top) = match self.floor {
+                // Only the empty bins with nothing below them are drawn at the floor
+                Some(floor) if count > 0.0 || bottom == 0.0 => {
+                    (bottom.max(floor), (bottom + count).max(floor))
+                }
+                None if count > 0.0 => (bottom, bottom + count),
+                _ => continue,
+            };
+            return Some(Rectangle::new(
+                [(self.edges[idx], top), (self.edges[idx + 1], bottom)],
+                self.style,
+            ));
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_bin_counts() {
+        let counts = super::bin_counts(&[0.0, 0.5, 1.0, 1.5, 2.0, 2.5, 3.0], &[0.0, 1.0, 2.0, 3.0]);
+        assert_eq!(counts, vec![2.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn test_bin_counts_skip_nan() {
+        let samples = [f64::NAN, 0.5, f64::NAN, 2.5, -1.0, f64::INFINITY];
+        let counts = super::bin_counts(&samples, &[0.0, 1.0, 2.0, 3.0]);
+        assert_eq!(counts, vec![1.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_log_histogram() {
+        let rects = Rc::new(RefCell::new(vec![]));
+        let rects_out = rects.clone();
+        let drawing_area = create_mocked_drawing_area(400, 400, |m| {
+            m.check_draw_rect(move |_, _, _, u, d| rects_out.borrow_mut().push((u, d)));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..4.0, (0.5..2000.0).log_scale())
+            .expect("Build chart error");
+
+        // 1000, 0, 10 and 1 samples in the four bins
+        let samples = [vec![0.5; 1000], vec![2.5; 10], vec![3.5]].concat();
+        let edges = [0.0, 1.0, 2.0, 3.0, 4.0];
+        let histogram = MatHistogram::new(samples, &edges, BLUE.filled()).log_floor(0.5);
+        assert_eq!(histogram.counts(), &[1000.0, 0.0, 10.0, 1.0]);
+        chart.draw_series(histogram).expect("Drawing Error");
+
+        let rects = rects.borrow();
+        assert_eq!(rects.len(), 4);
+        let floor = chart.backend_coord(&(0.0, 0.5)).1;
+        for ((top, bottom), count) in rects.iter().zip([1000.0, 0.5, 10.0, 1.0]) {
+            assert_eq!(bottom.1, floor);
+            assert_eq!(top.1, chart.backend_coord(&(0.0, count)).1);
+        }
+        // The bar heights grow with the logarithm of the counts: 1000 is 3 decades above 1,
+        // and 10 is 1 decade above 1
+        let height = |i: usize| f64::from(rects[i].1 .1 - rects[i].0 .1);
+        let decade = height(2) - height(3);
+        assert!((height(0) - height(3) - 3.0 * decade).abs() <= 2.0);
+        // The empty bin is flat, at the floor
+        assert_eq!(rects[1].0 .1, floor);
+    }
+
+    #[test]
+    fn test_stacked_histogram() {
+        let rects = Rc::new(RefCell::new(vec![]));
+        let rects_out = rects.clone();
+        let drawing_area = create_mocked_drawing_area(400, 400, |m| {
+            m.check_draw_rect(move |c, _, _, u, d| rects_out.borrow_mut().push((c, u, d)));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..3.0, 0.0..10.0)
+            .expect("Build chart error");
+
+        let a = vec![0.5, 0.5, 1.5, 2.5, 2.5, 2.5];
+        let b = vec![0.2, 1.2, 1.7, 1.9, 2.2];
+        let edges = [0.0, 1.0, 2.0, 3.0];
+        let layers = MatHistogram::stacked(&[a, b], &edges, &[BLUE.filled(), RED.filled()]);
+        assert_eq!(layers[0].counts(), &[2.0, 1.0, 3.0]);
+        assert_eq!(layers[1].counts(), &[1.0, 3.0, 1.0]);
+        for layer in layers {
+            chart.draw_series(layer).expect("Drawing Error");
+        }
+
+        let rects = rects.borrow();
+        assert_eq!(rects.len(), 6);
+        let (lower, upper) = rects.split_at(3);
+        let totals = [3.0, 4.0, 4.0];
+        for bin in 0..3 {
+            assert_eq!(lower[bin].0, BLUE.to_rgba());
+            assert_eq!(upper[bin].0, RED.to_rgba());
+            // The upper layer starts where the lower one ends
+            assert_eq!(upper[bin].2 .1, lower[bin].1 .1);
+            assert_eq!(lower[bin].2 .1, chart.backend_coord(&(0.0, 0.0)).1);
+            // and its top is the total count of the bin
+            assert_eq!(upper[bin].1 .1, chart.backend_coord(&(0.0, totals[bin])).1);
+        }
+    }
+}
@@ -0,0 +1,390 @@
+use crate::element::{BackendCoordAndArea, Drawable, PointCollection};
+use crate::style::colors::colormaps::{ColorMap, ViridisRGB};
+use crate::style::{Color, Norm};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use std::marker::PhantomData;
+use std::ops::Range;
+
+/// The number of colors the colormap of a [`MatImshow`] is sampled into, as in matplotlib
+const LUT_SIZE: usize = 256;
+
+/// How the pixels of a [`MatImshow`] between the centers of the cells are colored, see
+/// [`MatImshow::interpolation`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Interpolation {
+    /// Every pixel takes the value of the cell it falls into, giving a block per cell
+    #[default]
+    Nearest,
+    /// Every pixel takes the value interpolated linearly from the centers of the four
+    /// nearest cells, giving a smooth image
+    Bilinear,
+}
+
+/// How the image of a [`MatImshow`] fills its extent on screen, see [`MatImshow::aspect`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Aspect {
+    /// The cells are square on screen: the image is the largest one with square cells that
+    /// fits in its extent, centered in it
+    #[default]
+    Equal,
+    /// The image is stretched to fill its extent
+    Auto,
+}
+
+/**
+A 2D array displayed as an image, like matplotlib's `imshow`.
+
+Unlike a heatmap of cell rectangles, the image is rendered pixel by pixel over its extent, so
+that it can be interpolated smoothly between the cells, see [`MatImshow::interpolation`]. The
+first row of the array is at the top of the image, and each value is colored through a
+colormap over a [`Norm`], viridis over the range of the data by default, or in the under or
+over color of an extended norm; the cells without a finite value are left white.
+
+The image spans its extent in data coordinates, one unit per cell from the origin by
+default, see [`MatImshow::extent`]. As in matplotlib, the cells are square on screen unless
+the aspect is [`Aspect::Auto`], see [`MatImshow::aspect`].
+
+```rust
+use matplotters::prelude::*;
+
+let drawing_area = SVGBackend::new("imshow.svg", (300, 200)).into_drawing_area();
+let data = vec![vec![0.0, 1.0, 2.0], vec![3.0, 4.0, 5.0]];
+let image = MatImshow::new(data).extent(0.0..3.0, 0.0..2.0);
+let (x, y) = image.ranges();
+let mut chart = ChartBuilder::on(&drawing_area).build_cartesian_2d(x, y).unwrap();
+chart.draw_series(image).unwrap();
+```
+*/
+pub struct MatImshow<DB: DrawingBackend> {
+    data: Vec<Vec<f64>>,
+    colors: Vec<(u8, u8, u8)>,
+    norm: Norm,
+    extent: (Range<f64>, Range<f64>),
+    interpolation: Interpolation,
+    aspect: Aspect,
+    elements: Option<std::vec::IntoIter<ImageElement<DB>>>,
+}
+
+impl<DB: DrawingBackend> MatImshow<DB> {
+    /**
+    Creates an image of the array, given row by row from the top. The rows are expected to
+    have the same length; the longer ones are cut to the shortest one.
+
+    See [`MatImshow`] for more information.
+    */
+    pub fn new(data: Vec<Vec<f64>>) -> Self {
+        let cols = data.iter().map(Vec::len).min().unwrap_or(0);
+        let data: Vec<Vec<f64>> = data.into_iter().map(|row| row[..cols].to_vec()).collect();
+        let norm = Norm::autoscale(data.iter().flatten().copied());
+        let extent = (0.0..cols as f64, 0.0..data.len() as f64);
+        Self {
+            data,
+            colors: vec![],
+            norm,
+            extent,
+            interpolation: Interpolation::Nearest,
+            aspect: Aspect::Equal,
+            elements: None,
+        }
+        .colormap(&ViridisRGB {})
+    }
+
+    /**
+    Sets the colormap the values are colored with.
+
+    See [`MatImshow`] for more information.
+    */
+    pub fn colormap<C: Color, M: ColorMap<C, f64>>(mut self, colormap: &M) -> Self {
+        self.colors = (0..LUT_SIZE)
+            .map(|i| colormap.get_color(i as f64 / (LUT_SIZE - 1) as f64).rgb())
+            .collect();
+        self
+    }
+
+    /**
+    Sets the normalization of the values into the colormap, instead of the range of the data.
+
+    See [`MatImshow`] for more information.
+    */
+    pub fn norm(mut self, norm: Norm) -> Self {
+        self.norm = norm;
+        self
+    }
+
+    /**
+    Sets the extent of the image in data coordinates, like the `extent` of matplotlib's
+    `imshow`: its left and right edges are at the start and the end of `x`, and its bottom
+    and top edges at the start and the end of `y`.
+
+    See [`MatImshow`] for more information.
+    */
+    pub fn extent(mut self, x: Range<f64>, y: Range<f64>) -> Self {
+        self.extent = (x, y);
+        self
+    }
+
+    /**
+    Sets how the pixels are colored between the centers of the cells.
+
+    See [`MatImshow`] for more information.
+    */
+    pub fn interpolation(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /**
+    Sets how the image fills its extent on screen.
+
+    See [`MatImshow`] for more information.
+    */
+    pub fn aspect(mut self, aspect: Aspect) -> Self {
+        self.aspect = aspect;
+        self
+    }
+
+    /**
+    Returns the extent of the image, to be used as the ranges of the chart.
+
+    See [`MatImshow`] for more information.
+    */
+    pub fn ranges(&self) -> (Range<f64>, Range<f64>) {
+        self.extent.clone()
+    }
+
+    fn elements(&self) -> Vec<ImageElement<DB>> {
+        if self.data.iter().all(Vec::is_empty) {
+            return vec![];
+        }
+        let (x, y) = &self.extent;
+        let image = ImageElement {
+            corners: [(x.start, y.end), (x.end, y.start)],
+            data: self.data.clone(),
+            colors: self.colors.clone(),
+            norm: self.norm,
+            interpolation: self.interpolation,
+            aspect: self.aspect,
+            _p: PhantomData,
+        };
+        vec![image]
+    }
+}
+
+impl<DB: DrawingBackend> Iterator for MatImshow<DB> {
+    type Item = ImageElement<DB>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.elements.is_none() {
+            self.elements = Some(self.elements().into_iter());
+        }
+        self.elements.as_mut()?.next()
+    }
+}
+
+/// The image of a [`MatImshow`], anchored at its upper left and bottom right corners. The
+/// corners are translated without being moved into the plotting area, so that an image
+/// reaching out of the chart is cropped to the plotting area rather than squeezed into it.
+pub struct ImageElement<DB> {
+    corners: [(f64, f64); 2],
+    data: Vec<Vec<f64>>,
+    colors: Vec<(u8, u8, u8)>,
+    norm: Norm,
+    interpolation: Interpolation,
+    aspect: Aspect,
+    _p: PhantomData<DB>,
+}
+
+impl<DB> ImageElement<DB> {
+    /// The value at a position in the array, in cells from its upper left corner
+    fn value_at(&self, col: f64, row: f64) -> f64 {
+        let (rows, cols) = (self.data.len(), self.data[0].len());
+        let cell = |n: usize, v: f64| (v.max(0.0) as usize).min(n - 1);
+        match self.interpolation {
+            Interpolation::Nearest => self.data[cell(rows, row)][cell(cols, col)],
+            Interpolation::Bilinear => {
+                // Between the centers of the cells, clamped to the outer centers
+                let (u, v) = (
+                    (col - 0.5).clamp(0.0, (cols - 1) as f64),
+                    (row - 0.5).clamp(0.0, (rows - 1) as f64),
+                );
+                let (c0, r0) = (u as usize, v as usize);
+                let (c1, r1) = ((c0 + 1).min(cols - 1), (r0 + 1).min(rows - 1));
+                let (fu, fv) = (u - c0 as f64, v - r0 as f64);
+                let top = self.data[r0][c0] * (1.0 - fu) + self.data[r0][c1] * fu;
+                let bottom = self.data[r1][c0] * (1.0 - fu) + self.data[r1][c1] * fu;
+                top * (1.0 - fv) + bottom * fv
+            }
+        }
+    }
+
+    fn color_of(&self, value: f64) -> (u8, u8, u8) {
+        if !value.is_finite() {
+            return (255, 255, 255);
+        }
+        if let Some(color) = self.norm.extended_color(value) {
+            return color.rgb();
+        }
+        let t = self.norm.normalize(value).clamp(0.0, 1.0);
+        self.colors[(t * (self.colors.len() - 1) as f64).round() as usize]
+    }
+}
+
+impl<'a, DB> PointCollection<'a, (f64, f64), BackendCoordAndArea> for &'a ImageElement<DB> {
+    type Point = &'a (f64, f64);
+    type IntoIter = &'a [(f64, f64)];
+    fn point_iter(self) -> Self::IntoIter {
+        &self.corners
+    }
+}
+
+impl<DB: DrawingBackend> Drawable<DB, BackendCoordAndArea> for ImageElement<DB> {
+    fn draw<I: Iterator<Item = (BackendCoord, (BackendCoord, BackendCoord))>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let ((ul, area), (br, _)) = match (points.next(), points.next()) {
+            (Some(ul), Some(br)) => (ul, br),
+            _ => return Ok(()),
+        };
+        let (rows, cols) = (self.data.len() as f64, self.data[0].len() as f64);
+        let (mut x0, mut x1) = (f64::from(ul.0), f64::from(br.0));
+        let (mut y0, mut y1) = (f64::from(ul.1), f64::from(br.1));
+        if self.aspect == Aspect::Equal {
+            let cell = ((x1 - x0).abs() / cols).min((y1 - y0).abs() / rows);
+            let (cx, cy) = ((x0 + x1) / 2.0, (y0 + y1) / 2.0);
+            let hw = (x1 - x0).signum() * cell * cols / 2.0;
+            let hh = (y1 - y0).signum() * cell * rows / 2.0;
+            x0 = cx - hw;
+            x1 = cx + hw;
+            y0 = cy - hh;
+            y1 = cy + hh;
+        }
+
+        // The image cropped to the plotting area
+        let (left, top) = (x0.min(x1).round() as i32, y0.min(y1).round() as i32);
+        let (right, bottom) = (x0.max(x1).round() as i32, y0.max(y1).round() as i32);
+        let (left, top) = (left.max(area.0 .0), top.max(area.0 .1));
+        let (right, bottom) = (right.min(area.1 .0), bottom.min(area.1 .1));
+        if right <= left || bottom <= top {
+            return Ok(());
+        }
+        let (w, h) = ((right - left) as u32, (bottom - top) as u32);
+        let mut buffer = Vec::with_capacity((w * h * 3) as usize);
+        for py in top..bottom {
+            let row = (f64::from(py) + 0.5 - y0) / (y1 - y0) * rows;
+            for px in left..right {
+                let col = (f64::from(px) + 0.5 - x0) / (x1 - x0) * cols;
+                let (r, g, b) = self.color_of(self.value_at(col, row));
+                buffer.extend([r, g, b]);
+            }
+        }
+        backend.blit_bitmap((left, top), (w, h), &buffer)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use std::rc::Rc;
+
+    /// Draw the image on a 51 x 41 chart over `0..5` and `0..4`, 10 pixels per unit, and
+    /// return the color of every pixel drawn
+    fn draw_image(image: MatImshow<MockedBackend>) -> HashMap<BackendCoord, RGBAColor> {
+        let pixels = Rc::new(RefCell::new(HashMap::new()));
+        let pixels_out = pixels.clone();
+        let drawing_area = create_mocked_drawing_area(51, 41, |m| {
+            m.check_draw_pixel(move |c, pos| {
+                pixels_out.borrow_mut().insert(pos, c);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..5.0, 0.0..4.0)
+            .expect("Build chart error");
+        assert_eq!(chart.backend_coord(&(1.0, 3.0)), (10, 10));
+        chart.draw_series(image).expect("Drawing Error");
+        let ret = pixels.borrow().clone();
+        ret
+    }
+
+    #[test]
+    fn test_imshow_nearest() {
+        let data = vec![vec![0.0, 1.0, 2.0], vec![3.0, 4.0, 5.0]];
+        let image = MatImshow::new(data)
+            .extent(1.0..4.0, 1.0..3.0)
+            .norm(Norm::new(0.0, 5.0));
+        assert_eq!(image.ranges(), (1.0..4.0, 1.0..3.0));
+        let pixels = draw_image(image);
+
+        // The image covers its extent, from (10, 10) to (40, 30), and nothing else
+        assert_eq!(pixels.len(), 30 * 20);
+        // The colormap is sampled in 256 colors
+        let color = |v: f64| ViridisRGB::get_color((v / 5.0 * 255.0).round() / 255.0).rgb();
+        for ((x, y), c) in &pixels {
+            assert!((10..40).contains(x) && (10..30).contains(y));
+            // Each cell is a 10 x 10 block, the first row at the top
+            let (col, row) = ((x - 10) / 10, (y - 10) / 10);
+            assert_eq!(c.rgb(), color(f64::from(row * 3 + col)));
+        }
+    }
+
+    #[test]
+    fn test_imshow_cropped_to_chart() {
+        // Ten cells over 0..10, twice as wide as the chart over 0..5
+        let data = vec![(0..10).map(f64::from).collect::<Vec<_>>()];
+        let color = |v: f64| ViridisRGB::get_color((v / 9.0 * 255.0).round() / 255.0).rgb();
+        let check = |pixels: HashMap<BackendCoord, RGBAColor>, rows: std::ops::Range<i32>| {
+            // The cells keep their size, 10 pixels each, and the ones out of the chart are
+            // cropped instead of being squeezed in
+            assert_eq!(pixels.len() as i32, 51 * (rows.end - rows.start));
+            for ((x, y), c) in &pixels {
+                assert!((0..=50).contains(x) && rows.contains(y));
+                assert_eq!(c.rgb(), color(f64::from((*x / 10).min(9))));
+            }
+        };
+        let stretched = MatImshow::new(data.clone())
+            .extent(0.0..10.0, 0.0..4.0)
+            .aspect(Aspect::Auto);
+        check(draw_image(stretched), 0..40);
+        // The square cells are computed over the whole extent, 100 pixels wide: the cells
+        // are 10 x 10 pixels, centered in the extent 40 pixels high
+        let square = MatImshow::new(data).extent(0.0..10.0, 0.0..4.0);
+        check(draw_image(square), 15..25);
+    }
+
+    #[test]
+    fn test_imshow_aspect_and_bilinear() {
+        let data = vec![vec![0.0, 10.0], vec![10.0, 20.0]];
+        // A 2 x 2 image over a wide extent 4 units wide and 2 high
+        let stretched = draw_image(
+            MatImshow::new(data.clone())
+                .extent(0.0..4.0, 0.0..2.0)
+                .aspect(Aspect::Auto),
+        );
+        let xs = |pixels: &HashMap<BackendCoord, RGBAColor>| {
+            let xs: Vec<i32> = pixels.keys().map(|p| p.0).collect();
+            (*xs.iter().min().unwrap(), *xs.iter().max().unwrap())
+        };
+        assert_eq!(xs(&stretched), (0, 39));
+        // With square cells, the image is 20 pixels wide, centered in the extent
+        let square = draw_image(MatImshow::new(data.clone()).extent(0.0..4.0, 0.0..2.0));
+        assert_eq!(xs(&square), (10, 29));
+        assert_eq!(square.len(), 20 * 20);
+
+        // Bilinear interpolation goes from the top left to the bottom right value smoothly
+        let smooth = draw_image(
+            MatImshow::new(data)
+                .extent(0.0..4.0, 0.0..2.0)
+                .interpolation(Interpolation::Bilinear),
+        );
+        let distinct: std::collections::HashSet<_> = smooth.values().map(|c| c.rgb()).collect();
+        assert!(distinct.len() > 4);
+        let first = ViridisRGB::get_color(0.0).rgb();
+        let last = ViridisRGB::get_color(1.0).rgb();
+        assert_eq!(smooth[&(10, 20)].rgb(), first);
+        assert_eq!(smooth[&(29, 39)].rgb(), last);
+    }
+}
@@ -0,0 +1,293 @@
+use super::{MatLineSeries, MatSeriesError};
+use crate::chart::{LegendGlyph, StyledSeries};
+use crate::element::{
+    Drawable, DynElement, IntoDynElement, MarkerShape, PathElement, PointCollection,
+};
+use crate::style::ShapeStyle;
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+
+/**
+A line with markers and vertical error bars in a single series, like matplotlib's
+`errorbar(x, y, yerr=...)` with a connecting line.
+
+Every point gets a whisker from `y - yerr` to `y + yerr`, ended by caps, and the line with
+its markers is drawn on top of the whiskers. The points with a NaN y are gaps in the line
+and have no error bar. On a log-scaled y axis, see [`MatLineErrorSeries::log_floor`].
+*/
+pub struct MatLineErrorSeries<DB: DrawingBackend> {
+    // (x, y, yerr)
+    data: Vec<(f64, f64, f64)>,
+    style: ShapeStyle,
+    error_style: ShapeStyle,
+    point_size: u32,
+    cap_size: u32,
+    floor: Option<f64>,
+    elements: Option<std::vec::IntoIter<DynElement<'static, DB, (f64, f64)>>>,
+}
+
+impl<DB: DrawingBackend> MatLineErrorSeries<DB> {
+    /**
+    Creates the series from the points and their errors. The markers are 3 pixels, and the
+    caps 6 pixels wide, by default.
+
+    Returns [`MatSeriesError::LengthMismatch`] if `y` or `yerr` has not one value per x.
+    */
+    pub fn new<S: Into<ShapeStyle>>(
+        x: &[f64],
+        y: &[f64],
+        yerr: &[f64],
+        style: S,
+    ) -> Result<Self, MatSeriesError> {
+        for values in [y, yerr] {
+            if values.len() != x.len() {
+                return Err(MatSeriesError::LengthMismatch {
+                    expected: x.len(),
+                    actual: values.len(),
+                });
+            }
+        }
+        let style = style.into();
+        Ok(Self {
+            data: x
+                .iter()
+                .zip(y)
+                .zip(yerr)
+                .map(|((x, y), e)| (*x, *y, e.abs()))
+                .collect(),
+            style,
+            error_style: style,
+            point_size: 3,
+            cap_size: 6,
+            floor: None,
+            elements: None,
+        })
+    }
+
+    /**
+    Sets the style of the error bars, which is the style of the line by default.
+
+    See [`MatLineErrorSeries`] for more information.
+    */
+    pub fn error_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.error_style = style.into();
+        self
+    }
+
+    /**
+    Sets the size of the markers, in pixels. A size of 0 hides the markers.
+
+    See [`MatLineErrorSeries`] for more information.
+    */
+    pub fn point_size(mut self, size: u32) -> Self {
+        self.point_size = size;
+        self
+    }
+
+    /**
+    Sets the width of the caps ending the whiskers, in pixels. A width of 0 removes the caps.
+
+    See [`MatLineErrorSeries`] for more information.
+    */
+    pub fn cap_size(mut self, size: u32) -> Self {
+        self.cap_size = size;
+        self
+    }
+
+    /**
+    Prepares the error bars for a log-scaled y axis starting at `floor`, such as one built
+    with `(floor..top).log_scale()`. The whiskers still span `y - yerr` to `y + yerr` in data
+    space, so they are asymmetric on the axis, but a lower bound below the floor, which may
+    be zero or negative and can't be shown, is clamped to the floor, with its cap there. The
+    points with a y that is not positive are gaps in the line and have no error bar. The
+    floor must be positive.
+
+    See [`MatLineErrorSeries`] for more information.
+    */
+    pub fn log_floor(mut self, floor: f64) -> Self {
+        self.floor = Some(floor);
+        self
+    }
+
+    fn elements(&self) -> Vec<DynElement<'static, DB, (f64, f64)>> {
+        let mut ret = vec![];
+        for (x, y, e) in self.data.iter().copied() {
+            if !y.is_finite() || !e.is_finite() {
+                continue;
+            }
+            let (lo, hi) = match self.floor {
+                Some(_) if y <= 0.0 => continue,
+                Some(floor) => ((y - e).max(floor), (y + e).max(floor)),
+                None => (y - e, y + e),
+            };
+            ret.push(PathElement::new(vec![(x, lo), (x, hi)], self.error_style).into_dyn());
+            if self.cap_size > 0 {
+                for end in [lo, hi] {
+                    ret.push(Cap::new((x, end), self.cap_size, self.error_style).into_dyn());
+                }
+            }
+        }
+        let points = self.data.iter().map(|(x, y, _)| match self.floor {
+            Some(_) if *y <= 0.0 => (*x, f64::NAN),
+            _ => (*x, *y),
+        });
+        ret.extend(MatLineSeries::new(points, self.style).point_size(self.point_size));
+        ret
+    }
+}
+
+/// The horizontal cap ending a whisker, centered on a point and with a width in pixels
+struct Cap {
+    point: [(f64, f64); 1],
+    width: u32,
+    style: ShapeStyle,
+}
+
+impl Cap {
+    fn new(point: (f64, f64), width: u32, style: ShapeStyle) -> Self {
+        Self {
+            point: [point],
+            width,
+            style,
+        }
+    }
+}
+
+impl<'a> PointCollection<'a, (f64, f64)> for &'a Cap {
+    type Point = &'a (f64, f64);
+    type IntoIter = &'a [(f64, f64)];
+    fn point_iter(self) -> &'a [(f64, f64)] {
+        &self.point
+    }
+}
+
+impl<DB: DrawingBackend> Drawable<DB> for Cap {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        if let Some((x, y)) = points.next() {
+            let half = (self.width / 2) as i32;
+            backend.draw_path(vec![(x - half, y), (x + half, y)], &self.style)?;
+        }
+        Ok(())
+    }
+}
+
+impl<DB: DrawingBackend> StyledSeries for MatLineErrorSeries<DB> {
+    fn series_style(&self) -> ShapeStyle {
+        self.style
+    }
+
+    fn legend_glyph(&self) -> LegendGlyph {
+        match self.point_size {
+            0 => LegendGlyph::Line(self.style),
+            size => LegendGlyph::LineMarker(MarkerShape::Circle, size, self.style),
+        }
+    }
+}
+
+impl<DB: DrawingBackend> Iterator for MatLineErrorSeries<DB> {
+    type Item = DynElement<'static, DB, (f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.elements.is_none() {
+            self.elements = Some(self.elements().into_iter());
+        }
+        self.elements.as_mut()?.next()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_line_error_series() {
+        let paths = Rc::new(RefCell::new(vec![]));
+        let circles = Rc::new(RefCell::new(vec![]));
+        let (paths_out, circles_out) = (paths.clone(), circles.clone());
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_path(move |c, _, p| paths_out.borrow_mut().push((c, p)));
+            m.check_draw_circle(move |c, _, _, center, r| {
+                assert_eq!(c, RED.to_rgba());
+                assert_eq!(r, 3);
+                circles_out.borrow_mut().push(center);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Build chart error");
+
+        let (x, y, yerr) = ([1.0, 2.0, 3.0], [2.0, 4.0, 3.0], [0.5, 1.0, 2.0]);
+        let series = MatLineErrorSeries::new(&x, &y, &yerr, RED)
+            .unwrap()
+            .error_style(BLUE)
+            .cap_size(8);
+        chart.draw_series(series).expect("Drawing Error");
+
+        let c = |x: f64, y: f64| chart.backend_coord(&(x, y));
+        let paths = paths.borrow();
+        // A whisker and two caps per point, then the line
+        assert_eq!(paths.len(), 3 * 3 + 1);
+        for i in 0..3 {
+            let (lo, hi) = (y[i] - yerr[i], y[i] + yerr[i]);
+            let bar = &paths[3 * i..3 * i + 3];
+            assert!(bar.iter().all(|(color, _)| *color == BLUE.to_rgba()));
+            assert_eq!(bar[0].1, vec![c(x[i], lo), c(x[i], hi)]);
+            for (cap, end) in bar[1..].iter().zip([lo, hi]) {
+                let (cx, cy) = c(x[i], end);
+                assert_eq!(cap.1, vec![(cx - 4, cy), (cx + 4, cy)]);
+            }
+        }
+        let (color, line) = &paths[9];
+        assert_eq!(*color, RED.to_rgba());
+        assert_eq!(*line, vec![c(1.0, 2.0), c(2.0, 4.0), c(3.0, 3.0)]);
+        assert_eq!(
+            *circles.borrow(),
+            vec![c(1.0, 2.0), c(2.0, 4.0), c(3.0, 3.0)]
+        );
+
+        assert!(MatLineErrorSeries::<MockedBackend>::new(&x, &y, &yerr[1..], RED).is_err());
+    }
+
+    #[test]
+    fn test_log_floor() {
+        let paths = Rc::new(RefCell::new(vec![]));
+        let paths_out = paths.clone();
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_path(move |_, _, p| paths_out.borrow_mut().push(p));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..4.0, (0.1..100.0).log_scale())
+            .expect("Build chart error");
+
+        // The first lower bound is negative, the second one is positive. The point at zero
+        // can't be shown and has no error bar.
+        let (x, y, yerr) = ([1.0, 2.0, 3.0], [0.5, 10.0, 0.0], [1.5, 5.0, 1.0]);
+        let series = MatLineErrorSeries::new(&x, &y, &yerr, RED)
+            .unwrap()
+            .point_size(0)
+            .log_floor(0.1);
+        chart.draw_series(series).expect("Drawing Error");
+
+        let c = |x: f64, y: f64| chart.backend_coord(&(x, y));
+        let paths = paths.borrow();
+        assert_eq!(paths.len(), 2 * 3 + 1);
+        assert_eq!(paths[6], vec![c(1.0, 0.5), c(2.0, 10.0)]);
+        let cap = |(cx, cy): (i32, i32)| vec![(cx - 3, cy), (cx + 3, cy)];
+        // The lower cap is clamped to the floor, at the bottom of the axis
+        assert_eq!(paths[0], vec![c(1.0, 0.1), c(1.0, 2.0)]);
+        assert_eq!(paths[1], cap(c(1.0, 0.1)));
+        assert_eq!(paths[2], cap(c(1.0, 2.0)));
+        // The bar from 5 to 15 is longer below the point than above it in log space
+        let (_, lo) = c(2.0, 5.0);
+        let (_, mid) = c(2.0, 10.0);
+        let (_, hi) = c(2.0, 15.0);
+        assert_eq!(paths[4], cap((c(2.0, 5.0).0, lo)));
+        assert_eq!(paths[5], cap((c(2.0, 15.0).0, hi)));
+        assert!(lo - mid > mid - hi);
+    }
+}
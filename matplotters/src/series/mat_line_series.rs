@@ -1,16 +1,28 @@
-use crate::element::{Circle, DashedPathElement, DynElement, IntoDynElement, PathElement};
-use crate::style::{ShapeStyle, SizeDesc, BLACK};
-use itertools::Itertools;
-use num_traits::PrimInt;
-use plotters_backend::DrawingBackend;
+use crate::chart::{LegendGlyph, StyledSeries};
+use crate::element::{
+    Drawable, DynElement, IntoDynElement, Marker, MarkerShape, PathElement, PointCollection, Text,
+};
+use crate::style::{
+    Color, ColorCycle, IntoFont, LineStyle, LineStyleCycle, MarkerCycle, Pattern, RcParams,
+    ShapeStyle, TextStyle, BLACK,
+};
+use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
+use std::cell::{Cell, RefCell};
 use std::cmp::Ordering;
+use std::collections::HashSet;
 use std::iter::repeat;
 use std::marker::PhantomData;
+use std::ops::Sub;
+use std::rc::Rc;
 
 /**
 The line series object, which takes an iterator of data points in guest coordinate system
 and creates appropriate lines and points with the given style.
 
+Like in matplotlib, a point with a NaN coordinate is missing: it has no marker and it breaks
+the line into separate segments, unless the missing values are interpolated, see
+[`MatLineSeries::interpolate_missing`].
+
 # Example
 
 ```
@@ -37,31 +49,494 @@ pub struct MatLineSeries<DB: DrawingBackend, X, Y> {
     style: ShapeStyle,
     y: Vec<Y>,
     x: Vec<X>,
+    missing: Vec<bool>,
+    interpolated: Vec<bool>,
+    point_idx: usize,
     point_size: u32,
+    marker: MarkerShape,
+    marker_pattern: Option<Pattern>,
+    line_style: LineStyle,
+    downsample: Option<DownsampleReport>,
+    gap_report: Option<GapReport>,
+    render_mode: RenderMode,
+    step: Option<StepMode>,
+    pending_segments: Option<std::vec::IntoIter<StyledSegment<X, Y>>>,
+    transform: Option<PointTransform<X, Y>>,
+    max_gap: Option<GapCheck<X>>,
+    simplify: Option<f64>,
+    highlight: Option<Highlight>,
+    trend: Option<Trend<Y>>,
+    shadow: Option<(BackendCoord, ShapeStyle)>,
+    pending_shadow: Option<std::vec::IntoIter<ShadowPart<X, Y>>>,
+    named: Vec<(usize, String)>,
+    named_idx: usize,
     phantom: PhantomData<DB>,
 }
 
-// impl<DB: DrawingBackend, Coord: Clone + 'static> Iterator for MatLineSeries<DB, Coord> {
-//     type Item = DynElement<'static, DB, Coord>;
-//     fn next(&mut self) -> Option<Self::Item> {
-//         if !self.data.is_empty() {
-//             if self.point_size > 0 && self.point_idx < self.data.len() {
-//                 let idx = self.point_idx;
-//                 self.point_idx += 1;
-//                 return Some(
-//                     Circle::new(self.data[idx].clone(), self.point_size, self.style).into_dyn(),
-//                 );
-//             }
-//             let mut data = vec![];
-//             std::mem::swap(&mut self.data, &mut data);
-//             Some(PathElement::new(data, self.style).into_dyn())
-//         } else {
-//             None
-//         }
-//     }
-// }
+/// A transform applied to the points of a [`MatLineSeries`] when they are drawn
+type PointTransform<X, Y> = Rc<dyn Fn(X, Y) -> (X, Y)>;
+
+/// Whether the line breaks between two consecutive x values, see [`MatLineSeries::max_gap`]
+type GapCheck<X> = Rc<dyn Fn(&X, &X) -> bool>;
+
+/// A marker, by the index of its point, or a line of the shadow of a [`MatLineSeries`]
+enum ShadowPart<X, Y> {
+    Marker(usize),
+    Line(Vec<(X, Y)>, LineStyle),
+}
+
+/// The highlighted subset of the points of a [`MatLineSeries`], with its style, and whether
+/// the rest of the series is dimmed
+type Highlight = (HashSet<usize>, ShapeStyle, bool);
+
+/// How two consecutive y values compare, with the styles of the increasing, decreasing and
+/// flat parts of the line, see [`MatLineSeries::color_by_trend`]
+type Trend<Y> = (Rc<dyn Fn(&Y, &Y) -> Ordering>, [ShapeStyle; 3]);
+
+/// A part of the line of a [`MatLineSeries`] with its style and its dash pattern
+type StyledSegment<X, Y> = (Vec<(X, Y)>, ShapeStyle, LineStyle);
+
+/// How the line of a [`MatLineSeries`] is rendered, see [`MatLineSeries::render_mode`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum RenderMode {
+    /// The points are connected by a path
+    #[default]
+    Line,
+    /// For every pixel column, a vertical line is drawn from the minimum to the maximum of
+    /// the points falling into the column, the usual way of rendering dense waveforms
+    MinMaxEnvelope,
+}
+
+/// The way the points of a [`MatLineSeries`] are connected when drawn as steps, like the
+/// `steps-pre` and `steps-post` draw styles of matplotlib, see [`MatLineSeries::step`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StepMode {
+    /// The step to the next y value happens at the previous x value
+    Pre,
+    /// The step to the next y value happens at the next x value
+    Post,
+}
+
+/// The opacity of the points and the line of a [`MatLineSeries`] outside of the highlighted
+/// subset, relative to the base style, see [`MatLineSeries::highlight_indices`]
+const DIMMED_ALPHA: f64 = 0.3;
+
+/// Whether a value is a NaN, which marks a missing point of the series. Only floating point
+/// values are not equal to themselves.
+#[allow(clippy::eq_op)]
+fn is_nan<T: PartialEq>(value: &T) -> bool {
+    value != value
+}
+
+impl<DB: DrawingBackend, X: Clone + PartialEq, Y: Clone + PartialEq> MatLineSeries<DB, X, Y> {
+    fn is_missing(&self, idx: usize) -> bool {
+        self.missing.get(idx).copied().unwrap_or(false)
+            || is_nan(&self.x[idx])
+            || is_nan(&self.y[idx])
+    }
+
+    /// Whether the y value of the point at the index was interpolated, see
+    /// [`MatLineSeries::interpolate_missing`]
+    fn is_interpolated(&self, idx: usize) -> bool {
+        self.interpolated.get(idx).copied().unwrap_or(false)
+    }
+
+    /// Whether the point at the index is measured, neither missing nor interpolated
+    fn is_measured(&self, idx: usize) -> bool {
+        !self.is_missing(idx) && !self.is_interpolated(idx)
+    }
+
+    /// The point at the index, as drawn
+    fn point(&self, idx: usize) -> (X, Y) {
+        let (x, y) = (self.x[idx].clone(), self.y[idx].clone());
+        match &self.transform {
+            Some(transform) => transform(x, y),
+            None => (x, y),
+        }
+    }
+
+    /// Whether the line breaks between the point at the index and the previous one, because
+    /// they are too far apart in x
+    fn breaks_before(&self, idx: usize) -> bool {
+        match &self.max_gap {
+            Some(too_far) if idx > 0 => too_far(&self.x[idx - 1], &self.x[idx]),
+            _ => false,
+        }
+    }
+
+    fn is_highlighted(&self, idx: usize) -> bool {
+        matches!(&self.highlight, Some((selected, _, _)) if selected.contains(&idx))
+    }
+
+    /// The style of the point at the index, which depends on the highlighted subset
+    fn point_style(&self, idx: usize) -> ShapeStyle {
+        match &self.highlight {
+            Some((selected, style, _)) if selected.contains(&idx) => *style,
+            Some((_, _, true)) => self.dimmed_style(),
+            _ => self.style,
+        }
+    }
+
+    fn dimmed_style(&self) -> ShapeStyle {
+        ShapeStyle {
+            color: self.style.color.mix(DIMMED_ALPHA),
+            ..self.style
+        }
+    }
+
+    /// The lines to draw with their styles: the whole line, then the highlighted parts on top
+    /// of it if a subset is highlighted
+    fn styled_segments(&self) -> Vec<StyledSegment<X, Y>> {
+        let base = self.base_segments();
+        match &self.highlight {
+            None => base
+                .into_iter()
+                .flat_map(|(s, line_style)| {
+                    self.split_by_trend(s)
+                        .into_iter()
+                        .map(move |(s, style)| (s, style, line_style))
+                })
+                .collect(),
+            Some((_, style, dim)) => {
+                let highlighted =
+                    self.segments(|idx| !self.is_missing(idx) && self.is_highlighted(idx));
+                let rest = if *dim {
+                    self.dimmed_style()
+                } else {
+                    self.style
+                };
+                base.into_iter()
+                    .map(|(s, line_style)| (s, rest, line_style))
+                    .chain(
+                        highlighted
+                            .into_iter()
+                            .map(|s| (s, *style, self.line_style)),
+                    )
+                    .collect()
+            }
+        }
+    }
+
+    /// Split a part of the line at its turning points into the runs where it increases,
+    /// decreases or stays flat, with the style of their trend. The turning points belong to
+    /// both runs they join. Without a trend the part is kept whole, with the base style.
+    fn split_by_trend(&self, segment: Vec<(X, Y)>) -> Vec<(Vec<(X, Y)>, ShapeStyle)> {
+        let (compare, styles) = match &self.trend {
+            Some(trend) => trend,
+            None => return vec![(segment, self.style)],
+        };
+        let style = |trend: Ordering| match trend {
+            Ordering::Greater => styles[0],
+            Ordering::Less => styles[1],
+            Ordering::Equal => styles[2],
+        };
+        let mut ret = vec![];
+        let mut current: Vec<(X, Y)> = vec![];
+        let mut current_trend = Ordering::Equal;
+        for point in segment {
+            if let Some(last) = current.last() {
+                let trend = compare(&point.1, &last.1);
+                if current.len() > 1 && trend != current_trend {
+                    let turning_point = last.clone();
+                    ret.push((std::mem::take(&mut current), style(current_trend)));
+                    current.push(turning_point);
+                }
+                current_trend = trend;
+            }
+            current.push(point);
+        }
+        if current.len() > 1 {
+            ret.push((current, style(current_trend)));
+        }
+        ret
+    }
+
+    /// The whole line with its dash patterns: the measured parts, then the interpolated
+    /// parts, dotted, which span from the last measured point before them to the first one
+    /// after them
+    fn base_segments(&self) -> Vec<(Vec<(X, Y)>, LineStyle)> {
+        let mut ret: Vec<_> = self
+            .segments(|idx| self.is_measured(idx))
+            .into_iter()
+            .map(|s| (s, self.line_style))
+            .collect();
+        let mut idx = 0;
+        while idx < self.x.len() {
+            if !self.is_interpolated(idx) {
+                idx += 1;
+                continue;
+            }
+            let start = idx.saturating_sub(1);
+            while self.is_interpolated(idx) {
+                idx += 1;
+            }
+            let estimated = self.segments(|i| (start..=idx).contains(&i) && !self.is_missing(i));
+            ret.extend(estimated.into_iter().map(|s| (s, LineStyle::Dotted)));
+        }
+        ret
+    }
+
+    /// Split the line into the runs of consecutive points that are included and close enough
+    /// in x, and add the corners of the steps if needed. Runs of a single point have no line
+    /// to draw.
+    fn segments(&self, include: impl Fn(usize) -> bool) -> Vec<Vec<(X, Y)>> {
+        let mut ret = vec![];
+        let mut current: Vec<(X, Y)> = vec![];
+        for idx in 0..self.x.len() {
+            if !include(idx) || self.breaks_before(idx) {
+                if current.len() > 1 {
+                    ret.push(std::mem::take(&mut current));
+                }
+                current.clear();
+                if !include(idx) {
+                    continue;
+                }
+            }
+            let point = self.point(idx);
+            if let (Some(mode), Some(last)) = (self.step, current.last()) {
+                let corner = match mode {
+                    StepMode::Pre => (last.0.clone(), point.1.clone()),
+                    StepMode::Post => (point.0.clone(), last.1.clone()),
+                };
+                if corner != point && &corner != last {
+                    current.push(corner);
+                }
+            }
+            current.push(point);
+        }
+        if current.len() > 1 {
+            ret.push(current);
+        }
+        ret
+    }
+
+    /**
+    Returns the number of elements the series emits when drawn, without drawing it: one
+    marker per measured point if the points are shown, plus one path per run of consecutive
+    measured points that are not too far apart, one per run of interpolated points, and one
+    more per run of highlighted points. A shadow doubles the markers and the paths of the
+    whole line. Each named marker adds one more, see [`MatLineSeries::named_markers`].
+
+    See [`MatLineSeries`] for more information and examples.
+    */
+    pub fn element_count(&self) -> usize {
+        let mut markers = 0;
+        let mut segments = 0;
+        let mut run = 0;
+        for idx in 0..self.x.len() {
+            if !self.is_measured(idx) {
+                run = 0;
+                continue;
+            }
+            if self.breaks_before(idx) {
+                run = 0;
+            }
+            markers += 1;
+            run += 1;
+            if run == 2 {
+                segments += 1;
+            }
+        }
+        if self.point_size == 0 {
+            markers = 0;
+        }
+        if self.interpolated.contains(&true) {
+            segments = self.base_segments().len();
+        }
+        if self.trend.is_some() && self.highlight.is_none() {
+            segments = self
+                .base_segments()
+                .into_iter()
+                .map(|(s, _)| self.split_by_trend(s).len())
+                .sum();
+        }
+        let mut count = segments + markers;
+        if self.shadow.is_some() {
+            count *= 2;
+        }
+        if self.highlight.is_some() {
+            count += self
+                .segments(|idx| !self.is_missing(idx) && self.is_highlighted(idx))
+                .len();
+        }
+        count += self
+            .named
+            .iter()
+            .filter(|(idx, _)| *idx < self.x.len() && !self.is_missing(*idx))
+            .count();
+        count
+    }
+}
+
+impl<DB: DrawingBackend, X: Clone + PartialEq + 'static, Y: Clone + PartialEq + 'static>
+    MatLineSeries<DB, X, Y>
+{
+    fn marker_element(
+        &self,
+        idx: usize,
+        style: ShapeStyle,
+        offset: Option<BackendCoord>,
+    ) -> DynElement<'static, DB, (X, Y)> {
+        let marker = Marker::new(self.point(idx), self.point_size, self.marker, style);
+        match self.marker_pattern {
+            Some(pattern) => with_offset(marker.pattern(pattern), offset),
+            None => with_offset(marker, offset),
+        }
+    }
+
+    /// A reference marker at a named point, a diamond larger than the markers of the points,
+    /// labeled with the name above its right
+    fn named_marker_element(&self, idx: usize, name: String) -> DynElement<'static, DB, (X, Y)> {
+        NamedMarker {
+            point: [self.point(idx)],
+            name,
+            size: self.point_size.max(3) + 2,
+            style: self.style,
+        }
+        .into_dyn()
+    }
+
+    fn line_element(
+        &self,
+        data: Vec<(X, Y)>,
+        style: ShapeStyle,
+        line_style: LineStyle,
+        offset: Option<BackendCoord>,
+    ) -> DynElement<'static, DB, (X, Y)> {
+        if self.downsample.is_some() || self.render_mode != RenderMode::Line {
+            // The shadow is left out of the downsampling report
+            let report = self.downsample.clone().filter(|_| offset.is_none());
+            return with_offset(
+                DecimatedPath::new(data, style, self.render_mode, report),
+                offset,
+            );
+        }
+        if line_style != LineStyle::Solid || self.simplify.is_some() {
+            let pattern = line_style.dash_pattern(style.stroke_width);
+            let path = PatternPath::new(data, style, pattern).tolerance(self.simplify);
+            return with_offset(path, offset);
+        }
+        with_offset(PathElement::new(data, style), offset)
+    }
+
+    /// The markers and the line of the shadow, drawn before the series
+    fn shadow_parts(&self) -> Vec<ShadowPart<X, Y>> {
+        if self.shadow.is_none() {
+            return vec![];
+        }
+        let mut ret = vec![];
+        if self.point_size > 0 {
+            let points = (0..self.x.len()).filter(|idx| self.is_measured(*idx));
+            ret.extend(points.map(ShadowPart::Marker));
+        }
+        let lines = self.base_segments();
+        ret.extend(
+            lines
+                .into_iter()
+                .map(|(s, line_style)| ShadowPart::Line(s, line_style)),
+        );
+        ret
+    }
+}
+
+impl<DB: DrawingBackend, X: Clone + PartialEq + 'static, Y: Clone + PartialEq + 'static> Iterator
+    for MatLineSeries<DB, X, Y>
+{
+    type Item = DynElement<'static, DB, (X, Y)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pending_shadow.is_none() {
+            self.pending_shadow = Some(self.shadow_parts().into_iter());
+        }
+        if let (Some(part), Some((offset, style))) = (
+            self.pending_shadow.as_mut().and_then(|p| p.next()),
+            self.shadow,
+        ) {
+            return Some(match part {
+                ShadowPart::Marker(idx) => self.marker_element(idx, style, Some(offset)),
+                ShadowPart::Line(data, line_style) => {
+                    self.line_element(data, style, line_style, Some(offset))
+                }
+            });
+        }
+        while self.point_size > 0 && self.point_idx < self.x.len() {
+            let idx = self.point_idx;
+            self.point_idx += 1;
+            if self.is_measured(idx) {
+                return Some(self.marker_element(idx, self.point_style(idx), None));
+            }
+        }
+        if self.pending_segments.is_none() {
+            if let Some(report) = &self.gap_report {
+                let skipped: Vec<_> = (0..self.x.len()).filter(|i| self.is_missing(*i)).collect();
+                report.set(self.x.len() - skipped.len(), skipped);
+            }
+            self.pending_segments = Some(self.styled_segments().into_iter());
+        }
+        if let Some((data, style, line_style)) = self.pending_segments.as_mut()?.next() {
+            return Some(self.line_element(data, style, line_style, None));
+        }
+        while let Some((idx, name)) = self.named.get(self.named_idx).cloned() {
+            self.named_idx += 1;
+            if idx < self.x.len() && !self.is_missing(idx) {
+                return Some(self.named_marker_element(idx, name));
+            }
+        }
+        None
+    }
+}
 
 impl<DB: DrawingBackend, X, Y> MatLineSeries<DB, X, Y> {
+    /**
+    Creates a new line series based on a data iterator and a given style. A style with the
+    default stroke width of 1 takes the line width of the [`RcParams`].
+
+    See [`MatLineSeries`] for more information and examples.
+    */
+    pub fn new<I: IntoIterator<Item = (X, Y)>, S: Into<ShapeStyle>>(iter: I, style: S) -> Self {
+        let (x, y) = iter.into_iter().unzip();
+        let mut style = style.into();
+        if style.stroke_width == 1 {
+            style.stroke_width = RcParams::get().line_width;
+        }
+        Self {
+            style,
+            y,
+            x,
+            missing: vec![],
+            interpolated: vec![],
+            point_idx: 0,
+            point_size: 0,
+            marker: MarkerShape::Circle,
+            marker_pattern: None,
+            line_style: LineStyle::Solid,
+            downsample: None,
+            gap_report: None,
+            render_mode: RenderMode::Line,
+            step: None,
+            pending_segments: None,
+            transform: None,
+            max_gap: None,
+            simplify: None,
+            highlight: None,
+            trend: None,
+            shadow: None,
+            pending_shadow: None,
+            named: vec![],
+            named_idx: 0,
+            phantom: PhantomData,
+        }
+    }
+
+    /**
+    Sets the style of the line and the points in the series.
+
+    See [`MatLineSeries`] for more information and examples.
+    */
+    pub fn style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
     /**
     Sets the size of the points in the series, in pixels.
 
@@ -71,6 +546,794 @@ impl<DB: DrawingBackend, X, Y> MatLineSeries<DB, X, Y> {
         self.point_size = size;
         self
     }
+
+    /**
+    Sets the shape of the points in the series, which are circles by default.
+
+    See [`MatLineSeries`] for more information and examples.
+    */
+    pub fn marker(mut self, shape: MarkerShape) -> Self {
+        self.marker = shape;
+        self
+    }
+
+    /**
+    Fills the points with a pattern instead of a solid color, which suits large points, see
+    [`Marker::pattern`].
+
+    See [`MatLineSeries`] for more information and examples.
+    */
+    pub fn marker_fill_pattern(mut self, pattern: Pattern) -> Self {
+        self.marker_pattern = Some(pattern);
+        self
+    }
+
+    /**
+    Uses the next marker shape of the default [`MarkerCycle`], so that several series are
+    distinguishable without colors. The points are shown with the marker size of the
+    [`RcParams`] if they were hidden.
+
+    See [`MatLineSeries`] for more information and examples.
+    */
+    pub fn auto_marker(mut self) -> Self {
+        self.marker = MarkerCycle::next_default();
+        if self.point_size == 0 {
+            self.point_size = RcParams::get().marker_size;
+        }
+        self
+    }
+
+    /**
+    Uses the next color of the default [`ColorCycle`] for the line and the points, keeping
+    the other properties of the style.
+
+    See [`MatLineSeries`] for more information and examples.
+    */
+    pub fn auto_color(mut self) -> Self {
+        self.style.color = ColorCycle::next_default().to_rgba();
+        self
+    }
+
+    /**
+    Sets the dash pattern of the line, which is solid by default.
+
+    See [`MatLineSeries`] for more information and examples.
+    */
+    pub fn line_style(mut self, style: LineStyle) -> Self {
+        self.line_style = style;
+        self
+    }
+
+    /**
+    Uses the next line style of the default [`LineStyleCycle`], so that overlapping lines are
+    distinguishable without colors.
+
+    See [`MatLineSeries`] for more information and examples.
+    */
+    pub fn auto_line_style(mut self) -> Self {
+        self.line_style = LineStyleCycle::next_default();
+        self
+    }
+
+    /**
+    Highlights a subset of the points, such as the selection of an interactive plot: the
+    points at the given indices are drawn with the highlight style, and so are the parts of
+    the line between consecutive ones, on top of the whole line. The rest of the series is
+    dimmed to 30% of the opacity of the base style.
+
+    See [`MatLineSeries`] for more information and examples.
+    */
+    pub fn highlight_indices<I: IntoIterator<Item = usize>, S: Into<ShapeStyle>>(
+        mut self,
+        indices: I,
+        highlight_style: S,
+    ) -> Self {
+        self.highlight = Some((indices.into_iter().collect(), highlight_style.into(), true));
+        self
+    }
+
+    /**
+    Emphasizes the most recent points of a live or streaming series: the last `count` points
+    are drawn with the emphasis style, usually brighter or thicker, and so is the line between
+    them, on top of the whole line. If `fade` is set, the older points and the rest of the
+    line are dimmed to 30% of the opacity of the base style, otherwise they keep it.
+
+    See [`MatLineSeries`] for more information and examples.
+    */
+    pub fn emphasize_recent<S: Into<ShapeStyle>>(
+        mut self,
+        count: usize,
+        emphasis_style: S,
+        fade: bool,
+    ) -> Self {
+        let recent = self.x.len().saturating_sub(count)..self.x.len();
+        self.highlight = Some((recent.collect(), emphasis_style.into(), fade));
+        self
+    }
+
+    /**
+    Colors the line by its trend: it is split at its turning points into the runs where it
+    increases, decreases or stays flat, drawn with `up_style`, `down_style` and `flat_style`
+    respectively, like the rises and falls of a price chart. The markers keep the style of
+    the series. A highlighted subset takes precedence over the trend colors.
+
+    See [`MatLineSeries`] for more information and examples.
+    */
+    pub fn color_by_trend<U: Into<ShapeStyle>, D: Into<ShapeStyle>, F: Into<ShapeStyle>>(
+        mut self,
+        up_style: U,
+        down_style: D,
+        flat_style: F,
+    ) -> Self
+    where
+        Y: PartialOrd,
+    {
+        self.trend = Some((
+            Rc::new(|next: &Y, prev: &Y| next.partial_cmp(prev).unwrap_or(Ordering::Equal)),
+            [up_style.into(), down_style.into(), flat_style.into()],
+        ));
+        self
+    }
+
+    /**
+    Tags points with names, such as the events of a trajectory, and draws a labeled
+    reference marker at each of them, on top of the series: a filled diamond larger than the
+    markers of the points, with the name above its right. The indices out of the series or
+    of missing points are left out.
+
+    See [`MatLineSeries`] for more information and examples.
+    */
+    pub fn named_markers(mut self, names: Vec<(usize, String)>) -> Self {
+        self.named = names;
+        self
+    }
+
+    /**
+    Draws a drop shadow behind the series: a copy of its line and markers shifted by
+    `offset` pixels, in the color at the given opacity. Backends have no blur, so the shadow
+    has sharp edges.
+
+    See [`MatLineSeries`] for more information and examples.
+    */
+    pub fn shadow<C: Color>(mut self, offset: (i32, i32), color: C, alpha: f64) -> Self {
+        let style = ShapeStyle {
+            color: color.mix(alpha),
+            ..self.style
+        };
+        self.shadow = Some((offset, style));
+        self
+    }
+
+    /**
+    Breaks the line between consecutive points that are more than `dx` apart in x, as if
+    there were a missing point between them, while closer points stay connected. This keeps
+    outages of irregular time series visible. The markers of the points are still drawn.
+
+    See [`MatLineSeries`] for more information and examples.
+    */
+    pub fn max_gap<D>(mut self, dx: D) -> Self
+    where
+        X: Clone + Sub<Output = D> + 'static,
+        D: PartialOrd + 'static,
+    {
+        self.max_gap = Some(Rc::new(move |prev: &X, next: &X| {
+            next.clone() - prev.clone() > dx
+        }));
+        self
+    }
+
+    /**
+    Simplifies the line when it is drawn with the Ramer-Douglas-Peucker algorithm, which
+    removes the points that stay within `tolerance` pixels of the simplified line, such as
+    collinear points, while keeping the ends and the corners. Unlike the downsampling, which
+    keeps the extremes of every pixel column of dense data, this keeps the shape of the line
+    with fewer points, reducing the size of vector output. The markers of the points are
+    still drawn. The simplification is left out of a downsampled line.
+
+    See [`MatLineSeries`] for more information and examples.
+    */
+    pub fn simplify(mut self, tolerance: f64) -> Self {
+        self.simplify = Some(tolerance);
+        self
+    }
+
+    /**
+    Collapses the runs of consecutive identical points into single points, such as the
+    repeated samples of a logged signal, to reduce the overdraw of the markers and of the
+    line without changing its shape. The missing points are never collapsed, so the gaps
+    stay. The indices of the points selected before, such as the highlighted and the named
+    ones, are moved to the points left, a collapsed point taking the index of its run.
+
+    See [`MatLineSeries`] for more information and examples.
+    */
+    pub fn dedup_consecutive(mut self) -> Self
+    where
+        X: PartialEq,
+        Y: PartialEq,
+    {
+        let keep: Vec<bool> = (0..self.x.len())
+            .map(|idx| {
+                idx == 0
+                    || self.missing.get(idx).copied().unwrap_or(false)
+                    || self.missing.get(idx - 1).copied().unwrap_or(false)
+                    || self.x[idx] != self.x[idx - 1]
+                    || self.y[idx] != self.y[idx - 1]
+            })
+            .collect();
+        // The index of each point among the points left, the first point being always kept
+        let remapped: Vec<usize> = keep
+            .iter()
+            .scan(0, |kept, keep| {
+                *kept += usize::from(*keep);
+                Some(*kept - 1)
+            })
+            .collect();
+        let remap = |idx: usize| remapped.get(idx).copied();
+        if let Some((highlighted, _, _)) = self.highlight.as_mut() {
+            *highlighted = highlighted.iter().filter_map(|idx| remap(*idx)).collect();
+        }
+        self.named = std::mem::take(&mut self.named)
+            .into_iter()
+            .filter_map(|(idx, name)| Some((remap(idx)?, name)))
+            .collect();
+        fn retain<T>(values: &mut Vec<T>, keep: &[bool]) {
+            let mut idx = 0;
+            values.retain(|_| {
+                idx += 1;
+                keep.get(idx - 1).copied().unwrap_or(true)
+            });
+        }
+        retain(&mut self.x, &keep);
+        retain(&mut self.y, &keep);
+        retain(&mut self.missing, &keep);
+        retain(&mut self.interpolated, &keep);
+        self
+    }
+
+    /**
+    Draws the line as steps instead of straight segments between the points.
+
+    See [`MatLineSeries`] for more information and examples.
+    */
+    pub fn step(mut self, mode: StepMode) -> Self {
+        self.step = Some(mode);
+        self
+    }
+
+    /**
+    Sets how the line is rendered, see [`RenderMode`].
+
+    See [`MatLineSeries`] for more information and examples.
+    */
+    pub fn render_mode(mut self, mode: RenderMode) -> Self {
+        self.render_mode = mode;
+        self
+    }
+
+    /**
+    Enables the automatic downsampling of the line.
+
+    When a line has far more points than the drawing area has pixel columns, the points
+    falling into the same pixel column are reduced to the minimum and the maximum of the
+    column before drawing, which keeps the extremes of the signal visible. The returned
+    [`DownsampleReport`] tells how many points were reduced once the series is drawn.
+
+    See [`MatLineSeries`] for more information and examples.
+    */
+    pub fn auto_downsample(&mut self) -> DownsampleReport {
+        let report = DownsampleReport::default();
+        self.downsample = Some(report.clone());
+        report
+    }
+
+    /**
+    Returns the stored points of the series, which are not affected by the unit conversions.
+
+    See [`MatLineSeries`] for more information and examples.
+    */
+    pub fn points(&self) -> impl Iterator<Item = (&X, &Y)> {
+        self.x.iter().zip(self.y.iter())
+    }
+
+    /// Compose a transform with the current one, applied when the points are drawn
+    fn map_points<F: Fn(X, Y) -> (X, Y) + 'static>(mut self, f: F) -> Self
+    where
+        X: 'static,
+        Y: 'static,
+    {
+        self.transform = Some(match self.transform.take() {
+            Some(prev) => Rc::new(move |x, y| {
+                let (x, y) = prev(x, y);
+                f(x, y)
+            }),
+            None => Rc::new(f),
+        });
+        self
+    }
+
+    /**
+    Enables the reporting of the missing points of the line.
+
+    The returned [`GapReport`] tells, once the series is drawn, how many points were drawn
+    and which points were skipped because they have a NaN coordinate, which helps surfacing
+    data quality issues.
+
+    See [`MatLineSeries`] for more information and examples.
+    */
+    pub fn gap_report(&mut self) -> GapReport {
+        let report = GapReport::default();
+        self.gap_report = Some(report.clone());
+        report
+    }
+}
+
+/// The points of a [`MatLineSeries`] that were drawn and skipped, see
+/// [`MatLineSeries::gap_report`]. The report is filled in when the series is drawn.
+#[derive(Clone, Default, Debug)]
+pub struct GapReport(Rc<RefCell<(usize, Vec<usize>)>>);
+
+impl GapReport {
+    /// The number of points that are not missing, which were drawn
+    pub fn drawn_points(&self) -> usize {
+        self.0.borrow().0
+    }
+
+    /// The indices of the points with a NaN coordinate, which were skipped
+    pub fn skipped_indices(&self) -> Vec<usize> {
+        self.0.borrow().1.clone()
+    }
+
+    fn set(&self, drawn: usize, skipped: Vec<usize>) {
+        *self.0.borrow_mut() = (drawn, skipped);
+    }
+}
+
+impl<DB: DrawingBackend, X, Y> StyledSeries for MatLineSeries<DB, X, Y> {
+    fn series_style(&self) -> ShapeStyle {
+        self.style
+    }
+
+    fn legend_glyph(&self) -> LegendGlyph {
+        match self.point_size {
+            0 => LegendGlyph::Line(self.style),
+            size => LegendGlyph::LineMarker(self.marker, size, self.style),
+        }
+    }
+}
+
+/// The number of points of a line that were actually drawn after the automatic
+/// downsampling of a [`MatLineSeries`], see [`MatLineSeries::auto_downsample`].
+/// The report is filled in when the series is drawn.
+#[derive(Clone, Default, Debug)]
+pub struct DownsampleReport(Rc<Cell<(usize, usize)>>);
+
+impl DownsampleReport {
+    /// The number of points of the line before the downsampling
+    pub fn input_points(&self) -> usize {
+        self.0.get().0
+    }
+
+    /// The number of points drawn after the downsampling
+    pub fn output_points(&self) -> usize {
+        self.0.get().1
+    }
+
+    /// The number of points removed by the downsampling
+    pub fn reduced_points(&self) -> usize {
+        self.input_points().saturating_sub(self.output_points())
+    }
+
+    fn add(&self, input: usize, output: usize) {
+        let (i, o) = self.0.get();
+        self.0.set((i + input, o + output));
+    }
+}
+
+/// The ratio between the number of points and the number of pixel columns above which the
+/// automatic downsampling kicks in
+const OVERDRAW_RATIO: usize = 4;
+
+/// Split the points into the runs falling into the same pixel column, and return the indices
+/// of the point with the minimal and the maximal y of every run
+fn column_extents(points: &[BackendCoord]) -> Vec<(usize, usize)> {
+    let mut ret = Vec::new();
+    let mut start = 0;
+    while start < points.len() {
+        let column = points[start].0;
+        let mut end = start;
+        let (mut min, mut max) = (start, start);
+        while end < points.len() && points[end].0 == column {
+            if points[end].1 < points[min].1 {
+                min = end;
+            }
+            if points[end].1 > points[max].1 {
+                max = end;
+            }
+            end += 1;
+        }
+        ret.push((min, max));
+        start = end;
+    }
+    ret
+}
+
+/// Reduce the points of every run falling into the same pixel column to the minimum and the
+/// maximum of the run, in the order they occur
+fn decimate_columns(points: &[BackendCoord]) -> Vec<BackendCoord> {
+    let mut ret = Vec::new();
+    for (min, max) in column_extents(points) {
+        ret.push(points[min.min(max)]);
+        if min != max {
+            ret.push(points[min.max(max)]);
+        }
+    }
+    ret
+}
+
+/// A path which gets decimated per pixel column at drawing time: either when it is much
+/// denser than the drawing area, or always when it is rendered as a min/max envelope
+struct DecimatedPath<Coord> {
+    points: Vec<Coord>,
+    style: ShapeStyle,
+    mode: RenderMode,
+    report: Option<DownsampleReport>,
+}
+
+impl<Coord> DecimatedPath<Coord> {
+    fn new(
+        points: Vec<Coord>,
+        style: ShapeStyle,
+        mode: RenderMode,
+        report: Option<DownsampleReport>,
+    ) -> Self {
+        Self {
+            points,
+            style,
+            mode,
+            report,
+        }
+    }
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a DecimatedPath<Coord> {
+    type Point = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for DecimatedPath<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        (width, _): (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let mut points: Vec<_> = points.collect();
+        let input = points.len();
+        if self.mode == RenderMode::MinMaxEnvelope {
+            let extents = column_extents(&points);
+            if let Some(report) = &self.report {
+                // A column holding a single sample is drawn as a single point
+                let output = extents
+                    .iter()
+                    .map(|(min, max)| if min == max { 1 } else { 2 })
+                    .sum();
+                report.add(input, output);
+            }
+            for (min, max) in extents {
+                backend.draw_line(points[min], points[max], &self.style)?;
+            }
+            return Ok(());
+        }
+        if self.report.is_some() && input > OVERDRAW_RATIO * width.max(1) as usize {
+            points = decimate_columns(&points);
+        }
+        if let Some(report) = &self.report {
+            report.add(input, points.len());
+        }
+        backend.draw_path(points, &self.style)
+    }
+}
+
+impl<DB: DrawingBackend, X: 'static> MatLineSeries<DB, X, f64> {
+    /**
+    Multiplies the y values by `factor` when they are drawn, to display the data in another
+    unit, such as `scale_y(3.281)` for meters to feet. The stored data is untouched.
+
+    The unit conversions apply in the order they are set.
+
+    See [`MatLineSeries`] for more information and examples.
+    */
+    pub fn scale_y(self, factor: f64) -> Self {
+        self.map_points(move |x, y| (x, y * factor))
+    }
+
+    /**
+    Adds `delta` to the y values when they are drawn. The stored data is untouched.
+
+    See [`MatLineSeries::scale_y`] for more information.
+    */
+    pub fn offset_y(self, delta: f64) -> Self {
+        self.map_points(move |x, y| (x, y + delta))
+    }
+}
+
+impl<DB: DrawingBackend, Y: 'static> MatLineSeries<DB, f64, Y> {
+    /**
+    Multiplies the x values by `factor` when they are drawn. The stored data is untouched.
+
+    See [`MatLineSeries::scale_y`] for more information.
+    */
+    pub fn scale_x(self, factor: f64) -> Self {
+        self.map_points(move |x, y| (x * factor, y))
+    }
+
+    /**
+    Adds `delta` to the x values when they are drawn. The stored data is untouched.
+
+    See [`MatLineSeries::scale_y`] for more information.
+    */
+    pub fn offset_x(self, delta: f64) -> Self {
+        self.map_points(move |x, y| (x + delta, y))
+    }
+}
+
+impl<DB: DrawingBackend> MatLineSeries<DB, f64, f64> {
+    /**
+    Fills the missing y values between measured points by linear interpolation in x, like
+    pandas' `interpolate`, so that the line stays continuous. The interpolated parts of the
+    line are dotted and their points have no marker, to show that they are estimated rather
+    than measured. The missing points before the first measured point and after the last
+    one, and those with a missing x, stay missing.
+
+    See [`MatLineSeries`] for more information and examples.
+    */
+    pub fn interpolate_missing(mut self) -> Self {
+        let measured: Vec<usize> = (0..self.x.len())
+            .filter(|idx| !self.is_missing(*idx))
+            .collect();
+        self.interpolated = vec![false; self.x.len()];
+        for pair in measured.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+            let ((xa, ya), (xb, yb)) = ((self.x[a], self.y[a]), (self.x[b], self.y[b]));
+            for idx in a + 1..b {
+                if self.x[idx].is_nan() {
+                    continue;
+                }
+                self.y[idx] = if xb == xa {
+                    ya
+                } else {
+                    ya + (yb - ya) * (self.x[idx] - xa) / (xb - xa)
+                };
+                self.interpolated[idx] = true;
+                if let Some(missing) = self.missing.get_mut(idx) {
+                    *missing = false;
+                }
+            }
+        }
+        self
+    }
+}
+
+/// A labeled reference marker at a named point of a [`MatLineSeries`], see
+/// [`MatLineSeries::named_markers`]
+struct NamedMarker<Coord> {
+    point: [Coord; 1],
+    name: String,
+    size: u32,
+    style: ShapeStyle,
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a NamedMarker<Coord> {
+    type Point = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.point
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for NamedMarker<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (x, y) = match points.next() {
+            Some(point) => point,
+            None => return Ok(()),
+        };
+        let marker = Marker::new((x, y), self.size, MarkerShape::Diamond, self.style.filled());
+        marker.draw(std::iter::once((x, y)), backend, parent_dim)?;
+        let offset = self.size as i32 + 2;
+        let font: TextStyle = ("sans-serif", 12).into_font().color(&self.style.color);
+        let label = (x + offset, y - offset);
+        Text::new(self.name.as_str(), label, font).draw(std::iter::once(label), backend, parent_dim)
+    }
+}
+
+/// An element drawn shifted by an offset in pixels, such as the shadow of a series
+struct Offset<Coord, E> {
+    points: Vec<Coord>,
+    element: E,
+    offset: BackendCoord,
+}
+
+impl<'a, Coord, E> PointCollection<'a, Coord> for &'a Offset<Coord, E> {
+    type Point = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, E: Drawable<DB>, DB: DrawingBackend> Drawable<DB> for Offset<Coord, E> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let (dx, dy) = self.offset;
+        let points = points.map(|(x, y)| (x + dx, y + dy));
+        self.element.draw(points, backend, parent_dim)
+    }
+}
+
+/// Shift the element by the offset if there is one
+fn with_offset<DB, Coord, E>(
+    element: E,
+    offset: Option<BackendCoord>,
+) -> DynElement<'static, DB, Coord>
+where
+    DB: DrawingBackend,
+    Coord: Clone,
+    Coord: 'static,
+    E: Drawable<DB> + 'static,
+    for<'a> &'a E: PointCollection<'a, Coord>,
+{
+    match offset {
+        Some(offset) => {
+            let points = element.point_iter().into_iter();
+            let points = points
+                .map(|p| std::borrow::Borrow::<Coord>::borrow(&p).clone())
+                .collect();
+            Offset {
+                points,
+                element,
+                offset,
+            }
+            .into_dyn()
+        }
+        None => element.into_dyn(),
+    }
+}
+
+/// A path drawn as the dashes of a pattern of alternating dash and gap lengths, in pixels.
+/// The pattern continues across the corners of the path. With a tolerance, the path is
+/// simplified before it is drawn, see [`MatLineSeries::simplify`].
+struct PatternPath<Coord> {
+    points: Vec<Coord>,
+    style: ShapeStyle,
+    pattern: Vec<f64>,
+    tolerance: Option<f64>,
+}
+
+impl<Coord> PatternPath<Coord> {
+    fn new(points: Vec<Coord>, style: ShapeStyle, pattern: Vec<f64>) -> Self {
+        Self {
+            points,
+            style,
+            pattern,
+            tolerance: None,
+        }
+    }
+
+    fn tolerance(mut self, tolerance: Option<f64>) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+}
+
+/// Simplify a path with the Ramer-Douglas-Peucker algorithm: the ends are kept, and so is
+/// the point of a stretch farthest from the line between the ends of the stretch if it is
+/// more than `tolerance` pixels away, which splits the stretch in two
+fn simplify_path(points: &[BackendCoord], tolerance: f64) -> Vec<BackendCoord> {
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+    let distance = |p: BackendCoord, a: BackendCoord, b: BackendCoord| {
+        let (px, py) = (f64::from(p.0 - a.0), f64::from(p.1 - a.1));
+        let (dx, dy) = (f64::from(b.0 - a.0), f64::from(b.1 - a.1));
+        let len2 = dx * dx + dy * dy;
+        let t = if len2 == 0.0 {
+            0.0
+        } else {
+            ((px * dx + py * dy) / len2).clamp(0.0, 1.0)
+        };
+        (px - t * dx).hypot(py - t * dy)
+    };
+    let mut keep = vec![false; points.len()];
+    let last = points.len() - 1;
+    keep[0] = true;
+    keep[last] = true;
+    let mut stretches = vec![(0, last)];
+    while let Some((first, end)) = stretches.pop() {
+        let farthest = (first + 1..end)
+            .map(|i| (i, distance(points[i], points[first], points[end])))
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+        if let Some((i, d)) = farthest {
+            if d > tolerance {
+                keep[i] = true;
+                stretches.push((first, i));
+                stretches.push((i, end));
+            }
+        }
+    }
+    points
+        .iter()
+        .zip(keep)
+        .filter_map(|(p, k)| k.then_some(*p))
+        .collect()
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a PatternPath<Coord> {
+    type Point = &'a Coord;
+    type IntoIter = &'a [Coord];
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<Coord, DB: DrawingBackend> Drawable<DB> for PatternPath<Coord> {
+    fn draw<I: Iterator<Item = BackendCoord>>(
+        &self,
+        points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let mut points: Vec<_> = points.collect();
+        if let Some(tolerance) = self.tolerance {
+            points = simplify_path(&points, tolerance);
+        }
+        if self.pattern.iter().sum::<f64>() <= 0.0 {
+            return backend.draw_path(points, &self.style);
+        }
+        let to_i = |(x, y): (f64, f64)| (x.round() as i32, y.round() as i32);
+        let (mut idx, mut left) = (0, self.pattern[0]);
+        let mut dash = vec![];
+        for pair in points.windows(2) {
+            let (x0, y0) = (f64::from(pair[0].0), f64::from(pair[0].1));
+            let (dx, dy) = (f64::from(pair[1].0) - x0, f64::from(pair[1].1) - y0);
+            let len = dx.hypot(dy);
+            let mut pos = 0.0;
+            while pos < len {
+                let on = idx % 2 == 0;
+                let step = left.min(len - pos);
+                if on && dash.is_empty() {
+                    dash.push(to_i((x0 + dx * pos / len, y0 + dy * pos / len)));
+                }
+                pos += step;
+                left -= step;
+                if on {
+                    dash.push(to_i((x0 + dx * pos / len, y0 + dy * pos / len)));
+                }
+                if left <= 0.0 {
+                    if on {
+                        backend.draw_path(std::mem::take(&mut dash), &self.style)?;
+                    }
+                    idx = (idx + 1) % self.pattern.len();
+                    left = self.pattern[idx];
+                }
+            }
+        }
+        if dash.len() > 1 {
+            backend.draw_path(dash, &self.style)?;
+        }
+        Ok(())
+    }
 }
 
 macro_rules! impl_line_series_from_y_for_int_type {
@@ -96,13 +1359,7 @@ macro_rules! impl_line_series_from_y_for_int_type {
                     x = (0..possibly_wrong_y_len).collect();
                 }
 
-                Self {
-                    style: BLACK.into(),
-                    y,
-                    x,
-                    point_size: 0,
-                    phantom: PhantomData,
-                }
+                Self::new(x.into_iter().zip(y), BLACK)
             }
         }
     };
@@ -132,23 +1389,87 @@ impl<DB: DrawingBackend, X, Y> MatLineSeries<DB, X, Y> {
         let mut x: Vec<X> = x_iter.into_iter().collect();
         let mut y: Vec<Y> = y_iter.into_iter().collect();
 
-        match x.len().cmp(&y.len()) {
-            Ordering::Less => {
-                y = y.into_iter().take(x.len()).collect();
-            }
-            Ordering::Greater => {
-                x = x.into_iter().take(y.len()).collect();
-            }
-            _ => (),
-        }
+        match x.len().cmp(&y.len()) {
+            Ordering::Less => {
+                y = y.into_iter().take(x.len()).collect();
+            }
+            Ordering::Greater => {
+                x = x.into_iter().take(y.len()).collect();
+            }
+            _ => (),
+        }
+
+        Self::new(x.into_iter().zip(y), BLACK)
+    }
+}
+
+impl<DB: DrawingBackend, X, Y: Default> MatLineSeries<DB, X, Y> {
+    /**
+    Creates a new line series from the given iterators for x values and optional y values,
+    where `None` marks a missing point. Like NaN values, the missing points break the line
+    and have no marker, but this also works for integer y values.
+
+    The missing y values are stored as `Y::default()`, as returned by
+    [`MatLineSeries::points`].
+    */
+    pub fn from_xy_opt<XI: IntoIterator<Item = X>, YI: IntoIterator<Item = Option<Y>>>(
+        x_iter: XI,
+        y_iter: YI,
+    ) -> Self {
+        let y: Vec<Option<Y>> = y_iter.into_iter().collect();
+        let mut missing: Vec<bool> = y.iter().map(Option::is_none).collect();
+        let mut series = Self::from_xy(x_iter, y.into_iter().map(Option::unwrap_or_default));
+        missing.truncate(series.x.len());
+        series.missing = missing;
+        series
+    }
+}
+
+impl<DB: DrawingBackend, X: Clone, Y: Clone> MatLineSeries<DB, X, Y> {
+    /**
+    Creates a line series connecting the points in an explicit order, for shapes and
+    outlines whose points are not sorted by x: the line visits `points[order[0]]`, then
+    `points[order[1]]`, and so on. An index may appear several times, so that repeating the
+    first index at the end closes an outline; the indices past the end of the points are
+    ignored.
+
+    See [`MatLineSeries`] for more information and examples.
+    */
+    pub fn in_order<S: Into<ShapeStyle>>(points: &[(X, Y)], order: &[usize], style: S) -> Self {
+        Self::new(
+            order.iter().filter_map(|idx| points.get(*idx).cloned()),
+            style,
+        )
+    }
+}
+
+impl<DB: DrawingBackend, X: Clone + PartialOrd> MatLineSeries<DB, X, u32> {
+    /**
+    Creates the cumulative count of events from their timestamps, a step function that is
+    0 before the first event and increments by one at each event. Events at the same time
+    make a single step of their number. The timestamps need not be sorted.
 
-        Self {
-            style: BLACK.into(),
-            y,
-            x,
-            point_size: 0,
-            phantom: PhantomData,
+    See [`MatLineSeries`] for more information and examples.
+    */
+    pub fn cumulative_count<I: IntoIterator<Item = X>, S: Into<ShapeStyle>>(
+        events: I,
+        style: S,
+    ) -> Self {
+        let mut events: Vec<X> = events.into_iter().collect();
+        events.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        let mut points: Vec<(X, u32)> = vec![];
+        for (i, t) in events.into_iter().enumerate() {
+            match points.last_mut() {
+                Some(last) if last.0 == t => last.1 = i as u32 + 1,
+                _ => {
+                    if points.is_empty() {
+                        points.push((t.clone(), 0));
+                    }
+                    points.push((t, i as u32 + 1));
+                }
+            }
         }
+        Self::new(points, style).step(StepMode::Post)
     }
 }
 
@@ -202,7 +1523,11 @@ impl<DB: DrawingBackend, X, Y> MatLineSeries<DB, X, Y> {
 
 #[cfg(test)]
 mod test {
+    use super::{RenderMode, StepMode};
     use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use std::rc::Rc;
 
     #[test]
     fn test_line_series() {
@@ -227,12 +1552,12 @@ mod test {
             .build_cartesian_2d(0..100, 0..100)
             .expect("Build chart error");
 
-        // chart
-        //     .draw_series(MatLineSeries::new(
-        //         (0..100).map(|x| (x, x)),
-        //         Into::<ShapeStyle>::into(RED).stroke_width(3),
-        //     ))
-        //     .expect("Drawing Error");
+        chart
+            .draw_series(MatLineSeries::new(
+                (0..100).map(|x| (x, x)),
+                Into::<ShapeStyle>::into(RED).stroke_width(3),
+            ))
+            .expect("Drawing Error");
         chart
             .draw_series(DashedLineSeries::new(
                 (0..=50).map(|x| (0, x)),
@@ -242,4 +1567,859 @@ mod test {
             ))
             .expect("Drawing Error");
     }
+
+    #[test]
+    fn test_auto_downsample() {
+        let path = Rc::new(RefCell::new(vec![]));
+        let path_out = path.clone();
+        let drawing_area = create_mocked_drawing_area(500, 300, |m| {
+            m.check_draw_path(move |_, _, p| path_out.borrow_mut().extend(p));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..100_000, -1.5..1.5)
+            .expect("Build chart error");
+
+        let data: Vec<_> = (0..100_000)
+            .map(|i| {
+                (
+                    i,
+                    (f64::from(i) * 0.37).sin() + (f64::from(i) * 0.001).cos() * 0.5,
+                )
+            })
+            .collect();
+        let mut series = MatLineSeries::new(data.clone(), BLUE);
+        let report = series.auto_downsample();
+        chart.draw_series(series).expect("Drawing Error");
+
+        let path = path.borrow();
+        assert_eq!(report.input_points(), 100_000);
+        assert_eq!(report.output_points(), path.len());
+        assert_eq!(report.reduced_points(), 100_000 - path.len());
+        // Roughly two points per pixel column
+        assert!(path.len() <= 2 * 500);
+        assert!(path.len() >= 2 * 490);
+
+        // The extremes of every column are kept
+        let mut columns: HashMap<i32, (i32, i32)> = HashMap::new();
+        for p in data.iter().map(|p| chart.backend_coord(p)) {
+            let e = columns.entry(p.0).or_insert((p.1, p.1));
+            *e = (e.0.min(p.1), e.1.max(p.1));
+        }
+        for (column, (min, max)) in columns {
+            assert!(path.contains(&(column, min)));
+            assert!(path.contains(&(column, max)));
+        }
+    }
+
+    #[test]
+    fn test_no_downsample_when_sparse() {
+        let drawing_area = create_mocked_drawing_area(500, 300, |m| {
+            m.check_draw_path(|_, _, p| assert_eq!(p.len(), 1000));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..1000, 0..1000)
+            .expect("Build chart error");
+
+        let mut series = MatLineSeries::new((0..1000).map(|x| (x, x % 7)), BLUE);
+        let report = series.auto_downsample();
+        chart.draw_series(series).expect("Drawing Error");
+        assert_eq!(report.reduced_points(), 0);
+    }
+
+    #[test]
+    fn test_min_max_envelope() {
+        let lines = Rc::new(RefCell::new(vec![]));
+        let lines_out = lines.clone();
+        let drawing_area = create_mocked_drawing_area(200, 100, |m| {
+            m.check_draw_line(move |_, _, from, to| lines_out.borrow_mut().push((from, to)));
+            m.drop_check(|b| assert_eq!(b.num_draw_path_call, 0));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..20_000, -1.0..1.0)
+            .expect("Build chart error");
+
+        // An oscillation much faster than the pixel columns
+        let data: Vec<_> = (0..20_000)
+            .map(|i| (i, if i % 2 == 0 { 0.8 } else { -0.8 }))
+            .collect();
+        chart
+            .draw_series(
+                MatLineSeries::new(data.clone(), BLUE).render_mode(RenderMode::MinMaxEnvelope),
+            )
+            .expect("Drawing Error");
+
+        let lines = lines.borrow();
+        assert!(lines.len() >= 199 && lines.len() <= 200);
+        let (top, bottom) = (
+            chart.backend_coord(&(0, 0.8)).1,
+            chart.backend_coord(&(0, -0.8)).1,
+        );
+        for (i, &(from, to)) in lines.iter().enumerate() {
+            // One vertical extent per column, from the minimum to the maximum
+            assert_eq!(from.0, lines[0].0 .0 + i as i32);
+            assert_eq!(to.0, from.0);
+            assert_eq!((from.1, to.1), (top, bottom));
+        }
+    }
+
+    #[test]
+    fn test_sparse_min_max_envelope() {
+        let drawing_area = create_mocked_drawing_area(500, 300, |_| {});
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Build chart error");
+
+        // One sample per column, except for two samples sharing the last column
+        let data = [(1.0, 1.0), (3.0, 5.0), (5.0, 2.0), (8.0, 7.0), (8.001, 3.0)];
+        let mut series = MatLineSeries::new(data, BLUE).render_mode(RenderMode::MinMaxEnvelope);
+        let report = series.auto_downsample();
+        chart.draw_series(series).expect("Drawing Error");
+
+        assert_eq!(report.input_points(), 5);
+        assert_eq!(report.output_points(), 5);
+        assert_eq!(report.reduced_points(), 0);
+    }
+
+    #[test]
+    fn test_element_count() {
+        let check = |series: MatLineSeries<_, f64, f64>, expected: usize| {
+            assert_eq!(series.element_count(), expected);
+            let drawing_area = create_mocked_drawing_area(100, 100, move |m| {
+                m.drop_check(move |b| {
+                    assert_eq!(
+                        (b.num_draw_circle_call + b.num_draw_path_call) as usize,
+                        expected
+                    );
+                });
+            });
+            let mut chart = ChartBuilder::on(&drawing_area)
+                .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+                .expect("Build chart error");
+            chart.draw_series(series).expect("Drawing Error");
+        };
+
+        let y = vec![1.0, 2.0, f64::NAN, 4.0, 5.0, 6.0, f64::NAN, 8.0];
+        let x: Vec<f64> = (0..8).map(f64::from).collect();
+
+        check(MatLineSeries::from_xy(x.clone(), vec![1.0; 8]), 1);
+        check(
+            MatLineSeries::from_xy(x.clone(), vec![1.0; 8]).point_size(2),
+            9,
+        );
+        // The gaps split the line, and the isolated last point has no segment
+        check(MatLineSeries::from_xy(x.clone(), y.clone()), 2);
+        check(
+            MatLineSeries::from_xy(x.clone(), y.clone()).point_size(2),
+            8,
+        );
+        check(
+            MatLineSeries::from_xy(x.clone(), y.clone()).step(StepMode::Post),
+            2,
+        );
+        check(
+            MatLineSeries::from_xy(x, y)
+                .step(StepMode::Pre)
+                .point_size(3),
+            8,
+        );
+    }
+
+    #[test]
+    fn test_gap_report() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_circle_call, 4);
+                assert_eq!(b.num_draw_path_call, 1);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Build chart error");
+
+        let y = vec![1.0, f64::NAN, 3.0, 4.0, f64::NAN, 6.0];
+        let mut series = MatLineSeries::from_xy((0..6).map(f64::from), y).point_size(2);
+        let report = series.gap_report();
+        chart.draw_series(series).expect("Drawing Error");
+
+        assert_eq!(report.drawn_points(), 4);
+        assert_eq!(report.skipped_indices(), vec![1, 4]);
+    }
+
+    #[test]
+    fn test_marker_fill_pattern() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.drop_check(|b| {
+                assert_eq!(b.num_draw_rect_call, 0);
+                assert!(b.num_draw_line_call > 0);
+                // An outline per marker, and the line
+                assert_eq!(b.num_draw_path_call, 3);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Build chart error");
+        let series = MatLineSeries::new(vec![(2, 2), (8, 8)], BLUE.filled())
+            .point_size(8)
+            .marker(MarkerShape::Square)
+            .marker_fill_pattern(Pattern::Stripes(Hatch::Forward));
+        chart.draw_series(series).expect("Drawing Error");
+    }
+
+    #[test]
+    fn test_from_xy_opt() {
+        let paths = Rc::new(RefCell::new(vec![]));
+        let circles = Rc::new(RefCell::new(vec![]));
+        let (paths_out, circles_out) = (paths.clone(), circles.clone());
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_path(move |_, _, p| paths_out.borrow_mut().push(p));
+            m.check_draw_circle(move |_, _, _, c, _| circles_out.borrow_mut().push(c));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Build chart error");
+
+        // The missing point leaves two single points, with a marker but no line
+        let series = MatLineSeries::from_xy_opt(0..3, [Some(1), None, Some(3)]).point_size(2);
+        assert_eq!(series.element_count(), 2);
+        chart.draw_series(series).expect("Drawing Error");
+        assert!(paths.borrow().is_empty());
+        assert_eq!(
+            *circles.borrow(),
+            vec![chart.backend_coord(&(0, 1)), chart.backend_coord(&(2, 3))]
+        );
+
+        circles.borrow_mut().clear();
+        let y = [Some(1), Some(2), None, Some(3), Some(5)];
+        chart
+            .draw_series(MatLineSeries::from_xy_opt(0..5, y).point_size(2))
+            .expect("Drawing Error");
+        let c = |x, y| chart.backend_coord(&(x, y));
+        assert_eq!(
+            *paths.borrow(),
+            vec![vec![c(0, 1), c(1, 2)], vec![c(3, 3), c(4, 5)]]
+        );
+        assert!(!circles.borrow().contains(&c(2, 0)));
+        assert_eq!(circles.borrow().len(), 4);
+    }
+
+    #[test]
+    fn test_unit_conversion() {
+        let path = Rc::new(RefCell::new(vec![]));
+        let path_out = path.clone();
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_path(move |_, _, p| path_out.borrow_mut().extend(p));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..20.0, 0.0..50.0)
+            .expect("Build chart error");
+
+        let meters = vec![(1.0, 2.0), (2.0, 5.0), (3.0, 10.0)];
+        let series = MatLineSeries::new(meters.clone(), BLUE)
+            .scale_y(3.281)
+            .scale_x(2.0)
+            .offset_x(1.0);
+        // The stored data stays in meters
+        let stored: Vec<_> = series.points().map(|(x, y)| (*x, *y)).collect();
+        assert_eq!(stored, meters);
+        chart.draw_series(series).expect("Drawing Error");
+
+        let expected: Vec<_> = meters
+            .iter()
+            .map(|(x, y)| chart.backend_coord(&(x * 2.0 + 1.0, y * 3.281)))
+            .collect();
+        assert_eq!(*path.borrow(), expected);
+    }
+
+    #[test]
+    fn test_auto_marker() {
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.drop_check(|b| {
+                // A circle, a square and a triangle marker per point
+                assert_eq!(b.num_draw_circle_call, 2);
+                assert_eq!(b.num_draw_rect_call, 2);
+                assert_eq!(b.num_fill_polygon_call, 2);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Build chart error");
+
+        MarkerCycle::reset_default();
+        ColorCycle::reset_default();
+        let mut shapes = vec![];
+        let mut colors = vec![];
+        for i in 0..3 {
+            let y = f64::from(i);
+            let series = MatLineSeries::new(vec![(1.0, y), (2.0, y)], BLUE.filled())
+                .auto_marker()
+                .auto_color();
+            shapes.push(series.marker);
+            colors.push(series.style.color);
+            chart.draw_series(series).expect("Drawing Error");
+        }
+        assert_eq!(
+            shapes,
+            vec![
+                MarkerShape::Circle,
+                MarkerShape::Square,
+                MarkerShape::TriangleUp
+            ]
+        );
+        assert!(colors[0] != colors[1] && colors[1] != colors[2] && colors[0] != colors[2]);
+    }
+
+    #[test]
+    fn test_auto_line_style() {
+        LineStyleCycle::reset_default();
+        let styles: Vec<_> = (0..5)
+            .map(|_| {
+                MatLineSeries::<MockedBackend, f64, f64>::new(vec![], BLACK)
+                    .auto_line_style()
+                    .line_style
+            })
+            .collect();
+        assert_eq!(
+            styles,
+            vec![
+                LineStyle::Solid,
+                LineStyle::Dashed,
+                LineStyle::Dotted,
+                LineStyle::DashDot,
+                LineStyle::Solid
+            ]
+        );
+
+        // A dashed line is drawn as 6 pixel dashes separated by 4 pixel gaps
+        let dashes = Rc::new(RefCell::new(vec![]));
+        let dashes_out = dashes.clone();
+        let drawing_area = create_mocked_drawing_area(101, 101, |m| {
+            m.check_draw_path(move |_, _, p| dashes_out.borrow_mut().push(p));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..100, 0..100)
+            .expect("Build chart error");
+        chart
+            .draw_series(
+                MatLineSeries::new(vec![(0, 50), (53, 50), (100, 50)], BLACK)
+                    .line_style(LineStyle::Dashed),
+            )
+            .expect("Drawing Error");
+        let dashes = dashes.borrow();
+        assert_eq!(dashes.len(), 10);
+        assert_eq!(dashes[0], vec![(0, 50), (6, 50)]);
+        assert_eq!(dashes[1], vec![(10, 50), (16, 50)]);
+        // The dash crossing the corner goes through it
+        assert_eq!(dashes[5], vec![(50, 50), (53, 50), (56, 50)]);
+    }
+
+    #[test]
+    fn test_rc_params_line_width() {
+        RcParams::update(|p| p.line_width = 4);
+        let drawing_area = create_mocked_drawing_area(100, 100, |m| {
+            m.check_draw_path(|_, s, _| assert_eq!(s, 4));
+            m.check_draw_path(|_, s, _| assert_eq!(s, 2));
+            m.drop_check(|b| assert_eq!(b.num_draw_path_call, 2));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..10, 0..10)
+            .expect("Build chart error");
+        chart
+            .draw_series(MatLineSeries::new(vec![(0, 0), (5, 5)], RED))
+            .expect("Drawing Error");
+        // An explicit width overrides the default
+        chart
+            .draw_series(MatLineSeries::new(
+                vec![(0, 0), (5, 5)],
+                RED.stroke_width(2),
+            ))
+            .expect("Drawing Error");
+        RcParams::reset();
+    }
+
+    #[test]
+    fn test_steps() {
+        let drawing_area = create_mocked_drawing_area(101, 101, |m| {
+            m.check_draw_path(|_, _, p| {
+                assert_eq!(p, vec![(0, 90), (50, 90), (50, 70), (100, 70)]);
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_path_call, 1));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..2, 0..10)
+            .expect("Build chart error");
+        chart
+            .draw_series(
+                MatLineSeries::new(vec![(0, 1), (1, 3), (2, 3)], BLACK).step(StepMode::Post),
+            )
+            .expect("Drawing Error");
+    }
+
+    #[test]
+    fn test_highlight_indices() {
+        let circles = Rc::new(RefCell::new(vec![]));
+        let paths = Rc::new(RefCell::new(vec![]));
+        let (circles_out, paths_out) = (circles.clone(), paths.clone());
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_circle(move |c, _, _, center, _| {
+                circles_out.borrow_mut().push((c, center))
+            });
+            m.check_draw_path(move |c, _, p| paths_out.borrow_mut().push((c, p)));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..5.0, 0.0..5.0)
+            .expect("Build chart error");
+        let data: Vec<_> = (0..6).map(|x| (f64::from(x), 1.0)).collect();
+        let series = MatLineSeries::new(data.clone(), BLUE)
+            .point_size(3)
+            .highlight_indices([1, 2, 4], RED);
+        assert_eq!(series.element_count(), 6 + 2);
+        chart.draw_series(series).expect("Drawing Error");
+
+        let dimmed = BLUE.mix(0.3).to_rgba();
+        let circles = circles.borrow();
+        assert_eq!(circles.len(), 6);
+        for (idx, (c, center)) in circles.iter().enumerate() {
+            let expected = if [1, 2, 4].contains(&idx) {
+                RED.to_rgba()
+            } else {
+                dimmed
+            };
+            assert_eq!(*c, expected);
+            assert_eq!(*center, chart.backend_coord(&data[idx]));
+        }
+
+        // The dimmed line, then the highlighted segment between the points 1 and 2
+        let paths = paths.borrow();
+        assert_eq!(paths.len(), 2);
+        assert_eq!(paths[0].0, dimmed);
+        assert_eq!(paths[0].1.len(), 6);
+        assert_eq!(paths[1].0, RED.to_rgba());
+        assert_eq!(
+            paths[1].1,
+            vec![chart.backend_coord(&data[1]), chart.backend_coord(&data[2])]
+        );
+    }
+
+    #[test]
+    fn test_named_markers() {
+        let texts = Rc::new(RefCell::new(vec![]));
+        let diamonds = Rc::new(RefCell::new(vec![]));
+        let (texts_out, diamonds_out) = (texts.clone(), diamonds.clone());
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_text(move |c, _, _, pos, text| {
+                texts_out.borrow_mut().push((c, pos, text.to_string()))
+            });
+            m.check_fill_polygon(move |c, p| diamonds_out.borrow_mut().push((c, p)));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Build chart error");
+
+        let trajectory: Vec<_> = (0..10)
+            .map(|i| (f64::from(i), 9.0 - (f64::from(i) - 4.5).powi(2) / 3.0))
+            .collect();
+        let series = MatLineSeries::new(trajectory.clone(), RED).named_markers(vec![
+            (0, "launch".to_string()),
+            (9, "landing".to_string()),
+            (20, "out of range".to_string()),
+        ]);
+        assert_eq!(series.element_count(), 1 + 2);
+        chart.draw_series(series).expect("Drawing Error");
+
+        let texts = texts.borrow();
+        let diamonds = diamonds.borrow();
+        assert_eq!(texts.len(), 2);
+        assert_eq!(diamonds.len(), 2);
+        for (((c, pos, text), (fill, diamond)), (idx, name)) in texts
+            .iter()
+            .zip(diamonds.iter())
+            .zip([(0, "launch"), (9, "landing")])
+        {
+            let (x, y) = chart.backend_coord(&trajectory[idx]);
+            assert_eq!((*c, text.as_str()), (RED.to_rgba(), name));
+            assert_eq!(*fill, RED.to_rgba());
+            // The diamond is centered on the point, and the label is above its right
+            let xs: Vec<_> = diamond.iter().map(|p| p.0).collect();
+            let ys: Vec<_> = diamond.iter().map(|p| p.1).collect();
+            let center = (
+                (xs.iter().min().unwrap() + xs.iter().max().unwrap()) / 2,
+                (ys.iter().min().unwrap() + ys.iter().max().unwrap()) / 2,
+            );
+            assert_eq!(center, (x, y));
+            assert!(pos.0 > x && pos.1 < y);
+        }
+    }
+
+    #[test]
+    fn test_dedup_consecutive() {
+        let paths = Rc::new(RefCell::new(vec![]));
+        let circles = Rc::new(RefCell::new(0));
+        let (paths_out, circles_out) = (paths.clone(), circles.clone());
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_path(move |_, _, p| paths_out.borrow_mut().push(p));
+            m.check_draw_circle(move |_, _, _, _, _| *circles_out.borrow_mut() += 1);
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..5.0, 0.0..5.0)
+            .expect("Build chart error");
+
+        // A logged signal repeating its samples; a point back at the start of a run is kept
+        let logged = [
+            (0.0, 1.0),
+            (0.0, 1.0),
+            (0.0, 1.0),
+            (1.0, 2.0),
+            (2.0, 2.0),
+            (2.0, 2.0),
+            (3.0, 4.0),
+            (2.0, 2.0),
+            (4.0, f64::NAN),
+            (4.0, f64::NAN),
+        ];
+        let series = MatLineSeries::new(logged, BLUE)
+            .point_size(2)
+            .dedup_consecutive();
+        // Six points are left, and one path through the five before the gap
+        assert_eq!(series.element_count(), 5 + 1);
+        chart.draw_series(series).expect("Drawing Error");
+
+        let kept = [(0.0, 1.0), (1.0, 2.0), (2.0, 2.0), (3.0, 4.0), (2.0, 2.0)];
+        let expected: Vec<_> = kept.iter().map(|p| chart.backend_coord(p)).collect();
+        assert_eq!(*paths.borrow(), vec![expected]);
+        assert_eq!(*circles.borrow(), 5);
+    }
+
+    #[test]
+    fn test_dedup_consecutive_indices() {
+        let circles = Rc::new(RefCell::new(vec![]));
+        let texts = Rc::new(RefCell::new(vec![]));
+        let (circles_out, texts_out) = (circles.clone(), texts.clone());
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_circle(move |c, _, _, center, _| {
+                circles_out.borrow_mut().push((c, center))
+            });
+            m.check_draw_text(move |_, _, _, _, text| {
+                texts_out.borrow_mut().push(text.to_string())
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0..5, 0..5)
+            .expect("Build chart error");
+
+        // The missing point is stored as (1, 0), like the measured point after it
+        let y = [Some(1), Some(1), None, Some(0), Some(2), Some(2), Some(3)];
+        let series = MatLineSeries::from_xy_opt([0, 0, 1, 1, 2, 2, 3], y)
+            .point_size(2)
+            .highlight_indices([5], RED)
+            .named_markers(vec![(6, "end".to_string())])
+            .dedup_consecutive();
+        assert_eq!(series.points().count(), 5);
+        chart.draw_series(series).expect("Drawing Error");
+
+        // The measured point next to the missing one is kept, and the highlighted point of
+        // the collapsed run is the point left
+        let circles = circles.borrow();
+        let centers: Vec<_> = circles.iter().map(|(_, center)| *center).collect();
+        let kept = [(0, 1), (1, 0), (2, 2), (3, 3)];
+        let expected: Vec<_> = kept.iter().map(|p| chart.backend_coord(p)).collect();
+        assert_eq!(centers, expected);
+        let red: Vec<_> = circles
+            .iter()
+            .filter(|(c, _)| *c == RED.to_rgba())
+            .collect();
+        assert_eq!(red, vec![&(RED.to_rgba(), chart.backend_coord(&(2, 2)))]);
+        assert_eq!(*texts.borrow(), vec!["end"]);
+    }
+
+    #[test]
+    fn test_color_by_trend() {
+        let paths = Rc::new(RefCell::new(vec![]));
+        let paths_out = paths.clone();
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_path(move |c, _, p| paths_out.borrow_mut().push((c, p)));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..7.0, 0.0..5.0)
+            .expect("Build chart error");
+        // Up to a peak at 2, down to a valley at 4, flat, then up again
+        let data = [
+            (0.0, 1.0),
+            (1.0, 2.0),
+            (2.0, 4.0),
+            (3.0, 3.0),
+            (4.0, 1.0),
+            (5.0, 1.0),
+            (6.0, 3.0),
+        ];
+        let series = MatLineSeries::new(data, BLUE)
+            .point_size(0)
+            .color_by_trend(GREEN, RED, BLACK);
+        assert_eq!(series.element_count(), 4);
+        chart.draw_series(series).expect("Drawing Error");
+
+        let coords = |range: std::ops::RangeInclusive<usize>| -> Vec<_> {
+            data[range].iter().map(|p| chart.backend_coord(p)).collect()
+        };
+        let expected = vec![
+            (GREEN.to_rgba(), coords(0..=2)),
+            (RED.to_rgba(), coords(2..=4)),
+            (BLACK.to_rgba(), coords(4..=5)),
+            (GREEN.to_rgba(), coords(5..=6)),
+        ];
+        assert_eq!(*paths.borrow(), expected);
+    }
+
+    #[test]
+    fn test_emphasize_recent() {
+        for fade in [false, true] {
+            let circles = Rc::new(RefCell::new(vec![]));
+            let paths = Rc::new(RefCell::new(vec![]));
+            let (circles_out, paths_out) = (circles.clone(), paths.clone());
+            let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+                m.check_draw_circle(move |c, _, _, _, _| circles_out.borrow_mut().push(c));
+                m.check_draw_path(move |c, w, p| paths_out.borrow_mut().push((c, w, p.len())));
+            });
+            let mut chart = ChartBuilder::on(&drawing_area)
+                .build_cartesian_2d(0.0..8.0, 0.0..5.0)
+                .expect("Build chart error");
+            let data: Vec<_> = (0..8).map(|x| (f64::from(x), 1.0)).collect();
+            let series = MatLineSeries::new(data, BLUE)
+                .point_size(3)
+                .emphasize_recent(3, RED.stroke_width(3), fade);
+            chart.draw_series(series).expect("Drawing Error");
+
+            let base = if fade {
+                BLUE.mix(0.3).to_rgba()
+            } else {
+                BLUE.to_rgba()
+            };
+            // The last 3 points are emphasized, the older ones keep the base style
+            let expected: Vec<_> = [vec![base; 5], vec![RED.to_rgba(); 3]].concat();
+            assert_eq!(*circles.borrow(), expected);
+            // The whole line, then the line between the recent points on top of it
+            let width = RcParams::get().line_width;
+            assert_eq!(
+                *paths.borrow(),
+                vec![(base, width, 8), (RED.to_rgba(), 3, 3)]
+            );
+        }
+    }
+
+    #[test]
+    fn test_shadow() {
+        let circles = Rc::new(RefCell::new(vec![]));
+        let paths = Rc::new(RefCell::new(vec![]));
+        let (circles_out, paths_out) = (circles.clone(), paths.clone());
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_circle(move |c, _, _, center, _| {
+                circles_out.borrow_mut().push((c, center))
+            });
+            m.check_draw_path(move |c, w, p| paths_out.borrow_mut().push((c, w, p)));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Build chart error");
+        let data = vec![(1.0, 1.0), (5.0, 8.0), (9.0, 4.0)];
+        let series = MatLineSeries::new(data.clone(), RED.stroke_width(2))
+            .point_size(4)
+            .shadow((3, 2), BLACK, 0.4);
+        assert_eq!(series.element_count(), 2 * (3 + 1));
+        chart.draw_series(series).expect("Drawing Error");
+
+        let shadow = BLACK.mix(0.4).to_rgba();
+        let shifted: Vec<_> = data
+            .iter()
+            .map(|p| chart.backend_coord(p))
+            .map(|(x, y)| (x + 3, y + 2))
+            .collect();
+        let circles = circles.borrow();
+        assert_eq!(circles.len(), 6);
+        // The shadow is drawn first, beneath the series
+        for (idx, (c, center)) in circles.iter().enumerate() {
+            if idx < 3 {
+                assert_eq!((*c, *center), (shadow, shifted[idx]));
+            } else {
+                assert_eq!(*c, RED.to_rgba());
+                assert_eq!(*center, chart.backend_coord(&data[idx - 3]));
+            }
+        }
+        let paths = paths.borrow();
+        assert_eq!(paths.len(), 2);
+        assert_eq!(paths[0], (shadow, 2, shifted));
+        assert_eq!(paths[1].0, RED.to_rgba());
+    }
+
+    #[test]
+    fn test_max_gap() {
+        let paths = Rc::new(RefCell::new(vec![]));
+        let paths_out = paths.clone();
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_path(move |_, _, p| paths_out.borrow_mut().push(p));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..20.0, 0.0..10.0)
+            .expect("Build chart error");
+
+        // An outage between x = 3.5 and x = 10, the other gaps are bridged
+        let points = [(0.0, 1.0), (1.0, 2.0), (3.5, 3.0), (10.0, 4.0), (11.0, 5.0)];
+        let series = MatLineSeries::new(points, BLUE).max_gap(3.0);
+        assert_eq!(series.element_count(), 2);
+        chart.draw_series(series).expect("Drawing Error");
+        let c = |x, y| chart.backend_coord(&(x, y));
+        assert_eq!(
+            *paths.borrow(),
+            vec![
+                vec![c(0.0, 1.0), c(1.0, 2.0), c(3.5, 3.0)],
+                vec![c(10.0, 4.0), c(11.0, 5.0)]
+            ]
+        );
+
+        paths.borrow_mut().clear();
+        chart
+            .draw_series(MatLineSeries::new(points, BLUE).max_gap(10.0))
+            .expect("Drawing Error");
+        assert_eq!(paths.borrow().len(), 1);
+        assert_eq!(paths.borrow()[0].len(), 5);
+    }
+
+    #[test]
+    fn test_interpolate_missing() {
+        let paths = Rc::new(RefCell::new(vec![]));
+        let paths_out = paths.clone();
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_path(move |_, _, p| paths_out.borrow_mut().push(p));
+            m.drop_check(|b| assert_eq!(b.num_draw_circle_call, 4));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..5.0, 0.0..10.0)
+            .expect("Build chart error");
+
+        let nan = f64::NAN;
+        let points = [
+            (0.0, 2.0),
+            (1.0, 4.0),
+            (2.0, nan),
+            (3.0, nan),
+            (4.0, 4.0),
+            (5.0, 2.0),
+        ];
+        let series = MatLineSeries::new(points, BLUE)
+            .point_size(3)
+            .interpolate_missing();
+        // 4 markers, the 2 measured parts and the interpolated one
+        assert_eq!(series.element_count(), 7);
+        chart.draw_series(series).expect("Drawing Error");
+
+        let c = |x, y| chart.backend_coord(&(x, y));
+        let paths = paths.borrow();
+        // The measured parts are solid, a single path each
+        assert_eq!(paths[0], vec![c(0.0, 2.0), c(1.0, 4.0)]);
+        assert_eq!(paths[1], vec![c(4.0, 4.0), c(5.0, 2.0)]);
+        // The interpolated part is dotted: many short dashes along the flat line
+        // from (1, 4) to (4, 4)
+        let dots = &paths[2..];
+        assert!(dots.len() > 20);
+        let (start, end) = (c(1.0, 4.0), c(4.0, 4.0));
+        for dot in dots {
+            let (first, last) = (dot[0], dot[dot.len() - 1]);
+            assert!(last.0 - first.0 <= 4);
+            assert!(start.0 <= first.0 && last.0 <= end.0);
+            assert!(dot.iter().all(|p| p.1 == start.1));
+        }
+    }
+
+    #[test]
+    fn test_simplify() {
+        let paths = Rc::new(RefCell::new(vec![]));
+        let paths_out = paths.clone();
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_path(move |_, _, p| paths_out.borrow_mut().push(p));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Build chart error");
+
+        // A rise along y = x up to a corner at (5, 5), a plateau, and a slight bump that is
+        // within the tolerance
+        let mut points: Vec<(f64, f64)> = (0..=10)
+            .map(|i| (0.5 * f64::from(i), 0.5 * f64::from(i)))
+            .collect();
+        points.extend([(6.0, 5.0), (7.0, 5.02), (8.0, 5.0), (9.0, 5.0)]);
+        chart
+            .draw_series(MatLineSeries::new(points.clone(), BLUE).simplify(1.0))
+            .expect("Drawing Error");
+        let c = |x, y| chart.backend_coord(&(x, y));
+        assert_eq!(
+            *paths.borrow(),
+            vec![vec![c(0.0, 0.0), c(5.0, 5.0), c(9.0, 5.0)]]
+        );
+
+        // Without simplification, every point is drawn
+        paths.borrow_mut().clear();
+        chart
+            .draw_series(MatLineSeries::new(points.clone(), BLUE))
+            .expect("Drawing Error");
+        assert_eq!(paths.borrow()[0].len(), points.len());
+    }
+
+    #[test]
+    fn test_in_order() {
+        let path = Rc::new(RefCell::new(vec![]));
+        let path_out = path.clone();
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_path(move |_, _, p| path_out.borrow_mut().extend(p));
+            m.drop_check(|b| assert_eq!(b.num_draw_path_call, 1));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..4.0, 0.0..4.0)
+            .expect("Build chart error");
+
+        // The corners of a square, listed by x, visited around the square and back
+        let corners = [(1.0, 1.0), (1.0, 3.0), (3.0, 1.0), (3.0, 3.0)];
+        let order = [0, 2, 3, 1, 0, 7];
+        chart
+            .draw_series(MatLineSeries::in_order(&corners, &order, BLACK))
+            .expect("Drawing Error");
+
+        let expected: Vec<_> = order[..5]
+            .iter()
+            .map(|idx| chart.backend_coord(&corners[*idx]))
+            .collect();
+        assert_eq!(*path.borrow(), expected);
+    }
+
+    #[test]
+    fn test_cumulative_count() {
+        let path = Rc::new(RefCell::new(vec![]));
+        let path_out = path.clone();
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_path(move |_, _, p| path_out.borrow_mut().extend(p));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0u32..5)
+            .expect("Build chart error");
+
+        let events = [6.0, 1.5, 4.0, 6.0, 8.5];
+        chart
+            .draw_series(MatLineSeries::cumulative_count(events, RED))
+            .expect("Drawing Error");
+        let c = |x, y| chart.backend_coord(&(x, y));
+        // Holding between the events and rising at each of them, by 2 for the double event
+        assert_eq!(
+            *path.borrow(),
+            vec![
+                c(1.5, 0),
+                c(1.5, 1),
+                c(4.0, 1),
+                c(4.0, 2),
+                c(6.0, 2),
+                c(6.0, 4),
+                c(8.5, 4),
+                c(8.5, 5)
+            ]
+        );
+    }
 }
@@ -0,0 +1,176 @@
+use crate::element::{DynElement, IntoDynElement, Rectangle, Text};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{Color, IntoFont, ShapeStyle, TextStyle, BLUE, RED};
+use plotters_backend::DrawingBackend;
+use std::ops::Range;
+
+/**
+A population pyramid: two horizontal bar series extending left and right from a shared
+central axis at `x = 0`, one bar per category.
+
+The category `i` is centered at `y = i`, so the chart is meant to be built over
+[`MatPopulationPyramid::category_range`] for y, and over a symmetric range such as
+`-max..max` for x. The left values are drawn to the left of the axis, so their magnitude is
+what matters. The optional side labels are drawn above the bars of each side.
+*/
+pub struct MatPopulationPyramid<DB: DrawingBackend> {
+    left: Vec<f64>,
+    right: Vec<f64>,
+    left_style: ShapeStyle,
+    right_style: ShapeStyle,
+    labels: Option<(String, String)>,
+    bar_height: f64,
+    elements: Option<std::vec::IntoIter<DynElement<'static, DB, (f64, f64)>>>,
+}
+
+impl<DB: DrawingBackend> MatPopulationPyramid<DB> {
+    /**
+    Creates a population pyramid from the values of the left and the right side, per
+    category. The left side is blue and the right side is red by default.
+
+    See [`MatPopulationPyramid`] for more information.
+    */
+    pub fn new<L: IntoIterator<Item = f64>, R: IntoIterator<Item = f64>>(
+        left: L,
+        right: R,
+    ) -> Self {
+        Self {
+            left: left.into_iter().collect(),
+            right: right.into_iter().collect(),
+            left_style: BLUE.filled(),
+            right_style: RED.filled(),
+            labels: None,
+            bar_height: 0.8,
+            elements: None,
+        }
+    }
+
+    /**
+    Sets the styles of the bars of the left and the right side.
+
+    See [`MatPopulationPyramid`] for more information.
+    */
+    pub fn styles<L: Into<ShapeStyle>, R: Into<ShapeStyle>>(mut self, left: L, right: R) -> Self {
+        self.left_style = left.into();
+        self.right_style = right.into();
+        self
+    }
+
+    /**
+    Sets the labels drawn above the left and the right side, such as `"Male"` and `"Female"`.
+
+    See [`MatPopulationPyramid`] for more information.
+    */
+    pub fn labels<L: Into<String>, R: Into<String>>(mut self, left: L, right: R) -> Self {
+        self.labels = Some((left.into(), right.into()));
+        self
+    }
+
+    /**
+    Sets the height of the bars, as a fraction of the category spacing. It is 0.8 by default.
+
+    See [`MatPopulationPyramid`] for more information.
+    */
+    pub fn bar_height(mut self, height: f64) -> Self {
+        self.bar_height = height;
+        self
+    }
+
+    /**
+    Returns the y range fitting all the categories.
+
+    See [`MatPopulationPyramid`] for more information.
+    */
+    pub fn category_range(&self) -> Range<f64> {
+        let n = self.left.len().max(self.right.len());
+        -0.5..n as f64 - 0.5
+    }
+
+    fn elements(&self) -> Vec<DynElement<'static, DB, (f64, f64)>> {
+        let half = self.bar_height / 2.0;
+        let mut ret = vec![];
+        for (sign, values, style) in [
+            (-1.0, &self.left, self.left_style),
+            (1.0, &self.right, self.right_style),
+        ] {
+            for (i, v) in values.iter().enumerate() {
+                let (y, end) = (i as f64, sign * v.abs());
+                ret.push(
+                    Rectangle::new([(end.min(0.0), y + half), (end.max(0.0), y - half)], style)
+                        .into_dyn(),
+                );
+            }
+        }
+        if let Some((left, right)) = &self.labels {
+            let max = self
+                .left
+                .iter()
+                .chain(&self.right)
+                .fold(0.0_f64, |m, v| m.max(v.abs()));
+            let top = self.category_range().end;
+            let font = TextStyle::from(("sans-serif", 15).into_font())
+                .pos(Pos::new(HPos::Center, VPos::Bottom));
+            for (x, label) in [(-max / 2.0, left), (max / 2.0, right)] {
+                ret.push(Text::new(label.clone(), (x, top), font.clone()).into_dyn());
+            }
+        }
+        ret
+    }
+}
+
+impl<DB: DrawingBackend> Iterator for MatPopulationPyramid<DB> {
+    type Item = DynElement<'static, DB, (f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.elements.is_none() {
+            self.elements = Some(self.elements().into_iter());
+        }
+        self.elements.as_mut()?.next()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_population_pyramid() {
+        let rects = Rc::new(RefCell::new(vec![]));
+        let texts = Rc::new(RefCell::new(vec![]));
+        let (rects_out, texts_out) = (rects.clone(), texts.clone());
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_rect(move |c, _, _, ul, br| rects_out.borrow_mut().push((c, ul, br)));
+            m.check_draw_text(move |_, _, _, _, text| {
+                texts_out.borrow_mut().push(text.to_string())
+            });
+        });
+
+        let left = [30.0, 20.0, 10.0];
+        let right = [28.0, 22.0, 12.0];
+        let pyramid = MatPopulationPyramid::new(left, right).labels("Male", "Female");
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(-40.0..40.0, pyramid.category_range())
+            .expect("Build chart error");
+        chart.draw_series(pyramid).expect("Drawing Error");
+
+        let rects = rects.borrow();
+        assert_eq!(rects.len(), 6);
+        let center = chart.backend_coord(&(0.0, 0.0)).0;
+        for i in 0..3 {
+            let (lc, lul, lbr) = rects[i];
+            let (rc, rul, rbr) = rects[i + 3];
+            assert_eq!((lc, rc), (BLUE.to_rgba(), RED.to_rgba()));
+            // The left bar ends at the center and the right bar starts there
+            assert_eq!(lul.0, chart.backend_coord(&(-left[i], 0.0)).0);
+            assert_eq!(lbr.0, center);
+            assert_eq!(rul.0, center);
+            assert_eq!(rbr.0, chart.backend_coord(&(right[i], 0.0)).0);
+            // Both bars of a category are at the same height
+            assert_eq!((lul.1, lbr.1), (rul.1, rbr.1));
+            assert!(lul.1 < chart.backend_coord(&(0.0, i as f64)).1);
+            assert!(lbr.1 > chart.backend_coord(&(0.0, i as f64)).1);
+        }
+        assert_eq!(*texts.borrow(), vec!["Male", "Female"]);
+    }
+}
@@ -0,0 +1,216 @@
+use crate::chart::StyledSeries;
+use crate::element::{DynElement, IntoDynElement, Polygon, Text};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{Color, IntoFont, ShapeStyle, TextStyle, BLUE};
+use plotters_backend::DrawingBackend;
+use std::f64::consts::PI;
+use std::ops::Range;
+
+/**
+A radial bar chart, also known as a circular bar plot: one bar per category radiating from
+the origin, whose length is its value.
+
+The category `i` of `n` is centered at the angle `2πi/n`, counterclockwise from the positive
+x axis like matplotlib's polar axes, so the bars are evenly spaced around the circle. The
+bars start at the inner radius, see [`MatRadialBar::inner_radius`], and the values are
+expected to be non-negative. The chart is meant to be built over
+[`MatRadialBar::extent`] for both axes, on a square plotting area so that the circle stays
+round. The optional category labels are drawn just past the end of each bar.
+*/
+pub struct MatRadialBar<DB: DrawingBackend> {
+    values: Vec<f64>,
+    style: ShapeStyle,
+    labels: Vec<String>,
+    inner_radius: f64,
+    bar_width: f64,
+    elements: Option<std::vec::IntoIter<DynElement<'static, DB, (f64, f64)>>>,
+}
+
+impl<DB: DrawingBackend> MatRadialBar<DB> {
+    /// The number of points on the arc at the end of each bar
+    const ARC_POINTS: usize = 16;
+
+    /**
+    Creates a radial bar chart from the values of the categories, in blue by default.
+
+    See [`MatRadialBar`] for more information.
+    */
+    pub fn new<I: IntoIterator<Item = f64>>(values: I) -> Self {
+        Self {
+            values: values.into_iter().collect(),
+            style: BLUE.filled(),
+            labels: vec![],
+            inner_radius: 0.0,
+            bar_width: 0.8,
+            elements: None,
+        }
+    }
+
+    /**
+    Sets the style of the bars.
+
+    See [`MatRadialBar`] for more information.
+    */
+    pub fn style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /**
+    Sets the labels of the categories, drawn past the end of the bars.
+
+    See [`MatRadialBar`] for more information.
+    */
+    pub fn labels<I, L>(mut self, labels: I) -> Self
+    where
+        I: IntoIterator<Item = L>,
+        L: Into<String>,
+    {
+        self.labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /**
+    Sets the radius of the empty circle at the center, where the bars start. It is 0 by
+    default.
+
+    See [`MatRadialBar`] for more information.
+    */
+    pub fn inner_radius(mut self, radius: f64) -> Self {
+        self.inner_radius = radius;
+        self
+    }
+
+    /**
+    Sets the angular width of the bars, as a fraction of the angle between two categories.
+    It is 0.8 by default.
+
+    See [`MatRadialBar`] for more information.
+    */
+    pub fn bar_width(mut self, width: f64) -> Self {
+        self.bar_width = width;
+        self
+    }
+
+    /**
+    Returns the range fitting the longest bar around the origin, for both axes, with some
+    room for the labels.
+
+    See [`MatRadialBar`] for more information.
+    */
+    pub fn extent(&self) -> Range<f64> {
+        let max = self.values.iter().fold(0.0_f64, |m, v| m.max(*v));
+        let r = (self.inner_radius + max) * 1.15;
+        -r..r
+    }
+
+    fn angle(&self, i: usize) -> f64 {
+        2.0 * PI * i as f64 / self.values.len() as f64
+    }
+
+    fn elements(&self) -> Vec<DynElement<'static, DB, (f64, f64)>> {
+        let half_width = PI * self.bar_width / self.values.len() as f64;
+        let at = |r: f64, a: f64| (r * a.cos(), r * a.sin());
+        let mut ret = vec![];
+        for (i, v) in self.values.iter().enumerate() {
+            let (center, outer) = (self.angle(i), self.inner_radius + v);
+            let angles: Vec<f64> = (0..=Self::ARC_POINTS)
+                .map(|k| {
+                    center - half_width + 2.0 * half_width * k as f64 / Self::ARC_POINTS as f64
+                })
+                .collect();
+            let mut bar: Vec<_> = angles.iter().map(|&a| at(outer, a)).collect();
+            if self.inner_radius > 0.0 {
+                bar.extend(angles.iter().rev().map(|&a| at(self.inner_radius, a)));
+            } else {
+                bar.push((0.0, 0.0));
+            }
+            ret.push(Polygon::new(bar, self.style).into_dyn());
+        }
+        let font = TextStyle::from(("sans-serif", 12).into_font());
+        let pad = self.extent().end * 0.02;
+        for (i, label) in self.labels.iter().enumerate().take(self.values.len()) {
+            let (a, r) = (self.angle(i), self.inner_radius + self.values[i]);
+            let h = match a.cos() {
+                c if c > 0.1 => HPos::Left,
+                c if c < -0.1 => HPos::Right,
+                _ => HPos::Center,
+            };
+            let v = match a.sin() {
+                s if s > 0.1 => VPos::Bottom,
+                s if s < -0.1 => VPos::Top,
+                _ => VPos::Center,
+            };
+            let style = font.pos(Pos::new(h, v));
+            ret.push(Text::new(label.clone(), at(r + pad, a), style).into_dyn());
+        }
+        ret
+    }
+}
+
+impl<DB: DrawingBackend> StyledSeries for MatRadialBar<DB> {
+    fn series_style(&self) -> ShapeStyle {
+        self.style
+    }
+}
+
+impl<DB: DrawingBackend> Iterator for MatRadialBar<DB> {
+    type Item = DynElement<'static, DB, (f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.elements.is_none() {
+            self.elements = Some(self.elements().into_iter());
+        }
+        self.elements.as_mut()?.next()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::f64::consts::PI;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_radial_bar() {
+        let bars = Rc::new(RefCell::new(vec![]));
+        let bars_out = bars.clone();
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_fill_polygon(move |c, path| {
+                assert_eq!(c, GREEN.mix(0.8).to_rgba());
+                bars_out.borrow_mut().push(path);
+            });
+        });
+
+        let values = [4.0, 8.0, 2.0, 6.0, 10.0];
+        let radial = MatRadialBar::new(values).style(GREEN.mix(0.8).filled());
+        let extent = radial.extent();
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(extent.clone(), extent)
+            .expect("Build chart error");
+        chart.draw_series(radial).expect("Drawing Error");
+
+        let bars = bars.borrow();
+        assert_eq!(bars.len(), values.len());
+        let (cx, cy) = chart.backend_coord(&(0.0, 0.0));
+        for (i, (bar, value)) in bars.iter().zip(values).enumerate() {
+            // The bar reaches as far from the center as its value
+            let polar: Vec<(f64, f64)> = bar
+                .iter()
+                .map(|&(x, y)| (f64::from(x - cx), f64::from(cy - y)))
+                .filter(|&(x, y)| x != 0.0 || y != 0.0)
+                .map(|(x, y)| (x.hypot(y), y.atan2(x)))
+                .collect();
+            let length = polar.iter().fold(0.0_f64, |m, &(r, _)| m.max(r));
+            let expected = f64::from(chart.backend_coord(&(value, 0.0)).0 - cx);
+            assert!((length - expected).abs() <= 2.0, "bar {}: {}", i, length);
+            // It is centered at its own share of the circle
+            let (sx, sy) = polar
+                .iter()
+                .fold((0.0, 0.0), |(sx, sy), &(_, a)| (sx + a.cos(), sy + a.sin()));
+            let expected = 2.0 * PI * i as f64 / values.len() as f64;
+            let diff = (sy.atan2(sx) - expected).rem_euclid(2.0 * PI);
+            assert!(diff.min(2.0 * PI - diff) < 0.02, "bar {}: {}", i, diff);
+        }
+    }
+}
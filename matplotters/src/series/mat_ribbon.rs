@@ -0,0 +1,195 @@
+use super::MatSeriesError;
+use crate::chart::StyledSeries;
+use crate::element::{DynElement, IntoDynElement, PathElement, Polygon};
+use crate::style::{Color, ShapeStyle};
+use plotters_backend::DrawingBackend;
+
+/**
+A min-max ribbon series for data that is aggregated per x, like sensor readings binned
+by time: the mean line is drawn on top of a filled ribbon spanning the measured minimum
+and maximum at every x.
+
+Unlike [`crate::series::MatErrorBand`], which shades a tolerance computed around a
+center, the edges of the ribbon are the given extremes themselves, so the mean is
+generally not centered in it. By default the ribbon is filled with the line color at 20%
+opacity, see [`MatRibbon::ribbon_style`].
+*/
+pub struct MatRibbon<DB: DrawingBackend, X: Clone> {
+    line_style: ShapeStyle,
+    ribbon_style: ShapeStyle,
+    // (x, min, max, mean)
+    data: Vec<(X, f64, f64, f64)>,
+    state: u32,
+    _p: std::marker::PhantomData<DB>,
+}
+
+impl<DB: DrawingBackend, X: Clone> MatRibbon<DB, X> {
+    /**
+    Creates a ribbon from the x values and the minimum, maximum and mean at each of them.
+
+    Returns [`MatSeriesError::LengthMismatch`] if `min`, `max` or `mean` does not have one
+    value per x.
+    */
+    pub fn from_min_max_mean<I, Min, Max, Mean, S>(
+        x: I,
+        min: Min,
+        max: Max,
+        mean: Mean,
+        style: S,
+    ) -> Result<Self, MatSeriesError>
+    where
+        I: IntoIterator<Item = X>,
+        Min: IntoIterator<Item = f64>,
+        Max: IntoIterator<Item = f64>,
+        Mean: IntoIterator<Item = f64>,
+        S: Into<ShapeStyle>,
+    {
+        let x: Vec<X> = x.into_iter().collect();
+        let min: Vec<f64> = min.into_iter().collect();
+        let max: Vec<f64> = max.into_iter().collect();
+        let mean: Vec<f64> = mean.into_iter().collect();
+        for len in [min.len(), max.len(), mean.len()] {
+            if len != x.len() {
+                return Err(MatSeriesError::LengthMismatch {
+                    expected: x.len(),
+                    actual: len,
+                });
+            }
+        }
+        let line_style: ShapeStyle = style.into();
+        let data = x
+            .into_iter()
+            .zip(min)
+            .zip(max)
+            .zip(mean)
+            .map(|(((x, min), max), mean)| (x, min, max, mean))
+            .collect();
+        Ok(Self {
+            line_style,
+            ribbon_style: line_style.color.mix(0.2).filled(),
+            data,
+            state: 0,
+            _p: std::marker::PhantomData,
+        })
+    }
+
+    /**
+    Sets the style of the min-max ribbon.
+
+    See [`MatRibbon`] for more information.
+    */
+    pub fn ribbon_style<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.ribbon_style = style.into();
+        self
+    }
+}
+
+impl<DB: DrawingBackend, X: Clone> StyledSeries for MatRibbon<DB, X> {
+    fn series_style(&self) -> ShapeStyle {
+        self.line_style
+    }
+}
+
+impl<DB: DrawingBackend, X: Clone + 'static> Iterator for MatRibbon<DB, X> {
+    type Item = DynElement<'static, DB, (X, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.state == 0 {
+            self.state = 1;
+            let ribbon: Vec<_> = self
+                .data
+                .iter()
+                .map(|(x, _, max, _)| (x.clone(), *max))
+                .chain(
+                    self.data
+                        .iter()
+                        .rev()
+                        .map(|(x, min, _, _)| (x.clone(), *min)),
+                )
+                .collect();
+            Some(Polygon::new(ribbon, self.ribbon_style).into_dyn())
+        } else if self.state == 1 {
+            self.state = 2;
+            let line: Vec<_> = self
+                .data
+                .iter()
+                .map(|(x, _, _, mean)| (x.clone(), *mean))
+                .collect();
+            Some(PathElement::new(line, self.line_style).into_dyn())
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_min_max_ribbon() {
+        let ribbon = Rc::new(RefCell::new(vec![]));
+        let line = Rc::new(RefCell::new(vec![]));
+        let (ribbon_out, line_out) = (ribbon.clone(), line.clone());
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_fill_polygon(move |c, path| {
+                assert_eq!(c, BLUE.mix(0.2).to_rgba());
+                ribbon_out.borrow_mut().extend(path);
+            });
+            m.check_draw_path(move |c, _, path| {
+                assert_eq!(c, BLUE.to_rgba());
+                line_out.borrow_mut().extend(path);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 1);
+                assert_eq!(b.num_draw_path_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..5.0, 0.0..30.0)
+            .expect("Build chart error");
+
+        let x = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0];
+        let min = [2.0, 4.0, 3.0, 8.0, 10.0, 12.0];
+        let max = [9.0, 15.0, 12.0, 20.0, 27.0, 25.0];
+        let mean = [4.0, 11.0, 5.0, 16.0, 12.0, 22.0];
+        chart
+            .draw_series(
+                MatRibbon::from_min_max_mean(x, min, max, mean, BLUE).expect("Invalid input"),
+            )
+            .expect("Drawing Error");
+
+        let (ribbon, line) = (ribbon.borrow(), line.borrow());
+        let n = x.len();
+        assert_eq!(ribbon.len(), 2 * n);
+        for i in 0..n {
+            let upper = chart.backend_coord(&(x[i], max[i]));
+            let lower = chart.backend_coord(&(x[i], min[i]));
+            assert_eq!(ribbon[i], upper);
+            assert_eq!(ribbon[2 * n - 1 - i], lower);
+            let center = line[i];
+            assert_eq!(center, chart.backend_coord(&(x[i], mean[i])));
+            assert!(upper.1 <= center.1 && center.1 <= lower.1);
+        }
+    }
+
+    #[test]
+    fn test_ribbon_length_mismatch() {
+        let ribbon = MatRibbon::<crate::drawing::MockedBackend, f64>::from_min_max_mean(
+            [0.0, 1.0, 2.0],
+            [1.0, 2.0, 3.0],
+            [4.0, 5.0],
+            [2.0, 3.0, 4.0],
+            RED,
+        );
+        assert_eq!(
+            ribbon.err(),
+            Some(MatSeriesError::LengthMismatch {
+                expected: 3,
+                actual: 2
+            })
+        );
+    }
+}
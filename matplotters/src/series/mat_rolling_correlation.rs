@@ -0,0 +1,167 @@
+use super::{MatLineSeries, MatSeriesError};
+use crate::chart::StyledSeries;
+use crate::data::rolling_correlation;
+use crate::element::{DynElement, IntoDynElement, PathElement};
+use crate::style::{Color, ShapeStyle, BLACK};
+use plotters_backend::DrawingBackend;
+use std::ops::Range;
+
+/**
+The rolling correlation of two series, drawn as a line bounded to `[-1, 1]`, with reference
+lines at `±threshold`, as commonly used for pairs trading.
+
+The correlation at a point is the one of the window ending at this point, see
+[`rolling_correlation`]. The first `window - 1` points, and the windows where a series is
+constant, have no correlation and are gaps in the line. The chart is meant to be built over
+[`MatRollingCorrelation::y_range`] for y.
+*/
+pub struct MatRollingCorrelation<DB: DrawingBackend> {
+    x: Vec<f64>,
+    values: Vec<f64>,
+    style: ShapeStyle,
+    threshold: Option<(f64, ShapeStyle)>,
+    elements: Option<std::vec::IntoIter<DynElement<'static, DB, (f64, f64)>>>,
+}
+
+impl<DB: DrawingBackend> MatRollingCorrelation<DB> {
+    /**
+    Computes the rolling correlation of the series `a` and `b` sampled at `x`. The reference
+    lines are at `±0.5` by default.
+
+    Returns [`MatSeriesError::LengthMismatch`] if `a` or `b` has not one value per x.
+    */
+    pub fn new<S: Into<ShapeStyle>>(
+        x: &[f64],
+        a: &[f64],
+        b: &[f64],
+        window: usize,
+        style: S,
+    ) -> Result<Self, MatSeriesError> {
+        for series in [a, b] {
+            if series.len() != x.len() {
+                return Err(MatSeriesError::LengthMismatch {
+                    expected: x.len(),
+                    actual: series.len(),
+                });
+            }
+        }
+        Ok(Self {
+            x: x.to_vec(),
+            values: rolling_correlation(a, b, window),
+            style: style.into(),
+            threshold: Some((0.5, BLACK.mix(0.5).into())),
+            elements: None,
+        })
+    }
+
+    /**
+    Sets the threshold of the reference lines, and their style.
+
+    See [`MatRollingCorrelation`] for more information.
+    */
+    pub fn threshold<S: Into<ShapeStyle>>(mut self, threshold: f64, style: S) -> Self {
+        self.threshold = Some((threshold.abs(), style.into()));
+        self
+    }
+
+    /**
+    Removes the reference lines.
+
+    See [`MatRollingCorrelation`] for more information.
+    */
+    pub fn no_threshold(mut self) -> Self {
+        self.threshold = None;
+        self
+    }
+
+    /**
+    Returns the correlation at every x.
+
+    See [`MatRollingCorrelation`] for more information.
+    */
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    /**
+    Returns the range of the correlation, `-1.0..1.0`.
+
+    See [`MatRollingCorrelation`] for more information.
+    */
+    pub fn y_range(&self) -> Range<f64> {
+        -1.0..1.0
+    }
+
+    fn elements(&self) -> Vec<DynElement<'static, DB, (f64, f64)>> {
+        let mut ret = vec![];
+        if let (Some((t, style)), Some(first), Some(last)) =
+            (self.threshold, self.x.first(), self.x.last())
+        {
+            for y in [t, -t] {
+                ret.push(PathElement::new(vec![(*first, y), (*last, y)], style).into_dyn());
+            }
+        }
+        let points = self.x.iter().copied().zip(self.values.iter().copied());
+        ret.extend(MatLineSeries::new(points, self.style));
+        ret
+    }
+}
+
+impl<DB: DrawingBackend> StyledSeries for MatRollingCorrelation<DB> {
+    fn series_style(&self) -> ShapeStyle {
+        self.style
+    }
+}
+
+impl<DB: DrawingBackend> Iterator for MatRollingCorrelation<DB> {
+    type Item = DynElement<'static, DB, (f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.elements.is_none() {
+            self.elements = Some(self.elements().into_iter());
+        }
+        self.elements.as_mut()?.next()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_rolling_correlation_series() {
+        let x: Vec<f64> = (0..40).map(f64::from).collect();
+        let a: Vec<f64> = x.iter().map(|v| (v * 0.4).sin() + 0.02 * v).collect();
+        let anti: Vec<f64> = a.iter().map(|v| -3.0 * v + 1.0).collect();
+
+        let same = MatRollingCorrelation::<MockedBackend>::new(&x, &a, &a, 5, BLUE).unwrap();
+        assert!(same.values()[4..].iter().all(|v| (v - 1.0).abs() < 1e-12));
+
+        let paths = Rc::new(RefCell::new(vec![]));
+        let paths_out = paths.clone();
+        let drawing_area = create_mocked_drawing_area(401, 201, |m| {
+            m.check_draw_path(move |c, _, p| paths_out.borrow_mut().push((c, p)));
+        });
+        let series = MatRollingCorrelation::new(&x, &a, &anti, 5, BLUE)
+            .unwrap()
+            .threshold(0.8, RED);
+        assert!(series.values()[4..].iter().all(|v| (v + 1.0).abs() < 1e-12));
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..39.0, series.y_range())
+            .expect("Build chart error");
+        chart.draw_series(series).expect("Drawing Error");
+
+        let paths = paths.borrow();
+        assert_eq!(paths.len(), 3);
+        assert_eq!(paths[0].0, RED.to_rgba());
+        assert_eq!(paths[0].1[0], chart.backend_coord(&(0.0, 0.8)));
+        assert_eq!(paths[1].1[1], chart.backend_coord(&(39.0, -0.8)));
+        // The line starts once the first window is full, at a correlation of -1
+        let line = &paths[2].1;
+        assert_eq!(line.len(), 36);
+        assert_eq!(line[0], chart.backend_coord(&(4.0, -1.0)));
+
+        assert!(MatRollingCorrelation::<MockedBackend>::new(&x, &a[1..], &a, 5, BLUE).is_err());
+    }
+}
@@ -0,0 +1,264 @@
+use crate::element::{DynElement, IntoDynElement, Polygon, Rectangle, Text};
+use crate::style::text_anchor::{HPos, Pos, VPos};
+use crate::style::{Color, ColorCycle, IntoFont, RGBColor, TextStyle};
+use plotters_backend::DrawingBackend;
+use std::ops::Range;
+
+/**
+A simplified Sankey diagram: the flows between a column of source categories on the left and
+a column of target categories on the right, drawn as curved ribbons whose thickness is the
+flow value.
+
+Every category is a node as tall as the sum of its flows; the nodes of a column are stacked
+from the top, in the order their categories first appear in the flows, and the ribbons leave
+and reach the nodes in the order of the flows. The diagram spans `0..1` in x and
+[`MatSankey::y_range`] in y. Each source node takes the next color of the default
+[`ColorCycle`], and its ribbons are drawn in that color at 40% opacity; the target nodes are
+gray.
+*/
+pub struct MatSankey<DB: DrawingBackend> {
+    // (source, target, value)
+    flows: Vec<(usize, usize, f64)>,
+    sources: Vec<String>,
+    targets: Vec<String>,
+    node_width: f64,
+    node_gap: Option<f64>,
+    elements: Option<std::vec::IntoIter<DynElement<'static, DB, (f64, f64)>>>,
+}
+
+impl<DB: DrawingBackend> MatSankey<DB> {
+    /// The number of points on each curved edge of a ribbon
+    const RIBBON_POINTS: usize = 24;
+
+    /**
+    Creates a Sankey diagram from `(source, target, value)` flows.
+
+    See [`MatSankey`] for more information.
+    */
+    pub fn new<I, S, T>(flows: I) -> Self
+    where
+        I: IntoIterator<Item = (S, T, f64)>,
+        S: Into<String>,
+        T: Into<String>,
+    {
+        fn index_of(names: &mut Vec<String>, name: String) -> usize {
+            names.iter().position(|n| *n == name).unwrap_or_else(|| {
+                names.push(name);
+                names.len() - 1
+            })
+        }
+        let (mut sources, mut targets) = (vec![], vec![]);
+        let flows = flows
+            .into_iter()
+            .map(|(s, t, v)| {
+                let s = index_of(&mut sources, s.into());
+                (s, index_of(&mut targets, t.into()), v)
+            })
+            .collect();
+        Self {
+            flows,
+            sources,
+            targets,
+            node_width: 0.05,
+            node_gap: None,
+            elements: None,
+        }
+    }
+
+    /**
+    Sets the width of the nodes, as a fraction of the width of the diagram. It is 0.05 by
+    default.
+
+    See [`MatSankey`] for more information.
+    */
+    pub fn node_width(mut self, width: f64) -> Self {
+        self.node_width = width;
+        self
+    }
+
+    /**
+    Sets the vertical space between two nodes of a column, in flow units. It is 5% of the
+    total flow by default.
+
+    See [`MatSankey`] for more information.
+    */
+    pub fn node_gap(mut self, gap: f64) -> Self {
+        self.node_gap = Some(gap);
+        self
+    }
+
+    /**
+    Returns the y range fitting the taller of the two columns.
+
+    See [`MatSankey`] for more information.
+    */
+    pub fn y_range(&self) -> Range<f64> {
+        let (sources, targets) = self.node_tops();
+        let bottom =
+            |tops: &[f64], sizes: &[f64]| tops.last().zip(sizes.last()).map_or(0.0, |(t, s)| t - s);
+        let (source_sizes, target_sizes) = self.node_sizes();
+        let low = bottom(&sources, &source_sizes).min(bottom(&targets, &target_sizes));
+        low..0.0
+    }
+
+    fn gap(&self) -> f64 {
+        self.node_gap
+            .unwrap_or_else(|| 0.05 * self.flows.iter().map(|f| f.2).sum::<f64>())
+    }
+
+    fn node_sizes(&self) -> (Vec<f64>, Vec<f64>) {
+        let mut sources = vec![0.0; self.sources.len()];
+        let mut targets = vec![0.0; self.targets.len()];
+        for &(s, t, v) in &self.flows {
+            sources[s] += v;
+            targets[t] += v;
+        }
+        (sources, targets)
+    }
+
+    // The y of the top of every node, the columns starting at y = 0 and going down
+    fn node_tops(&self) -> (Vec<f64>, Vec<f64>) {
+        let gap = self.gap();
+        let tops = |sizes: Vec<f64>| {
+            let mut top = 0.0;
+            sizes
+                .into_iter()
+                .map(|size| {
+                    let ret = top;
+                    top -= size + gap;
+                    ret
+                })
+                .collect()
+        };
+        let (sources, targets) = self.node_sizes();
+        (tops(sources), tops(targets))
+    }
+
+    fn elements(&self) -> Vec<DynElement<'static, DB, (f64, f64)>> {
+        let (source_sizes, target_sizes) = self.node_sizes();
+        let (mut source_tops, mut target_tops) = self.node_tops();
+        let mut cycle = ColorCycle::default();
+        let colors: Vec<RGBColor> = self.sources.iter().map(|_| cycle.next_color()).collect();
+        let (left, right) = (self.node_width, 1.0 - self.node_width);
+
+        let mut ret = vec![];
+        let mut nodes = vec![];
+        let gray = vec![RGBColor(128, 128, 128); self.targets.len()];
+        for (tops, sizes, node_colors, x) in [
+            (&source_tops, &source_sizes, &colors, 0.0),
+            (&target_tops, &target_sizes, &gray, right),
+        ] {
+            for ((top, size), color) in tops.iter().zip(sizes).zip(node_colors) {
+                nodes.push(
+                    Rectangle::new(
+                        [(x, *top), (x + self.node_width, top - size)],
+                        color.filled(),
+                    )
+                    .into_dyn(),
+                );
+            }
+        }
+        for &(s, t, v) in &self.flows {
+            let (y0, y1) = (source_tops[s], target_tops[t]);
+            source_tops[s] -= v;
+            target_tops[t] -= v;
+            let edge: Vec<(f64, f64)> = (0..=Self::RIBBON_POINTS)
+                .map(|k| {
+                    let u = k as f64 / Self::RIBBON_POINTS as f64;
+                    let ease = u * u * (3.0 - 2.0 * u);
+                    (left + (right - left) * u, y0 + (y1 - y0) * ease)
+                })
+                .collect();
+            let ribbon: Vec<_> = edge
+                .iter()
+                .cloned()
+                .chain(edge.iter().rev().map(|&(x, y)| (x, y - v)))
+                .collect();
+            ret.push(Polygon::new(ribbon, colors[s].mix(0.4).filled()).into_dyn());
+        }
+        ret.extend(nodes);
+
+        let font = TextStyle::from(("sans-serif", 12).into_font());
+        let (source_tops, target_tops) = self.node_tops();
+        for (names, tops, sizes, x, pos) in [
+            (&self.sources, source_tops, source_sizes, left, HPos::Left),
+            (&self.targets, target_tops, target_sizes, right, HPos::Right),
+        ] {
+            let style = font.pos(Pos::new(pos, VPos::Center));
+            for ((name, top), size) in names.iter().zip(tops).zip(sizes) {
+                let at = (x, top - size / 2.0);
+                ret.push(Text::new(name.clone(), at, style.clone()).into_dyn());
+            }
+        }
+        ret
+    }
+}
+
+impl<DB: DrawingBackend> Iterator for MatSankey<DB> {
+    type Item = DynElement<'static, DB, (f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.elements.is_none() {
+            self.elements = Some(self.elements().into_iter());
+        }
+        self.elements.as_mut()?.next()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_sankey() {
+        let ribbons = Rc::new(RefCell::new(vec![]));
+        let texts = Rc::new(RefCell::new(vec![]));
+        let (ribbons_out, texts_out) = (ribbons.clone(), texts.clone());
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_fill_polygon(move |_, path| ribbons_out.borrow_mut().push(path));
+            m.check_draw_text(move |_, _, _, _, text| {
+                texts_out.borrow_mut().push(text.to_string())
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_rect_call, 4));
+        });
+
+        let flows = [("A", "X", 30.0), ("A", "Y", 10.0), ("B", "X", 20.0)];
+        let sankey = MatSankey::new(flows).node_width(0.1).node_gap(5.0);
+        assert_eq!(sankey.y_range(), -65.0..0.0);
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..1.0, sankey.y_range())
+            .expect("Build chart error");
+        chart.draw_series(sankey).expect("Drawing Error");
+
+        // A is on top of B, X on top of Y, and the flows are stacked in their nodes
+        let ends = [
+            ((0.0, -30.0), (0.0, -30.0)),
+            ((-30.0, -40.0), (-55.0, -65.0)),
+            ((-45.0, -65.0), (-30.0, -50.0)),
+        ];
+        let ribbons = ribbons.borrow();
+        assert_eq!(ribbons.len(), 3);
+        for (ribbon, ((top0, bottom0), (top1, bottom1))) in ribbons.iter().zip(ends) {
+            let n = ribbon.len() / 2;
+            let close =
+                |p: (i32, i32), q: (i32, i32)| (p.0 - q.0).abs() <= 1 && (p.1 - q.1).abs() <= 1;
+            assert!(close(ribbon[0], chart.backend_coord(&(0.1, top0))));
+            assert!(close(
+                ribbon[2 * n - 1],
+                chart.backend_coord(&(0.1, bottom0))
+            ));
+            assert!(close(ribbon[n - 1], chart.backend_coord(&(0.9, top1))));
+            assert!(close(ribbon[n], chart.backend_coord(&(0.9, bottom1))));
+            // The ribbon keeps the thickness of its flow all along
+            let thickness =
+                chart.backend_coord(&(0.0, bottom0)).1 - chart.backend_coord(&(0.0, top0)).1;
+            for k in 0..n {
+                let (top, bottom) = (ribbon[k], ribbon[2 * n - 1 - k]);
+                assert_eq!(top.0, bottom.0);
+                assert!((bottom.1 - top.1 - thickness).abs() <= 1);
+            }
+        }
+        assert_eq!(*texts.borrow(), vec!["A", "B", "X", "Y"]);
+    }
+}
@@ -0,0 +1,321 @@
+use crate::element::{BackendCoordAndArea, Drawable, PointCollection};
+use crate::style::colors::colormaps::{ColorMap, ViridisRGB};
+use crate::style::Color;
+use plotters_backend::{BackendColor, BackendCoord, DrawingBackend, DrawingErrorKind};
+use std::marker::PhantomData;
+use std::ops::Range;
+
+/// The number of colors the colormap of a [`MatScatterDensity`] is sampled into
+const LUT_SIZE: usize = 256;
+
+/**
+A scatter plot of many points rendered as a density image, like a lightweight datashader,
+for the point clouds too large or too overplotted to be drawn marker by marker.
+
+The points are counted per pixel into a density buffer over the extent of the series, the
+bounding box of the points by default, see [`MatScatterDensity::extent`]. Every pixel with
+at least one point is colored by its count through a colormap, viridis by default, from one
+point at the low end to the largest count at the high end, linearly or on a log scale, see
+[`MatScatterDensity::log_counts`]. The empty pixels are not drawn, so that the background
+of the chart shows through. The points out of the extent or not finite are left out.
+
+```rust
+use matplotters::prelude::*;
+
+let drawing_area = SVGBackend::new("scatter_density.svg", (300, 200)).into_drawing_area();
+let mut chart = ChartBuilder::on(&drawing_area).build_cartesian_2d(0.0..1.0, 0.0..1.0).unwrap();
+let points = (0..100_000).map(|i| {
+    let t = f64::from(i) / 100_000.0;
+    (t, (t * 40.0).sin() * 0.4 * t + 0.5)
+});
+chart.draw_series(MatScatterDensity::new(points).extent(0.0..1.0, 0.0..1.0)).unwrap();
+```
+*/
+pub struct MatScatterDensity<DB: DrawingBackend> {
+    points: Vec<(f64, f64)>,
+    colors: Vec<BackendColor>,
+    extent: Option<(Range<f64>, Range<f64>)>,
+    log: bool,
+    elements: Option<std::vec::IntoIter<DensityElement<DB>>>,
+}
+
+impl<DB: DrawingBackend> MatScatterDensity<DB> {
+    /**
+    Creates a density image of the points.
+
+    See [`MatScatterDensity`] for more information.
+    */
+    pub fn new<I: IntoIterator<Item = (f64, f64)>>(points: I) -> Self {
+        Self {
+            points: points
+                .into_iter()
+                .filter(|(x, y)| x.is_finite() && y.is_finite())
+                .collect(),
+            colors: vec![],
+            extent: None,
+            log: false,
+            elements: None,
+        }
+        .colormap(&ViridisRGB {})
+    }
+
+    /**
+    Sets the colormap the counts are colored with.
+
+    See [`MatScatterDensity`] for more information.
+    */
+    pub fn colormap<C: Color, M: ColorMap<C, f64>>(mut self, colormap: &M) -> Self {
+        self.colors = (0..LUT_SIZE)
+            .map(|i| {
+                colormap
+                    .get_color(i as f64 / (LUT_SIZE - 1) as f64)
+                    .to_backend_color()
+            })
+            .collect();
+        self
+    }
+
+    /**
+    Sets the extent the points are counted over in data coordinates, usually the ranges of
+    the chart, instead of the bounding box of the points.
+
+    See [`MatScatterDensity`] for more information.
+    */
+    pub fn extent(mut self, x: Range<f64>, y: Range<f64>) -> Self {
+        self.extent = Some((x, y));
+        self
+    }
+
+    /**
+    Colors the counts on a log scale, so that the sparse pixels stay distinguishable from
+    each other next to a dense cluster.
+
+    See [`MatScatterDensity`] for more information.
+    */
+    pub fn log_counts(mut self) -> Self {
+        self.log = true;
+        self
+    }
+
+    /**
+    Returns the extent the points are counted over, to be used as the ranges of the chart.
+
+    See [`MatScatterDensity`] for more information.
+    */
+    pub fn ranges(&self) -> (Range<f64>, Range<f64>) {
+        if let Some(extent) = &self.extent {
+            return extent.clone();
+        }
+        let bounds = |coord: fn(&(f64, f64)) -> f64| {
+            let values = self.points.iter().map(coord);
+            let lo = values.clone().fold(f64::INFINITY, f64::min);
+            let hi = values.fold(f64::NEG_INFINITY, f64::max);
+            match lo <= hi {
+                true => lo..hi,
+                false => 0.0..1.0,
+            }
+        };
+        (bounds(|p| p.0), bounds(|p| p.1))
+    }
+
+    fn elements(&self) -> Vec<DensityElement<DB>> {
+        let (x, y) = self.ranges();
+        let inside: Vec<_> = self
+            .points
+            .iter()
+            .filter(|(px, py)| (x.start..=x.end).contains(px) && (y.start..=y.end).contains(py))
+            .copied()
+            .collect();
+        if inside.is_empty() {
+            return vec![];
+        }
+        let image = DensityElement {
+            points: [vec![(x.start, y.end), (x.end, y.start)], inside].concat(),
+            colors: self.colors.clone(),
+            log: self.log,
+            _p: PhantomData,
+        };
+        vec![image]
+    }
+}
+
+impl<DB: DrawingBackend> Iterator for MatScatterDensity<DB> {
+    type Item = DensityElement<DB>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.elements.is_none() {
+            self.elements = Some(self.elements().into_iter());
+        }
+        self.elements.as_mut()?.next()
+    }
+}
+
+/// The density image of a [`MatScatterDensity`]: the upper left and bottom right corners of
+/// its extent, followed by the points. The points are translated without being moved into
+/// the plotting area, so that the points out of the chart are left out instead of being
+/// counted on its border.
+pub struct DensityElement<DB> {
+    points: Vec<(f64, f64)>,
+    colors: Vec<BackendColor>,
+    log: bool,
+    _p: PhantomData<DB>,
+}
+
+impl<'a, DB> PointCollection<'a, (f64, f64), BackendCoordAndArea> for &'a DensityElement<DB> {
+    type Point = &'a (f64, f64);
+    type IntoIter = &'a [(f64, f64)];
+    fn point_iter(self) -> Self::IntoIter {
+        &self.points
+    }
+}
+
+impl<DB: DrawingBackend> Drawable<DB, BackendCoordAndArea> for DensityElement<DB> {
+    fn draw<I: Iterator<Item = (BackendCoord, (BackendCoord, BackendCoord))>>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+        let ((ul, area), (br, _)) = match (points.next(), points.next()) {
+            (Some(ul), Some(br)) => (ul, br),
+            _ => return Ok(()),
+        };
+        // The extent cropped to the plotting area
+        let (left, top) = (ul.0.min(br.0).max(area.0 .0), ul.1.min(br.1).max(area.0 .1));
+        let right = (ul.0.max(br.0) + 1).min(area.1 .0);
+        let bottom = (ul.1.max(br.1) + 1).min(area.1 .1);
+        if right <= left || bottom <= top {
+            return Ok(());
+        }
+        let (w, h) = ((right - left) as usize, (bottom - top) as usize);
+        let mut counts = vec![0u32; w * h];
+        for ((x, y), _) in points {
+            if (left..right).contains(&x) && (top..bottom).contains(&y) {
+                counts[(y - top) as usize * w + (x - left) as usize] += 1;
+            }
+        }
+
+        let max = counts.iter().copied().max().unwrap_or(0);
+        let scale = |count: u32| match self.log {
+            true => f64::from(count).ln(),
+            false => f64::from(count - 1),
+        };
+        let span = scale(max).max(f64::MIN_POSITIVE);
+        for (idx, count) in counts.iter().enumerate().filter(|(_, c)| **c > 0) {
+            let t = (scale(*count) / span).clamp(0.0, 1.0);
+            let color = self.colors[(t * (self.colors.len() - 1) as f64).round() as usize];
+            let pos = (left + (idx % w) as i32, top + (idx / w) as i32);
+            backend.draw_pixel(pos, color)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_scatter_density() {
+        let pixels = Rc::new(RefCell::new(HashMap::new()));
+        let pixels_out = pixels.clone();
+        let drawing_area = create_mocked_drawing_area(101, 101, |m| {
+            m.check_draw_pixel(move |c, pos| {
+                pixels_out.borrow_mut().insert(pos, c);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Build chart error");
+
+        // A dense cluster of 1000 points in one pixel, 10 points in a second one, and a few
+        // scattered points on their own
+        let cluster = vec![(5.0, 5.0); 1000];
+        let medium = vec![(2.0, 8.0); 10];
+        let sparse = [(1.0, 1.0), (8.0, 2.0), (f64::NAN, 3.0), (20.0, 3.0)];
+        let points = [cluster, medium, sparse.to_vec()].concat();
+        let density = MatScatterDensity::new(points).extent(0.0..10.0, 0.0..10.0);
+        assert_eq!(density.ranges(), (0.0..10.0, 0.0..10.0));
+        chart.draw_series(density).expect("Drawing Error");
+
+        let pixels = pixels.borrow();
+        // Only the pixels with points are drawn
+        assert_eq!(pixels.len(), 4);
+        let color = |p: (f64, f64)| pixels[&chart.backend_coord(&p)];
+        let (low, high) = (ViridisRGB::get_color(0.0), ViridisRGB::get_color(1.0));
+        assert_eq!(color((5.0, 5.0)), high.to_rgba());
+        assert_eq!(color((1.0, 1.0)), low.to_rgba());
+        assert_eq!(color((8.0, 2.0)), low.to_rgba());
+        // The medium count is near the low end on a linear scale
+        let lut = |t: f64| ViridisRGB::get_color((t * 255.0).round() / 255.0).to_rgba();
+        assert_eq!(color((2.0, 8.0)), lut(9.0 / 999.0));
+        assert_ne!(color((2.0, 8.0)), high.to_rgba());
+    }
+
+    #[test]
+    fn test_scatter_density_out_of_chart() {
+        let pixels = Rc::new(RefCell::new(HashMap::new()));
+        let pixels_out = pixels.clone();
+        let drawing_area = create_mocked_drawing_area(101, 101, |m| {
+            m.check_draw_pixel(move |c, pos| {
+                pixels_out.borrow_mut().insert(pos, c);
+            });
+        });
+        // The chart only shows the middle of the data
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(2.0..8.0, 2.0..8.0)
+            .expect("Build chart error");
+
+        let points = [
+            vec![(0.0, 5.0); 1000],
+            vec![(9.0, 9.0); 1000],
+            vec![(5.0, 5.0); 10],
+            vec![(3.0, 4.0)],
+        ]
+        .concat();
+        chart
+            .draw_series(MatScatterDensity::new(points))
+            .expect("Drawing Error");
+
+        // The dense clusters out of the chart are not counted on its border
+        let pixels = pixels.borrow();
+        assert_eq!(pixels.len(), 2);
+        let (low, high) = (ViridisRGB::get_color(0.0), ViridisRGB::get_color(1.0));
+        assert_eq!(pixels[&chart.backend_coord(&(5.0, 5.0))], high.to_rgba());
+        assert_eq!(pixels[&chart.backend_coord(&(3.0, 4.0))], low.to_rgba());
+    }
+
+    #[test]
+    fn test_scatter_density_log_counts() {
+        let pixels = Rc::new(RefCell::new(HashMap::new()));
+        let pixels_out = pixels.clone();
+        let drawing_area = create_mocked_drawing_area(101, 101, |m| {
+            m.check_draw_pixel(move |c, pos| {
+                pixels_out.borrow_mut().insert(pos, c);
+            });
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..10.0)
+            .expect("Build chart error");
+
+        let points = [
+            vec![(5.0, 5.0); 100],
+            vec![(2.0, 8.0); 10],
+            vec![(1.0, 1.0)],
+        ]
+        .concat();
+        chart
+            .draw_series(MatScatterDensity::new(points).log_counts())
+            .expect("Drawing Error");
+
+        let pixels = pixels.borrow();
+        let lut = |t: f64| ViridisRGB::get_color((t * 255.0).round() / 255.0).to_rgba();
+        // The extent is the bounding box of the points; 10 points are halfway in log scale
+        assert_eq!(pixels[&chart.backend_coord(&(2.0, 8.0))], lut(0.5));
+        assert_eq!(pixels[&chart.backend_coord(&(5.0, 5.0))], lut(1.0));
+        assert_eq!(pixels[&chart.backend_coord(&(1.0, 1.0))], lut(0.0));
+    }
+}
@@ -0,0 +1,176 @@
+use super::MatSeriesError;
+use crate::chart::StyledSeries;
+use crate::element::{DynElement, IntoDynElement, PathElement, Polygon};
+use crate::style::{Color, ShapeStyle};
+use plotters_backend::DrawingBackend;
+
+/**
+A stairs series, the counterpart of matplotlib's `stairs(values, edges)`.
+
+It draws the step outline of pre-binned data: `values[i]` is the height of the step
+between `edges[i]` and `edges[i + 1]`, so there must be exactly one more edge than values.
+Optionally the area between the steps and a baseline is filled, see [`MatStairs::fill`].
+*/
+pub struct MatStairs<DB: DrawingBackend> {
+    style: ShapeStyle,
+    fill: Option<(f64, ShapeStyle)>,
+    values: Vec<f64>,
+    edges: Vec<f64>,
+    state: u32,
+    _p: std::marker::PhantomData<DB>,
+}
+
+impl<DB: DrawingBackend> MatStairs<DB> {
+    /**
+    Creates a stairs series from N values and N + 1 edges.
+
+    Returns [`MatSeriesError::LengthMismatch`] if `edges.len() != values.len() + 1`.
+    */
+    pub fn new<V, E, S>(values: V, edges: E, style: S) -> Result<Self, MatSeriesError>
+    where
+        V: IntoIterator<Item = f64>,
+        E: IntoIterator<Item = f64>,
+        S: Into<ShapeStyle>,
+    {
+        let values: Vec<f64> = values.into_iter().collect();
+        let edges: Vec<f64> = edges.into_iter().collect();
+        if edges.len() != values.len() + 1 {
+            return Err(MatSeriesError::LengthMismatch {
+                expected: values.len() + 1,
+                actual: edges.len(),
+            });
+        }
+        Ok(Self {
+            style: style.into(),
+            fill: None,
+            values,
+            edges,
+            state: 0,
+            _p: std::marker::PhantomData,
+        })
+    }
+
+    /**
+    Fills the area between the steps and `baseline`, using the line color at 20% opacity.
+
+    See [`MatStairs`] for more information.
+    */
+    pub fn fill(mut self, baseline: f64) -> Self {
+        self.fill = Some((baseline, self.style.color.mix(0.2).filled()));
+        self
+    }
+
+    /**
+    Fills the area between the steps and `baseline` with the given style.
+
+    See [`MatStairs`] for more information.
+    */
+    pub fn fill_with<S: Into<ShapeStyle>>(mut self, baseline: f64, style: S) -> Self {
+        self.fill = Some((baseline, style.into()));
+        self
+    }
+
+    fn outline(&self) -> Vec<(f64, f64)> {
+        let mut points = Vec::with_capacity(self.values.len() * 2);
+        for (i, &v) in self.values.iter().enumerate() {
+            points.push((self.edges[i], v));
+            points.push((self.edges[i + 1], v));
+        }
+        points
+    }
+}
+
+impl<DB: DrawingBackend> StyledSeries for MatStairs<DB> {
+    fn series_style(&self) -> ShapeStyle {
+        self.style
+    }
+}
+
+impl<DB: DrawingBackend> Iterator for MatStairs<DB> {
+    type Item = DynElement<'static, DB, (f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.values.is_empty() {
+            return None;
+        }
+        if self.state == 0 {
+            self.state = 1;
+            if let Some((baseline, style)) = self.fill {
+                let mut area = self.outline();
+                area.push((self.edges[self.edges.len() - 1], baseline));
+                area.push((self.edges[0], baseline));
+                return Some(Polygon::new(area, style).into_dyn());
+            }
+        }
+        if self.state == 1 {
+            self.state = 2;
+            return Some(PathElement::new(self.outline(), self.style).into_dyn());
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_stairs_length_mismatch() {
+        let err =
+            MatStairs::<crate::drawing::MockedBackend>::new(vec![1.0, 2.0], vec![0.0, 1.0], RED);
+        assert_eq!(
+            err.err(),
+            Some(MatSeriesError::LengthMismatch {
+                expected: 3,
+                actual: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_stairs() {
+        let values = [3.0, 1.0, 4.0];
+        let edges = [0.0, 2.0, 5.0, 10.0];
+        // The chart spans 0..10 x 0..5 over 500 pixels, so a guest unit is 50 pixels on x
+        // and 100 pixels on y
+        let px = |(x, y): (f64, f64)| ((x * 50.0) as i32, 500 - (y * 100.0) as i32);
+        let drawing_area = create_mocked_drawing_area(501, 501, move |m| {
+            m.check_fill_polygon(move |c, path| {
+                assert_eq!(c, RED.mix(0.2).to_rgba());
+                assert_eq!(path.len(), 8);
+                assert_eq!(path[6], px((10.0, 0.0)));
+                assert_eq!(path[7], px((0.0, 0.0)));
+            });
+            m.check_draw_path(move |c, _, path| {
+                assert_eq!(c, RED.to_rgba());
+                let expected: Vec<_> = [
+                    (0.0, 3.0),
+                    (2.0, 3.0),
+                    (2.0, 1.0),
+                    (5.0, 1.0),
+                    (5.0, 4.0),
+                    (10.0, 4.0),
+                ]
+                .iter()
+                .map(|&p| px(p))
+                .collect();
+                assert_eq!(path, expected);
+            });
+            m.drop_check(|b| {
+                assert_eq!(b.num_fill_polygon_call, 1);
+                assert_eq!(b.num_draw_path_call, 1);
+            });
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(0.0..10.0, 0.0..5.0)
+            .expect("Build chart error");
+
+        chart
+            .draw_series(
+                MatStairs::new(values, edges, RED)
+                    .expect("Length mismatch")
+                    .fill(0.0),
+            )
+            .expect("Drawing Error");
+    }
+}
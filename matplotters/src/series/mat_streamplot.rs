@@ -0,0 +1,373 @@
+use super::MatSeriesError;
+use crate::chart::StyledSeries;
+use crate::element::{Arrowhead, DynElement, IntoDynElement, PathElement};
+use crate::style::{Norm, ShapeStyle};
+use plotters_backend::DrawingBackend;
+
+#[cfg(feature = "colormaps")]
+use crate::style::{colors::colormaps::ColorMap, Color};
+
+/**
+A streamplot of a 2D vector field, like matplotlib's `streamplot`.
+
+The field is sampled on a grid: `u[j][i]` and `v[j][i]` are the components of the vector at
+`(x[i], y[j])`, and it is interpolated bilinearly in between. From each seed point, a
+streamline is traced forward along the field with the classic 4th order Runge-Kutta method,
+until it leaves the grid, reaches a point where the field vanishes, or after a maximal number
+of steps. The streamlines can end with arrowheads, see [`MatStreamplot::arrows`], and be
+colored by the magnitude of the field, see [`MatStreamplot::color_by_magnitude`].
+*/
+pub struct MatStreamplot<DB: DrawingBackend> {
+    x: Vec<f64>,
+    y: Vec<f64>,
+    u: Vec<Vec<f64>>,
+    v: Vec<Vec<f64>>,
+    seeds: Vec<(f64, f64)>,
+    step: f64,
+    max_steps: usize,
+    style: ShapeStyle,
+    arrow_size: u32,
+    colors: Option<Box<dyn Fn(f64) -> ShapeStyle>>,
+    elements: Option<std::vec::IntoIter<DynElement<'static, DB, (f64, f64)>>>,
+}
+
+impl<DB: DrawingBackend> MatStreamplot<DB> {
+    /**
+    Creates a streamplot of the field `(u, v)` sampled on the grid of `x` and `y`, which must
+    be increasing. The streamlines start from a 5 x 5 grid of seeds over the field, with a
+    step of half the smallest grid cell and at most 500 steps.
+
+    Returns [`MatSeriesError::LengthMismatch`] if `u` or `v` has not one row per y, or a row
+    has not one value per x.
+    */
+    pub fn new<S: Into<ShapeStyle>>(
+        x: &[f64],
+        y: &[f64],
+        u: &[Vec<f64>],
+        v: &[Vec<f64>],
+        style: S,
+    ) -> Result<Self, MatSeriesError> {
+        for field in [u, v] {
+            if field.len() != y.len() {
+                return Err(MatSeriesError::LengthMismatch {
+                    expected: y.len(),
+                    actual: field.len(),
+                });
+            }
+            if let Some(row) = field.iter().find(|row| row.len() != x.len()) {
+                return Err(MatSeriesError::LengthMismatch {
+                    expected: x.len(),
+                    actual: row.len(),
+                });
+            }
+        }
+        let min_cell = x
+            .windows(2)
+            .chain(y.windows(2))
+            .map(|w| w[1] - w[0])
+            .fold(f64::INFINITY, f64::min);
+        let mut ret = Self {
+            x: x.to_vec(),
+            y: y.to_vec(),
+            u: u.to_vec(),
+            v: v.to_vec(),
+            seeds: vec![],
+            step: if min_cell.is_finite() {
+                min_cell / 2.0
+            } else {
+                0.0
+            },
+            max_steps: 500,
+            style: style.into(),
+            arrow_size: 0,
+            colors: None,
+            elements: None,
+        };
+        if let (Some(x0), Some(x1), Some(y0), Some(y1)) = (x.first(), x.last(), y.first(), y.last())
+        {
+            let at = |lo: f64, hi: f64, i: usize| lo + (hi - lo) * (i as f64 + 0.5) / 5.0;
+            ret.seeds = (0..25)
+                .map(|i| (at(*x0, *x1, i % 5), at(*y0, *y1, i / 5)))
+                .collect();
+        }
+        Ok(ret)
+    }
+
+    /**
+    Sets the points the streamlines start from.
+
+    See [`MatStreamplot`] for more information.
+    */
+    pub fn seeds<I: IntoIterator<Item = (f64, f64)>>(mut self, seeds: I) -> Self {
+        self.seeds = seeds.into_iter().collect();
+        self
+    }
+
+    /**
+    Sets the integration step, in units of the field's parameter, and the maximal number of
+    steps of a streamline.
+
+    See [`MatStreamplot`] for more information.
+    */
+    pub fn step(mut self, step: f64, max_steps: usize) -> Self {
+        self.step = step;
+        self.max_steps = max_steps;
+        self
+    }
+
+    /**
+    Draws an arrowhead of the given size, in pixels, at the middle of every streamline,
+    pointing along the field. A size of 0 draws no arrowhead, which is the default.
+
+    See [`MatStreamplot`] for more information.
+    */
+    pub fn arrows(mut self, size: u32) -> Self {
+        self.arrow_size = size;
+        self
+    }
+
+    /**
+    Colors every step of the streamlines by the magnitude of the field at its start, with
+    the color `colormap.get_color(norm.normalize(magnitude))`, see [`Norm::colorize`].
+
+    See [`MatStreamplot::magnitude_norm`] for the norm spanning the field.
+    */
+    #[cfg(feature = "colormaps")]
+    pub fn color_by_magnitude<C: Color, M: ColorMap<C, f64>>(
+        mut self,
+        colormap: &M,
+        norm: Norm,
+    ) -> Self {
+        let style = self.style;
+        let colors: Vec<_> = (0..=255)
+            .map(|i| colormap.get_color(f64::from(i) / 255.0).to_rgba())
+            .collect();
+        self.colors = Some(Box::new(move |magnitude| {
+            let t = norm.normalize(magnitude).clamp(0.0, 1.0);
+            ShapeStyle {
+                color: norm
+                    .extended_color(magnitude)
+                    .unwrap_or(colors[(t * 255.0).round() as usize]),
+                ..style
+            }
+        }));
+        self
+    }
+
+    /**
+    Returns the normalization spanning the magnitudes of the field on the grid.
+
+    See [`MatStreamplot`] for more information.
+    */
+    pub fn magnitude_norm(&self) -> Norm {
+        Norm::autoscale(
+            self.u
+                .iter()
+                .flatten()
+                .zip(self.v.iter().flatten())
+                .map(|(u, v)| u.hypot(*v)),
+        )
+    }
+
+    /// The field at a point, interpolated bilinearly, or `None` outside of the grid
+    fn field_at(&self, (px, py): (f64, f64)) -> Option<(f64, f64)> {
+        let cell = |grid: &[f64], p: f64| -> Option<(usize, f64)> {
+            let (first, last) = (*grid.first()?, *grid.last()?);
+            if !(first..=last).contains(&p) {
+                return None;
+            }
+            if grid.len() == 1 {
+                return Some((0, 0.0));
+            }
+            let i = grid.partition_point(|g| *g <= p).clamp(1, grid.len() - 1) - 1;
+            Some((i, (p - grid[i]) / (grid[i + 1] - grid[i])))
+        };
+        let (i, tx) = cell(&self.x, px)?;
+        let (j, ty) = cell(&self.y, py)?;
+        let (i1, j1) = ((i + 1).min(self.x.len() - 1), (j + 1).min(self.y.len() - 1));
+        let lerp = |f: &[Vec<f64>]| {
+            let bottom = f[j][i] * (1.0 - tx) + f[j][i1] * tx;
+            let top = f[j1][i] * (1.0 - tx) + f[j1][i1] * tx;
+            bottom * (1.0 - ty) + top * ty
+        };
+        Some((lerp(&self.u), lerp(&self.v)))
+    }
+
+    /// One 4th order Runge-Kutta step, or `None` if it leaves the grid
+    fn rk4_step(&self, p: (f64, f64), h: f64) -> Option<(f64, f64)> {
+        let at = |k: (f64, f64), s: f64| (p.0 + k.0 * s, p.1 + k.1 * s);
+        let k1 = self.field_at(p)?;
+        let k2 = self.field_at(at(k1, h / 2.0))?;
+        let k3 = self.field_at(at(k2, h / 2.0))?;
+        let k4 = self.field_at(at(k3, h))?;
+        Some((
+            p.0 + h / 6.0 * (k1.0 + 2.0 * k2.0 + 2.0 * k3.0 + k4.0),
+            p.1 + h / 6.0 * (k1.1 + 2.0 * k2.1 + 2.0 * k3.1 + k4.1),
+        ))
+    }
+
+    /**
+    Traces the streamlines, one per seed inside the grid, as the points of their paths.
+
+    See [`MatStreamplot`] for more information.
+    */
+    pub fn streamlines(&self) -> Vec<Vec<(f64, f64)>> {
+        let mut ret = vec![];
+        for seed in &self.seeds {
+            let mut p = *seed;
+            let mut line = vec![p];
+            for _ in 0..self.max_steps {
+                match self.field_at(p) {
+                    Some((u, v)) if u.hypot(v) > 1e-12 => (),
+                    _ => break,
+                }
+                match self.rk4_step(p, self.step) {
+                    Some(next) => p = next,
+                    None => break,
+                }
+                line.push(p);
+            }
+            if self.field_at(*seed).is_some() {
+                ret.push(line);
+            }
+        }
+        ret
+    }
+
+    fn build_elements(&self) -> Vec<DynElement<'static, DB, (f64, f64)>> {
+        let mut ret = vec![];
+        for line in self.streamlines() {
+            if line.len() < 2 {
+                continue;
+            }
+            match &self.colors {
+                Some(colors) => {
+                    for pair in line.windows(2) {
+                        let (u, v) = self.field_at(pair[0]).unwrap_or((0.0, 0.0));
+                        let style = colors(u.hypot(v));
+                        ret.push(PathElement::new(pair.to_vec(), style).into_dyn());
+                    }
+                }
+                None => ret.push(PathElement::new(line.clone(), self.style).into_dyn()),
+            }
+            if self.arrow_size > 0 {
+                let mid = line.len() / 2;
+                let mid = mid.min(line.len() - 2);
+                let pair = (line[mid], line[mid + 1]);
+                ret.push(Arrowhead::new(pair, self.arrow_size, self.style).into_dyn());
+            }
+        }
+        ret
+    }
+}
+
+impl<DB: DrawingBackend> StyledSeries for MatStreamplot<DB> {
+    fn series_style(&self) -> ShapeStyle {
+        self.style
+    }
+}
+
+impl<DB: DrawingBackend> Iterator for MatStreamplot<DB> {
+    type Item = DynElement<'static, DB, (f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.elements.is_none() {
+            self.elements = Some(self.build_elements().into_iter());
+        }
+        self.elements.as_mut()?.next()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /// The counter-clockwise rotation `(u, v) = (-y, x)` over `[-2, 2]`
+    fn rotation() -> (Vec<f64>, Vec<Vec<f64>>, Vec<Vec<f64>>) {
+        let grid: Vec<f64> = (0..=8).map(|i| -2.0 + 0.5 * f64::from(i)).collect();
+        let u = grid.iter().map(|y| vec![-y; grid.len()]).collect();
+        let v = grid.iter().map(|_| grid.clone()).collect();
+        (grid, u, v)
+    }
+
+    #[test]
+    fn test_rotational_streamlines() {
+        let (grid, u, v) = rotation();
+        let plot = MatStreamplot::<MockedBackend>::new(&grid, &grid, &u, &v, BLUE)
+            .unwrap()
+            .seeds([(1.0, 0.0), (5.0, 5.0)])
+            .step(0.05, 40);
+        let lines = plot.streamlines();
+        // The seed outside of the grid has no streamline
+        assert_eq!(lines.len(), 1);
+        let line = &lines[0];
+        assert_eq!(line.len(), 41);
+        // Turning counter-clockwise on the unit circle, by one radian per unit of time
+        assert!(line[1].0 < 1.0 && line[1].1 > 0.0);
+        for (t, (x, y)) in line.iter().enumerate() {
+            let angle = 0.05 * t as f64;
+            assert!((x - angle.cos()).abs() < 1e-6);
+            assert!((y - angle.sin()).abs() < 1e-6);
+        }
+
+        assert!(MatStreamplot::<MockedBackend>::new(&grid, &grid[1..], &u, &v, BLUE).is_err());
+    }
+
+    #[test]
+    fn test_streamplot_drawing() {
+        let paths = Rc::new(RefCell::new(vec![]));
+        let paths_out = paths.clone();
+        let drawing_area = create_mocked_drawing_area(401, 401, |m| {
+            m.check_draw_path(move |_, _, p| paths_out.borrow_mut().push(p));
+            m.drop_check(|b| assert_eq!(b.num_fill_polygon_call, 1));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(-2.0..2.0, -2.0..2.0)
+            .expect("Build chart error");
+
+        let (grid, u, v) = rotation();
+        let plot = MatStreamplot::new(&grid, &grid, &u, &v, BLUE)
+            .unwrap()
+            .seeds([(0.0, -1.0)])
+            .step(0.1, 10)
+            .arrows(6);
+        chart.draw_series(plot).expect("Drawing Error");
+
+        let paths = paths.borrow();
+        assert_eq!(paths.len(), 1);
+        let path = &paths[0];
+        assert_eq!(path[0], chart.backend_coord(&(0.0, -1.0)));
+        // Below the center, the rotation goes to the right on the screen
+        assert!(path.windows(2).all(|w| w[1].0 > w[0].0));
+    }
+
+    #[test]
+    fn test_streamplot_magnitude_colors() {
+        let colors = Rc::new(RefCell::new(vec![]));
+        let colors_out = colors.clone();
+        let drawing_area = create_mocked_drawing_area(401, 401, |m| {
+            m.check_draw_path(move |c, _, _| colors_out.borrow_mut().push(c));
+        });
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(-2.0..2.0, -2.0..2.0)
+            .expect("Build chart error");
+
+        let (grid, u, v) = rotation();
+        let plot = MatStreamplot::new(&grid, &grid, &u, &v, BLUE)
+            .unwrap()
+            .seeds([(0.5, 0.0), (1.5, 0.0)])
+            .step(0.1, 3);
+        let norm = plot.magnitude_norm();
+        assert_eq!(norm.vmax(), 8f64.sqrt());
+        chart
+            .draw_series(plot.color_by_magnitude(&ViridisRGB {}, norm))
+            .expect("Drawing Error");
+
+        let colors = colors.borrow();
+        // A path per step; the outer streamline is faster, so brighter in viridis
+        assert_eq!(colors.len(), 6);
+        assert_ne!(colors[0], colors[3]);
+        assert!(colors[3].1 > colors[0].1);
+    }
+}
@@ -0,0 +1,125 @@
+use super::MatLineSeries;
+use crate::chart::{LegendGlyph, StyledSeries};
+use crate::coord::ranged1d::{AsRangedCoord, Ranged};
+use crate::element::DynElement;
+use crate::style::ShapeStyle;
+use plotters_backend::DrawingBackend;
+use std::ops::Range;
+
+/**
+A line series showing only the part of a time series inside a time window, a building block
+for scrolling, zooming and panning viewers.
+
+The full dataset is sliced to the points whose time is inside `[start, end]`, and the nice
+tick positions of the window are available from [`MatTimeWindow::ticks`]. The chart is meant
+to be built over [`MatTimeWindow::window`], so that the series fills the plotting area.
+*/
+pub struct MatTimeWindow<DB: DrawingBackend, X, Y> {
+    window: Range<X>,
+    line: MatLineSeries<DB, X, Y>,
+}
+
+impl<DB: DrawingBackend, X: PartialOrd + Clone, Y: Clone> MatTimeWindow<DB, X, Y> {
+    /**
+    Slices the dataset to the window `[window.start, window.end]`.
+
+    See [`MatTimeWindow`] for more information.
+    */
+    pub fn new<S: Into<ShapeStyle>>(data: &[(X, Y)], window: Range<X>, style: S) -> Self {
+        let visible: Vec<_> = data
+            .iter()
+            .filter(|(t, _)| *t >= window.start && *t <= window.end)
+            .cloned()
+            .collect();
+        Self {
+            window,
+            line: MatLineSeries::new(visible, style),
+        }
+    }
+
+    /**
+    Sets the size of the points in the series, in pixels.
+
+    See [`MatTimeWindow`] for more information.
+    */
+    pub fn point_size(mut self, size: u32) -> Self {
+        self.line = self.line.point_size(size);
+        self
+    }
+
+    /**
+    Returns the time window, to be used as the x range of the chart.
+
+    See [`MatTimeWindow`] for more information.
+    */
+    pub fn window(&self) -> Range<X> {
+        self.window.clone()
+    }
+
+    /**
+    Computes at most `max_ticks` nice tick positions inside the window, using the same
+    algorithm as the chart axis for the time type.
+
+    See [`MatTimeWindow`] for more information.
+    */
+    pub fn ticks(&self, max_ticks: usize) -> Vec<X>
+    where
+        Range<X>: AsRangedCoord<Value = X>,
+    {
+        let coord: <Range<X> as AsRangedCoord>::CoordDescType = self.window.clone().into();
+        coord.key_points(max_ticks)
+    }
+}
+
+impl<DB: DrawingBackend, X, Y> StyledSeries for MatTimeWindow<DB, X, Y> {
+    fn series_style(&self) -> ShapeStyle {
+        self.line.series_style()
+    }
+
+    fn legend_glyph(&self) -> LegendGlyph {
+        self.line.legend_glyph()
+    }
+}
+
+impl<DB: DrawingBackend, X: Clone + PartialEq + 'static, Y: Clone + PartialEq + 'static> Iterator
+    for MatTimeWindow<DB, X, Y>
+{
+    type Item = DynElement<'static, DB, (X, Y)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.line.next()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_time_window() {
+        let data: Vec<(f64, f64)> = (0..1000)
+            .map(|t| (f64::from(t), f64::from(t % 10)))
+            .collect();
+        let series = MatTimeWindow::new(&data, 200.0..300.0, BLUE);
+
+        let ticks = series.ticks(10);
+        for t in ticks.iter() {
+            assert!((200.0..=300.0).contains(t));
+        }
+        assert!(ticks.len() >= 2);
+
+        let drawing_area = create_mocked_drawing_area(1001, 101, |m| {
+            m.check_draw_path(|_, _, path| {
+                assert_eq!(path.len(), 101);
+                // The in-window points span the whole plotting area
+                assert_eq!(path[0].0, 0);
+                assert_eq!(path[100].0, 1000);
+            });
+            m.drop_check(|b| assert_eq!(b.num_draw_path_call, 1));
+        });
+
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(series.window(), 0.0..10.0)
+            .expect("Build chart error");
+        chart.draw_series(series).expect("Drawing Error");
+    }
+}
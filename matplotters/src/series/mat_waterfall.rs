@@ -0,0 +1,201 @@
+use crate::element::{DynElement, IntoDynElement, PathElement, Rectangle};
+use crate::style::{Color, ShapeStyle, GREEN, RED};
+use plotters_backend::DrawingBackend;
+use std::ops::Range;
+
+/**
+A waterfall chart, showing how a running total is built up by successive changes.
+
+The change `i` is drawn as a bar centered on x = `i`, floating from the running total before
+it to the running total after it: an increase is a green bar going up, and a decrease a red
+bar going down. Optional connector lines link each bar to the next at the running total
+between them, see [`MatWaterfall::connectors`]. The chart is meant to be built over
+`-0.5..n - 0.5` for x and [`MatWaterfall::y_range`] for y.
+*/
+pub struct MatWaterfall<DB: DrawingBackend> {
+    deltas: Vec<f64>,
+    increase: ShapeStyle,
+    decrease: ShapeStyle,
+    connectors: Option<ShapeStyle>,
+    width: f64,
+    elements: Option<std::vec::IntoIter<DynElement<'static, DB, (f64, f64)>>>,
+}
+
+impl<DB: DrawingBackend> MatWaterfall<DB> {
+    /**
+    Creates a waterfall chart from the successive changes of the total, starting at zero.
+
+    See [`MatWaterfall`] for more information.
+    */
+    pub fn new<I: IntoIterator<Item = f64>>(deltas: I) -> Self {
+        Self {
+            deltas: deltas.into_iter().collect(),
+            increase: GREEN.filled(),
+            decrease: RED.filled(),
+            connectors: None,
+            width: 0.8,
+            elements: None,
+        }
+    }
+
+    /**
+    Sets the styles of the bars of the increases and of the decreases.
+
+    See [`MatWaterfall`] for more information.
+    */
+    pub fn styles<I: Into<ShapeStyle>, D: Into<ShapeStyle>>(
+        mut self,
+        increase: I,
+        decrease: D,
+    ) -> Self {
+        self.increase = increase.into();
+        self.decrease = decrease.into();
+        self
+    }
+
+    /**
+    Sets the width of the bars. It is 0.8 by default.
+
+    See [`MatWaterfall`] for more information.
+    */
+    pub fn width(mut self, width: f64) -> Self {
+        self.width = width;
+        self
+    }
+
+    /**
+    Draws connector lines between consecutive bars, in the given style, usually a thin gray
+    line. Each connector goes from the right side of a bar to the left side of the next one,
+    at the running total where the first bar ends and the next one starts.
+
+    See [`MatWaterfall`] for more information.
+    */
+    pub fn connectors<S: Into<ShapeStyle>>(mut self, style: S) -> Self {
+        self.connectors = Some(style.into());
+        self
+    }
+
+    /**
+    Returns the running total after each change.
+
+    See [`MatWaterfall`] for more information.
+    */
+    pub fn totals(&self) -> Vec<f64> {
+        self.deltas
+            .iter()
+            .scan(0.0, |total, delta| {
+                *total += delta;
+                Some(*total)
+            })
+            .collect()
+    }
+
+    /**
+    Returns the y range fitting all the running totals and zero, with 5% of margin.
+
+    See [`MatWaterfall`] for more information.
+    */
+    pub fn y_range(&self) -> Range<f64> {
+        let (low, high) = self
+            .totals()
+            .into_iter()
+            .fold((0.0_f64, 0.0_f64), |(lo, hi), t| (lo.min(t), hi.max(t)));
+        let margin = (high - low).max(1.0) * 0.05;
+        low - margin..high + margin
+    }
+
+    fn elements(&self) -> Vec<DynElement<'static, DB, (f64, f64)>> {
+        let half = self.width / 2.0;
+        let mut ret = vec![];
+        let mut before = 0.0;
+        for (i, total) in self.totals().into_iter().enumerate() {
+            let x = i as f64;
+            let style = if total < before {
+                self.decrease
+            } else {
+                self.increase
+            };
+            ret.push(
+                Rectangle::new(
+                    [(x - half, before.max(total)), (x + half, before.min(total))],
+                    style,
+                )
+                .into_dyn(),
+            );
+            if let (Some(style), true) = (self.connectors, i + 1 < self.deltas.len()) {
+                ret.push(
+                    PathElement::new(vec![(x + half, total), (x + 1.0 - half, total)], style)
+                        .into_dyn(),
+                );
+            }
+            before = total;
+        }
+        ret
+    }
+}
+
+impl<DB: DrawingBackend> Iterator for MatWaterfall<DB> {
+    type Item = DynElement<'static, DB, (f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.elements.is_none() {
+            self.elements = Some(self.elements().into_iter());
+        }
+        self.elements.as_mut()?.next()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_waterfall_connectors() {
+        let rects = Rc::new(RefCell::new(vec![]));
+        let paths = Rc::new(RefCell::new(vec![]));
+        let (rects_out, paths_out) = (rects.clone(), paths.clone());
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_draw_rect(move |c, _, _, ul, br| rects_out.borrow_mut().push((c, ul, br)));
+            m.check_draw_path(move |c, w, p| {
+                assert_eq!(c, BLACK.mix(0.5).to_rgba());
+                assert_eq!(w, 2);
+                paths_out.borrow_mut().push(p);
+            });
+        });
+
+        let waterfall =
+            MatWaterfall::new([10.0, -4.0, 6.0, -2.0]).connectors(BLACK.mix(0.5).stroke_width(2));
+        assert_eq!(waterfall.totals(), vec![10.0, 6.0, 12.0, 10.0]);
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(-0.5..3.5, waterfall.y_range())
+            .expect("Build chart error");
+        chart.draw_series(waterfall).expect("Drawing Error");
+
+        let rects = rects.borrow();
+        assert_eq!(rects.len(), 4);
+        let colors: Vec<_> = rects.iter().map(|r| r.0).collect();
+        let (green, red) = (GREEN.to_rgba(), RED.to_rgba());
+        assert_eq!(colors, vec![green, red, green, red]);
+
+        // One connector between each pair of bars, at the running total between them: it
+        // starts at the end of a bar and reaches the start of the next one
+        let paths = paths.borrow();
+        assert_eq!(paths.len(), 3);
+        let y = |v: f64| chart.backend_coord(&(0.0, v)).1;
+        let ends = [
+            (rects[0].1 .1, rects[1].1 .1),
+            (rects[1].2 .1, rects[2].2 .1),
+        ];
+        assert_eq!(ends[0], (y(10.0), y(10.0)));
+        assert_eq!(ends[1], (y(6.0), y(6.0)));
+        assert_eq!(rects[2].1 .1, y(12.0));
+        assert_eq!(rects[3].1 .1, y(12.0));
+        for (i, (path, total)) in paths.iter().zip([10.0, 6.0, 12.0]).enumerate() {
+            assert_eq!(path.len(), 2);
+            assert!(path.iter().all(|p| p.1 == y(total)), "connector {}", i);
+            assert_eq!(path[0].0, rects[i].2 .0);
+            assert_eq!(path[1].0, rects[i + 1].1 .0);
+        }
+    }
+}
@@ -0,0 +1,250 @@
+use crate::element::{DynElement, IntoDynElement, Polygon};
+use crate::style::{Color, ColorCycle, RGBColor};
+use plotters_backend::DrawingBackend;
+use std::ops::Range;
+
+/**
+A wind rose: a polar histogram of directions, like the wind roses of meteorology, whose
+sectors are split by a magnitude such as the wind speed.
+
+The directions are in degrees on the compass, 0 being north (the positive y axis) and 90
+east (the positive x axis). They are binned into sectors of equal width, the sector 0 being
+centered on north, and each sector is drawn as a bar radiating from the origin, as long as
+the number of directions in it. The bar is stacked from the center outwards with one
+segment per magnitude category, see [`MatWindRose::magnitude_bins`], each category taking
+the next color of the default [`ColorCycle`]. The chart is meant to be built over
+[`MatWindRose::extent`] for both axes, on a square plotting area so that the rose stays
+round.
+*/
+pub struct MatWindRose<DB: DrawingBackend> {
+    // (direction, magnitude)
+    data: Vec<(f64, f64)>,
+    sectors: usize,
+    edges: Vec<f64>,
+    sector_width: f64,
+    elements: Option<std::vec::IntoIter<DynElement<'static, DB, (f64, f64)>>>,
+}
+
+impl<DB: DrawingBackend> MatWindRose<DB> {
+    /// The number of points on each arc of a segment
+    const ARC_POINTS: usize = 16;
+
+    /**
+    Creates a wind rose from `(direction, magnitude)` pairs, binned into `sectors` sectors,
+    with a single magnitude category.
+
+    See [`MatWindRose`] for more information.
+    */
+    pub fn new<I: IntoIterator<Item = (f64, f64)>>(data: I, sectors: usize) -> Self {
+        Self {
+            data: data
+                .into_iter()
+                .filter(|(d, m)| d.is_finite() && m.is_finite())
+                .collect(),
+            sectors: sectors.max(1),
+            edges: vec![],
+            sector_width: 0.9,
+            elements: None,
+        }
+    }
+
+    /**
+    Splits the magnitudes into categories at the sorted `edges`: the category `k` holds the
+    magnitudes from `edges[k]` to `edges[k + 1]`, the last one the magnitudes from the last
+    edge up, and the magnitudes below the first edge go to the first category. There is one
+    category per edge.
+
+    See [`MatWindRose`] for more information.
+    */
+    pub fn magnitude_bins(mut self, edges: &[f64]) -> Self {
+        self.edges = edges.to_vec();
+        self
+    }
+
+    /**
+    Sets the angular width of the bars, as a fraction of the width of a sector. It is 0.9 by
+    default.
+
+    See [`MatWindRose`] for more information.
+    */
+    pub fn sector_width(mut self, width: f64) -> Self {
+        self.sector_width = width;
+        self
+    }
+
+    /**
+    Returns the compass direction of the center of a sector, in degrees.
+
+    See [`MatWindRose`] for more information.
+    */
+    pub fn sector_direction(&self, sector: usize) -> f64 {
+        360.0 * sector as f64 / self.sectors as f64
+    }
+
+    /**
+    Returns the number of directions of each magnitude category in each sector, indexed by
+    sector then by category.
+
+    See [`MatWindRose`] for more information.
+    */
+    pub fn counts(&self) -> Vec<Vec<usize>> {
+        let categories = self.edges.len().max(1);
+        let mut counts = vec![vec![0; categories]; self.sectors];
+        let step = 360.0 / self.sectors as f64;
+        for &(direction, magnitude) in &self.data {
+            let sector = ((direction + step / 2.0).rem_euclid(360.0) / step) as usize;
+            let category = self
+                .edges
+                .partition_point(|e| *e <= magnitude)
+                .saturating_sub(1);
+            counts[sector.min(self.sectors - 1)][category] += 1;
+        }
+        counts
+    }
+
+    /**
+    Returns the range fitting the longest bar around the origin, for both axes.
+
+    See [`MatWindRose`] for more information.
+    */
+    pub fn extent(&self) -> Range<f64> {
+        let max = self
+            .counts()
+            .iter()
+            .map(|c| c.iter().sum::<usize>())
+            .max()
+            .unwrap_or(0);
+        let r = (max as f64 * 1.1).max(1.0);
+        -r..r
+    }
+
+    fn elements(&self) -> Vec<DynElement<'static, DB, (f64, f64)>> {
+        let half_width = 180.0 * self.sector_width / self.sectors as f64;
+        let at = |r: f64, direction: f64| {
+            let a = direction.to_radians();
+            (r * a.sin(), r * a.cos())
+        };
+        let mut cycle = ColorCycle::default();
+        let colors: Vec<RGBColor> = (0..self.edges.len().max(1))
+            .map(|_| cycle.next_color())
+            .collect();
+        let mut ret = vec![];
+        for (sector, counts) in self.counts().into_iter().enumerate() {
+            let center = self.sector_direction(sector);
+            let directions: Vec<f64> = (0..=Self::ARC_POINTS)
+                .map(|k| {
+                    center - half_width + 2.0 * half_width * k as f64 / Self::ARC_POINTS as f64
+                })
+                .collect();
+            let mut inner = 0.0;
+            for (count, color) in counts.into_iter().zip(&colors) {
+                if count == 0 {
+                    continue;
+                }
+                let outer = inner + count as f64;
+                let mut segment: Vec<_> = directions.iter().map(|&d| at(outer, d)).collect();
+                if inner > 0.0 {
+                    segment.extend(directions.iter().rev().map(|&d| at(inner, d)));
+                } else {
+                    segment.push((0.0, 0.0));
+                }
+                ret.push(Polygon::new(segment, color.filled()).into_dyn());
+                inner = outer;
+            }
+        }
+        ret
+    }
+}
+
+impl<DB: DrawingBackend> Iterator for MatWindRose<DB> {
+    type Item = DynElement<'static, DB, (f64, f64)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.elements.is_none() {
+            self.elements = Some(self.elements().into_iter());
+        }
+        self.elements.as_mut()?.next()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_wind_rose() {
+        let segments = Rc::new(RefCell::new(vec![]));
+        let segments_out = segments.clone();
+        let drawing_area = create_mocked_drawing_area(501, 501, |m| {
+            m.check_fill_polygon(move |c, path| segments_out.borrow_mut().push((c, path)));
+        });
+
+        // Mostly from the north, some from the east and from the west; none from the south
+        let data = [
+            (0.0, 1.0),
+            (355.0, 3.0),
+            (20.0, 6.0),
+            (-10.0, 1.5),
+            (90.0, 2.5),
+            (100.0, 0.5),
+            (270.0, 7.0),
+        ];
+        let rose = MatWindRose::new(data, 4).magnitude_bins(&[0.0, 2.0, 5.0]);
+        assert_eq!(
+            rose.counts(),
+            vec![vec![2, 1, 1], vec![1, 1, 0], vec![0, 0, 0], vec![0, 0, 1]]
+        );
+        assert_eq!(rose.sector_direction(1), 90.0);
+        let extent = rose.extent();
+        let mut chart = ChartBuilder::on(&drawing_area)
+            .build_cartesian_2d(extent.clone(), extent)
+            .expect("Build chart error");
+        chart.draw_series(rose).expect("Drawing Error");
+
+        let segments = segments.borrow();
+        assert_eq!(segments.len(), 6);
+        let mut cycle = ColorCycle::default();
+        let colors: Vec<RGBAColor> = (0..3).map(|_| cycle.next_color().to_rgba()).collect();
+        let (cx, cy) = chart.backend_coord(&(0.0, 0.0));
+        let unit_x = f64::from(chart.backend_coord(&(4.0, 0.0)).0 - cx) / 4.0;
+        let unit_y = f64::from(cy - chart.backend_coord(&(0.0, 4.0)).1) / 4.0;
+        // (sector direction, category, inner radius, outer radius) of each segment
+        let expected = [
+            (0.0, 0, 0.0, 2.0),
+            (0.0, 1, 2.0, 3.0),
+            (0.0, 2, 3.0, 4.0),
+            (90.0, 0, 0.0, 1.0),
+            (90.0, 1, 1.0, 2.0),
+            (270.0, 2, 0.0, 1.0),
+        ];
+        for ((c, path), (direction, category, inner, outer)) in segments.iter().zip(expected) {
+            assert_eq!(*c, colors[category]);
+            let polar: Vec<(f64, f64)> = path
+                .iter()
+                .map(|&(x, y)| (f64::from(x - cx) / unit_x, f64::from(cy - y) / unit_y))
+                .map(|(x, y)| (x.hypot(y), x.atan2(y).to_degrees()))
+                .collect();
+            // The segment is stacked between the counts of the previous categories and its own
+            let radii = polar.iter().map(|p| p.0);
+            let (low, high) = radii.fold((f64::INFINITY, 0.0_f64), |(lo, hi), r| {
+                (lo.min(r), hi.max(r))
+            });
+            assert!((low - inner).abs() < 0.05 && (high - outer).abs() < 0.05);
+            // and centered on the direction of its sector, clockwise from north
+            let (sx, sy) = polar
+                .iter()
+                .filter(|p| p.0 > 0.01)
+                .fold((0.0, 0.0), |(sx, sy), &(_, a)| {
+                    (sx + a.to_radians().sin(), sy + a.to_radians().cos())
+                });
+            let diff = (sx.atan2(sy).to_degrees() - direction).rem_euclid(360.0);
+            assert!(
+                diff.min(360.0 - diff) < 1.0,
+                "sector {}: {}",
+                direction,
+                diff
+            );
+        }
+    }
+}
@@ -16,8 +16,59 @@ mod area_series;
 mod histogram;
 #[cfg(feature = "line_series")]
 mod line_series;
+#[cfg(feature = "histogram")]
+mod mat_bar_series;
+#[cfg(feature = "boxplot")]
+mod mat_box_plot;
+#[cfg(feature = "point_series")]
+mod mat_bubble;
+#[cfg(all(feature = "chrono", feature = "colormaps"))]
+mod mat_calendar_heatmap;
+#[cfg(feature = "point_series")]
+mod mat_category_scatter;
+#[cfg(feature = "colormaps")]
+mod mat_colorbar;
+#[cfg(feature = "line_series")]
+mod mat_decision_boundary;
+#[cfg(feature = "point_series")]
+mod mat_density_contour;
+#[cfg(feature = "line_series")]
+mod mat_ecdf;
+mod mat_error;
+#[cfg(feature = "area_series")]
+mod mat_error_band;
+#[cfg(feature = "area_series")]
+mod mat_fill_between;
+#[cfg(feature = "histogram")]
+mod mat_histogram;
+#[cfg(feature = "colormaps")]
+mod mat_imshow;
+#[cfg(feature = "line_series")]
+mod mat_line_error_series;
 #[cfg(feature = "line_series")]
 mod mat_line_series;
+#[cfg(feature = "histogram")]
+mod mat_population_pyramid;
+#[cfg(feature = "histogram")]
+mod mat_radial_bar;
+#[cfg(feature = "area_series")]
+mod mat_ribbon;
+#[cfg(feature = "line_series")]
+mod mat_rolling_correlation;
+#[cfg(feature = "area_series")]
+mod mat_sankey;
+#[cfg(feature = "colormaps")]
+mod mat_scatter_density;
+#[cfg(feature = "line_series")]
+mod mat_stairs;
+#[cfg(feature = "line_series")]
+mod mat_streamplot;
+#[cfg(feature = "line_series")]
+mod mat_time_window;
+#[cfg(feature = "histogram")]
+mod mat_waterfall;
+#[cfg(feature = "histogram")]
+mod mat_wind_rose;
 #[cfg(feature = "point_series")]
 mod point_series;
 #[cfg(feature = "surface_series")]
@@ -29,8 +80,59 @@ pub use area_series::AreaSeries;
 pub use histogram::Histogram;
 #[cfg(feature = "line_series")]
 pub use line_series::{DashedLineSeries, LineSeries};
+#[cfg(feature = "histogram")]
+pub use mat_bar_series::{BarWidth, MatBarSeries};
+#[cfg(feature = "boxplot")]
+pub use mat_box_plot::MatBoxPlot;
+#[cfg(feature = "point_series")]
+pub use mat_bubble::{MatBubbleSeries, SizeScale};
+#[cfg(all(feature = "chrono", feature = "colormaps"))]
+pub use mat_calendar_heatmap::MatCalendarHeatmap;
+#[cfg(feature = "point_series")]
+pub use mat_category_scatter::MatCategoryScatter;
+#[cfg(feature = "colormaps")]
+pub use mat_colorbar::MatColorbar;
+#[cfg(feature = "line_series")]
+pub use mat_decision_boundary::MatDecisionBoundary;
+#[cfg(feature = "point_series")]
+pub use mat_density_contour::MatDensityContour;
+#[cfg(feature = "line_series")]
+pub use mat_ecdf::{EcdfStyle, MatEcdf};
+pub use mat_error::MatSeriesError;
+#[cfg(feature = "area_series")]
+pub use mat_error_band::MatErrorBand;
+#[cfg(feature = "area_series")]
+pub use mat_fill_between::MatFillBetween;
+#[cfg(feature = "histogram")]
+pub use mat_histogram::MatHistogram;
+#[cfg(feature = "colormaps")]
+pub use mat_imshow::{Aspect, Interpolation, MatImshow};
+#[cfg(feature = "line_series")]
+pub use mat_line_error_series::MatLineErrorSeries;
 #[cfg(feature = "line_series")]
-pub use mat_line_series::MatLineSeries;
+pub use mat_line_series::{DownsampleReport, GapReport, MatLineSeries, RenderMode, StepMode};
+#[cfg(feature = "histogram")]
+pub use mat_population_pyramid::MatPopulationPyramid;
+#[cfg(feature = "histogram")]
+pub use mat_radial_bar::MatRadialBar;
+#[cfg(feature = "area_series")]
+pub use mat_ribbon::MatRibbon;
+#[cfg(feature = "line_series")]
+pub use mat_rolling_correlation::MatRollingCorrelation;
+#[cfg(feature = "area_series")]
+pub use mat_sankey::MatSankey;
+#[cfg(feature = "colormaps")]
+pub use mat_scatter_density::MatScatterDensity;
+#[cfg(feature = "line_series")]
+pub use mat_stairs::MatStairs;
+#[cfg(feature = "line_series")]
+pub use mat_streamplot::MatStreamplot;
+#[cfg(feature = "line_series")]
+pub use mat_time_window::MatTimeWindow;
+#[cfg(feature = "histogram")]
+pub use mat_waterfall::MatWaterfall;
+#[cfg(feature = "histogram")]
+pub use mat_wind_rose::MatWindRose;
 #[cfg(feature = "point_series")]
 pub use point_series::PointSeries;
 #[cfg(feature = "surface_series")]
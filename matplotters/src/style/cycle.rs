@@ -0,0 +1,234 @@
+use super::{Color, LineStyle, RGBColor};
+use crate::element::MarkerShape;
+use std::cell::RefCell;
+
+/// The default color cycle of matplotlib, the `tab10` palette
+const TAB10: [RGBColor; 10] = [
+    RGBColor(31, 119, 180),
+    RGBColor(255, 127, 14),
+    RGBColor(44, 160, 44),
+    RGBColor(214, 39, 40),
+    RGBColor(148, 103, 189),
+    RGBColor(140, 86, 75),
+    RGBColor(227, 119, 194),
+    RGBColor(127, 127, 127),
+    RGBColor(188, 189, 34),
+    RGBColor(23, 190, 207),
+];
+
+/// The default marker sequence, the filled markers first
+const MARKERS: [MarkerShape; 7] = [
+    MarkerShape::Circle,
+    MarkerShape::Square,
+    MarkerShape::TriangleUp,
+    MarkerShape::Diamond,
+    MarkerShape::TriangleDown,
+    MarkerShape::Cross,
+    MarkerShape::Plus,
+];
+
+/// The default line style sequence
+const LINE_STYLES: [LineStyle; 4] = [
+    LineStyle::Solid,
+    LineStyle::Dashed,
+    LineStyle::Dotted,
+    LineStyle::DashDot,
+];
+
+thread_local! {
+    static DEFAULT_COLOR_CYCLE: RefCell<ColorCycle> = RefCell::new(ColorCycle::default());
+    static DEFAULT_MARKER_CYCLE: RefCell<MarkerCycle> = RefCell::new(MarkerCycle::default());
+    static DEFAULT_LINE_STYLE_CYCLE: RefCell<LineStyleCycle> =
+        RefCell::new(LineStyleCycle::default());
+}
+
+/**
+A cycle of colors, like the matplotlib `axes.prop_cycle` colors, which gives every new series
+the next color of the sequence.
+
+The series builders pulling from the cycle, such as
+[`crate::series::MatLineSeries::auto_color`], use a default cycle per thread, which can be
+restarted with [`ColorCycle::reset_default`].
+*/
+#[derive(Clone, Debug)]
+pub struct ColorCycle {
+    colors: Vec<RGBColor>,
+    idx: usize,
+}
+
+impl ColorCycle {
+    /// Create a cycle over the given colors
+    pub fn new<C: Color>(colors: &[C]) -> Self {
+        Self {
+            colors: colors
+                .iter()
+                .map(|c| {
+                    let (r, g, b) = c.rgb();
+                    RGBColor(r, g, b)
+                })
+                .collect(),
+            idx: 0,
+        }
+    }
+
+    /// Get the next color of the cycle, starting over after the last one
+    pub fn next_color(&mut self) -> RGBColor {
+        if self.colors.is_empty() {
+            return TAB10[0];
+        }
+        let color = self.colors[self.idx % self.colors.len()];
+        self.idx += 1;
+        color
+    }
+
+    /// Get the next color of the default cycle of the current thread
+    pub fn next_default() -> RGBColor {
+        DEFAULT_COLOR_CYCLE.with(|c| c.borrow_mut().next_color())
+    }
+
+    /// Restart the default cycle of the current thread from its first color
+    pub fn reset_default() {
+        DEFAULT_COLOR_CYCLE.with(|c| c.borrow_mut().idx = 0);
+    }
+
+    /// Replace the default cycle of the current thread, see also [`crate::style::RcParams`]
+    pub fn set_default(cycle: ColorCycle) {
+        DEFAULT_COLOR_CYCLE.with(|c| *c.borrow_mut() = cycle);
+    }
+
+    /// The colors of the cycle
+    pub fn colors(&self) -> &[RGBColor] {
+        &self.colors
+    }
+}
+
+impl Default for ColorCycle {
+    fn default() -> Self {
+        Self::new(&TAB10)
+    }
+}
+
+/**
+A cycle of marker shapes, which makes the series distinguishable without colors, such as in
+grayscale prints.
+
+The series builders pulling from the cycle, such as
+[`crate::series::MatLineSeries::auto_marker`], use a default cycle per thread, which can be
+restarted with [`MarkerCycle::reset_default`]. It is independent from the color cycle, so
+both can be used together.
+*/
+#[derive(Clone, Debug)]
+pub struct MarkerCycle {
+    markers: Vec<MarkerShape>,
+    idx: usize,
+}
+
+impl MarkerCycle {
+    /// Create a cycle over the given marker shapes
+    pub fn new(markers: &[MarkerShape]) -> Self {
+        Self {
+            markers: markers.to_vec(),
+            idx: 0,
+        }
+    }
+
+    /// Get the next marker of the cycle, starting over after the last one
+    pub fn next_marker(&mut self) -> MarkerShape {
+        if self.markers.is_empty() {
+            return MarkerShape::Circle;
+        }
+        let marker = self.markers[self.idx % self.markers.len()];
+        self.idx += 1;
+        marker
+    }
+
+    /// Get the next marker of the default cycle of the current thread
+    pub fn next_default() -> MarkerShape {
+        DEFAULT_MARKER_CYCLE.with(|c| c.borrow_mut().next_marker())
+    }
+
+    /// Restart the default cycle of the current thread from its first marker
+    pub fn reset_default() {
+        DEFAULT_MARKER_CYCLE.with(|c| c.borrow_mut().idx = 0);
+    }
+}
+
+impl Default for MarkerCycle {
+    fn default() -> Self {
+        Self::new(&MARKERS)
+    }
+}
+
+/**
+A cycle of line styles, which makes overlapping lines distinguishable without colors, such as
+in grayscale prints.
+
+The series builders pulling from the cycle, such as
+[`crate::series::MatLineSeries::auto_line_style`], use a default cycle per thread, which can
+be restarted with [`LineStyleCycle::reset_default`].
+*/
+#[derive(Clone, Debug)]
+pub struct LineStyleCycle {
+    styles: Vec<LineStyle>,
+    idx: usize,
+}
+
+impl LineStyleCycle {
+    /// Create a cycle over the given line styles
+    pub fn new(styles: &[LineStyle]) -> Self {
+        Self {
+            styles: styles.to_vec(),
+            idx: 0,
+        }
+    }
+
+    /// Get the next line style of the cycle, starting over after the last one
+    pub fn next_line_style(&mut self) -> LineStyle {
+        if self.styles.is_empty() {
+            return LineStyle::Solid;
+        }
+        let style = self.styles[self.idx % self.styles.len()];
+        self.idx += 1;
+        style
+    }
+
+    /// Get the next line style of the default cycle of the current thread
+    pub fn next_default() -> LineStyle {
+        DEFAULT_LINE_STYLE_CYCLE.with(|c| c.borrow_mut().next_line_style())
+    }
+
+    /// Restart the default cycle of the current thread from its first line style
+    pub fn reset_default() {
+        DEFAULT_LINE_STYLE_CYCLE.with(|c| c.borrow_mut().idx = 0);
+    }
+}
+
+impl Default for LineStyleCycle {
+    fn default() -> Self {
+        Self::new(&LINE_STYLES)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cycles() {
+        let mut colors = ColorCycle::new(&[RGBColor(1, 2, 3), RGBColor(4, 5, 6)]);
+        assert_eq!(colors.next_color(), RGBColor(1, 2, 3));
+        assert_eq!(colors.next_color(), RGBColor(4, 5, 6));
+        assert_eq!(colors.next_color(), RGBColor(1, 2, 3));
+
+        let mut markers = MarkerCycle::default();
+        let first: Vec<_> = (0..7).map(|_| markers.next_marker()).collect();
+        assert_eq!(first, MARKERS);
+        assert_eq!(markers.next_marker(), MarkerShape::Circle);
+
+        MarkerCycle::reset_default();
+        assert_eq!(MarkerCycle::next_default(), MarkerShape::Circle);
+        assert_eq!(MarkerCycle::next_default(), MarkerShape::Square);
+        MarkerCycle::reset_default();
+        assert_eq!(MarkerCycle::next_default(), MarkerShape::Circle);
+    }
+}
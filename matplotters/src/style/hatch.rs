@@ -0,0 +1,41 @@
+/// The hatch pattern of an area, like the matplotlib `hatch`, which stays distinguishable
+/// when printed in black and white
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Hatch {
+    /// Rising diagonal lines, `"/"`
+    Forward,
+    /// Falling diagonal lines, `"\"`
+    Backward,
+    /// Horizontal lines, `"-"`
+    Horizontal,
+    /// Vertical lines, `"|"`
+    Vertical,
+    /// Horizontal and vertical lines, `"+"`
+    Cross,
+    /// Rising and falling diagonal lines, `"x"`
+    DiagonalCross,
+}
+
+impl Hatch {
+    /// The directions of the lines of the pattern on the screen, in degrees counter-clockwise
+    /// from the x axis.
+    pub fn angles(&self) -> &'static [f64] {
+        match self {
+            Hatch::Forward => &[45.0],
+            Hatch::Backward => &[-45.0],
+            Hatch::Horizontal => &[0.0],
+            Hatch::Vertical => &[90.0],
+            Hatch::Cross => &[0.0, 90.0],
+            Hatch::DiagonalCross => &[45.0, -45.0],
+        }
+    }
+}
+
+/// A repeating pattern filling a shape, such as a large marker, instead of a solid color
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Pattern {
+    /// Parallel stripes, in the directions of a hatch
+    Stripes(Hatch),
+    /// A square grid of dots
+    Dots,
+}
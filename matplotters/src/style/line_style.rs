@@ -0,0 +1,28 @@
+/// The dash pattern of a line, like the matplotlib `linestyle`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum LineStyle {
+    /// A continuous line, `"-"`
+    #[default]
+    Solid,
+    /// A dashed line, `"--"`
+    Dashed,
+    /// A dotted line, `":"`
+    Dotted,
+    /// Alternating dashes and dots, `"-."`
+    DashDot,
+}
+
+impl LineStyle {
+    /// The lengths of the alternating dashes and gaps of the pattern, in pixels, for a line of
+    /// the given stroke width. It is empty for a solid line.
+    pub fn dash_pattern(&self, stroke_width: u32) -> Vec<f64> {
+        let w = f64::from(stroke_width.max(1));
+        let pattern: &[f64] = match self {
+            LineStyle::Solid => &[],
+            LineStyle::Dashed => &[6.0, 4.0],
+            LineStyle::Dotted => &[1.5, 3.0],
+            LineStyle::DashDot => &[8.0, 3.0, 1.5, 3.0],
+        };
+        pattern.iter().map(|v| v * w).collect()
+    }
+}
@@ -3,8 +3,13 @@
 */
 mod color;
 pub mod colors;
+mod cycle;
 mod font;
+mod hatch;
+mod line_style;
+mod norm;
 mod palette;
+mod rc_params;
 mod shape;
 mod size;
 mod text;
@@ -13,6 +18,7 @@ mod text;
 pub use self::palette::*;
 pub use color::{Color, HSLColor, PaletteColor, RGBAColor, RGBColor};
 pub use colors::{BLACK, BLUE, CYAN, GREEN, MAGENTA, RED, TRANSPARENT, WHITE, YELLOW};
+pub use cycle::{ColorCycle, LineStyleCycle, MarkerCycle};
 
 #[cfg(feature = "full_palette")]
 pub use colors::full_palette;
@@ -23,6 +29,10 @@ pub use font::{
     FontDesc, FontError, FontFamily, FontResult, FontStyle, FontTransform, IntoFont, LayoutBox,
 };
 
+pub use hatch::{Hatch, Pattern};
+pub use line_style::LineStyle;
+pub use norm::{Extend, Norm};
+pub use rc_params::RcParams;
 pub use shape::ShapeStyle;
 pub use size::{AsRelative, RelativeSize, SizeDesc};
 pub use text::text_anchor;
@@ -0,0 +1,212 @@
+use super::{Color, RGBAColor};
+
+#[cfg(feature = "colormaps")]
+use super::colors::colormaps::ColorMap;
+
+/// The ends of a [`Norm`] whose out of range values take their own color, like the `extend`
+/// of a matplotlib colorbar, see [`Norm::extend`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Extend {
+    /// The values out of the range take the end colors of the colormap
+    #[default]
+    Neither,
+    /// The values below `vmin` take the under color
+    Min,
+    /// The values above `vmax` take the over color
+    Max,
+    /// The values below `vmin` take the under color, and the values above `vmax` the over
+    /// color
+    Both,
+}
+
+impl Extend {
+    /// Whether the values below `vmin` take the under color
+    pub fn has_min(self) -> bool {
+        matches!(self, Extend::Min | Extend::Both)
+    }
+
+    /// Whether the values above `vmax` take the over color
+    pub fn has_max(self) -> bool {
+        matches!(self, Extend::Max | Extend::Both)
+    }
+}
+
+/**
+A linear normalization of data values into `[0, 1]`, like matplotlib's `Normalize`. It is
+used together with a colormap to color elements by value.
+
+The values `vmin` and `vmax` are mapped to 0 and 1. Values out of the range are mapped out
+of `[0, 1]`, and the colormaps clamp them to their end colors, unless the norm is extended:
+the values beyond an extended end take the under or over color instead, see
+[`Norm::extend`], and a colorbar of the norm shows a triangle at that end.
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Norm {
+    vmin: f64,
+    vmax: f64,
+    extend: Extend,
+    under: Option<RGBAColor>,
+    over: Option<RGBAColor>,
+}
+
+impl Norm {
+    /**
+    Creates a normalization mapping `vmin` to 0 and `vmax` to 1.
+
+    See [`Norm`] for more information.
+    */
+    pub fn new(vmin: f64, vmax: f64) -> Self {
+        Self {
+            vmin,
+            vmax,
+            extend: Extend::Neither,
+            under: None,
+            over: None,
+        }
+    }
+
+    /**
+    Creates a normalization spanning the finite values of the data, or `[0, 1]` if there is
+    none.
+
+    See [`Norm`] for more information.
+    */
+    pub fn autoscale<I: IntoIterator<Item = f64>>(values: I) -> Self {
+        let (vmin, vmax) = values
+            .into_iter()
+            .filter(|v| v.is_finite())
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), v| {
+                (lo.min(v), hi.max(v))
+            });
+        if vmin > vmax {
+            Self::new(0.0, 1.0)
+        } else {
+            Self::new(vmin, vmax)
+        }
+    }
+
+    /// The value mapped to 0.
+    pub fn vmin(&self) -> f64 {
+        self.vmin
+    }
+
+    /// The value mapped to 1.
+    pub fn vmax(&self) -> f64 {
+        self.vmax
+    }
+
+    /**
+    Sets the ends whose out of range values take the under or over color. The under and over
+    colors are the end colors of the colormap unless they are set, see
+    [`Norm::under_color`] and [`Norm::over_color`].
+
+    See [`Norm`] for more information.
+    */
+    pub fn extend(mut self, extend: Extend) -> Self {
+        self.extend = extend;
+        self
+    }
+
+    /**
+    Sets the color of the values below `vmin`, when the lower end is extended.
+
+    See [`Norm`] for more information.
+    */
+    pub fn under_color<C: Color>(mut self, color: &C) -> Self {
+        self.under = Some(color.to_rgba());
+        self
+    }
+
+    /**
+    Sets the color of the values above `vmax`, when the upper end is extended.
+
+    See [`Norm`] for more information.
+    */
+    pub fn over_color<C: Color>(mut self, color: &C) -> Self {
+        self.over = Some(color.to_rgba());
+        self
+    }
+
+    /// The ends of the norm which are extended.
+    pub fn get_extend(&self) -> Extend {
+        self.extend
+    }
+
+    /**
+    Returns the under or over color of a value beyond an extended end, if the color is set,
+    or `None` if the value is to be colored through the colormap.
+
+    See [`Norm`] for more information.
+    */
+    pub fn extended_color(&self, value: f64) -> Option<RGBAColor> {
+        if value < self.vmin && self.extend.has_min() {
+            self.under
+        } else if value > self.vmax && self.extend.has_max() {
+            self.over
+        } else {
+            None
+        }
+    }
+
+    /**
+    Returns the color of a value, `colormap.get_color(norm.normalize(value))` unless the value
+    takes the under or over color, see [`Norm::extend`].
+
+    See [`Norm`] for more information.
+    */
+    #[cfg(feature = "colormaps")]
+    pub fn colorize<C: Color, M: ColorMap<C, f64>>(&self, colormap: &M, value: f64) -> RGBAColor {
+        self.extended_color(value)
+            .unwrap_or_else(|| colormap.get_color(self.normalize(value)).to_rgba())
+    }
+
+    /**
+    Maps a value into `[0, 1]`. If `vmin == vmax`, every value is mapped to 0.
+
+    See [`Norm`] for more information.
+    */
+    pub fn normalize(&self, value: f64) -> f64 {
+        let span = self.vmax - self.vmin;
+        if span == 0.0 {
+            0.0
+        } else {
+            (value - self.vmin) / span
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_norm() {
+        let norm = Norm::new(10.0, 20.0);
+        assert_eq!(norm.normalize(10.0), 0.0);
+        assert_eq!(norm.normalize(15.0), 0.5);
+        assert_eq!(norm.normalize(30.0), 2.0);
+
+        let norm = Norm::autoscale(vec![3.0, f64::NAN, -1.0, 7.0]);
+        assert_eq!((norm.vmin(), norm.vmax()), (-1.0, 7.0));
+        assert_eq!(Norm::autoscale(vec![]), Norm::new(0.0, 1.0));
+        assert_eq!(Norm::new(1.0, 1.0).normalize(5.0), 0.0);
+    }
+
+    #[test]
+    fn test_extended_colors() {
+        use crate::style::{BLACK, WHITE};
+        let norm = Norm::new(0.0, 1.0)
+            .extend(Extend::Min)
+            .under_color(&BLACK)
+            .over_color(&WHITE);
+        assert_eq!(norm.extended_color(-0.5), Some(BLACK.to_rgba()));
+        assert_eq!(norm.extended_color(0.5), None);
+        // The upper end is not extended
+        assert_eq!(norm.extended_color(1.5), None);
+        // Without colors, the extended ends take the end colors of the colormap
+        assert_eq!(
+            Norm::new(0.0, 1.0).extend(Extend::Both).extended_color(2.0),
+            None
+        );
+    }
+}
@@ -0,0 +1,94 @@
+use super::{ColorCycle, RGBColor};
+use std::cell::RefCell;
+
+thread_local! {
+    static RC_PARAMS: RefCell<RcParams> = RefCell::new(RcParams::default());
+}
+
+/**
+The default style parameters, like matplotlib's `rcParams`.
+
+The parameters are read when a series or a mesh is created, so the ones created after
+[`RcParams::set`] inherit the new defaults, unless they are overridden explicitly. The
+parameters are kept per thread, like the default cycles.
+
+- `line_width`: The stroke width of the lines whose style has the default width of 1, such
+  as [`crate::series::MatLineSeries`]
+- `marker_size`: The size of the markers shown by
+  [`crate::series::MatLineSeries::auto_marker`], in pixels
+- `font_size`: The size of the mesh labels in pixels, or the size relative to the chart
+  if it is `None`
+- `grid`: Whether the mesh lines of the chart are drawn
+- `color_cycle`: The colors of the default [`ColorCycle`]
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct RcParams {
+    /// The default stroke width of the lines
+    pub line_width: u32,
+    /// The default marker size, in pixels
+    pub marker_size: u32,
+    /// The default label font size, in pixels
+    pub font_size: Option<u32>,
+    /// Whether the grid is drawn by default
+    pub grid: bool,
+    /// The colors of the default color cycle
+    pub color_cycle: Vec<RGBColor>,
+}
+
+impl Default for RcParams {
+    fn default() -> Self {
+        Self {
+            line_width: 1,
+            marker_size: 3,
+            font_size: None,
+            grid: true,
+            color_cycle: ColorCycle::default().colors().to_vec(),
+        }
+    }
+}
+
+impl RcParams {
+    /// Get the current parameters
+    pub fn get() -> RcParams {
+        RC_PARAMS.with(|p| p.borrow().clone())
+    }
+
+    /// Replace the current parameters. The default color cycle starts over from the first
+    /// color of the new cycle.
+    pub fn set(params: RcParams) {
+        ColorCycle::set_default(ColorCycle::new(&params.color_cycle));
+        RC_PARAMS.with(|p| *p.borrow_mut() = params);
+    }
+
+    /// Change some of the current parameters
+    pub fn update<F: FnOnce(&mut RcParams)>(f: F) {
+        let mut params = Self::get();
+        f(&mut params);
+        Self::set(params);
+    }
+
+    /// Restore the default parameters
+    pub fn reset() {
+        Self::set(RcParams::default());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rc_params() {
+        RcParams::update(|p| {
+            p.grid = false;
+            p.color_cycle = vec![RGBColor(1, 2, 3)];
+        });
+        assert!(!RcParams::get().grid);
+        assert_eq!(ColorCycle::next_default(), RGBColor(1, 2, 3));
+        assert_eq!(ColorCycle::next_default(), RGBColor(1, 2, 3));
+
+        RcParams::reset();
+        assert_eq!(RcParams::get(), RcParams::default());
+        assert_eq!(ColorCycle::next_default(), RGBColor(31, 119, 180));
+    }
+}